@@ -9,6 +9,7 @@ use {
     crate::cli::Cli,
     anyhow::Result,
     clap::Parser,
+    csv_storage::CsvStorage,
     futures::executor::block_on,
     gluesql_core::{
         ast::{Expr, SetExpr, Statement, ToSql, Values},
@@ -52,6 +53,7 @@ enum Storage {
     Memory,
     Sled,
     Json,
+    Csv,
 }
 
 pub fn run() -> Result<()> {
@@ -83,6 +85,14 @@ pub fn run() -> Result<()> {
                 args.execute,
             );
         }
+        (Some(path), Some(Storage::Csv), _) => {
+            println!("[csv-storage] connected to {}", path);
+
+            run(
+                CsvStorage::new(path).expect("failed to load csv-storage"),
+                args.execute,
+            );
+        }
         (Some(path), None, Some(dump_path)) => {
             let mut storage = SledStorage::new(path).expect("failed to load sled-storage");
 
@@ -147,11 +157,14 @@ pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()
                     table_name: schema.table_name.clone(),
                     columns: Vec::new(),
                     source: gluesql_core::ast::Query {
+                        with: None,
                         body: SetExpr::Values(Values(exprs_list)),
                         order_by: Vec::new(),
                         limit: None,
                         offset: None,
                     },
+                    on_conflict: None,
+                    returning: Vec::new(),
                 }
                 .to_sql();
 
@@ -105,12 +105,22 @@ impl<'a, W: Write> Print<W> {
         match payload {
             Payload::Create => self.writeln("Table created")?,
             Payload::DropTable => self.writeln("Table dropped")?,
+            Payload::DropView => self.writeln("View dropped")?,
+            Payload::RefreshMaterializedView => self.writeln("Materialized view refreshed")?,
             Payload::DropFunction => self.writeln("Function dropped")?,
+            Payload::Merge(n) => affected(*n, "merged")?,
+            Payload::Truncate => self.writeln("Table truncated")?,
             Payload::AlterTable => self.writeln("Table altered")?,
             Payload::CreateIndex => self.writeln("Index created")?,
             Payload::DropIndex => self.writeln("Index dropped")?,
             Payload::Commit => self.writeln("Commit completed")?,
             Payload::Rollback => self.writeln("Rollback completed")?,
+            Payload::Savepoint => self.writeln("Savepoint created")?,
+            Payload::RollbackToSavepoint => self.writeln("Rollback to savepoint completed")?,
+            Payload::ReleaseSavepoint => self.writeln("Savepoint released")?,
+            Payload::SetTransaction => self.writeln("Isolation level set")?,
+            Payload::Flush => self.writeln("Flush completed")?,
+            Payload::Analyze => self.writeln("Analyze completed")?,
             Payload::StartTransaction => self.writeln("Transaction started")?,
             Payload::Insert(n) => affected(*n, "inserted")?,
             Payload::Delete(n) => affected(*n, "deleted")?,
@@ -385,9 +395,25 @@ mod tests {
         test!(Payload::AlterTable, "Table altered");
         test!(Payload::CreateIndex, "Index created");
         test!(Payload::DropIndex, "Index dropped");
+        test!(Payload::DropView, "View dropped");
+        test!(
+            Payload::RefreshMaterializedView,
+            "Materialized view refreshed"
+        );
         test!(Payload::DropFunction, "Function dropped");
+        test!(Payload::Merge(3), "3 rows merged");
+        test!(Payload::Truncate, "Table truncated");
         test!(Payload::Commit, "Commit completed");
         test!(Payload::Rollback, "Rollback completed");
+        test!(Payload::Savepoint, "Savepoint created");
+        test!(
+            Payload::RollbackToSavepoint,
+            "Rollback to savepoint completed"
+        );
+        test!(Payload::ReleaseSavepoint, "Savepoint released");
+        test!(Payload::SetTransaction, "Isolation level set");
+        test!(Payload::Flush, "Flush completed");
+        test!(Payload::Analyze, "Analyze completed");
         test!(Payload::StartTransaction, "Transaction started");
         test!(Payload::Insert(0), "0 row inserted");
         test!(Payload::Insert(1), "1 row inserted");
@@ -1,9 +1,10 @@
 use {
     serde::{Deserialize, Serialize},
-    strum_macros::Display,
+    std::fmt,
+    strum_macros::AsRefStr,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, AsRefStr)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DataType {
     Boolean,
@@ -31,4 +32,43 @@ pub enum DataType {
     List,
     Decimal,
     Point,
+    /// `DECIMAL(precision, scale)`, appended after the other variants so that the data types
+    /// stored before it was added keep their encoding
+    DecimalPrecision(u32, u32),
+}
+
+/// `COLLATE <collation>` of a text column or expression. Text under `NOCASE` is compared,
+/// sorted and matched by `LIKE` ignoring the letter case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, AsRefStr)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum Collation {
+    Binary,
+    NoCase,
+}
+
+impl fmt::Display for Collation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::DecimalPrecision(precision, scale) => {
+                write!(f, "DECIMAL({precision}, {scale})")
+            }
+            _ => f.write_str(self.as_ref()),
+        }
+    }
+}
+
+impl DataType {
+    /// Precision and scale of `DECIMAL(precision, scale)`, `None` for the other data types
+    pub fn decimal_precision(&self) -> Option<(u32, u32)> {
+        match self {
+            DataType::DecimalPrecision(precision, scale) => Some((*precision, *scale)),
+            _ => None,
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use {
-    super::{DataType, Expr},
+    super::{Collation, DataType, Expr},
     crate::ast::ToSql,
     serde::{Deserialize, Serialize},
 };
@@ -31,6 +31,8 @@ pub struct ColumnDef {
     pub default: Option<Expr>,
     /// `{ PRIMARY KEY | UNIQUE }`
     pub unique: Option<ColumnUniqueOption>,
+    /// `COLLATE <collation>`
+    pub collation: Option<Collation>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -38,6 +40,32 @@ pub struct ColumnUniqueOption {
     pub is_primary: bool,
 }
 
+/// `[ CONSTRAINT <name> ] FOREIGN KEY (<column>) REFERENCES <table> (<column>)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ForeignKey {
+    pub name: String,
+    pub referencing_column_name: String,
+    pub referenced_table_name: String,
+    pub referenced_column_name: String,
+    pub on_delete: ReferentialAction,
+    pub on_update: ReferentialAction,
+}
+
+/// `[ CONSTRAINT <name> ] CHECK (<expr>)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expr: Expr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OperateFunctionArg {
     pub name: String,
@@ -78,13 +106,19 @@ impl ToSql for ColumnDef {
             nullable,
             default,
             unique,
+            collation,
         } = self;
         {
             let nullable = match nullable {
                 true => "NULL",
                 false => "NOT NULL",
             };
-            let column_def = format!(r#""{name}" {data_type} {nullable}"#);
+            let column_def = match collation {
+                Some(collation) => {
+                    format!(r#""{name}" {data_type} COLLATE {collation} {nullable}"#)
+                }
+                None => format!(r#""{name}" {data_type} {nullable}"#),
+            };
             let default = default
                 .as_ref()
                 .map(|expr| format!("DEFAULT {}", expr.to_sql()));
@@ -110,6 +144,48 @@ impl ToSql for ColumnUniqueOption {
     }
 }
 
+impl ToSql for ForeignKey {
+    fn to_sql(&self) -> String {
+        let ForeignKey {
+            name,
+            referencing_column_name,
+            referenced_table_name,
+            referenced_column_name,
+            on_delete,
+            on_update,
+        } = self;
+
+        format!(
+            r#"CONSTRAINT "{name}" FOREIGN KEY ("{referencing_column_name}") REFERENCES "{referenced_table_name}" ("{referenced_column_name}") ON DELETE {} ON UPDATE {}"#,
+            on_delete.to_sql(),
+            on_update.to_sql()
+        )
+    }
+}
+
+impl ToSql for CheckConstraint {
+    fn to_sql(&self) -> String {
+        let CheckConstraint { name, expr } = self;
+
+        match name {
+            Some(name) => format!(r#"CONSTRAINT "{name}" CHECK ({})"#, expr.to_sql()),
+            None => format!("CHECK ({})", expr.to_sql()),
+        }
+    }
+}
+
+impl ToSql for ReferentialAction {
+    fn to_sql(&self) -> String {
+        match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+        }
+        .to_owned()
+    }
+}
+
 impl ToSql for OperateFunctionArg {
     fn to_sql(&self) -> String {
         let OperateFunctionArg {
@@ -128,7 +204,8 @@ impl ToSql for OperateFunctionArg {
 #[cfg(test)]
 mod tests {
     use crate::ast::{
-        AstLiteral, ColumnDef, ColumnUniqueOption, DataType, Expr, OperateFunctionArg, ToSql,
+        AstLiteral, BinaryOperator, CheckConstraint, Collation, ColumnDef, ColumnUniqueOption,
+        DataType, Expr, ForeignKey, OperateFunctionArg, ReferentialAction, ToSql,
     };
 
     #[test]
@@ -141,6 +218,7 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                collation: None,
             }
             .to_sql()
         );
@@ -153,6 +231,20 @@ mod tests {
                 nullable: true,
                 default: None,
                 unique: None,
+                collation: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""name" TEXT COLLATE NOCASE NOT NULL UNIQUE"#,
+            ColumnDef {
+                name: "name".to_owned(),
+                data_type: DataType::Text,
+                nullable: false,
+                default: None,
+                unique: Some(ColumnUniqueOption { is_primary: false }),
+                collation: Some(Collation::NoCase),
             }
             .to_sql()
         );
@@ -165,6 +257,20 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                collation: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""price" DECIMAL(10, 2) NULL"#,
+            ColumnDef {
+                name: "price".to_owned(),
+                data_type: DataType::DecimalPrecision(10, 2),
+                nullable: true,
+                default: None,
+                unique: None,
+                collation: None,
             }
             .to_sql()
         );
@@ -177,6 +283,7 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: None,
+                collation: None,
             }
             .to_sql()
         );
@@ -189,6 +296,63 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                collation: None,
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_foreign_key() {
+        assert_eq!(
+            r#"CONSTRAINT "FK_user_id-User_id" FOREIGN KEY ("user_id") REFERENCES "User" ("id") ON DELETE CASCADE ON UPDATE NO ACTION"#,
+            ForeignKey {
+                name: "FK_user_id-User_id".to_owned(),
+                referencing_column_name: "user_id".to_owned(),
+                referenced_table_name: "User".to_owned(),
+                referenced_column_name: "id".to_owned(),
+                on_delete: ReferentialAction::Cascade,
+                on_update: ReferentialAction::NoAction,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CONSTRAINT "parent" FOREIGN KEY ("parent_id") REFERENCES "Node" ("id") ON DELETE SET NULL ON UPDATE RESTRICT"#,
+            ForeignKey {
+                name: "parent".to_owned(),
+                referencing_column_name: "parent_id".to_owned(),
+                referenced_table_name: "Node".to_owned(),
+                referenced_column_name: "id".to_owned(),
+                on_delete: ReferentialAction::SetNull,
+                on_update: ReferentialAction::Restrict,
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_check_constraint() {
+        let expr = Expr::BinaryOp {
+            left: Box::new(Expr::Identifier("price".to_owned())),
+            op: BinaryOperator::Gt,
+            right: Box::new(Expr::Identifier("cost".to_owned())),
+        };
+
+        assert_eq!(
+            r#"CHECK ("price" > "cost")"#,
+            CheckConstraint {
+                name: None,
+                expr: expr.clone(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CONSTRAINT "profitable" CHECK ("price" > "cost")"#,
+            CheckConstraint {
+                name: Some("profitable".to_owned()),
+                expr,
             }
             .to_sql()
         );
@@ -1,11 +1,13 @@
 use {
     super::{
-        Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, Query, ToSql,
-        ToSqlUnquoted, UnaryOperator,
+        Aggregate, AstLiteral, BinaryOperator, Collation, DataType, DateTimeField, Function, Query,
+        ToSql, ToSqlUnquoted, UnaryOperator,
     },
     serde::{Deserialize, Serialize},
 };
 
+/// Expressions are stored in schemas by `DEFAULT` and indexes, so new variants are appended
+/// after the others to keep the encoding of the stored expressions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Expr {
     Identifier(String),
@@ -77,6 +79,23 @@ pub enum Expr {
         leading_field: Option<DateTimeField>,
         last_field: Option<DateTimeField>,
     },
+    Collate {
+        expr: Box<Expr>,
+        collation: Collation,
+    },
+    AnySubquery {
+        expr: Box<Expr>,
+        op: BinaryOperator,
+        subquery: Box<Query>,
+    },
+    AllSubquery {
+        expr: Box<Expr>,
+        op: BinaryOperator,
+        subquery: Box<Query>,
+    },
+    Array {
+        elem: Vec<Expr>,
+    },
 }
 
 impl ToSql for Expr {
@@ -226,6 +245,18 @@ impl Expr {
                 ),
                 false => format!("{} IN ({})", expr.to_sql_with(quoted), subquery.to_sql()),
             },
+            Expr::AnySubquery { expr, op, subquery } => format!(
+                "{} {} ANY({})",
+                expr.to_sql_with(quoted),
+                op.to_sql(),
+                subquery.to_sql()
+            ),
+            Expr::AllSubquery { expr, op, subquery } => format!(
+                "{} {} ALL({})",
+                expr.to_sql_with(quoted),
+                op.to_sql(),
+                subquery.to_sql()
+            ),
             Expr::Exists { subquery, negated } => match negated {
                 true => format!("NOT EXISTS({})", subquery.to_sql()),
                 false => format!("EXISTS({})", subquery.to_sql()),
@@ -239,7 +270,19 @@ impl Expr {
                     .join("");
                 format!("{obj}{indexes}")
             }
+            Expr::Array { elem } => {
+                let elem = elem
+                    .iter()
+                    .map(|expr| expr.to_sql_with(quoted))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("ARRAY[{elem}]")
+            }
             Expr::Subquery(query) => format!("({})", query.to_sql()),
+            Expr::Collate { expr, collation } => {
+                format!("{} COLLATE {collation}", expr.to_sql_with(quoted))
+            }
             Expr::Interval {
                 expr,
                 leading_field,
@@ -265,8 +308,8 @@ mod tests {
 
     use {
         crate::ast::{
-            AstLiteral, BinaryOperator, DataType, DateTimeField, Expr, Query, Select, SelectItem,
-            SetExpr, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, UnaryOperator,
+            AstLiteral, BinaryOperator, Collation, DataType, DateTimeField, Expr, Query, Select,
+            SelectItem, SetExpr, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, UnaryOperator,
         },
         bigdecimal::BigDecimal,
         regex::Regex,
@@ -431,7 +474,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -459,7 +504,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -482,11 +529,43 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#""id" > ALL(SELECT * FROM "FOO")"#,
+            Expr::AllSubquery {
+                expr: Box::new(Expr::Identifier("id".to_owned())),
+                op: BinaryOperator::Gt,
+                subquery: Box::new(Query {
+                    with: None,
+                    body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
+                        projection: vec![SelectItem::Wildcard],
+                        from: TableWithJoins {
+                            relation: TableFactor::Table {
+                                name: "FOO".to_owned(),
+                                alias: None,
+                                index: None,
+                            },
+                            joins: Vec::new(),
+                        },
+                        selection: None,
+                        group_by: Vec::new(),
+                        having: None,
+                    })),
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                }),
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             r#"EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -513,7 +592,9 @@ mod tests {
             r#"NOT EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -539,7 +620,9 @@ mod tests {
         assert_eq!(
             r#"(SELECT * FROM "FOO")"#,
             Expr::Subquery(Box::new(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -659,6 +742,26 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"ARRAY["tag", 'a']"#,
+            Expr::Array {
+                elem: vec![
+                    Expr::Identifier("tag".to_owned()),
+                    Expr::Literal(AstLiteral::QuotedString("a".to_owned()))
+                ]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""name" COLLATE NOCASE"#,
+            Expr::Collate {
+                expr: Box::new(Expr::Identifier("name".to_owned())),
+                collation: Collation::NoCase,
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             r#"INTERVAL "col1" + 3 DAY"#,
             &Expr::Interval {
@@ -1,10 +1,12 @@
 use {
-    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr},
+    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr, OrderByExpr},
     crate::ast::ToSql,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
 };
 
+/// Functions are stored in schemas as a part of expressions, so new variants are appended
+/// after the others to keep the encoding of the stored expressions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum Function {
@@ -204,6 +206,46 @@ pub enum Function {
         end_index: Expr,
         values: Option<Expr>,
     },
+    Encode {
+        expr: Expr,
+        format: Expr,
+    },
+    Decode {
+        expr: Expr,
+        format: Expr,
+    },
+    CurrentDate(),
+    DateTrunc {
+        field: Expr,
+        expr: Expr,
+    },
+    NullIf {
+        expr: Expr,
+        value: Expr,
+    },
+    Least(Vec<Expr>),
+    RegexpMatches {
+        expr: Expr,
+        pattern: Expr,
+        flags: Option<Expr>,
+    },
+    RegexpReplace {
+        expr: Expr,
+        pattern: Expr,
+        replacement: Expr,
+        flags: Option<Expr>,
+    },
+    Sha256(Expr),
+    Hex(Expr),
+    Base64(Expr),
+    JsonExtract {
+        expr: Expr,
+        path: Expr,
+    },
+    JsonValue {
+        expr: Expr,
+        path: Expr,
+    },
 }
 
 impl ToSql for Function {
@@ -277,6 +319,9 @@ impl ToSql for Function {
             Function::IfNull { expr, then } => {
                 format!("IFNULL({}, {})", expr.to_sql(), then.to_sql())
             }
+            Function::NullIf { expr, value } => {
+                format!("NULLIF({}, {})", expr.to_sql(), value.to_sql())
+            }
             Function::Rand(e) => match e {
                 Some(v) => format!("RAND({})", v.to_sql()),
                 None => "RAND()".to_owned(),
@@ -328,8 +373,12 @@ impl ToSql for Function {
             Function::Radians(e) => format!("RADIANS({})", e.to_sql()),
             Function::Degrees(e) => format!("DEGREES({})", e.to_sql()),
             Function::Now() => "NOW()".to_owned(),
+            Function::CurrentDate() => "CURRENT_DATE".to_owned(),
             Function::Pi() => "PI()".to_owned(),
             Function::LastDay(expr) => format!("LAST_DAY({})", expr.to_sql()),
+            Function::DateTrunc { field, expr } => {
+                format!("DATE_TRUNC({}, {})", field.to_sql(), expr.to_sql())
+            }
             Function::Ltrim { expr, chars } => match chars {
                 None => format!("LTRIM({})", expr.to_sql()),
                 Some(chars) => format!("LTRIM({}, {})", expr.to_sql(), chars.to_sql()),
@@ -362,6 +411,45 @@ impl ToSql for Function {
             Function::Unwrap { expr, selector } => {
                 format!("UNWRAP({}, {})", expr.to_sql(), selector.to_sql())
             }
+            Function::JsonExtract { expr, path } => {
+                format!("JSON_EXTRACT({}, {})", expr.to_sql(), path.to_sql())
+            }
+            Function::JsonValue { expr, path } => {
+                format!("JSON_VALUE({}, {})", expr.to_sql(), path.to_sql())
+            }
+            Function::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            } => match flags {
+                None => format!("REGEXP_MATCHES({}, {})", expr.to_sql(), pattern.to_sql()),
+                Some(flags) => format!(
+                    "REGEXP_MATCHES({}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    flags.to_sql()
+                ),
+            },
+            Function::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            } => match flags {
+                None => format!(
+                    "REGEXP_REPLACE({}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    replacement.to_sql()
+                ),
+                Some(flags) => format!(
+                    "REGEXP_REPLACE({}, {}, {}, {})",
+                    expr.to_sql(),
+                    pattern.to_sql(),
+                    replacement.to_sql(),
+                    flags.to_sql()
+                ),
+            },
             Function::GenerateUuid() => "GENERATE_UUID()".to_owned(),
             Function::Greatest(items) => {
                 let items = items
@@ -371,6 +459,14 @@ impl ToSql for Function {
                     .join(", ");
                 format!("GREATEST({})", items)
             }
+            Function::Least(items) => {
+                let items = items
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("LEAST({})", items)
+            }
             Function::Format { expr, format } => {
                 format!("FORMAT({}, {})", expr.to_sql(), format.to_sql())
             }
@@ -406,6 +502,15 @@ impl ToSql for Function {
             Function::Ascii(e) => format!("ASCII({})", e.to_sql()),
             Function::Chr(e) => format!("CHR({})", e.to_sql()),
             Function::Md5(e) => format!("MD5({})", e.to_sql()),
+            Function::Sha256(e) => format!("SHA256({})", e.to_sql()),
+            Function::Hex(e) => format!("HEX({})", e.to_sql()),
+            Function::Base64(e) => format!("BASE64({})", e.to_sql()),
+            Function::Encode { expr, format } => {
+                format!("ENCODE({}, {})", expr.to_sql(), format.to_sql())
+            }
+            Function::Decode { expr, format } => {
+                format!("DECODE({}, {})", expr.to_sql(), format.to_sql())
+            }
             Function::Append { expr, value } => {
                 format!(
                     "APPEND({items}, {value})",
@@ -486,7 +591,23 @@ impl ToSql for Function {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Aggregate {
+pub struct Aggregate {
+    pub func: AggregateFunction,
+    /// `COUNT(DISTINCT ...)`, `SUM(DISTINCT ...)`, ...
+    pub distinct: bool,
+    /// `OVER (...)` of window functions, which are computed for every row instead of
+    /// collapsing the rows of a group
+    pub over: Option<WindowSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WindowSpec {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AggregateFunction {
     Count(CountArgExpr),
     Sum(Expr),
     Max(Expr),
@@ -494,22 +615,125 @@ pub enum Aggregate {
     Avg(Expr),
     Variance(Expr),
     Stdev(Expr),
+    /// Functions below are only available as window functions
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    Lead {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+}
+
+impl Aggregate {
+    pub fn new(func: AggregateFunction) -> Self {
+        Self {
+            func,
+            distinct: false,
+            over: None,
+        }
+    }
+
+    pub fn distinct(func: AggregateFunction) -> Self {
+        Self {
+            func,
+            distinct: true,
+            over: None,
+        }
+    }
+
+    pub fn over(func: AggregateFunction, over: WindowSpec) -> Self {
+        Self {
+            func,
+            distinct: false,
+            over: Some(over),
+        }
+    }
 }
 
 impl ToSql for Aggregate {
     fn to_sql(&self) -> String {
-        match self {
-            Aggregate::Count(cae) => format!("COUNT({})", cae.to_sql()),
-            Aggregate::Sum(e) => format!("SUM({})", e.to_sql()),
-            Aggregate::Max(e) => format!("MAX({})", e.to_sql()),
-            Aggregate::Min(e) => format!("MIN({})", e.to_sql()),
-            Aggregate::Avg(e) => format!("AVG({})", e.to_sql()),
-            Aggregate::Variance(e) => format!("VARIANCE({})", e.to_sql()),
-            Aggregate::Stdev(e) => format!("STDEV({})", e.to_sql()),
+        let (name, arg) = match &self.func {
+            AggregateFunction::Count(cae) => ("COUNT", cae.to_sql()),
+            AggregateFunction::Sum(e) => ("SUM", e.to_sql()),
+            AggregateFunction::Max(e) => ("MAX", e.to_sql()),
+            AggregateFunction::Min(e) => ("MIN", e.to_sql()),
+            AggregateFunction::Avg(e) => ("AVG", e.to_sql()),
+            AggregateFunction::Variance(e) => ("VARIANCE", e.to_sql()),
+            AggregateFunction::Stdev(e) => ("STDEV", e.to_sql()),
+            AggregateFunction::RowNumber => ("ROW_NUMBER", String::new()),
+            AggregateFunction::Rank => ("RANK", String::new()),
+            AggregateFunction::DenseRank => ("DENSE_RANK", String::new()),
+            AggregateFunction::Lag {
+                expr,
+                offset,
+                default,
+            } => ("LAG", offset_args(expr, offset, default)),
+            AggregateFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => ("LEAD", offset_args(expr, offset, default)),
+        };
+
+        let func = match self.distinct {
+            true => format!("{name}(DISTINCT {arg})"),
+            false => format!("{name}({arg})"),
+        };
+
+        match &self.over {
+            Some(over) => format!("{func} OVER ({})", over.to_sql()),
+            None => func,
         }
     }
 }
 
+fn offset_args(expr: &Expr, offset: &Option<Expr>, default: &Option<Expr>) -> String {
+    std::iter::once(expr)
+        .chain(offset)
+        .chain(default)
+        .map(ToSql::to_sql)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl ToSql for WindowSpec {
+    fn to_sql(&self) -> String {
+        let partition_by = (!self.partition_by.is_empty()).then(|| {
+            let exprs = self
+                .partition_by
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("PARTITION BY {exprs}")
+        });
+        let order_by = (!self.order_by.is_empty()).then(|| {
+            let exprs = self
+                .order_by
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("ORDER BY {exprs}")
+        });
+
+        partition_by
+            .into_iter()
+            .chain(order_by)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CountArgExpr {
     Expr(Expr),
@@ -529,8 +753,8 @@ impl ToSql for CountArgExpr {
 mod tests {
     use {
         crate::ast::{
-            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function, ToSql,
-            TrimWhereField,
+            Aggregate, AggregateFunction, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr,
+            Function, OrderByExpr, ToSql, TrimWhereField, WindowSpec,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -743,6 +967,14 @@ mod tests {
             }))
             .to_sql()
         );
+        assert_eq!(
+            r#"NULLIF("score", 0)"#,
+            &Expr::Function(Box::new(Function::NullIf {
+                expr: Expr::Identifier("score".to_owned()),
+                value: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("0").unwrap()))
+            }))
+            .to_sql()
+        );
 
         assert_eq!(
             "RAND()",
@@ -956,6 +1188,11 @@ mod tests {
 
         assert_eq!("NOW()", &Expr::Function(Box::new(Function::Now())).to_sql());
 
+        assert_eq!(
+            "CURRENT_DATE",
+            &Expr::Function(Box::new(Function::CurrentDate())).to_sql()
+        );
+
         assert_eq!("PI()", &Expr::Function(Box::new(Function::Pi())).to_sql());
 
         assert_eq!(
@@ -1065,6 +1302,15 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "LEAST(16, 9)",
+            &Expr::Function(Box::new(Function::Least(vec![
+                Expr::Literal(AstLiteral::Number(BigDecimal::from_str("16").unwrap())),
+                Expr::Literal(AstLiteral::Number(BigDecimal::from_str("9").unwrap()))
+            ])))
+            .to_sql()
+        );
+
         assert_eq!(
             "FORMAT(DATE '2022-10-12', '%Y-%m')",
             &Expr::Function(Box::new(Function::Format {
@@ -1077,6 +1323,45 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"JSON_EXTRACT("doc", '$.items[0]')"#,
+            &Expr::Function(Box::new(Function::JsonExtract {
+                expr: Expr::Identifier("doc".to_owned()),
+                path: Expr::Literal(AstLiteral::QuotedString("$.items[0]".to_owned()))
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"JSON_VALUE("doc", '$.name')"#,
+            &Expr::Function(Box::new(Function::JsonValue {
+                expr: Expr::Identifier("doc".to_owned()),
+                path: Expr::Literal(AstLiteral::QuotedString("$.name".to_owned()))
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REGEXP_MATCHES("line", '(\d+) ms')"#,
+            &Expr::Function(Box::new(Function::RegexpMatches {
+                expr: Expr::Identifier("line".to_owned()),
+                pattern: Expr::Literal(AstLiteral::QuotedString("(\\d+) ms".to_owned())),
+                flags: None
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REGEXP_REPLACE("line", 'a+', 'b', 'gi')"#,
+            &Expr::Function(Box::new(Function::RegexpReplace {
+                expr: Expr::Identifier("line".to_owned()),
+                pattern: Expr::Literal(AstLiteral::QuotedString("a+".to_owned())),
+                replacement: Expr::Literal(AstLiteral::QuotedString("b".to_owned())),
+                flags: Some(Expr::Literal(AstLiteral::QuotedString("gi".to_owned())))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "LAST_DAY(DATE '2022-10-12')",
             &Expr::Function(Box::new(Function::LastDay(Expr::TypedString {
@@ -1086,6 +1371,18 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "DATE_TRUNC('month', DATE '2022-10-12')",
+            &Expr::Function(Box::new(Function::DateTrunc {
+                field: Expr::Literal(AstLiteral::QuotedString("month".to_owned())),
+                expr: Expr::TypedString {
+                    data_type: DataType::Date,
+                    value: "2022-10-12".to_owned()
+                }
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "TO_DATE('2022-10-12', '%Y-%m-%d')",
             &Expr::Function(Box::new(Function::ToDate {
@@ -1168,6 +1465,45 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "SHA256('GlueSQL')",
+            &Expr::Function(Box::new(Function::Sha256(Expr::Literal(
+                AstLiteral::QuotedString("GlueSQL".to_owned())
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"HEX("data")"#,
+            &Expr::Function(Box::new(Function::Hex(Expr::Identifier("data".to_owned())))).to_sql()
+        );
+
+        assert_eq!(
+            r#"BASE64("data")"#,
+            &Expr::Function(Box::new(Function::Base64(Expr::Identifier(
+                "data".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "ENCODE(\"data\", 'base64')",
+            &Expr::Function(Box::new(Function::Encode {
+                expr: Expr::Identifier("data".to_owned()),
+                format: Expr::Literal(AstLiteral::QuotedString("base64".to_owned()))
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "DECODE('R2x1ZQ==', 'base64')",
+            &Expr::Function(Box::new(Function::Decode {
+                expr: Expr::Literal(AstLiteral::QuotedString("R2x1ZQ==".to_owned())),
+                format: Expr::Literal(AstLiteral::QuotedString("base64".to_owned()))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"EXTRACT(MINUTE FROM '2022-05-05 01:02:03')"#,
             &Expr::Function(Box::new(Function::Extract {
@@ -1338,46 +1674,136 @@ mod tests {
 
     #[test]
     fn to_sql_aggregate() {
+        let aggregate = |func| Expr::Aggregate(Box::new(Aggregate::new(func)));
+        let id = || Expr::Identifier("id".to_owned());
+
         assert_eq!(
             r#"MAX("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Max(Expr::Identifier("id".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Max(id())).to_sql()
         );
 
         assert_eq!(
             "COUNT(*)",
-            Expr::Aggregate(Box::new(Aggregate::Count(CountArgExpr::Wildcard))).to_sql()
+            aggregate(AggregateFunction::Count(CountArgExpr::Wildcard)).to_sql()
         );
 
         assert_eq!(
             r#"MIN("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Min(Expr::Identifier("id".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Min(id())).to_sql()
         );
 
         assert_eq!(
             r#"SUM("price")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Sum(Expr::Identifier(
-                "price".to_owned()
-            ))))
-            .to_sql()
+            aggregate(AggregateFunction::Sum(Expr::Identifier("price".to_owned()))).to_sql()
         );
 
         assert_eq!(
             r#"AVG("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Avg(Expr::Identifier("pay".to_owned())))).to_sql()
+            aggregate(AggregateFunction::Avg(Expr::Identifier("pay".to_owned()))).to_sql()
         );
         assert_eq!(
             r#"VARIANCE("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Variance(Expr::Identifier(
+            aggregate(AggregateFunction::Variance(Expr::Identifier(
                 "pay".to_owned()
-            ))))
+            )))
             .to_sql()
         );
         assert_eq!(
             r#"STDEV("total")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Stdev(Expr::Identifier(
+            aggregate(AggregateFunction::Stdev(Expr::Identifier(
                 "total".to_owned()
+            )))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"COUNT(DISTINCT "id")"#,
+            Expr::Aggregate(Box::new(Aggregate::distinct(AggregateFunction::Count(
+                CountArgExpr::Expr(id())
             ))))
             .to_sql()
         );
+        assert_eq!(
+            r#"SUM(DISTINCT "id")"#,
+            Expr::Aggregate(Box::new(Aggregate::distinct(AggregateFunction::Sum(id())))).to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_window() {
+        let id = || Expr::Identifier("id".to_owned());
+        let window = |func, partition_by, order_by| {
+            Expr::Aggregate(Box::new(Aggregate::over(
+                func,
+                WindowSpec {
+                    partition_by,
+                    order_by,
+                },
+            )))
+        };
+        let order_by = || {
+            vec![OrderByExpr {
+                expr: id(),
+                asc: Some(false),
+                nulls_first: None,
+            }]
+        };
+
+        assert_eq!(
+            "ROW_NUMBER() OVER ()",
+            window(AggregateFunction::RowNumber, vec![], vec![]).to_sql()
+        );
+        assert_eq!(
+            r#"RANK() OVER (PARTITION BY "team" ORDER BY "id" DESC)"#,
+            window(
+                AggregateFunction::Rank,
+                vec![Expr::Identifier("team".to_owned())],
+                order_by()
+            )
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DENSE_RANK() OVER (ORDER BY "id" DESC)"#,
+            window(AggregateFunction::DenseRank, vec![], order_by()).to_sql()
+        );
+        assert_eq!(
+            r#"LAG("id") OVER (ORDER BY "id" DESC)"#,
+            window(
+                AggregateFunction::Lag {
+                    expr: id(),
+                    offset: None,
+                    default: None
+                },
+                vec![],
+                order_by()
+            )
+            .to_sql()
+        );
+        assert_eq!(
+            r#"LEAD("id", 2, 0) OVER (ORDER BY "id" DESC)"#,
+            window(
+                AggregateFunction::Lead {
+                    expr: id(),
+                    offset: Some(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("2").unwrap()
+                    ))),
+                    default: Some(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("0").unwrap()
+                    )))
+                },
+                vec![],
+                order_by()
+            )
+            .to_sql()
+        );
+        assert_eq!(
+            r#"SUM("id") OVER (PARTITION BY "team")"#,
+            window(
+                AggregateFunction::Sum(id()),
+                vec![Expr::Identifier("team".to_owned())],
+                vec![]
+            )
+            .to_sql()
+        );
     }
 }
@@ -8,10 +8,10 @@ mod query;
 
 pub use {
     ast_literal::{AstLiteral, DateTimeField, TrimWhereField},
-    data_type::DataType,
+    data_type::{Collation, DataType},
     ddl::*,
     expr::Expr,
-    function::{Aggregate, CountArgExpr, Function},
+    function::{Aggregate, AggregateFunction, CountArgExpr, Function, WindowSpec},
     operator::*,
     query::*,
 };
@@ -33,6 +33,13 @@ pub enum Statement {
     },
     /// SELECT, VALUES
     Query(Query),
+    /// SELECT .. FOR UPDATE [NOWAIT]
+    QueryForUpdate {
+        query: Query,
+        nowait: bool,
+    },
+    /// EXPLAIN SELECT ..
+    Explain(Query),
     /// INSERT
     Insert {
         /// TABLE
@@ -41,6 +48,10 @@ pub enum Statement {
         columns: Vec<String>,
         /// A SQL query that specifies what to insert
         source: Query,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// UPDATE
     Update {
@@ -50,6 +61,12 @@ pub enum Statement {
         assignments: Vec<Assignment>,
         /// WHERE
         selection: Option<Expr>,
+        /// ORDER BY
+        order_by: Vec<OrderByExpr>,
+        /// LIMIT
+        limit: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// DELETE
     Delete {
@@ -57,16 +74,55 @@ pub enum Statement {
         table_name: String,
         /// WHERE
         selection: Option<Expr>,
+        /// ORDER BY
+        order_by: Vec<OrderByExpr>,
+        /// LIMIT
+        limit: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
+    },
+    /// MERGE
+    Merge {
+        /// INTO
+        table: TableFactor,
+        /// USING
+        source: TableFactor,
+        /// ON
+        on: Expr,
+        /// WHEN [NOT] MATCHED
+        clauses: Vec<MergeClause>,
     },
     /// CREATE TABLE
     CreateTable {
         if_not_exists: bool,
+        /// `CREATE TEMPORARY TABLE`, kept by `Glue` only for its own lifetime
+        temporary: bool,
         /// Table name
         name: String,
         /// Optional schema
         columns: Option<Vec<ColumnDef>>,
         source: Option<Box<Query>>,
         engine: Option<String>,
+        foreign_keys: Vec<ForeignKey>,
+        checks: Vec<CheckConstraint>,
+    },
+    /// CREATE VIEW
+    CreateView {
+        or_replace: bool,
+        materialized: bool,
+        name: String,
+        query: Box<Query>,
+    },
+    /// REFRESH MATERIALIZED VIEW
+    RefreshMaterializedView {
+        name: String,
+    },
+    /// DROP VIEW
+    DropView {
+        /// An optional `IF EXISTS` clause. (Non-standard.)
+        if_exists: bool,
+        /// One or more views to drop.
+        names: Vec<String>,
     },
     /// CREATE FUNCTION
     CreateFunction {
@@ -74,6 +130,8 @@ pub enum Statement {
         name: String,
         /// Optional schema
         args: Vec<OperateFunctionArg>,
+        /// Type the result is cast to, `RETURNS` clause
+        return_type: Option<DataType>,
         return_: Expr,
     },
     /// ALTER TABLE
@@ -89,6 +147,10 @@ pub enum Statement {
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<String>,
     },
+    /// TRUNCATE TABLE
+    Truncate {
+        table_name: String,
+    },
     /// DROP FUNCTION
     DropFunction {
         /// An optional `IF EXISTS` clause. (Non-standard.)
@@ -113,6 +175,28 @@ pub enum Statement {
     Commit,
     /// ROLLBACK
     Rollback,
+    /// SAVEPOINT
+    Savepoint {
+        name: String,
+    },
+    /// ROLLBACK TO SAVEPOINT
+    RollbackToSavepoint {
+        name: String,
+    },
+    /// RELEASE SAVEPOINT
+    ReleaseSavepoint {
+        name: String,
+    },
+    /// SET TRANSACTION ISOLATION LEVEL
+    SetTransaction {
+        isolation_level: IsolationLevel,
+    },
+    /// FLUSH
+    Flush,
+    /// ANALYZE, every table when `table_name` is not given
+    Analyze {
+        table_name: Option<String>,
+    },
     /// SHOW VARIABLE
     ShowVariable(Variable),
     ShowIndexes(String),
@@ -124,6 +208,49 @@ pub struct Assignment {
     pub value: Expr,
 }
 
+/// `ON CONFLICT [(column, ..)] DO NOTHING | DO UPDATE SET ..` of `INSERT`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OnConflict {
+    /// Conflict target, empty when omitted
+    pub columns: Vec<String>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        selection: Option<Expr>,
+    },
+}
+
+/// `WHEN [NOT] MATCHED [AND predicate] THEN ..` of `MERGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MergeClause {
+    MatchedUpdate {
+        predicate: Option<Expr>,
+        assignments: Vec<Assignment>,
+    },
+    MatchedDelete {
+        predicate: Option<Expr>,
+    },
+    NotMatched {
+        predicate: Option<Expr>,
+        /// Target columns, empty when omitted
+        columns: Vec<String>,
+        values: Vec<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
@@ -131,6 +258,28 @@ pub enum Variable {
     Version,
 }
 
+impl Statement {
+    /// Whether the statement leaves both data and schema of the storage as they are
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            Statement::ShowColumns { .. }
+                | Statement::Query(_)
+                | Statement::Explain(_)
+                | Statement::StartTransaction
+                | Statement::Commit
+                | Statement::Rollback
+                | Statement::Savepoint { .. }
+                | Statement::RollbackToSavepoint { .. }
+                | Statement::ReleaseSavepoint { .. }
+                | Statement::SetTransaction { .. }
+                | Statement::Flush
+                | Statement::ShowVariable(_)
+                | Statement::ShowIndexes(_)
+        )
+    }
+}
+
 impl ToSql for Statement {
     fn to_sql(&self) -> String {
         match self {
@@ -141,47 +290,94 @@ impl ToSql for Statement {
                 table_name,
                 columns,
                 source,
+                on_conflict,
+                returning,
             } => {
                 let columns = match columns.is_empty() {
                     true => "".to_owned(),
                     false => format!("({}) ", columns.join(", ")),
                 };
+                let on_conflict = match on_conflict {
+                    Some(on_conflict) => format!(" {}", on_conflict.to_sql()),
+                    None => "".to_owned(),
+                };
 
-                format!("INSERT INTO {table_name} {columns}{};", source.to_sql())
+                format!(
+                    "INSERT INTO {table_name} {columns}{}{on_conflict}{};",
+                    source.to_sql(),
+                    returning_to_sql(returning)
+                )
             }
             Statement::Update {
                 table_name,
                 assignments,
                 selection,
+                order_by,
+                limit,
+                returning,
             } => {
                 let assignments = assignments
                     .iter()
                     .map(ToSql::to_sql)
                     .collect::<Vec<_>>()
                     .join(", ");
-                match selection {
-                    Some(expr) => {
-                        format!(
-                            r#"UPDATE "{table_name}" SET {assignments} WHERE {};"#,
-                            expr.to_sql()
-                        )
-                    }
-                    None => format!(r#"UPDATE "{table_name}" SET {assignments};"#),
-                }
+                let selection = match selection {
+                    Some(expr) => format!(" WHERE {}", expr.to_sql()),
+                    None => "".to_owned(),
+                };
+
+                format!(
+                    r#"UPDATE "{table_name}" SET {assignments}{selection}{}{};"#,
+                    order_by_limit_to_sql(order_by, limit),
+                    returning_to_sql(returning)
+                )
             }
             Statement::Delete {
                 table_name,
                 selection,
-            } => match selection {
-                Some(expr) => format!(r#"DELETE FROM "{table_name}" WHERE {};"#, expr.to_sql()),
-                None => format!(r#"DELETE FROM "{table_name}";"#),
-            },
+                order_by,
+                limit,
+                returning,
+            } => {
+                let selection = match selection {
+                    Some(expr) => format!(" WHERE {}", expr.to_sql()),
+                    None => "".to_owned(),
+                };
+
+                format!(
+                    r#"DELETE FROM "{table_name}"{selection}{}{};"#,
+                    order_by_limit_to_sql(order_by, limit),
+                    returning_to_sql(returning)
+                )
+            }
+            Statement::Merge {
+                table,
+                source,
+                on,
+                clauses,
+            } => {
+                let clauses = clauses
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                format!(
+                    "MERGE INTO {} USING {} ON {} {clauses};",
+                    table.to_sql(),
+                    source.to_sql(),
+                    on.to_sql()
+                )
+            }
             Statement::CreateTable {
                 if_not_exists,
+                temporary,
                 name,
                 columns,
                 source,
                 engine,
+                foreign_keys,
+                checks,
             } => {
                 let if_not_exists = if_not_exists.then_some("IF NOT EXISTS");
                 let body = match source {
@@ -189,22 +385,23 @@ impl ToSql for Statement {
                     None if columns.is_none() => None,
                     None => {
                         let columns = columns
-                            .as_ref()
-                            .map(|columns| {
-                                columns
-                                    .iter()
-                                    .map(ToSql::to_sql)
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            })
-                            .unwrap_or_else(|| "".to_owned());
+                            .iter()
+                            .flatten()
+                            .map(ToSql::to_sql)
+                            .chain(foreign_keys.iter().map(ToSql::to_sql))
+                            .chain(checks.iter().map(ToSql::to_sql))
+                            .collect::<Vec<_>>()
+                            .join(", ");
 
                         Some(format!("({columns})"))
                     }
                 };
                 let engine = engine.as_ref().map(|engine| format!("ENGINE = {engine}"));
                 let sql = vec![
-                    Some("CREATE TABLE"),
+                    Some(match temporary {
+                        true => "CREATE TEMPORARY TABLE",
+                        false => "CREATE TABLE",
+                    }),
                     if_not_exists,
                     Some(&format! {r#""{name}""#}),
                     body.as_deref(),
@@ -217,12 +414,40 @@ impl ToSql for Statement {
 
                 format!("{sql};")
             }
+            Statement::CreateView {
+                or_replace,
+                materialized,
+                name,
+                query,
+            } => {
+                let or_replace = or_replace.then_some(" OR REPLACE").unwrap_or("");
+                let materialized = materialized.then_some(" MATERIALIZED").unwrap_or("");
+
+                format!(
+                    r#"CREATE{or_replace}{materialized} VIEW "{name}" AS {};"#,
+                    query.to_sql()
+                )
+            }
+            Statement::RefreshMaterializedView { name } => {
+                format!(r#"REFRESH MATERIALIZED VIEW "{name}";"#)
+            }
+            Statement::DropView { if_exists, names } => {
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match if_exists {
+                    true => format!("DROP VIEW IF EXISTS {};", names),
+                    false => format!("DROP VIEW {};", names),
+                }
+            }
             Statement::CreateFunction {
                 or_replace,
                 name,
                 args,
+                return_type,
                 return_,
-                ..
             } => {
                 let or_replace = or_replace.then_some(" OR REPLACE").unwrap_or("");
                 let args = args
@@ -230,8 +455,12 @@ impl ToSql for Statement {
                     .map(ToSql::to_sql)
                     .collect::<Vec<_>>()
                     .join(", ");
+                let return_type = return_type
+                    .as_ref()
+                    .map(|data_type| format!(" RETURNS {data_type}"))
+                    .unwrap_or_default();
                 let return_ = format!(" RETURN {}", return_.to_sql());
-                format!("CREATE{or_replace} FUNCTION {name}({args}){return_};")
+                format!("CREATE{or_replace} FUNCTION {name}({args}){return_type}{return_};")
             }
             Statement::AlterTable { name, operation } => {
                 format!(r#"ALTER TABLE "{name}" {};"#, operation.to_sql())
@@ -247,6 +476,7 @@ impl ToSql for Statement {
                     false => format!("DROP TABLE {};", names),
                 }
             }
+            Statement::Truncate { table_name } => format!(r#"TRUNCATE TABLE "{table_name}";"#),
             Statement::DropFunction { if_exists, names } => {
                 let names = names.join(", ");
                 match if_exists {
@@ -270,6 +500,22 @@ impl ToSql for Statement {
             Statement::StartTransaction => "START TRANSACTION;".to_owned(),
             Statement::Commit => "COMMIT;".to_owned(),
             Statement::Rollback => "ROLLBACK;".to_owned(),
+            Statement::Savepoint { name } => format!(r#"SAVEPOINT "{name}";"#),
+            Statement::RollbackToSavepoint { name } => {
+                format!(r#"ROLLBACK TO SAVEPOINT "{name}";"#)
+            }
+            Statement::ReleaseSavepoint { name } => format!(r#"RELEASE SAVEPOINT "{name}";"#),
+            Statement::SetTransaction { isolation_level } => {
+                format!(
+                    "SET TRANSACTION ISOLATION LEVEL {};",
+                    isolation_level.to_sql()
+                )
+            }
+            Statement::Flush => "FLUSH;".to_owned(),
+            Statement::Analyze { table_name } => match table_name {
+                Some(table_name) => format!(r#"ANALYZE "{table_name}";"#),
+                None => "ANALYZE;".to_owned(),
+            },
             Statement::ShowVariable(variable) => match variable {
                 Variable::Tables => "SHOW TABLES;".to_owned(),
                 Variable::Functions => "SHOW FUNCTIONS;".to_owned(),
@@ -283,19 +529,149 @@ impl ToSql for Statement {
     }
 }
 
+fn order_by_limit_to_sql(order_by: &[OrderByExpr], limit: &Option<Expr>) -> String {
+    let order_by = match order_by.is_empty() {
+        true => "".to_owned(),
+        false => format!(
+            " ORDER BY {}",
+            order_by
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let limit = match limit {
+        Some(expr) => format!(" LIMIT {}", expr.to_sql()),
+        None => "".to_owned(),
+    };
+
+    format!("{order_by}{limit}")
+}
+
+fn returning_to_sql(returning: &[SelectItem]) -> String {
+    match returning.is_empty() {
+        true => "".to_owned(),
+        false => format!(
+            " RETURNING {}",
+            returning
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+impl ToSql for IsolationLevel {
+    fn to_sql(&self) -> String {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+        .to_owned()
+    }
+}
+
 impl ToSql for Assignment {
     fn to_sql(&self) -> String {
         format!(r#""{}" = {}"#, self.id, self.value.to_sql())
     }
 }
 
+impl ToSql for OnConflict {
+    fn to_sql(&self) -> String {
+        let OnConflict { columns, action } = self;
+        let columns = match columns.is_empty() {
+            true => "".to_owned(),
+            false => format!(" ({})", columns.join(", ")),
+        };
+
+        match action {
+            OnConflictAction::DoNothing => format!("ON CONFLICT{columns} DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match selection {
+                    Some(expr) => format!(
+                        "ON CONFLICT{columns} DO UPDATE SET {assignments} WHERE {}",
+                        expr.to_sql()
+                    ),
+                    None => format!("ON CONFLICT{columns} DO UPDATE SET {assignments}"),
+                }
+            }
+        }
+    }
+}
+
+impl ToSql for MergeClause {
+    fn to_sql(&self) -> String {
+        let predicate = |predicate: &Option<Expr>| match predicate {
+            Some(expr) => format!(" AND {}", expr.to_sql()),
+            None => "".to_owned(),
+        };
+
+        match self {
+            MergeClause::MatchedUpdate {
+                predicate: expr,
+                assignments,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "WHEN MATCHED{} THEN UPDATE SET {assignments}",
+                    predicate(expr)
+                )
+            }
+            MergeClause::MatchedDelete { predicate: expr } => {
+                format!("WHEN MATCHED{} THEN DELETE", predicate(expr))
+            }
+            MergeClause::NotMatched {
+                predicate: expr,
+                columns,
+                values,
+            } => {
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => format!(" ({})", columns.join(", ")),
+                };
+                let values = values
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "WHEN NOT MATCHED{} THEN INSERT{columns} VALUES ({values})",
+                    predicate(expr)
+                )
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
         crate::ast::{
-            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, ColumnDef, DataType, Expr,
-            OperateFunctionArg, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
-            TableFactor, TableWithJoins, ToSql, Values, Variable,
+            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, ColumnDef,
+            ColumnUniqueOption, DataType, Expr, ForeignKey, IsolationLevel, MergeClause,
+            OnConflict, OnConflictAction, OperateFunctionArg, OrderByExpr, Query,
+            ReferentialAction, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins, ToSql, Values, Variable,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -320,6 +696,7 @@ mod tests {
                 table_name: "Test".into(),
                 columns: vec!["id".to_owned(), "num".to_owned(), "name".to_owned()],
                 source: Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
@@ -328,7 +705,71 @@ mod tests {
                     order_by: vec![],
                     limit: None,
                     offset: None
-                }
+                },
+                on_conflict: None,
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"INSERT INTO Test (id, name) VALUES (1, 'Hello') ON CONFLICT (id) DO UPDATE SET "name" = "excluded"."name" WHERE "name" <> 'World';"#,
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: vec!["id".to_owned(), "name".to_owned()],
+                source: Query {
+                    with: None,
+                    body: SetExpr::Values(Values(vec![vec![
+                        Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
+                        Expr::Literal(AstLiteral::QuotedString("Hello".to_owned()))
+                    ]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict {
+                    columns: vec!["id".to_owned()],
+                    action: OnConflictAction::DoUpdate {
+                        assignments: vec![Assignment {
+                            id: "name".to_owned(),
+                            value: Expr::CompoundIdentifier {
+                                alias: "excluded".to_owned(),
+                                ident: "name".to_owned(),
+                            },
+                        }],
+                        selection: Some(Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier("name".to_owned())),
+                            op: BinaryOperator::NotEq,
+                            right: Box::new(Expr::Literal(AstLiteral::QuotedString(
+                                "World".to_owned()
+                            ))),
+                        }),
+                    },
+                }),
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            "INSERT INTO Test VALUES (1) ON CONFLICT DO NOTHING;",
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: Vec::new(),
+                source: Query {
+                    with: None,
+                    body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    ))]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict {
+                    columns: Vec::new(),
+                    action: OnConflictAction::DoNothing,
+                }),
+                returning: Vec::new(),
             }
             .to_sql()
         );
@@ -352,7 +793,10 @@ mod tests {
                         value: Expr::Literal(AstLiteral::QuotedString("blue".to_owned()))
                     }
                 ],
-                selection: None
+                selection: None,
+                order_by: Vec::new(),
+                limit: None,
+                returning: Vec::new()
             }
             .to_sql()
         );
@@ -369,7 +813,10 @@ mod tests {
                     left: Box::new(Expr::Identifier("a".to_owned())),
                     op: BinaryOperator::Gt,
                     right: Box::new(Expr::Identifier("b".to_owned()))
-                })
+                }),
+                order_by: Vec::new(),
+                limit: None,
+                returning: Vec::new()
             }
             .to_sql()
         )
@@ -381,7 +828,10 @@ mod tests {
             r#"DELETE FROM "Foo";"#,
             Statement::Delete {
                 table_name: "Foo".into(),
-                selection: None
+                selection: None,
+                order_by: Vec::new(),
+                limit: None,
+                returning: Vec::new()
             }
             .to_sql()
         );
@@ -394,7 +844,127 @@ mod tests {
                     left: Box::new(Expr::Identifier("item".to_owned())),
                     op: BinaryOperator::Eq,
                     right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned())))
-                })
+                }),
+                order_by: Vec::new(),
+                limit: None,
+                returning: Vec::new()
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" WHERE "id" = 1 RETURNING "id" AS "id", "item" AS "name";"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("id".to_owned())),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    )))
+                }),
+                order_by: Vec::new(),
+                limit: None,
+                returning: vec![
+                    SelectItem::Expr {
+                        expr: Expr::Identifier("id".to_owned()),
+                        label: "id".to_owned()
+                    },
+                    SelectItem::Expr {
+                        expr: Expr::Identifier("item".to_owned()),
+                        label: "name".to_owned()
+                    }
+                ]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Queue" ORDER BY "ts" DESC LIMIT 100;"#,
+            Statement::Delete {
+                table_name: "Queue".into(),
+                selection: None,
+                order_by: vec![OrderByExpr {
+                    expr: Expr::Identifier("ts".to_owned()),
+                    asc: Some(false),
+                    nulls_first: None,
+                }],
+                limit: Some(Expr::Literal(AstLiteral::Number(
+                    BigDecimal::from_str("100").unwrap()
+                ))),
+                returning: Vec::new()
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_merge() {
+        assert_eq!(
+            r#"MERGE INTO "Item" "t" USING "Stock" ON "t"."id" = "Stock"."id" WHEN MATCHED AND "Stock"."qty" = 0 THEN DELETE WHEN MATCHED THEN UPDATE SET "qty" = "Stock"."qty" WHEN NOT MATCHED THEN INSERT (id, qty) VALUES ("Stock"."id", "Stock"."qty");"#,
+            Statement::Merge {
+                table: TableFactor::Table {
+                    name: "Item".to_owned(),
+                    alias: Some(TableAlias {
+                        name: "t".to_owned(),
+                        columns: Vec::new(),
+                    }),
+                    index: None,
+                },
+                source: TableFactor::Table {
+                    name: "Stock".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                on: Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "t".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier {
+                        alias: "Stock".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                },
+                clauses: vec![
+                    MergeClause::MatchedDelete {
+                        predicate: Some(Expr::BinaryOp {
+                            left: Box::new(Expr::CompoundIdentifier {
+                                alias: "Stock".to_owned(),
+                                ident: "qty".to_owned(),
+                            }),
+                            op: BinaryOperator::Eq,
+                            right: Box::new(Expr::Literal(AstLiteral::Number(
+                                BigDecimal::from_str("0").unwrap()
+                            ))),
+                        }),
+                    },
+                    MergeClause::MatchedUpdate {
+                        predicate: None,
+                        assignments: vec![Assignment {
+                            id: "qty".to_owned(),
+                            value: Expr::CompoundIdentifier {
+                                alias: "Stock".to_owned(),
+                                ident: "qty".to_owned(),
+                            },
+                        }],
+                    },
+                    MergeClause::NotMatched {
+                        predicate: None,
+                        columns: vec!["id".to_owned(), "qty".to_owned()],
+                        values: vec![
+                            Expr::CompoundIdentifier {
+                                alias: "Stock".to_owned(),
+                                ident: "id".to_owned(),
+                            },
+                            Expr::CompoundIdentifier {
+                                alias: "Stock".to_owned(),
+                                ident: "qty".to_owned(),
+                            },
+                        ],
+                    },
+                ],
             }
             .to_sql()
         );
@@ -406,10 +976,13 @@ mod tests {
             r#"CREATE TABLE IF NOT EXISTS "Foo";"#,
             Statement::CreateTable {
                 if_not_exists: true,
+                temporary: false,
                 name: "Foo".into(),
                 columns: None,
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -418,10 +991,28 @@ mod tests {
             r#"CREATE TABLE "Foo";"#,
             Statement::CreateTable {
                 if_not_exists: false,
+                temporary: false,
                 name: "Foo".into(),
                 columns: None,
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE TEMPORARY TABLE IF NOT EXISTS "Foo";"#,
+            Statement::CreateTable {
+                if_not_exists: true,
+                temporary: true,
+                name: "Foo".into(),
+                columns: None,
+                source: None,
+                engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -430,6 +1021,7 @@ mod tests {
             r#"CREATE TABLE IF NOT EXISTS "Foo" ("id" BOOLEAN NOT NULL);"#,
             Statement::CreateTable {
                 if_not_exists: true,
+                temporary: false,
                 name: "Foo".into(),
                 columns: Some(vec![ColumnDef {
                     name: "id".to_owned(),
@@ -437,9 +1029,12 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    collation: None,
                 },]),
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -448,6 +1043,7 @@ mod tests {
             r#"CREATE TABLE "Foo" ("id" INT NOT NULL, "num" INT NULL, "name" TEXT NOT NULL);"#,
             Statement::CreateTable {
                 if_not_exists: false,
+                temporary: false,
                 name: "Foo".into(),
                 columns: Some(vec![
                     ColumnDef {
@@ -456,6 +1052,7 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        collation: None,
                     },
                     ColumnDef {
                         name: "num".to_owned(),
@@ -463,6 +1060,7 @@ mod tests {
                         nullable: true,
                         default: None,
                         unique: None,
+                        collation: None,
                     },
                     ColumnDef {
                         name: "name".to_owned(),
@@ -470,10 +1068,13 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        collation: None,
                     }
                 ]),
                 source: None,
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -485,10 +1086,13 @@ mod tests {
             r#"CREATE TABLE "Foo" AS SELECT "id", "count" FROM "Bar";"#,
             Statement::CreateTable {
                 if_not_exists: false,
+                temporary: false,
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![
                             SelectItem::Expr {
                                 expr: Expr::Identifier("id".to_owned()),
@@ -516,6 +1120,8 @@ mod tests {
                     offset: None
                 })),
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -524,9 +1130,11 @@ mod tests {
             r#"CREATE TABLE IF NOT EXISTS "Foo" AS VALUES (TRUE);"#,
             Statement::CreateTable {
                 if_not_exists: true,
+                temporary: false,
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Boolean(
                         true
                     ))]])),
@@ -535,6 +1143,8 @@ mod tests {
                     offset: None
                 })),
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -546,10 +1156,13 @@ mod tests {
             r#"CREATE TABLE "Foo" ENGINE = MEMORY;"#,
             Statement::CreateTable {
                 if_not_exists: false,
+                temporary: false,
                 name: "Foo".into(),
                 columns: None,
                 source: None,
                 engine: Some("MEMORY".to_owned()),
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -558,6 +1171,7 @@ mod tests {
             r#"CREATE TABLE "Foo" ("id" BOOLEAN NOT NULL) ENGINE = SLED;"#,
             Statement::CreateTable {
                 if_not_exists: false,
+                temporary: false,
                 name: "Foo".into(),
                 columns: Some(vec![ColumnDef {
                     name: "id".to_owned(),
@@ -565,9 +1179,54 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    collation: None,
                 },]),
                 source: None,
                 engine: Some("SLED".to_owned()),
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_table_with_foreign_key() {
+        assert_eq!(
+            r#"CREATE TABLE "Child" ("id" INT NOT NULL PRIMARY KEY, "parent_id" INT NULL, CONSTRAINT "FK_parent_id-Parent_id" FOREIGN KEY ("parent_id") REFERENCES "Parent" ("id") ON DELETE SET NULL ON UPDATE NO ACTION);"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                temporary: false,
+                name: "Child".into(),
+                columns: Some(vec![
+                    ColumnDef {
+                        name: "id".to_owned(),
+                        data_type: DataType::Int,
+                        nullable: false,
+                        default: None,
+                        unique: Some(ColumnUniqueOption { is_primary: true }),
+                        collation: None,
+                    },
+                    ColumnDef {
+                        name: "parent_id".to_owned(),
+                        data_type: DataType::Int,
+                        nullable: true,
+                        default: None,
+                        unique: None,
+                        collation: None,
+                    }
+                ]),
+                source: None,
+                engine: None,
+                foreign_keys: vec![ForeignKey {
+                    name: "FK_parent_id-Parent_id".to_owned(),
+                    referencing_column_name: "parent_id".to_owned(),
+                    referenced_table_name: "Parent".to_owned(),
+                    referenced_column_name: "id".to_owned(),
+                    on_delete: ReferentialAction::SetNull,
+                    on_update: ReferentialAction::NoAction,
+                }],
+                checks: Vec::new(),
             }
             .to_sql()
         );
@@ -587,6 +1246,7 @@ mod tests {
                         BigDecimal::from_str("0").unwrap()
                     ))),
                 }],
+                return_type: None,
                 return_: Expr::Identifier("num".to_owned())
             }
             .to_sql()
@@ -597,10 +1257,32 @@ mod tests {
                 or_replace: true,
                 name: "add".into(),
                 args: vec![],
+                return_type: None,
                 return_: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap()))
             }
             .to_sql()
         );
+        assert_eq!(
+            r#"CREATE FUNCTION add_tax("p" FLOAT) RETURNS FLOAT RETURN "p" * 1.1;"#,
+            Statement::CreateFunction {
+                or_replace: false,
+                name: "add_tax".into(),
+                args: vec![OperateFunctionArg {
+                    name: "p".into(),
+                    data_type: DataType::Float,
+                    default: None,
+                }],
+                return_type: Some(DataType::Float),
+                return_: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("p".to_owned())),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1.1").unwrap()
+                    ))),
+                },
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -618,6 +1300,7 @@ mod tests {
                             BigDecimal::from_str("10").unwrap()
                         ))),
                         unique: None,
+                        collation: None,
                     }
                 }
             }
@@ -692,6 +1375,14 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"TRUNCATE TABLE "Test";"#,
+            Statement::Truncate {
+                table_name: "Test".into()
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             r#"DROP TABLE "Foo", "Bar";"#,
             Statement::DropTable {
@@ -702,6 +1393,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_view() {
+        assert_eq!(
+            r#"CREATE OR REPLACE VIEW "Foo" AS SELECT "id" FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: true,
+                materialized: false,
+                name: "Foo".into(),
+                query: Box::new(Query {
+                    with: None,
+                    body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
+                        projection: vec![SelectItem::Expr {
+                            expr: Expr::Identifier("id".to_owned()),
+                            label: "".to_owned()
+                        }],
+                        from: TableWithJoins {
+                            relation: TableFactor::Table {
+                                name: "Bar".to_owned(),
+                                alias: None,
+                                index: None
+                            },
+                            joins: vec![]
+                        },
+                        selection: None,
+                        group_by: vec![],
+                        having: None
+                    })),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                })
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP VIEW IF EXISTS "Foo", "Bar";"#,
+            Statement::DropView {
+                if_exists: true,
+                names: vec!["Foo".into(), "Bar".into()]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"REFRESH MATERIALIZED VIEW "Foo";"#,
+            Statement::RefreshMaterializedView { name: "Foo".into() }.to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_delete_function() {
         assert_eq!(
@@ -741,7 +1483,8 @@ mod tests {
                 table_name: "Test".into(),
                 column: OrderByExpr {
                     expr: Expr::Identifier("LastName".to_owned()),
-                    asc: None
+                    asc: None,
+                    nulls_first: None,
                 }
             }
             .to_sql()
@@ -765,6 +1508,43 @@ mod tests {
         assert_eq!("START TRANSACTION;", Statement::StartTransaction.to_sql());
         assert_eq!("COMMIT;", Statement::Commit.to_sql());
         assert_eq!("ROLLBACK;", Statement::Rollback.to_sql());
+        assert_eq!(
+            r#"SAVEPOINT "sp1";"#,
+            Statement::Savepoint {
+                name: "sp1".to_owned()
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"ROLLBACK TO SAVEPOINT "sp1";"#,
+            Statement::RollbackToSavepoint {
+                name: "sp1".to_owned()
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"RELEASE SAVEPOINT "sp1";"#,
+            Statement::ReleaseSavepoint {
+                name: "sp1".to_owned()
+            }
+            .to_sql()
+        );
+        assert_eq!("FLUSH;", Statement::Flush.to_sql());
+        assert_eq!("ANALYZE;", Statement::Analyze { table_name: None }.to_sql());
+        assert_eq!(
+            r#"ANALYZE "Item";"#,
+            Statement::Analyze {
+                table_name: Some("Item".to_owned())
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            "SET TRANSACTION ISOLATION LEVEL READ COMMITTED;",
+            Statement::SetTransaction {
+                isolation_level: IsolationLevel::ReadCommitted
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -43,6 +43,14 @@ pub enum BinaryOperator {
     Xor,
     BitwiseAnd,
     BitwiseShiftLeft,
+    Contains,
+    ContainedBy,
+    Arrow,
+    LongArrow,
+    RegexMatch,
+    RegexIMatch,
+    RegexNotMatch,
+    RegexNotIMatch,
 }
 
 impl ToSql for BinaryOperator {
@@ -65,6 +73,14 @@ impl ToSql for BinaryOperator {
             BinaryOperator::Xor => "XOR".to_owned(),
             BinaryOperator::BitwiseAnd => "&".to_owned(),
             BinaryOperator::BitwiseShiftLeft => "<<".to_owned(),
+            BinaryOperator::Contains => "@>".to_owned(),
+            BinaryOperator::ContainedBy => "<@".to_owned(),
+            BinaryOperator::Arrow => "->".to_owned(),
+            BinaryOperator::LongArrow => "->>".to_owned(),
+            BinaryOperator::RegexMatch => "~".to_owned(),
+            BinaryOperator::RegexIMatch => "~*".to_owned(),
+            BinaryOperator::RegexNotMatch => "!~".to_owned(),
+            BinaryOperator::RegexNotIMatch => "!~*".to_owned(),
         }
     }
 }
@@ -261,6 +277,62 @@ mod tests {
             }
             .to_sql()
         );
+        assert_eq!(
+            r#""tags" @> "items""#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("tags".to_owned())),
+                op: BinaryOperator::Contains,
+                right: Box::new(Expr::Identifier("items".to_owned()))
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#""tags" <@ "items""#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("tags".to_owned())),
+                op: BinaryOperator::ContainedBy,
+                right: Box::new(Expr::Identifier("items".to_owned()))
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#""doc" -> 'a'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("doc".to_owned())),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::Literal(AstLiteral::QuotedString("a".to_owned())))
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#""doc" ->> 'a'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("doc".to_owned())),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Literal(AstLiteral::QuotedString("a".to_owned())))
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#""line" ~ 'err(or)?'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("line".to_owned())),
+                op: BinaryOperator::RegexMatch,
+                right: Box::new(Expr::Literal(AstLiteral::QuotedString(
+                    "err(or)?".to_owned()
+                )))
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#""line" !~* 'warn'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("line".to_owned())),
+                op: BinaryOperator::RegexNotIMatch,
+                right: Box::new(Expr::Literal(AstLiteral::QuotedString("warn".to_owned())))
+            }
+            .to_sql()
+        );
         assert_eq!(
             "+8",
             Expr::UnaryOp {
@@ -8,20 +8,54 @@ use {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Query {
+    pub with: Option<With>,
     pub body: SetExpr,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Expr>,
 }
 
+/// Default upper bound on the number of iterations a recursive CTE may run.
+pub const DEFAULT_MAX_RECURSION: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct With {
+    pub recursive: bool,
+    pub cte_tables: Vec<Cte>,
+    /// Iterations allowed for each recursive CTE before execution fails
+    pub max_recursion: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cte {
+    pub alias: TableAlias,
+    pub query: Query,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    /// `left UNION [ALL] right`, `left INTERSECT [ALL] right` or `left EXCEPT [ALL] right`
+    SetOperation {
+        op: SetOperator,
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Select {
+    pub distinct: bool,
     pub projection: Vec<SelectItem>,
     pub from: TableWithJoins,
     /// WHERE
@@ -68,9 +102,24 @@ pub enum TableFactor {
         subquery: Query,
         alias: TableAlias,
     },
+    /// `SERIES(end)` counts from 1, `SERIES(start, end)` and `SERIES(start, end, step)` count
+    /// from `start` by `step`, which is 1, or a day for dates and timestamps, unless given
     Series {
         alias: TableAlias,
-        size: Expr,
+        start: Option<Expr>,
+        end: Expr,
+        step: Option<Expr>,
+    },
+    /// Expands a list into rows of a single column
+    Unnest {
+        alias: TableAlias,
+        expr: Expr,
+    },
+    /// Table-valued function registered by the host application
+    Function {
+        name: String,
+        args: Vec<Expr>,
+        alias: TableAlias,
     },
     Dictionary {
         dict: Dictionary,
@@ -114,11 +163,15 @@ pub enum JoinExecutor {
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
+    RightOuter(JoinConstraint),
+    FullOuter(JoinConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JoinConstraint {
     On(Expr),
+    Using(Vec<String>),
+    Natural,
     None,
 }
 
@@ -126,6 +179,7 @@ pub enum JoinConstraint {
 pub struct OrderByExpr {
     pub expr: Expr,
     pub asc: Option<bool>,
+    pub nulls_first: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -151,12 +205,18 @@ impl Query {
         };
 
         let Query {
+            with,
             body,
             order_by,
             limit,
             offset,
         } = self;
 
+        let with = match with {
+            Some(with) => format!("{} ", with.to_sql_with(quoted)),
+            None => "".to_owned(),
+        };
+
         let order_by = if order_by.is_empty() {
             "".to_owned()
         } else {
@@ -185,9 +245,57 @@ impl Query {
             .join(" ");
 
         if string.is_empty() {
-            body.to_sql_with(quoted)
+            format!("{with}{}", body.to_sql_with(quoted))
         } else {
-            format!("{} {}", body.to_sql_with(quoted), string)
+            format!("{with}{} {}", body.to_sql_with(quoted), string)
+        }
+    }
+}
+
+impl ToSql for With {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for With {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl With {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let With {
+            recursive,
+            cte_tables,
+            ..
+        } = self;
+
+        let cte_tables = cte_tables
+            .iter()
+            .map(|Cte { alias, query }| {
+                let TableAlias { name, columns } = alias;
+                let quote = |ident: &String| match quoted {
+                    true => format!(r#""{ident}""#),
+                    false => ident.to_owned(),
+                };
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => format!("({})", columns.iter().map(quote).join(", ")),
+                };
+
+                format!(
+                    "{}{columns} AS ({})",
+                    quote(name),
+                    query.to_sql_with(quoted)
+                )
+            })
+            .join(", ");
+
+        match recursive {
+            true => format!("WITH RECURSIVE {cte_tables}"),
+            false => format!("WITH {cte_tables}"),
         }
     }
 }
@@ -211,6 +319,23 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                quoted,
+            ) => {
+                let all = if *all { " ALL" } else { "" };
+
+                format!(
+                    "{} {op}{all} {}",
+                    left.to_sql_with(quoted),
+                    right.to_sql_with(quoted)
+                )
+            }
         }
     }
 }
@@ -235,6 +360,7 @@ impl Select {
         };
 
         let Select {
+            distinct,
             projection,
             from,
             selection,
@@ -245,6 +371,10 @@ impl Select {
             .iter()
             .map(|item| item.to_sql_with(quoted))
             .join(", ");
+        let projection = match distinct {
+            true => format!("DISTINCT {projection}"),
+            false => projection,
+        };
 
         let selection = match selection {
             Some(expr) => format!("WHERE {}", to_sql(expr)),
@@ -381,8 +511,36 @@ impl TableFactor {
                     alias.to_sql_with(quoted)
                 )
             }
-            (TableFactor::Series { alias, size }, _) => {
-                format!("SERIES({}) {}", to_sql(size), alias.to_sql_with(quoted))
+            (
+                TableFactor::Series {
+                    alias,
+                    start,
+                    end,
+                    step,
+                },
+                _,
+            ) => {
+                let args = start
+                    .iter()
+                    .chain(Some(end))
+                    .chain(step)
+                    .map(to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("SERIES({args}) {}", alias.to_sql_with(quoted))
+            }
+            (TableFactor::Unnest { alias, expr }, _) => {
+                format!("UNNEST({}) {}", to_sql(expr), alias.to_sql_with(quoted))
+            }
+            (TableFactor::Function { name, args, alias }, _) => {
+                let args = args.iter().map(to_sql).collect::<Vec<_>>().join(", ");
+                let name = match quoted {
+                    true => format!(r#""{name}""#),
+                    false => name.to_owned(),
+                };
+
+                format!("{name}({args}) {}", alias.to_sql_with(quoted))
             }
             (TableFactor::Dictionary { dict, alias }, true) => {
                 format!(r#""{dict}" {}"#, alias.to_sql_with(quoted))
@@ -440,8 +598,31 @@ impl Join {
         let (join_operator, join_constraint) = match join_operator {
             JoinOperator::Inner(join_constraint) => ("INNER JOIN", join_constraint),
             JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER JOIN", join_constraint),
+            JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER JOIN", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER JOIN", join_constraint),
         };
 
+        match join_constraint {
+            JoinConstraint::Using(columns) => {
+                let columns = columns
+                    .iter()
+                    .map(|column| match quoted {
+                        true => format!(r#""{column}""#),
+                        false => column.to_owned(),
+                    })
+                    .join(", ");
+
+                return format!(
+                    "{join_operator} {} USING ({columns})",
+                    relation.to_sql_with(quoted)
+                );
+            }
+            JoinConstraint::Natural => {
+                return format!("NATURAL {join_operator} {}", relation.to_sql_with(quoted));
+            }
+            JoinConstraint::On(_) | JoinConstraint::None => {}
+        }
+
         let (join_constraint, join_executor) = match quoted {
             true => (join_constraint.to_sql(), join_executor.to_sql()),
             false => (
@@ -519,7 +700,9 @@ impl JoinConstraint {
         match (self, quoted) {
             (JoinConstraint::On(expr), true) => expr.to_sql(),
             (JoinConstraint::On(expr), false) => expr.to_sql_unquoted(),
-            (JoinConstraint::None, _) => "".to_owned(),
+            (JoinConstraint::Using(_) | JoinConstraint::Natural | JoinConstraint::None, _) => {
+                "".to_owned()
+            }
         }
     }
 }
@@ -538,16 +721,25 @@ impl ToSqlUnquoted for OrderByExpr {
 
 impl OrderByExpr {
     fn to_sql_with(&self, quoted: bool) -> String {
-        let OrderByExpr { expr, asc } = self;
+        let OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        } = self;
         let expr = match quoted {
             true => expr.to_sql(),
             false => expr.to_sql_unquoted(),
         };
-
-        match asc {
+        let expr = match asc {
             Some(true) => format!("{} ASC", expr),
             Some(false) => format!("{} DESC", expr),
             None => expr,
+        };
+
+        match nulls_first {
+            Some(true) => format!("{} NULLS FIRST", expr),
+            Some(false) => format!("{} NULLS LAST", expr),
+            None => expr,
         }
     }
 }
@@ -590,9 +782,10 @@ mod tests {
     use {
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                AstLiteral, BinaryOperator, Cte, Dictionary, Expr, Join, JoinConstraint,
+                JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+                SetOperator, TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                With, DEFAULT_MAX_RECURSION,
             },
             parse_sql::parse_expr,
             translate::translate_expr,
@@ -612,11 +805,14 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual =
             r#"SELECT * FROM "FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -643,6 +839,49 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"WITH RECURSIVE "T"("n") AS (VALUES (1)) SELECT * FROM "T""#.to_owned();
+        let expected = Query {
+            with: Some(With {
+                recursive: true,
+                cte_tables: vec![Cte {
+                    alias: TableAlias {
+                        name: "T".to_owned(),
+                        columns: vec!["n".to_owned()],
+                    },
+                    query: Query {
+                        with: None,
+                        body: SetExpr::Values(Values(vec![vec![Expr::Literal(
+                            AstLiteral::Number(BigDecimal::from_str("1").unwrap()),
+                        )]])),
+                        order_by: Vec::new(),
+                        limit: None,
+                        offset: None,
+                    },
+                }],
+                max_recursion: DEFAULT_MAX_RECURSION,
+            }),
+            body: SetExpr::Select(Box::new(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "T".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -650,10 +889,13 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual = "SELECT * FROM FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -686,6 +928,7 @@ mod tests {
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "FOO" AS "F" INNER JOIN "PlayerItem""#.to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -728,12 +971,39 @@ mod tests {
         ]))
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"SELECT * FROM "FOO" UNION ALL VALUES (1)"#.to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: Box::new(SetExpr::Select(Box::new(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "FOO".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            }))),
+            right: Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::Number(BigDecimal::from_str("1").unwrap()),
+            )]]))),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
     fn to_sql_unquoted_set_expr() {
         let actual = "SELECT * FROM FOO AS F INNER JOIN PlayerItem".to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -790,6 +1060,20 @@ mod tests {
         ]))
         .to_sql_unquoted();
         assert_eq!(actual, expected);
+
+        let actual = "VALUES (1) EXCEPT VALUES (2)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: false,
+            left: Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::Number(BigDecimal::from_str("1").unwrap()),
+            )]]))),
+            right: Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::Number(BigDecimal::from_str("2").unwrap()),
+            )]]))),
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -797,6 +1081,7 @@ mod tests {
         let actual =
             r#"SELECT * FROM "FOO" AS "F" GROUP BY "name" HAVING "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -820,8 +1105,31 @@ mod tests {
         .to_sql();
         assert_eq!(actual, expected);
 
+        let actual = r#"SELECT DISTINCT "name" AS "name" FROM "FOO""#.to_owned();
+        let expected = Select {
+            distinct: true,
+            projection: vec![SelectItem::Expr {
+                expr: Expr::Identifier("name".to_owned()),
+                label: "name".to_owned(),
+            }],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
         let actual = r#"SELECT * FROM "FOO" WHERE "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -847,6 +1155,7 @@ mod tests {
     fn to_sql_unquoted_select() {
         let actual = "SELECT * FROM FOO AS F GROUP BY name HAVING name = 'glue'".to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -872,6 +1181,7 @@ mod tests {
 
         let actual = "SELECT * FROM FOO WHERE name = 'glue'".to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -980,7 +1290,9 @@ mod tests {
         let actual = r#"(SELECT * FROM "FOO") AS "F""#;
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1012,7 +1324,35 @@ mod tests {
                 name: "S".to_owned(),
                 columns: Vec::new(),
             },
-            size: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("3").unwrap())),
+            start: None,
+            end: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("3").unwrap())),
+            step: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"UNNEST("tags") AS "T""#;
+        let expected = TableFactor::Unnest {
+            alias: TableAlias {
+                name: "T".to_owned(),
+                columns: Vec::new(),
+            },
+            expr: Expr::Identifier("tags".to_owned()),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""read_log"('app', 10) AS "L""#;
+        let expected = TableFactor::Function {
+            name: "read_log".to_owned(),
+            args: vec![
+                Expr::Literal(AstLiteral::QuotedString("app".to_owned())),
+                Expr::Literal(AstLiteral::Number(BigDecimal::from_str("10").unwrap())),
+            ],
+            alias: TableAlias {
+                name: "L".to_owned(),
+                columns: Vec::new(),
+            },
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1046,7 +1386,9 @@ mod tests {
         let actual = "(SELECT * FROM FOO) AS F";
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1078,7 +1420,34 @@ mod tests {
                 name: "S".to_owned(),
                 columns: Vec::new(),
             },
-            size: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("3").unwrap())),
+            start: None,
+            end: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("3").unwrap())),
+            step: None,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "UNNEST(tags) AS T";
+        let expected = TableFactor::Unnest {
+            alias: TableAlias {
+                name: "T".to_owned(),
+                columns: Vec::new(),
+            },
+            expr: Expr::Identifier("tags".to_owned()),
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "read_log(10) AS L";
+        let expected = TableFactor::Function {
+            name: "read_log".to_owned(),
+            args: vec![Expr::Literal(AstLiteral::Number(
+                BigDecimal::from_str("10").unwrap(),
+            ))],
+            alias: TableAlias {
+                name: "L".to_owned(),
+                columns: Vec::new(),
+            },
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1197,6 +1566,65 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"RIGHT OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::RightOuter(JoinConstraint::On(expr(
+                r#""PlayerItem"."user_id" = "Player"."id""#,
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"FULL OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::On(expr(
+                r#""PlayerItem"."user_id" = "Player"."id""#,
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"INNER JOIN "PlayerItem" USING ("user_id", "group_id")"#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Using(vec![
+                "user_id".to_owned(),
+                "group_id".to_owned(),
+            ])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"NATURAL LEFT OUTER JOIN "PlayerItem""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::LeftOuter(JoinConstraint::Natural),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -1233,6 +1661,19 @@ mod tests {
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
+        let actual = "INNER JOIN PlayerItem USING (user_id)";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Using(vec!["user_id".to_owned()])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
         let actual = "LEFT OUTER JOIN PlayerItem";
         let expected = Join {
             relation: TableFactor::Table {
@@ -1291,6 +1732,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1299,6 +1741,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1307,6 +1750,28 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_sql_order_by_expr_nulls() {
+        let actual = r#""foo" ASC NULLS FIRST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: Some(true),
+            nulls_first: Some(true),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""foo" NULLS LAST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: None,
+            nulls_first: Some(false),
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1318,6 +1783,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1326,6 +1792,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1334,6 +1801,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -49,9 +49,12 @@ impl Build for CreateTableNode {
         Ok(Statement::CreateTable {
             name: table_name,
             if_not_exists: self.if_not_exists,
+            temporary: false,
             columns,
             source: None,
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
         })
     }
 }
@@ -1,7 +1,7 @@
 use {
-    super::{Build, ExprNode},
+    super::{Build, ExprNode, OrderByExprList},
     crate::{
-        ast::{Expr, Statement},
+        ast::{Expr, OrderByExpr, Statement},
         result::Result,
     },
 };
@@ -10,6 +10,8 @@ use {
 pub struct DeleteNode<'a> {
     table_name: String,
     filter_expr: Option<ExprNode<'a>>,
+    order_by: Option<OrderByExprList<'a>>,
+    limit: Option<ExprNode<'a>>,
 }
 
 impl<'a> DeleteNode<'a> {
@@ -17,6 +19,8 @@ impl<'a> DeleteNode<'a> {
         Self {
             table_name,
             filter_expr: None,
+            order_by: None,
+            limit: None,
         }
     }
 
@@ -25,16 +29,37 @@ impl<'a> DeleteNode<'a> {
 
         self
     }
+
+    pub fn order_by<T: Into<OrderByExprList<'a>>>(mut self, expr_list: T) -> Self {
+        self.order_by = Some(expr_list.into());
+
+        self
+    }
+
+    pub fn limit<T: Into<ExprNode<'a>>>(mut self, expr: T) -> Self {
+        self.limit = Some(expr.into());
+
+        self
+    }
 }
 
 impl<'a> Build for DeleteNode<'a> {
     fn build(self) -> Result<Statement> {
         let table_name = self.table_name;
         let selection = self.filter_expr.map(Expr::try_from).transpose()?;
+        let order_by = self
+            .order_by
+            .map(Vec::<OrderByExpr>::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let limit = self.limit.map(Expr::try_from).transpose()?;
 
         Ok(Statement::Delete {
             table_name,
             selection,
+            order_by,
+            limit,
+            returning: Vec::new(),
         })
     }
 }
@@ -42,7 +67,7 @@ impl<'a> Build for DeleteNode<'a> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ast::Expr,
+        ast::{Expr, ToSql},
         ast_builder::{col, table, test, Build},
     };
 
@@ -69,5 +94,15 @@ mod tests {
             .build();
         let expected = "DELETE FROM Person WHERE name IS NULL";
         test(actual, expected);
+
+        let actual = table("Queue")
+            .delete()
+            .filter("done = FALSE")
+            .order_by("ts DESC")
+            .limit(100)
+            .build()
+            .map(|statement| statement.to_sql());
+        let expected = r#"DELETE FROM "Queue" WHERE "done" = FALSE ORDER BY "ts" DESC LIMIT 100;"#;
+        assert_eq!(actual, Ok(expected.to_owned()));
     }
 }
@@ -152,6 +152,7 @@ mod test {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -167,6 +168,7 @@ mod test {
             };
 
             let query = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
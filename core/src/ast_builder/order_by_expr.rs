@@ -38,7 +38,11 @@ impl<'a> TryFrom<OrderByExprNode<'a>> for OrderByExpr {
             OrderByExprNode::Expr(expr_node) => {
                 let expr = Expr::try_from(expr_node)?;
 
-                Ok(OrderByExpr { expr, asc: None })
+                Ok(OrderByExpr {
+                    expr,
+                    asc: None,
+                    nulls_first: None,
+                })
             }
         }
     }
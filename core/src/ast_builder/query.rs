@@ -95,6 +95,7 @@ impl<'a> TryFrom<QueryNode<'a>> for Query {
                     .collect::<Result<Vec<_>>>()?;
 
                 Ok(Query {
+                    with: None,
                     body: SetExpr::Values(Values(values)),
                     order_by: Vec::new(),
                     limit: None,
@@ -173,6 +174,7 @@ mod test {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -188,6 +190,7 @@ mod test {
             };
 
             Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -217,6 +217,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -232,6 +233,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -203,6 +203,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -218,6 +219,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
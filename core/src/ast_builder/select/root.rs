@@ -98,7 +98,9 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
             },
             TableType::Series(args) => TableFactor::Series {
                 alias: alias_or_name(alias, self.table_node.table_name),
-                size: args.try_into()?,
+                start: None,
+                end: args.try_into()?,
+                step: None,
             },
             TableType::Derived { subquery, alias } => TableFactor::Derived {
                 subquery: Query::try_from(*subquery)?,
@@ -115,6 +117,7 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
         };
 
         Ok(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from,
             selection: None,
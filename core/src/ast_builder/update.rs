@@ -1,7 +1,7 @@
 use {
-    super::{AssignmentNode, Build, ExprNode},
+    super::{AssignmentNode, Build, ExprNode, OrderByExprList},
     crate::{
-        ast::{Assignment, Expr, Statement},
+        ast::{Assignment, Expr, OrderByExpr, Statement},
         result::Result,
     },
 };
@@ -11,6 +11,8 @@ pub struct UpdateNode<'a> {
     table_name: String,
     assignments: Vec<AssignmentNode<'a>>,
     selection: Option<ExprNode<'a>>,
+    order_by: Option<OrderByExprList<'a>>,
+    limit: Option<ExprNode<'a>>,
 }
 
 impl<'a> UpdateNode<'a> {
@@ -19,6 +21,8 @@ impl<'a> UpdateNode<'a> {
             table_name,
             assignments: Vec::new(),
             selection: None,
+            order_by: None,
+            limit: None,
         }
     }
 
@@ -32,6 +36,16 @@ impl<'a> UpdateNode<'a> {
             .push(AssignmentNode::Expr(id.to_owned(), value.into()));
         self
     }
+
+    pub fn order_by<T: Into<OrderByExprList<'a>>>(mut self, expr_list: T) -> Self {
+        self.order_by = Some(expr_list.into());
+        self
+    }
+
+    pub fn limit<T: Into<ExprNode<'a>>>(mut self, expr: T) -> Self {
+        self.limit = Some(expr.into());
+        self
+    }
 }
 
 impl<'a> Build for UpdateNode<'a> {
@@ -43,17 +57,29 @@ impl<'a> Build for UpdateNode<'a> {
             .into_iter()
             .map(Assignment::try_from)
             .collect::<Result<Vec<_>>>()?;
+        let order_by = self
+            .order_by
+            .map(Vec::<OrderByExpr>::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let limit = self.limit.map(Expr::try_from).transpose()?;
         Ok(Statement::Update {
             table_name,
             assignments,
             selection,
+            order_by,
+            limit,
+            returning: Vec::new(),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ast_builder::{table, test, Build};
+    use crate::{
+        ast::ToSql,
+        ast_builder::{table, test, Build},
+    };
 
     #[test]
     fn update() {
@@ -89,5 +115,14 @@ mod tests {
             .build();
         let expected = "UPDATE Foo SET id = 2, head_item = (SELECT id FROM head_item WHERE level = 3 LIMIT 1) WHERE body_item = 1";
         test(actual, expected);
+        let actual = table("Foo")
+            .update()
+            .set("done", true)
+            .order_by(vec!["priority DESC", "id"])
+            .limit(10)
+            .build()
+            .map(|statement| statement.to_sql());
+        let expected = r#"UPDATE "Foo" SET "done" = TRUE ORDER BY "priority" DESC, "id" LIMIT 10;"#;
+        assert_eq!(actual, Ok(expected.to_owned()));
     }
 }
@@ -1,12 +1,22 @@
 use {
-    crate::ast::{Expr, OperateFunctionArg},
+    super::Value,
+    crate::{
+        ast::{DataType, Expr, OperateFunctionArg},
+        result::Result,
+    },
     serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        fmt::{self, Debug},
+        sync::{Arc, PoisonError, RwLock},
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomFunction {
     pub func_name: String,
     pub args: Vec<OperateFunctionArg>,
+    pub return_type: Option<DataType>,
     pub body: Expr,
 }
 
@@ -22,3 +32,100 @@ impl CustomFunction {
         format!("{name}({args})")
     }
 }
+
+pub type NativeFunctionBody = dyn Fn(Vec<Value>) -> Result<Value> + Send + Sync;
+
+/// Scalar function implemented by the host application, see `Glue::register_function`.
+/// Arguments are cast to `arg_types` before `body` is called.
+#[derive(Clone)]
+pub struct NativeFunction {
+    pub func_name: String,
+    pub arg_types: Vec<DataType>,
+    pub body: Arc<NativeFunctionBody>,
+}
+
+impl Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeFunction")
+            .field("func_name", &self.func_name)
+            .field("arg_types", &self.arg_types)
+            .finish_non_exhaustive()
+    }
+}
+
+pub type NativeTableFunctionBody = dyn Fn(Vec<Value>) -> Result<Vec<Vec<Value>>> + Send + Sync;
+
+/// Table-valued function implemented by the host application, see
+/// `Glue::register_table_function`. Every row returned by `body` has a value for each of
+/// `columns`.
+#[derive(Clone)]
+pub struct NativeTableFunction {
+    pub func_name: String,
+    pub arg_types: Vec<DataType>,
+    pub columns: Vec<String>,
+    pub body: Arc<NativeTableFunctionBody>,
+}
+
+impl Debug for NativeTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeTableFunction")
+            .field("func_name", &self.func_name)
+            .field("arg_types", &self.arg_types)
+            .field("columns", &self.columns)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Native functions keyed by their uppercased names. Clones share the same functions,
+/// so a registry can be handed over to other threads.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionRegistry {
+    functions: Arc<RwLock<HashMap<String, NativeFunction>>>,
+    table_functions: Arc<RwLock<HashMap<String, NativeTableFunction>>>,
+}
+
+impl FunctionRegistry {
+    pub fn insert(&self, func: NativeFunction) {
+        self.functions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(func.func_name.to_uppercase(), func);
+    }
+
+    pub fn remove(&self, func_name: &str) -> Option<NativeFunction> {
+        self.functions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&func_name.to_uppercase())
+    }
+
+    pub fn get(&self, func_name: &str) -> Option<NativeFunction> {
+        self.functions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&func_name.to_uppercase())
+            .cloned()
+    }
+
+    pub fn insert_table_function(&self, func: NativeTableFunction) {
+        self.table_functions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(func.func_name.to_uppercase(), func);
+    }
+
+    pub fn remove_table_function(&self, func_name: &str) -> Option<NativeTableFunction> {
+        self.table_functions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&func_name.to_uppercase())
+    }
+
+    pub fn get_table_function(&self, func_name: &str) -> Option<NativeTableFunction> {
+        self.table_functions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&func_name.to_uppercase())
+            .cloned()
+    }
+}
@@ -236,6 +236,7 @@ impl<'a> Literal<'a> {
     pub fn like(&self, other: &Literal<'a>, case_sensitive: bool) -> Result<Self> {
         match (self, other) {
             (Text(l), Text(r)) => l.like(r, case_sensitive).map(Boolean),
+            (Null, Text(_)) | (Text(_), Null) | (Null, Null) => Ok(Null),
             _ => Err(LiteralError::LikeOnNonString {
                 base: format!("{:?}", self),
                 pattern: format!("{:?}", other),
@@ -5,6 +5,7 @@ mod key;
 mod literal;
 mod point;
 mod row;
+mod statistics;
 mod string_ext;
 mod table;
 
@@ -13,13 +14,17 @@ pub mod value;
 
 pub use {
     bigdecimal_ext::BigDecimalExt,
-    function::CustomFunction,
+    function::{
+        CustomFunction, FunctionRegistry, NativeFunction, NativeFunctionBody, NativeTableFunction,
+        NativeTableFunctionBody,
+    },
     interval::{Interval, IntervalError},
     key::{Key, KeyError},
     literal::{Literal, LiteralError},
     point::Point,
     row::{Row, RowError},
-    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError, SchemaView},
+    statistics::{ColumnStatistics, TableStatistics},
     string_ext::{StringExt, StringExtError},
     table::{get_alias, get_index, TableError},
     value::{HashMapJsonExt, NumericBinaryOperator, Value, ValueError},
@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::{ColumnDef, Expr, OrderByExpr, Statement, ToSql},
+        ast::{CheckConstraint, ColumnDef, Expr, ForeignKey, OrderByExpr, Query, Statement, ToSql},
         prelude::{parse, translate},
         result::Result,
     },
@@ -33,6 +33,18 @@ pub struct Schema {
     pub column_defs: Option<Vec<ColumnDef>>,
     pub indexes: Vec<SchemaIndex>,
     pub engine: Option<String>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub checks: Vec<CheckConstraint>,
+    /// Stored query of a view, `None` for a table
+    pub view: Option<SchemaView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaView {
+    pub query: Query,
+    /// Rows of a materialized view are stored like the rows of a table and recomputed only
+    /// by `REFRESH MATERIALIZED VIEW`
+    pub materialized: bool,
 }
 
 impl Schema {
@@ -42,15 +54,43 @@ impl Schema {
             column_defs,
             indexes,
             engine,
-            ..
+            foreign_keys,
+            checks,
+            view,
         } = self;
 
+        let create_view = match view {
+            Some(SchemaView {
+                query,
+                materialized,
+            }) => {
+                let create_view = Statement::CreateView {
+                    or_replace: false,
+                    materialized: *materialized,
+                    name: table_name.to_owned(),
+                    query: Box::new(query.to_owned()),
+                }
+                .to_sql();
+
+                // Rows of a materialized view are stored, so its columns are kept by CREATE TABLE
+                if !materialized {
+                    return create_view;
+                }
+
+                Some(create_view)
+            }
+            None => None,
+        };
+
         let create_table = Statement::CreateTable {
             if_not_exists: false,
+            temporary: false,
             name: table_name.to_owned(),
             columns: column_defs.to_owned(),
             engine: engine.to_owned(),
             source: None,
+            foreign_keys: foreign_keys.to_owned(),
+            checks: checks.to_owned(),
         }
         .to_sql();
 
@@ -61,6 +101,7 @@ impl Schema {
         });
 
         iter::once(create_table)
+            .chain(create_view)
             .chain(create_indexes)
             .collect::<Vec<_>>()
             .join("\n")
@@ -70,34 +111,40 @@ impl Schema {
         let created = Utc::now().naive_utc();
         let statements = parse(ddl)?;
 
-        let indexes = statements
-            .iter()
-            .skip(1)
-            .map(|create_index| {
-                let create_index = translate(create_index)?;
-                match create_index {
-                    Statement::CreateIndex {
+        let mut indexes = Vec::new();
+        let mut view = None;
+
+        for statement in statements.iter().skip(1) {
+            match translate(statement)? {
+                Statement::CreateIndex {
+                    name,
+                    column: OrderByExpr { expr, asc, .. },
+                    ..
+                } => {
+                    let order = asc
+                        .and_then(|bool| bool.then_some(SchemaIndexOrd::Asc))
+                        .unwrap_or(SchemaIndexOrd::Both);
+
+                    indexes.push(SchemaIndex {
                         name,
-                        column: OrderByExpr { expr, asc },
-                        ..
-                    } => {
-                        let order = asc
-                            .and_then(|bool| bool.then_some(SchemaIndexOrd::Asc))
-                            .unwrap_or(SchemaIndexOrd::Both);
-
-                        let index = SchemaIndex {
-                            name,
-                            expr,
-                            order,
-                            created,
-                        };
-
-                        Ok(index)
-                    }
-                    _ => Err(SchemaParseError::CannotParseDDL.into()),
+                        expr,
+                        order,
+                        created,
+                    });
                 }
-            })
-            .collect::<Result<Vec<_>>>()?;
+                Statement::CreateView {
+                    query,
+                    materialized: true,
+                    ..
+                } if view.is_none() => {
+                    view = Some(SchemaView {
+                        query: *query,
+                        materialized: true,
+                    });
+                }
+                _ => return Err(SchemaParseError::CannotParseDDL.into()),
+            }
+        }
 
         let create_table = statements.get(0).ok_or(SchemaParseError::CannotParseDDL)?;
         let create_table = translate(create_table)?;
@@ -107,12 +154,34 @@ impl Schema {
                 name,
                 columns,
                 engine,
+                foreign_keys,
+                checks,
                 ..
             } => Ok(Schema {
                 table_name: name,
                 column_defs: columns,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
+            }),
+            Statement::CreateView {
+                name,
+                query,
+                materialized: false,
+                ..
+            } if indexes.is_empty() && view.is_none() => Ok(Schema {
+                table_name: name,
+                column_defs: None,
+                indexes,
+                engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                view: Some(SchemaView {
+                    query: *query,
+                    materialized: false,
+                }),
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
         }
@@ -130,9 +199,13 @@ mod tests {
     use {
         super::SchemaParseError,
         crate::{
-            ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr},
+            ast::{
+                AstLiteral, BinaryOperator, CheckConstraint, ColumnDef, ColumnUniqueOption, Expr,
+                ForeignKey, Query, ReferentialAction, Select, SelectItem, SetExpr, TableFactor,
+                TableWithJoins,
+            },
             chrono::Utc,
-            data::{Schema, SchemaIndex, SchemaIndexOrd},
+            data::{Schema, SchemaIndex, SchemaIndexOrd, SchemaView},
             prelude::DataType,
         },
     };
@@ -143,7 +216,9 @@ mod tests {
             column_defs,
             indexes,
             engine,
-            ..
+            foreign_keys,
+            checks,
+            view,
         } = actual;
 
         let Schema {
@@ -151,12 +226,17 @@ mod tests {
             column_defs: column_defs_e,
             indexes: indexes_e,
             engine: engine_e,
-            ..
+            foreign_keys: foreign_keys_e,
+            checks: checks_e,
+            view: view_e,
         } = expected;
 
         assert_eq!(table_name, table_name_e);
         assert_eq!(column_defs, column_defs_e);
         assert_eq!(engine, engine_e);
+        assert_eq!(foreign_keys, foreign_keys_e);
+        assert_eq!(checks, checks_e);
+        assert_eq!(view, view_e);
         indexes
             .into_iter()
             .zip(indexes_e)
@@ -190,6 +270,7 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    collation: None,
                 },
                 ColumnDef {
                     name: "name".to_owned(),
@@ -197,10 +278,14 @@ mod tests {
                     nullable: true,
                     default: Some(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
                     unique: None,
+                    collation: None,
                 },
             ]),
             indexes: Vec::new(),
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'glue');"#;
@@ -214,6 +299,9 @@ mod tests {
             column_defs: None,
             indexes: Vec::new(),
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
         };
         let ddl = r#"CREATE TABLE "Test";"#;
         assert_eq!(schema.to_ddl(), ddl);
@@ -232,9 +320,13 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                collation: None,
             }]),
             indexes: Vec::new(),
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL PRIMARY KEY);"#;
@@ -244,6 +336,153 @@ mod tests {
         assert_schema(actual, schema);
     }
 
+    #[test]
+    fn table_with_foreign_key() {
+        let schema = Schema {
+            table_name: "Child".to_owned(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: "id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    unique: Some(ColumnUniqueOption { is_primary: true }),
+                    collation: None,
+                },
+                ColumnDef {
+                    name: "parent_id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                    collation: None,
+                },
+            ]),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: vec![ForeignKey {
+                name: "FK_parent_id-Parent_id".to_owned(),
+                referencing_column_name: "parent_id".to_owned(),
+                referenced_table_name: "Parent".to_owned(),
+                referenced_column_name: "id".to_owned(),
+                on_delete: ReferentialAction::Cascade,
+                on_update: ReferentialAction::NoAction,
+            }],
+            checks: Vec::new(),
+            view: None,
+        };
+
+        let ddl = r#"CREATE TABLE "Child" ("id" INT NOT NULL PRIMARY KEY, "parent_id" INT NULL, CONSTRAINT "FK_parent_id-Parent_id" FOREIGN KEY ("parent_id") REFERENCES "Parent" ("id") ON DELETE CASCADE ON UPDATE NO ACTION);"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn table_with_check() {
+        let schema = Schema {
+            table_name: "Item".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "price".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: None,
+                collation: None,
+            }]),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: vec![CheckConstraint {
+                name: Some("positive_price".to_owned()),
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("price".to_owned())),
+                    op: BinaryOperator::Gt,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(0.into()))),
+                },
+            }],
+            view: None,
+        };
+
+        let ddl = r#"CREATE TABLE "Item" ("price" INT NOT NULL, CONSTRAINT "positive_price" CHECK ("price" > 0));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn view() {
+        let query = Query {
+            with: None,
+            body: SetExpr::Select(Box::new(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Item".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: Some(Expr::Literal(AstLiteral::Number(10.into()))),
+            offset: None,
+        };
+
+        let schema = Schema {
+            table_name: "Recent".to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: Some(SchemaView {
+                query: query.clone(),
+                materialized: false,
+            }),
+        };
+
+        let ddl = r#"CREATE VIEW "Recent" AS SELECT * FROM "Item" LIMIT 10;"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+
+        let schema = Schema {
+            table_name: "Recent".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "id".to_owned(),
+                data_type: DataType::Int,
+                nullable: true,
+                default: None,
+                unique: None,
+                collation: None,
+            }]),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: Some(SchemaView {
+                query,
+                materialized: true,
+            }),
+        };
+
+        let ddl = r#"CREATE TABLE "Recent" ("id" INT NULL);
+CREATE MATERIALIZED VIEW "Recent" AS SELECT * FROM "Item" LIMIT 10;"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
     #[test]
     fn invalid_ddl() {
         // Only Statement::CreateTable is supported
@@ -263,6 +502,7 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    collation: None,
                 },
                 ColumnDef {
                     name: "name".to_owned(),
@@ -270,6 +510,7 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    collation: None,
                 },
             ]),
             indexes: vec![
@@ -287,6 +528,9 @@ mod tests {
                 },
             ],
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
 CREATE INDEX "User_id" ON "User" ("id");
@@ -313,6 +557,7 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    collation: None,
                 },
                 ColumnDef {
                     name: ";".to_owned(),
@@ -320,6 +565,7 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    collation: None,
                 },
             ]),
             indexes: vec![SchemaIndex {
@@ -329,6 +575,9 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                 created: Utc::now().naive_utc(),
             }],
             engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
 CREATE INDEX "." ON "1" (";");"#;
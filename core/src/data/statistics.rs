@@ -0,0 +1,24 @@
+use {
+    super::Value,
+    serde::{Deserialize, Serialize},
+};
+
+/// Statistics of a table collected by `ANALYZE`, the planner orders joins by them
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableStatistics {
+    pub row_count: usize,
+    /// Statistics of each column of the schema, empty for schemaless tables
+    pub columns: Vec<ColumnStatistics>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStatistics {
+    pub name: String,
+    /// Number of the distinct values which are not NULL
+    pub distinct_count: usize,
+    pub null_count: usize,
+    /// The least and the greatest of the values which are not NULL, `None` when the values
+    /// cannot be compared with each other or every value is NULL
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+}
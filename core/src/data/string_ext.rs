@@ -22,13 +22,63 @@ impl StringExt for str {
             }
         };
 
-        Ok(Regex::new(&format!(
-            "^{}$",
-            regex::escape(match_pattern.as_str())
-                .replace('%', ".*")
-                .replace('_', ".")
-        ))
-        .map_err(|_| StringExtError::UnreachablePatternParsing)?
-        .is_match(match_string.as_str()))
+        let mut chars = match_pattern.chars();
+        let mut regex_pattern = String::from("(?s)^");
+
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => regex_pattern.push_str(".*"),
+                '_' => regex_pattern.push('.'),
+                '\\' => {
+                    let escaped = chars.next().unwrap_or('\\');
+
+                    regex_pattern.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+                }
+                c => regex_pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+            }
+        }
+
+        regex_pattern.push('$');
+
+        Ok(Regex::new(&regex_pattern)
+            .map_err(|_| StringExtError::UnreachablePatternParsing)?
+            .is_match(match_string.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringExt;
+
+    #[test]
+    fn like() {
+        let test = |target: &str, pattern: &str, expected: bool| {
+            assert_eq!(
+                target.like(pattern, true).unwrap(),
+                expected,
+                "{target} LIKE {pattern}"
+            );
+        };
+
+        test("abc", "a%", true);
+        test("abc", "_b_", true);
+        test("abc", "%", true);
+        test("", "%", true);
+        test("abc", "__", false);
+        test("a\nb", "a_b", true);
+        test("a\nb", "a%", true);
+        test("100%", "100\\%", true);
+        test("1000", "100\\%", false);
+        test("a_c", "a\\_c", true);
+        test("abc", "a\\_c", false);
+        test("a\\b", "a\\\\b", true);
+        test("a\\", "a\\", true);
+        test("a.c", "a.c", true);
+        test("abc", "a.c", false);
+        test("a*c", "a*c", true);
+        test("(x)", "(%)", true);
+
+        assert!("ABC".like("a%", false).unwrap());
+        assert!(!"ABC".like("a%", true).unwrap());
     }
 }
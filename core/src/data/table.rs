@@ -28,6 +28,14 @@ pub fn get_alias(table_factor: &TableFactor) -> &String {
             alias: TableAlias { name, .. },
             ..
         }
+        | TableFactor::Unnest {
+            alias: TableAlias { name, .. },
+            ..
+        }
+        | TableFactor::Function {
+            alias: TableAlias { name, .. },
+            ..
+        }
         | TableFactor::Dictionary {
             alias: TableAlias { name, .. },
             ..
@@ -40,6 +48,8 @@ pub fn get_index(table_factor: &TableFactor) -> Option<&IndexItem> {
         TableFactor::Table { index, .. } => index.as_ref(),
         TableFactor::Derived { .. }
         | TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Function { .. }
         | TableFactor::Dictionary { .. } => None,
     }
 }
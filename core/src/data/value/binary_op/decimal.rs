@@ -14,6 +14,7 @@ impl PartialEq<Value> for Decimal {
     fn eq(&self, other: &Value) -> bool {
         match other {
             I8(other) => *self == Decimal::from(*other),
+            I16(other) => *self == Decimal::from(*other),
             I32(other) => *self == Decimal::from(*other),
             I64(other) => *self == Decimal::from(*other),
             I128(other) => *self == Decimal::from(*other),
@@ -38,6 +39,7 @@ impl PartialOrd<Value> for Decimal {
     fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
         match *other {
             I8(rhs) => self.partial_cmp(&(Decimal::from(rhs))),
+            I16(rhs) => self.partial_cmp(&(Decimal::from(rhs))),
             I32(rhs) => self.partial_cmp(&(Decimal::from(rhs))),
             I64(rhs) => self.partial_cmp(&(Decimal::from(rhs))),
             I128(rhs) => self.partial_cmp(&(Decimal::from(rhs))),
@@ -172,7 +172,16 @@ impl TryBinaryOperator for f32 {
             F64(rhs) => Ok(F32(lhs / rhs as f32)),
             F32(rhs) => Ok(F32(lhs / rhs)),
             Decimal(rhs) => Decimal::from_f32_retain(lhs)
-                .map(|x| Ok(Decimal(x * rhs)))
+                .map(|x| {
+                    x.checked_div(rhs).map(Decimal).ok_or_else(|| {
+                        ValueError::BinaryOperationOverflow {
+                            lhs: F32(lhs),
+                            rhs: Decimal(rhs),
+                            operator: NumericBinaryOperator::Divide,
+                        }
+                        .into()
+                    })
+                })
                 .unwrap_or_else(|| {
                     Err(ValueError::FloatToDecimalConversionFailure(lhs.into()).into())
                 }),
@@ -166,7 +166,16 @@ impl TryBinaryOperator for f64 {
             F32(rhs) => Ok(F64(lhs / rhs as f64)),
             F64(rhs) => Ok(F64(lhs / rhs)),
             Decimal(rhs) => Decimal::from_f64_retain(lhs)
-                .map(|x| Ok(Decimal(x * rhs)))
+                .map(|x| {
+                    x.checked_div(rhs).map(Decimal).ok_or_else(|| {
+                        ValueError::BinaryOperationOverflow {
+                            lhs: F64(lhs),
+                            rhs: Decimal(rhs),
+                            operator: NumericBinaryOperator::Divide,
+                        }
+                        .into()
+                    })
+                })
                 .unwrap_or_else(|| Err(ValueError::FloatToDecimalConversionFailure(lhs).into())),
             Null => Ok(Null),
             _ => Err(ValueError::NonNumericMathOperation {
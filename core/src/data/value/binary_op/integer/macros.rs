@@ -13,7 +13,7 @@ macro_rules! impl_interval_method {
 }
 
 macro_rules! impl_method {
-    ($lhs_variant: ident, $lhs_primitive: ident, $lhs: ident, $method: ident, $op: ident, $rhs: ident) => {{
+    ($lhs_variant: ident, $lhs_primitive: ident, $lhs: ident, $method: ident, $try_method: ident, $op: ident, $rhs: ident) => {{
         match *$rhs {
             I8(rhs) => $lhs
                 .$method($lhs_primitive::try_from($rhs)?)
@@ -115,36 +115,24 @@ macro_rules! impl_method {
                     }
                     .into()
                 }),
-            F32(rhs) => $lhs
-                .$method($lhs_primitive::try_from($rhs)?)
-                .ok_or_else(|| {
-                    ValueError::BinaryOperationOverflow {
-                        lhs: $lhs_variant($lhs),
-                        rhs: F32(rhs),
-                        operator: $op,
-                    }
-                    .into()
-                }),
-            F64(rhs) => $lhs
-                .$method($lhs_primitive::try_from($rhs)?)
-                .ok_or_else(|| {
-                    ValueError::BinaryOperationOverflow {
-                        lhs: $lhs_variant($lhs),
-                        rhs: F64(rhs),
-                        operator: $op,
-                    }
-                    .into()
-                }),
-            Decimal(rhs) => $lhs
-                .$method($lhs_primitive::try_from($rhs)?)
-                .ok_or_else(|| {
-                    ValueError::BinaryOperationOverflow {
+            F32(_) | F64(_) => return ($lhs as f64).$try_method($rhs),
+            Decimal(rhs) => {
+                use rust_decimal::prelude::ToPrimitive;
+
+                let lhs = $lhs
+                    .to_i128()
+                    .and_then(|v| rust_decimal::Decimal::try_from_i128_with_scale(v, 0).ok());
+
+                return match lhs {
+                    Some(lhs) => lhs.$try_method(&Decimal(rhs)),
+                    None => Err(ValueError::BinaryOperationOverflow {
                         lhs: $lhs_variant($lhs),
                         rhs: Decimal(rhs),
                         operator: $op,
                     }
-                    .into()
-                }),
+                    .into()),
+                };
+            }
             Null => return Ok(Null),
             Interval(rhs) => {
                 super::macros::impl_interval_method!($method, $lhs_variant, $op, $lhs, rhs);
@@ -176,27 +164,67 @@ macro_rules! impl_try_binary_op {
 
             fn try_add(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_add, Add, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_add,
+                    try_add,
+                    Add,
+                    rhs
+                )
             }
 
             fn try_subtract(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_sub, Subtract, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_sub,
+                    try_subtract,
+                    Subtract,
+                    rhs
+                )
             }
 
             fn try_multiply(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_mul, Multiply, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_mul,
+                    try_multiply,
+                    Multiply,
+                    rhs
+                )
             }
 
             fn try_divide(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_div, Divide, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_div,
+                    try_divide,
+                    Divide,
+                    rhs
+                )
             }
 
             fn try_modulo(&self, rhs: &Self::Rhs) -> Result<Value> {
                 let lhs = *self;
-                super::macros::impl_method!($variant, $primitive, lhs, checked_rem, Modulo, rhs)
+                super::macros::impl_method!(
+                    $variant,
+                    $primitive,
+                    lhs,
+                    checked_rem,
+                    try_modulo,
+                    Modulo,
+                    rhs
+                )
             }
         }
     };
@@ -233,18 +261,6 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn add_overflow() {
-                assert_eq!(
-                    $primitive::MAX.try_add(&Decimal(Decimal::from(1))),
-                    overflow_err($variant($primitive::MAX), Decimal(Decimal::from(1)), Add)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_add(&F32(1.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(1.0_f32), Add)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_add(&F64(1.0)),
-                    overflow_err($variant($primitive::MAX), F64(1.0), Add)
-                );
                 assert_eq!(
                     $primitive::MAX.try_add(&I8(1)),
                     overflow_err($variant($primitive::MAX), I8(1), Add)
@@ -289,22 +305,6 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn sub_overflow() {
-                assert_eq!(
-                    $primitive::MIN.try_subtract(&Decimal(Decimal::from(1))),
-                    overflow_err(
-                        $variant($primitive::MIN),
-                        Decimal(Decimal::from(1)),
-                        Subtract
-                    )
-                );
-                assert_eq!(
-                    $primitive::MIN.try_subtract(&F32(1.0_f32)),
-                    overflow_err($variant($primitive::MIN), F32(1.0_f32), Subtract)
-                );
-                assert_eq!(
-                    $primitive::MIN.try_subtract(&F64(1.0)),
-                    overflow_err($variant($primitive::MIN), F64(1.0), Subtract)
-                );
                 assert_eq!(
                     $primitive::MIN.try_subtract(&I8(1)),
                     overflow_err($variant($primitive::MIN), I8(1), Subtract)
@@ -349,22 +349,6 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn mul_overflow() {
-                assert_eq!(
-                    $primitive::MAX.try_multiply(&Decimal(Decimal::from(2))),
-                    overflow_err(
-                        $variant($primitive::MAX),
-                        Decimal(Decimal::from(2)),
-                        Multiply
-                    )
-                );
-                assert_eq!(
-                    $primitive::MAX.try_multiply(&F32(2.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(2.0_f32), Multiply)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_multiply(&F64(2.0)),
-                    overflow_err($variant($primitive::MAX), F64(2.0), Multiply)
-                );
                 assert_eq!(
                     $primitive::MAX.try_multiply(&I8(2)),
                     overflow_err($variant($primitive::MAX), I8(2), Multiply)
@@ -409,18 +393,6 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn div_overflow() {
-                assert_eq!(
-                    $primitive::MAX.try_divide(&Decimal(Decimal::from(0))),
-                    overflow_err($variant($primitive::MAX), Decimal(Decimal::from(0)), Divide)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_divide(&F32(0.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(0.0_f32), Divide)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_divide(&F64(0.0)),
-                    overflow_err($variant($primitive::MAX), F64(0.0), Divide)
-                );
                 assert_eq!(
                     $primitive::MAX.try_divide(&I8(0)),
                     overflow_err($variant($primitive::MAX), I8(0), Divide)
@@ -465,18 +437,6 @@ macro_rules! generate_binary_op_tests {
 
             #[test]
             fn mod_overflow() {
-                assert_eq!(
-                    $primitive::MAX.try_modulo(&Decimal(Decimal::from(0))),
-                    overflow_err($variant($primitive::MAX), Decimal(Decimal::from(0)), Modulo)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_modulo(&F32(0.0_f32)),
-                    overflow_err($variant($primitive::MAX), F32(0.0_f32), Modulo)
-                );
-                assert_eq!(
-                    $primitive::MAX.try_modulo(&F64(0.0)),
-                    overflow_err($variant($primitive::MAX), F64(0.0), Modulo)
-                );
                 assert_eq!(
                     $primitive::MAX.try_modulo(&I8(0)),
                     overflow_err($variant($primitive::MAX), I8(0), Modulo)
@@ -519,13 +479,41 @@ macro_rules! generate_binary_op_tests {
                 );
             }
 
+            #[test]
+            fn fractional_rhs() {
+                let base: $primitive = 3;
+
+                assert_eq!(base.try_multiply(&F64(1.5)), Ok(F64(4.5)));
+                assert_eq!(base.try_divide(&F32(2.0_f32)), Ok(F64(1.5)));
+                assert_eq!(
+                    base.try_add(&Decimal(Decimal::new(5, 1))),
+                    Ok(Decimal(Decimal::new(35, 1)))
+                );
+                assert_eq!(
+                    $primitive::MAX.try_add(&F64(1.0)),
+                    Ok(F64($primitive::MAX as f64 + 1.0))
+                );
+                assert_eq!(
+                    base.try_divide(&Decimal(Decimal::ZERO)),
+                    Err(ValueError::BinaryOperationOverflow {
+                        lhs: Decimal(Decimal::from(3)),
+                        rhs: Decimal(Decimal::ZERO),
+                        operator: Divide,
+                    }
+                    .into())
+                );
+            }
+
             #[test]
             fn try_add() {
                 let base: $primitive = 1;
 
-                assert_eq!(base.try_add(&Decimal(Decimal::ONE)), Ok($variant(2)));
-                assert_eq!(base.try_add(&F32(1.0_f32)), Ok($variant(2)));
-                assert_eq!(base.try_add(&F64(1.0)), Ok($variant(2)));
+                assert_eq!(
+                    base.try_add(&Decimal(Decimal::ONE)),
+                    Ok(Decimal(Decimal::TWO))
+                );
+                assert_eq!(base.try_add(&F32(1.0_f32)), Ok(F64(2.0)));
+                assert_eq!(base.try_add(&F64(1.0)), Ok(F64(2.0)));
                 assert_eq!(base.try_add(&I8(1)), Ok($variant(2)));
                 assert_eq!(base.try_add(&I16(1)), Ok($variant(2)));
                 assert_eq!(base.try_add(&I32(1)), Ok($variant(2)));
@@ -552,9 +540,12 @@ macro_rules! generate_binary_op_tests {
             fn try_subtract() {
                 let base: $primitive = 1;
 
-                assert_eq!(base.try_subtract(&Decimal(Decimal::ONE)), Ok($variant(0)));
-                assert_eq!(base.try_subtract(&F32(1.0_f32)), Ok($variant(0)));
-                assert_eq!(base.try_subtract(&F64(1.0)), Ok($variant(0)));
+                assert_eq!(
+                    base.try_subtract(&Decimal(Decimal::ONE)),
+                    Ok(Decimal(Decimal::ZERO))
+                );
+                assert_eq!(base.try_subtract(&F32(1.0_f32)), Ok(F64(0.0)));
+                assert_eq!(base.try_subtract(&F64(1.0)), Ok(F64(0.0)));
                 assert_eq!(base.try_subtract(&I8(1)), Ok($variant(0)));
                 assert_eq!(base.try_subtract(&I16(1)), Ok($variant(0)));
                 assert_eq!(base.try_subtract(&I32(1)), Ok($variant(0)));
@@ -581,9 +572,12 @@ macro_rules! generate_binary_op_tests {
             fn try_multiply() {
                 let base: $primitive = 3;
 
-                assert_eq!(base.try_multiply(&Decimal(Decimal::TWO)), Ok($variant(6)));
-                assert_eq!(base.try_multiply(&F32(2.0_f32)), Ok($variant(6)));
-                assert_eq!(base.try_multiply(&F64(2.0)), Ok($variant(6)));
+                assert_eq!(
+                    base.try_multiply(&Decimal(Decimal::TWO)),
+                    Ok(Decimal(Decimal::from(6)))
+                );
+                assert_eq!(base.try_multiply(&F32(2.0_f32)), Ok(F64(6.0)));
+                assert_eq!(base.try_multiply(&F64(2.0)), Ok(F64(6.0)));
                 assert_eq!(base.try_multiply(&I8(2)), Ok($variant(6)));
                 assert_eq!(base.try_multiply(&I16(2)), Ok($variant(6)));
                 assert_eq!(base.try_multiply(&I32(2)), Ok($variant(6)));
@@ -610,9 +604,12 @@ macro_rules! generate_binary_op_tests {
             fn try_divide() {
                 let base: $primitive = 6;
 
-                assert_eq!(base.try_divide(&Decimal(Decimal::TWO)), Ok($variant(3)));
-                assert_eq!(base.try_divide(&F32(2.0_f32)), Ok($variant(3)));
-                assert_eq!(base.try_divide(&F64(2.0)), Ok($variant(3)));
+                assert_eq!(
+                    base.try_divide(&Decimal(Decimal::TWO)),
+                    Ok(Decimal(Decimal::from(3)))
+                );
+                assert_eq!(base.try_divide(&F32(2.0_f32)), Ok(F64(3.0)));
+                assert_eq!(base.try_divide(&F64(2.0)), Ok(F64(3.0)));
                 assert_eq!(base.try_divide(&I8(2)), Ok($variant(3)));
                 assert_eq!(base.try_divide(&I16(2)), Ok($variant(3)));
                 assert_eq!(base.try_divide(&I32(2)), Ok($variant(3)));
@@ -639,9 +636,12 @@ macro_rules! generate_binary_op_tests {
             fn try_modulo() {
                 let base: $primitive = 9;
 
-                assert_eq!(base.try_modulo(&Decimal(Decimal::ONE)), Ok($variant(0)));
-                assert_eq!(base.try_modulo(&F32(1.0_f32)), Ok($variant(0)));
-                assert_eq!(base.try_modulo(&F64(1.0)), Ok($variant(0)));
+                assert_eq!(
+                    base.try_modulo(&Decimal(Decimal::ONE)),
+                    Ok(Decimal(Decimal::ZERO))
+                );
+                assert_eq!(base.try_modulo(&F32(1.0_f32)), Ok(F64(0.0)));
+                assert_eq!(base.try_modulo(&F64(1.0)), Ok(F64(0.0)));
                 assert_eq!(base.try_modulo(&I8(1)), Ok($variant(0)));
                 assert_eq!(base.try_modulo(&I16(1)), Ok($variant(0)));
                 assert_eq!(base.try_modulo(&I32(1)), Ok($variant(0)));
@@ -671,8 +671,12 @@ macro_rules! impl_partial_cmp_ord_method {
     ($primitive: ident) => {
         impl PartialEq<Value> for $primitive {
             fn eq(&self, other: &Value) -> bool {
-                if matches!(other, Value::Bool(_)) {
-                    return false;
+                match other {
+                    Value::Bool(_) => return false,
+                    Value::F32(_) | Value::F64(_) | Value::Decimal(_) => {
+                        return self.partial_cmp(other) == Some(Ordering::Equal);
+                    }
+                    _ => {}
                 }
 
                 let lhs = *self;
@@ -687,8 +691,19 @@ macro_rules! impl_partial_cmp_ord_method {
 
         impl PartialOrd<Value> for $primitive {
             fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
-                if matches!(other, Value::Bool(_)) {
-                    return None;
+                use rust_decimal::prelude::{Decimal, ToPrimitive};
+
+                match other {
+                    Value::Bool(_) => return None,
+                    Value::F32(rhs) => return (*self as f64).partial_cmp(&(*rhs as f64)),
+                    Value::F64(rhs) => return (*self as f64).partial_cmp(rhs),
+                    Value::Decimal(rhs) => {
+                        return self
+                            .to_i128()
+                            .and_then(|v| Decimal::try_from_i128_with_scale(v, 0).ok())
+                            .and_then(|lhs| lhs.partial_cmp(rhs));
+                    }
+                    _ => {}
                 }
 
                 let lhs = self;
@@ -730,6 +745,8 @@ macro_rules! generate_cmp_ord_tests {
                 assert_eq!(base, U128(1));
 
                 assert_ne!(base, Bool(true));
+                assert_ne!(base, F64(1.5));
+                assert_ne!(base, Decimal(Decimal::new(15, 1)));
             }
 
             #[test]
@@ -787,6 +804,13 @@ macro_rules! generate_cmp_ord_tests {
                 assert_eq!(base.partial_cmp(&U64(2)), Some(Ordering::Less));
                 assert_eq!(base.partial_cmp(&U128(2)), Some(Ordering::Less));
 
+                assert_eq!(base.partial_cmp(&F64(1.5)), Some(Ordering::Less));
+                assert_eq!(base.partial_cmp(&F32(0.5_f32)), Some(Ordering::Greater));
+                assert_eq!(
+                    base.partial_cmp(&Decimal(Decimal::new(15, 1))),
+                    Some(Ordering::Less)
+                );
+
                 assert_eq!(base.partial_cmp(&Bool(true)), None);
             }
         }
@@ -735,6 +735,7 @@ impl TryFrom<&Value> for NaiveTime {
     fn try_from(v: &Value) -> Result<NaiveTime> {
         Ok(match v {
             Value::Time(value) => *value,
+            Value::Timestamp(value) => value.time(),
             Value::Str(value) => parse_time(value).ok_or(ValueError::ImpossibleCast)?,
             _ => return Err(ValueError::ImpossibleCast.into()),
         })
@@ -1940,6 +1941,10 @@ mod tests {
 
         test!(&Value::Time(time(10, 0, 0, 0)), Ok(time(10, 0, 0, 0)));
         test!(&Value::Str("10:00:00".to_owned()), Ok(time(10, 0, 0, 0)));
+        test!(
+            &Value::Timestamp(date(2021, 11, 20).and_time(time(10, 0, 0, 0))),
+            Ok(time(10, 0, 0, 0))
+        );
         test!(&Value::F32(1.0_f32), Err(ValueError::ImpossibleCast.into()));
         test!(&Value::F64(1.0), Err(ValueError::ImpossibleCast.into()));
     }
@@ -0,0 +1,81 @@
+use {
+    super::ValueError,
+    crate::result::{Error, Result},
+    rust_decimal::{Decimal, RoundingStrategy},
+};
+
+/// `DECIMAL(precision, scale)` takes values with at most `scale` digits after the decimal
+/// point and `precision - scale` digits before it.
+pub fn validate_decimal(v: &Decimal, precision: u32, scale: u32) -> Result<()> {
+    let limit = Decimal::from_i128_with_scale(10_i128.pow(precision - scale), 0);
+
+    match v.round_dp(scale) == *v && v.abs() < limit {
+        true => Ok(()),
+        false => Err(Error::Value(ValueError::DecimalOutOfRange {
+            value: v.to_string(),
+            precision,
+            scale,
+        })),
+    }
+}
+
+/// Rounds to the declared scale, half away from zero, before the value is validated.
+pub fn round_decimal(v: Decimal, precision_scale: Option<(u32, u32)>) -> Result<Decimal> {
+    match precision_scale {
+        Some((precision, scale)) => {
+            let v = v.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+
+            validate_decimal(&v, precision, scale).map(|_| v)
+        }
+        None => Ok(v),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{round_decimal, validate_decimal},
+        crate::data::value::ValueError,
+        rust_decimal::Decimal,
+    };
+
+    #[test]
+    fn validate_decimal_and_round_decimal() {
+        let out_of_range = |value: &str, precision, scale| {
+            Err(ValueError::DecimalOutOfRange {
+                value: value.to_owned(),
+                precision,
+                scale,
+            }
+            .into())
+        };
+
+        assert_eq!(validate_decimal(&Decimal::new(12345, 2), 5, 2), Ok(()));
+        assert_eq!(validate_decimal(&Decimal::new(-99, 0), 2, 0), Ok(()));
+        assert_eq!(
+            validate_decimal(&Decimal::new(123456, 3), 5, 2),
+            out_of_range("123.456", 5, 2)
+        );
+        assert_eq!(
+            validate_decimal(&Decimal::new(100, 0), 4, 2),
+            out_of_range("100", 4, 2)
+        );
+
+        assert_eq!(
+            round_decimal(Decimal::new(123455, 3), Some((5, 2))),
+            Ok(Decimal::new(12346, 2))
+        );
+        assert_eq!(
+            round_decimal(Decimal::new(-5, 1), Some((1, 0))),
+            Ok(Decimal::new(-1, 0))
+        );
+        assert_eq!(
+            round_decimal(Decimal::new(9995, 1), Some((3, 0))),
+            out_of_range("1000", 3, 0)
+        );
+        assert_eq!(
+            round_decimal(Decimal::new(123456, 3), None),
+            Ok(Decimal::new(123456, 3))
+        );
+    }
+}
@@ -47,6 +47,16 @@ pub enum ValueError {
     #[error("failed to parse Decimal: {0}")]
     FailedToParseDecimal(String),
 
+    #[error("integer {value} is out of range for {data_type}")]
+    IntegerOutOfRange { data_type: DataType, value: String },
+
+    #[error("decimal {value} is out of range for DECIMAL({precision}, {scale})")]
+    DecimalOutOfRange {
+        value: String,
+        precision: u32,
+        scale: u32,
+    },
+
     #[error("failed to parse hex string: {0}")]
     FailedToParseHexString(String),
 
@@ -69,6 +79,9 @@ pub enum ValueError {
     #[error("unary minus operation for non numeric value")]
     UnaryMinusOnNonNumeric,
 
+    #[error("unary minus operation overflow")]
+    UnaryMinusOverflow,
+
     #[error("unary factorial operation for non numeric value")]
     FactorialOnNonNumeric,
 
@@ -38,6 +38,13 @@ impl Value {
         HashMap::parse_json_object(value).map(Value::Map)
     }
 
+    /// Parses any JSON document, including scalars, into a value
+    pub fn parse_json(value: &str) -> Result<Value> {
+        serde_json::from_str::<JsonValue>(value)
+            .map_err(|_| ValueError::InvalidJsonString(value.to_owned()))?
+            .try_into()
+    }
+
     pub fn parse_json_list(value: &str) -> Result<Value> {
         let value = serde_json::from_str(value)
             .map_err(|_| ValueError::InvalidJsonString(value.to_owned()))?;
@@ -150,6 +157,19 @@ mod tests {
             Value::parse_json_list(r#"{ "a": 30 }"#),
             Err(ValueError::JsonArrayTypeRequired.into())
         );
+        assert_eq!(
+            Value::parse_json(r#"[1, "a", null]"#),
+            Ok(Value::List(vec![
+                Value::I64(1),
+                Value::Str("a".to_owned()),
+                Value::Null
+            ]))
+        );
+        assert_eq!(Value::parse_json("1.5"), Ok(Value::F64(1.5)));
+        assert_eq!(
+            Value::parse_json("{ a: 1 }"),
+            Err(ValueError::InvalidJsonString("{ a: 1 }".to_owned()).into())
+        );
     }
 
     #[test]
@@ -1,6 +1,7 @@
 use {
     super::{
         date::{parse_date, parse_time, parse_timestamp},
+        decimal::{round_decimal, validate_decimal},
         error::ValueError,
         Value,
     },
@@ -10,7 +11,7 @@ use {
         result::{Error, Result},
     },
     bigdecimal::BigDecimal,
-    chrono::NaiveDate,
+    chrono::{NaiveDate, NaiveDateTime},
     rust_decimal::Decimal,
     std::{
         cmp::Ordering,
@@ -48,27 +49,54 @@ impl TryFrom<Literal<'_>> for Value {
     }
 }
 
+/// Text given for `BYTEA` is read as hex, with or without the `\x` prefix of PostgreSQL.
+pub(super) fn parse_bytea(v: &str) -> Option<Vec<u8>> {
+    hex::decode(v.strip_prefix("\\x").unwrap_or(v)).ok()
+}
+
+enum DateOrTimestamp {
+    Date(NaiveDate),
+    Timestamp(NaiveDateTime),
+}
+
+/// Text compared with a `DATE` value is read as a date, or as a timestamp when it carries a
+/// time part, in which case the date is taken at midnight.
+fn parse_date_or_timestamp(v: &str) -> Option<DateOrTimestamp> {
+    match v.parse::<NaiveDate>() {
+        Ok(v) => Some(DateOrTimestamp::Date(v)),
+        Err(_) => parse_timestamp(v).map(DateOrTimestamp::Timestamp),
+    }
+}
+
 impl Value {
     pub fn evaluate_eq_with_literal(&self, other: &Literal<'_>) -> bool {
         match (self, other) {
             (Value::Bool(l), Literal::Boolean(r)) => l == r,
-            (Value::I8(l), Literal::Number(r)) => r.to_i8().map(|r| *l == r).unwrap_or(false),
-            (Value::I16(l), Literal::Number(r)) => r.to_i16().map(|r| *l == r).unwrap_or(false),
-            (Value::I32(l), Literal::Number(r)) => r.to_i32().map(|r| *l == r).unwrap_or(false),
-            (Value::I64(l), Literal::Number(r)) => r.to_i64().map(|r| *l == r).unwrap_or(false),
-            (Value::I128(l), Literal::Number(r)) => r.to_i128().map(|r| *l == r).unwrap_or(false),
-            (Value::U8(l), Literal::Number(r)) => r.to_u8().map(|r| *l == r).unwrap_or(false),
-            (Value::U16(l), Literal::Number(r)) => r.to_u16().map(|r| *l == r).unwrap_or(false),
-            (Value::U32(l), Literal::Number(r)) => r.to_u32().map(|r| *l == r).unwrap_or(false),
-            (Value::U64(l), Literal::Number(r)) => r.to_u64().map(|r| *l == r).unwrap_or(false),
-            (Value::U128(l), Literal::Number(r)) => r.to_u128().map(|r| *l == r).unwrap_or(false),
+            (Value::I8(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::I16(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::I32(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::I64(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::I128(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::U8(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::U16(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::U32(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::U64(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
+            (Value::U128(l), Literal::Number(r)) => &BigDecimal::from(*l) == r.as_ref(),
             (Value::F32(l), Literal::Number(r)) => r.to_f32().map(|r| *l == r).unwrap_or(false),
             (Value::F64(l), Literal::Number(r)) => r.to_f64().map(|r| *l == r).unwrap_or(false),
+            (Value::Decimal(l), Literal::Number(r)) => {
+                &BigDecimal::new(l.mantissa().into(), l.scale() as i64) == r.as_ref()
+            }
             (Value::Str(l), Literal::Text(r)) => l == r.as_ref(),
             (Value::Bytea(l), Literal::Bytea(r)) => l == r,
-            (Value::Date(l), Literal::Text(r)) => match r.parse::<NaiveDate>() {
-                Ok(r) => l == &r,
-                Err(_) => false,
+            (Value::Bytea(l), Literal::Text(r)) => parse_bytea(r).map(|r| l == &r).unwrap_or(false),
+            (Value::Date(l), Literal::Text(r)) => match parse_date_or_timestamp(r) {
+                Some(DateOrTimestamp::Date(r)) => l == &r,
+                Some(DateOrTimestamp::Timestamp(r)) => l
+                    .and_hms_opt(0, 0, 0)
+                    .map(|date_time| date_time == r)
+                    .unwrap_or(false),
+                None => false,
             },
             (Value::Timestamp(l), Literal::Text(r)) => match parse_timestamp(r) {
                 Some(r) => l == &r,
@@ -93,29 +121,38 @@ impl Value {
                 }
             }
             (Value::Null, Literal::Null) => false,
+            (_, Literal::Text(_)) => self
+                .coerce_text_literal(other)
+                .map(|other| self.evaluate_eq(&other))
+                .unwrap_or(false),
             _ => false,
         }
     }
 
     pub fn evaluate_cmp_with_literal(&self, other: &Literal<'_>) -> Option<Ordering> {
         match (self, other) {
-            (Value::I8(l), Literal::Number(r)) => l.partial_cmp(&r.to_i8()?),
-            (Value::I16(l), Literal::Number(r)) => l.partial_cmp(&r.to_i16()?),
-            (Value::I32(l), Literal::Number(r)) => l.partial_cmp(&r.to_i32()?),
-            (Value::I64(l), Literal::Number(r)) => l.partial_cmp(&r.to_i64()?),
-            (Value::I128(l), Literal::Number(r)) => l.partial_cmp(&r.to_i128()?),
-            (Value::U8(l), Literal::Number(r)) => l.partial_cmp(&r.to_u8()?),
-            (Value::U16(l), Literal::Number(r)) => l.partial_cmp(&r.to_u16()?),
-            (Value::U32(l), Literal::Number(r)) => l.partial_cmp(&r.to_u32()?),
-            (Value::U64(l), Literal::Number(r)) => l.partial_cmp(&r.to_u64()?),
-            (Value::U128(l), Literal::Number(r)) => l.partial_cmp(&r.to_u128()?),
+            (Value::I8(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::I16(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::I32(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::I64(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::I128(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::U8(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::U16(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::U32(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::U64(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
+            (Value::U128(l), Literal::Number(r)) => BigDecimal::from(*l).partial_cmp(r),
             (Value::F32(l), Literal::Number(r)) => l.partial_cmp(&r.to_f32()?),
             (Value::F64(l), Literal::Number(r)) => l.partial_cmp(&r.to_f64()?),
             (Value::Decimal(l), Literal::Number(r)) => {
                 BigDecimal::new(l.mantissa().into(), l.scale() as i64).partial_cmp(r)
             }
             (Value::Str(l), Literal::Text(r)) => Some(l.as_str().cmp(r)),
-            (Value::Date(l), Literal::Text(r)) => l.partial_cmp(&r.parse::<NaiveDate>().ok()?),
+            (Value::Bytea(l), Literal::Bytea(r)) => Some(l.cmp(r)),
+            (Value::Bytea(l), Literal::Text(r)) => Some(l.cmp(&parse_bytea(r)?)),
+            (Value::Date(l), Literal::Text(r)) => match parse_date_or_timestamp(r)? {
+                DateOrTimestamp::Date(r) => l.partial_cmp(&r),
+                DateOrTimestamp::Timestamp(r) => l.and_hms_opt(0, 0, 0)?.partial_cmp(&r),
+            },
             (Value::Timestamp(l), Literal::Text(r)) => l.partial_cmp(&parse_timestamp(r)?),
             (Value::Time(l), Literal::Text(r)) => l.partial_cmp(&parse_time(r)?),
             (Value::Uuid(l), Literal::Text(r)) => l.partial_cmp(&parse_uuid(r).ok()?),
@@ -129,10 +166,19 @@ impl Value {
                     None
                 }
             }
+            (_, Literal::Text(_)) => self.evaluate_cmp(&self.coerce_text_literal(other)?),
             _ => None,
         }
     }
 
+    /// Reads a text literal as the type of the value, as `CAST` would, so that `'10'` and
+    /// `'true'` compare with numbers and booleans.
+    fn coerce_text_literal(&self, literal: &Literal<'_>) -> Option<Value> {
+        let data_type = self.get_type()?;
+
+        Value::try_cast_from_literal(&data_type, literal).ok()
+    }
+
     pub fn try_from_literal(data_type: &DataType, literal: &Literal<'_>) -> Result<Value> {
         match (data_type, literal) {
             (DataType::Boolean, Literal::Boolean(v)) => Ok(Value::Bool(*v)),
@@ -186,9 +232,9 @@ impl Value {
                 .ok_or_else(|| ValueError::UnreachableNumberParsing.into()),
             (DataType::Text, Literal::Text(v)) => Ok(Value::Str(v.to_string())),
             (DataType::Bytea, Literal::Bytea(v)) => Ok(Value::Bytea(v.to_vec())),
-            (DataType::Bytea, Literal::Text(v)) => hex::decode(v.as_ref())
+            (DataType::Bytea, Literal::Text(v)) => parse_bytea(v)
                 .map(Value::Bytea)
-                .map_err(|_| ValueError::FailedToParseHexString(v.to_string()).into()),
+                .ok_or_else(|| ValueError::FailedToParseHexString(v.to_string()).into()),
             (DataType::Inet, Literal::Text(v)) => IpAddr::from_str(v.as_ref())
                 .map(Value::Inet)
                 .map_err(|_| ValueError::FailedToParseInetString(v.to_string()).into()),
@@ -215,11 +261,21 @@ impl Value {
             (DataType::Uuid, Literal::Bytea(v)) => parse_uuid(&hex::encode(v)).map(Value::Uuid),
             (DataType::Map, Literal::Text(v)) => Value::parse_json_map(v),
             (DataType::List, Literal::Text(v)) => Value::parse_json_list(v),
-            (DataType::Decimal, Literal::Number(v)) => v
-                .to_string()
-                .parse::<Decimal>()
-                .map(Value::Decimal)
-                .map_err(|_| ValueError::FailedToParseDecimal(v.to_string()).into()),
+            (
+                data_type @ (DataType::Decimal | DataType::DecimalPrecision(..)),
+                Literal::Number(v),
+            ) => {
+                let v = v
+                    .to_string()
+                    .parse::<Decimal>()
+                    .map_err(|_| ValueError::FailedToParseDecimal(v.to_string()))?;
+
+                if let Some((precision, scale)) = data_type.decimal_precision() {
+                    validate_decimal(&v, precision, scale)?;
+                }
+
+                Ok(Value::Decimal(v))
+            }
             (_, Literal::Null) => Ok(Value::Null),
             _ => Err(ValueError::IncompatibleLiteralForDataType {
                 data_type: data_type.clone(),
@@ -410,19 +466,30 @@ impl Value {
 
                 Ok(Value::F64(v))
             }
-            (DataType::Decimal, Literal::Text(v)) => v
+            (
+                data_type @ (DataType::Decimal | DataType::DecimalPrecision(..)),
+                Literal::Text(v),
+            ) => v
                 .parse::<Decimal>()
-                .map(Value::Decimal)
-                .map_err(|_| ValueError::LiteralCastFromTextToDecimalFailed(v.to_string()).into()),
-            (DataType::Decimal, Literal::Number(v)) => v
+                .map_err(|_| ValueError::LiteralCastFromTextToDecimalFailed(v.to_string()).into())
+                .and_then(|v| round_decimal(v, data_type.decimal_precision()))
+                .map(Value::Decimal),
+            (
+                data_type @ (DataType::Decimal | DataType::DecimalPrecision(..)),
+                Literal::Number(v),
+            ) => v
                 .to_string()
                 .parse::<Decimal>()
-                .map(Value::Decimal)
-                .map_err(|_| ValueError::LiteralCastFromTextToDecimalFailed(v.to_string()).into()),
-            (DataType::Decimal, Literal::Boolean(v)) => {
+                .map_err(|_| ValueError::LiteralCastFromTextToDecimalFailed(v.to_string()).into())
+                .and_then(|v| round_decimal(v, data_type.decimal_precision()))
+                .map(Value::Decimal),
+            (
+                data_type @ (DataType::Decimal | DataType::DecimalPrecision(..)),
+                Literal::Boolean(v),
+            ) => {
                 let v = if *v { Decimal::ONE } else { Decimal::ZERO };
 
-                Ok(Value::Decimal(v))
+                round_decimal(v, data_type.decimal_precision()).map(Value::Decimal)
             }
 
             (DataType::Text, Literal::Number(v)) => Ok(Value::Str(v.to_string())),
@@ -449,7 +516,7 @@ impl Value {
             | (DataType::Uint128, Literal::Null)
             | (DataType::Float32, Literal::Null)
             | (DataType::Float, Literal::Null)
-            | (DataType::Decimal, Literal::Null)
+            | (DataType::Decimal | DataType::DecimalPrecision(..), Literal::Null)
             | (DataType::Text, Literal::Null) => Ok(Value::Null),
             (DataType::Date, Literal::Text(v)) => parse_date(v)
                 .map(Value::Date)
@@ -549,10 +616,16 @@ mod tests {
         assert!(Value::U32(64).evaluate_eq_with_literal(num!("64")));
         assert!(Value::U64(64).evaluate_eq_with_literal(num!("64")));
         assert!(Value::U128(64).evaluate_eq_with_literal(num!("64")));
+        assert!(!Value::I64(1).evaluate_eq_with_literal(num!("1.5")));
+        assert!(Value::Decimal(Decimal::new(20, 1)).evaluate_eq_with_literal(num!("2")));
+        assert!(!Value::Decimal(Decimal::new(25, 1)).evaluate_eq_with_literal(num!("2")));
         assert!(Value::F32(7.123).evaluate_eq_with_literal(num!("7.123")));
         assert!(Value::F64(7.123).evaluate_eq_with_literal(num!("7.123")));
         assert!(Value::Str("Hello".to_owned()).evaluate_eq_with_literal(text!("Hello")));
         assert!(Value::Bytea(bytea()).evaluate_eq_with_literal(&Literal::Bytea(bytea())));
+        assert!(Value::Bytea(bytea()).evaluate_eq_with_literal(text!("123456")));
+        assert!(Value::Bytea(bytea()).evaluate_eq_with_literal(text!("\\x123456")));
+        assert!(!Value::Bytea(bytea()).evaluate_eq_with_literal(text!("12345")));
         assert!(inet("127.0.0.1").evaluate_eq_with_literal(text!("127.0.0.1")));
         assert!(inet("::1").evaluate_eq_with_literal(text!("::1")));
         assert!(inet("0.0.0.0").evaluate_eq_with_literal(num!("0")));
@@ -562,6 +635,12 @@ mod tests {
         assert!(!inet("::1").evaluate_eq_with_literal(num!("-1")));
         assert!(Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("2021-11-20")));
         assert!(!Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("202=abcdef")));
+        assert!(
+            Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("2021-11-20T00:00:00Z"))
+        );
+        assert!(
+            !Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("2021-11-20 09:30:00"))
+        );
         assert!(Value::Timestamp(date_time(2021, 11, 20, 10, 0, 0, 0))
             .evaluate_eq_with_literal(text!("2021-11-20T10:00:00Z")));
         assert!(!Value::Timestamp(date_time(2021, 11, 20, 10, 0, 0, 0))
@@ -569,6 +648,16 @@ mod tests {
         assert!(Value::Time(time(10, 0, 0, 0)).evaluate_eq_with_literal(text!("10:00:00")));
         assert!(!Value::Time(time(10, 0, 0, 0)).evaluate_eq_with_literal(text!("FALSE")));
         assert!(Value::Uuid(uuid).evaluate_eq_with_literal(text!(uuid_text)));
+        assert!(Value::I64(10).evaluate_eq_with_literal(text!("10")));
+        assert!(!Value::I64(10).evaluate_eq_with_literal(text!("ten")));
+        assert!(Value::F64(1.5).evaluate_eq_with_literal(text!("1.5")));
+        assert!(Value::Decimal(Decimal::new(15, 1)).evaluate_eq_with_literal(text!("1.50")));
+        assert!(Value::Bool(true).evaluate_eq_with_literal(text!("TRUE")));
+        assert!(!Value::Bool(true).evaluate_eq_with_literal(text!("0")));
+        assert!(Value::parse_json_list("[1, 2]")
+            .unwrap()
+            .evaluate_eq_with_literal(text!("[1,2]")));
+        assert!(!Value::Null.evaluate_eq_with_literal(text!("NULL")));
     }
 
     #[test]
@@ -607,6 +696,16 @@ mod tests {
             text("2021-11-21"),
             Some(Ordering::Equal),
         );
+        test(
+            Value::Date(date(2021, 11, 21)),
+            text("2021-11-21 09:30:00"),
+            Some(Ordering::Less),
+        );
+        test(
+            Value::Date(date(2021, 11, 21)),
+            text("2021-11-20T23:59:59Z"),
+            Some(Ordering::Greater),
+        );
         test(
             Value::Timestamp(date_time(2021, 11, 21, 10, 0, 0, 0)),
             text("2021-11-21T10:00:00Z"),
@@ -642,6 +741,10 @@ mod tests {
             Literal::Number(Cow::Owned(BigDecimal::new(9876543210u128.into(), 0))),
             Some(Ordering::Equal),
         );
+        test(Value::I64(10), text("9"), Some(Ordering::Greater));
+        test(Value::U8(10), text("300"), None);
+        test(Value::F64(1.5), text("2"), Some(Ordering::Less));
+        test(Value::Bool(false), text("true"), Some(Ordering::Less));
         test(Value::Null, num(1), None);
     }
 
@@ -755,6 +858,11 @@ mod tests {
             Value::Bytea(bytea("1234"))
         );
         test!(DataType::Bytea, text!("1234"), Value::Bytea(bytea("1234")));
+        test!(
+            DataType::Bytea,
+            text!("\\x1234"),
+            Value::Bytea(bytea("1234"))
+        );
         assert_eq!(
             Value::try_from_literal(&DataType::Bytea, &text!("123")),
             Err(ValueError::FailedToParseHexString("123".to_owned()).into())
@@ -844,6 +952,20 @@ mod tests {
             num!("200"),
             Value::Decimal(Decimal::new(200, 0))
         );
+        test!(
+            DataType::DecimalPrecision(5, 2),
+            num!("123.45"),
+            Value::Decimal(Decimal::new(12345, 2))
+        );
+        assert_eq!(
+            Value::try_from_literal(&DataType::DecimalPrecision(5, 2), &num!("1.234")),
+            Err(ValueError::DecimalOutOfRange {
+                value: "1.234".to_owned(),
+                precision: 5,
+                scale: 2,
+            }
+            .into())
+        );
     }
 
     #[test]
@@ -1009,6 +1131,22 @@ mod tests {
         test!(DataType::Float, num!("123456.789"), Value::F64(123456.789));
         test!(DataType::Float, Literal::Boolean(true), Value::F64(1.0));
         test!(DataType::Float, Literal::Boolean(false), Value::F64(0.0));
+
+        test!(
+            DataType::Decimal,
+            text!("12.345"),
+            Value::Decimal(Decimal::new(12345, 3))
+        );
+        test!(
+            DataType::DecimalPrecision(4, 2),
+            num!("12.345"),
+            Value::Decimal(Decimal::new(1235, 2))
+        );
+        test!(
+            DataType::DecimalPrecision(4, 2),
+            Literal::Boolean(true),
+            Value::Decimal(Decimal::ONE)
+        );
         test!(
             DataType::Text,
             num!("1234567890"),
@@ -8,6 +8,7 @@ use {
     binary_op::TryBinaryOperator,
     chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
     core::ops::Sub,
+    decimal::{round_decimal, validate_decimal},
     rust_decimal::Decimal,
     serde::{Deserialize, Serialize},
     std::{cmp::Ordering, collections::HashMap, fmt::Debug, net::IpAddr},
@@ -16,6 +17,7 @@ use {
 mod binary_op;
 mod convert;
 mod date;
+mod decimal;
 mod error;
 mod expr;
 mod json;
@@ -73,6 +75,7 @@ impl Value {
             (Value::U128(l), _) => l == other,
             (Value::F32(l), _) => l == other,
             (Value::F64(l), _) => l == other,
+            (Value::Decimal(l), _) => l == other,
             (Value::Date(l), Value::Timestamp(r)) => l
                 .and_hms_opt(0, 0, 0)
                 .map(|date_time| &date_time == r)
@@ -100,7 +103,7 @@ impl Value {
             (Value::U128(l), _) => l.partial_cmp(other),
             (Value::F32(l), _) => l.partial_cmp(other),
             (Value::F64(l), _) => l.partial_cmp(other),
-            (Value::Decimal(l), Value::Decimal(r)) => Some(l.cmp(r)),
+            (Value::Decimal(l), _) => l.partial_cmp(other),
             (Value::Bool(l), Value::Bool(r)) => Some(l.cmp(r)),
             (Value::Str(l), Value::Str(r)) => Some(l.cmp(r)),
             (Value::Bytea(l), Value::Bytea(r)) => Some(l.cmp(r)),
@@ -171,6 +174,11 @@ impl Value {
     }
 
     pub fn validate_type(&self, data_type: &DataType) -> Result<()> {
+        if let (Value::Decimal(v), DataType::DecimalPrecision(precision, scale)) = (self, data_type)
+        {
+            return validate_decimal(v, *precision, *scale);
+        }
+
         let valid = match self {
             Value::I8(_) => matches!(data_type, DataType::Int8),
             Value::I16(_) => matches!(data_type, DataType::Int16),
@@ -184,7 +192,10 @@ impl Value {
             Value::U128(_) => matches!(data_type, DataType::Uint128),
             Value::F32(_) => matches!(data_type, DataType::Float32),
             Value::F64(_) => matches!(data_type, DataType::Float),
-            Value::Decimal(_) => matches!(data_type, DataType::Decimal),
+            Value::Decimal(_) => matches!(
+                data_type,
+                DataType::Decimal | DataType::DecimalPrecision(..)
+            ),
             Value::Bool(_) => matches!(data_type, DataType::Boolean),
             Value::Str(_) => matches!(data_type, DataType::Text),
             Value::Bytea(_) => matches!(data_type, DataType::Bytea),
@@ -211,6 +222,39 @@ impl Value {
         Ok(())
     }
 
+    /// Converts an integer to the integer type of the column it is stored into, so that a
+    /// value of any width fits a column as long as it is in the range of the column type.
+    pub fn coerce_integer(self, data_type: &DataType) -> Result<Value> {
+        let is_integer = |data_type: &DataType| {
+            matches!(
+                data_type,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int
+                    | DataType::Int128
+                    | DataType::Uint8
+                    | DataType::Uint16
+                    | DataType::Uint32
+                    | DataType::Uint64
+                    | DataType::Uint128
+            )
+        };
+
+        match self.get_type() {
+            Some(from) if &from != data_type && is_integer(&from) && is_integer(data_type) => {
+                self.cast(data_type).map_err(|_| {
+                    ValueError::IntegerOutOfRange {
+                        data_type: data_type.clone(),
+                        value: String::from(&self),
+                    }
+                    .into()
+                })
+            }
+            _ => Ok(self),
+        }
+    }
+
     pub fn validate_null(&self, nullable: bool) -> Result<()> {
         if !nullable && matches!(self, Value::Null) {
             return Err(ValueError::NullValueOnNotNullField.into());
@@ -219,6 +263,18 @@ impl Value {
         Ok(())
     }
 
+    /// Casts the value to `data_type`, which `CAST(expr AS TYPE)` evaluates with.
+    ///
+    /// - `NULL` casts to `NULL`, and a value of the target type is kept as it is.
+    /// - Numbers and booleans cast among each other, and fail when the value does not fit
+    ///   the target type. Only `0` and `1` cast to `BOOLEAN`.
+    /// - Text casts to every type by parsing it: hex for `BYTEA`, JSON for `MAP` and `LIST`.
+    /// - Every value casts to `TEXT`; maps and lists are written as JSON.
+    /// - `DATE` and `TIMESTAMP` cast to each other, and `TIMESTAMP` casts to `TIME`.
+    /// - Any other pair fails with [`ValueError::UnimplementedCast`].
+    ///
+    /// Text literals compared with, or calculated with, values of another type are read as
+    /// that type following the same rules.
     pub fn cast(&self, data_type: &DataType) -> Result<Self> {
         match (data_type, self) {
             (DataType::Int8, Value::I8(_))
@@ -243,7 +299,9 @@ impl Value {
             | (DataType::Timestamp, Value::Timestamp(_))
             | (DataType::Time, Value::Time(_))
             | (DataType::Interval, Value::Interval(_))
-            | (DataType::Uuid, Value::Uuid(_)) => Ok(self.clone()),
+            | (DataType::Uuid, Value::Uuid(_))
+            | (DataType::Map, Value::Map(_))
+            | (DataType::List, Value::List(_)) => Ok(self.clone()),
 
             (_, Value::Null) => Ok(Value::Null),
 
@@ -260,7 +318,10 @@ impl Value {
             (DataType::Uint128, value) => value.try_into().map(Value::U128),
             (DataType::Float32, value) => value.try_into().map(Value::F32),
             (DataType::Float, value) => value.try_into().map(Value::F64),
-            (DataType::Decimal, value) => value.try_into().map(Value::Decimal),
+            (data_type @ (DataType::Decimal | DataType::DecimalPrecision(..)), value) => value
+                .try_into()
+                .and_then(|v| round_decimal(v, data_type.decimal_precision()))
+                .map(Value::Decimal),
             (DataType::Text, value) => Ok(Value::Str(value.into())),
             (DataType::Date, value) => value.try_into().map(Value::Date),
             (DataType::Time, value) => value.try_into().map(Value::Time),
@@ -270,8 +331,8 @@ impl Value {
             (DataType::Uuid, value) => value.try_into().map(Value::Uuid),
             (DataType::Inet, value) => value.try_into().map(Value::Inet),
             (DataType::Point, value) => value.try_into().map(Value::Point),
-            (DataType::Bytea, Value::Str(value)) => hex::decode(value)
-                .map_err(|_| ValueError::CastFromHexToByteaFailed(value.clone()).into())
+            (DataType::Bytea, Value::Str(value)) => literal::parse_bytea(value)
+                .ok_or_else(|| ValueError::CastFromHexToByteaFailed(value.clone()).into())
                 .map(Value::Bytea),
             (DataType::List, Value::Str(value)) => Self::parse_json_list(value),
             (DataType::Map, Value::Str(value)) => Self::parse_json_map(value),
@@ -657,12 +718,14 @@ impl Value {
     pub fn unary_minus(&self) -> Result<Value> {
         use Value::*;
 
+        let overflow = || ValueError::UnaryMinusOverflow.into();
+
         match self {
-            I8(a) => Ok(I8(-a)),
-            I16(a) => Ok(I16(-a)),
-            I32(a) => Ok(I32(-a)),
-            I64(a) => Ok(I64(-a)),
-            I128(a) => Ok(I128(-a)),
+            I8(a) => a.checked_neg().map(I8).ok_or_else(overflow),
+            I16(a) => a.checked_neg().map(I16).ok_or_else(overflow),
+            I32(a) => a.checked_neg().map(I32).ok_or_else(overflow),
+            I64(a) => a.checked_neg().map(I64).ok_or_else(overflow),
+            I128(a) => a.checked_neg().map(I128).ok_or_else(overflow),
             F32(a) => Ok(F32(-a)),
             F64(a) => Ok(F64(-a)),
             Decimal(a) => Ok(Decimal(-a)),
@@ -729,6 +792,7 @@ impl Value {
 
         match (self, other) {
             (Str(a), Str(b)) => a.like(b, case_sensitive).map(Bool),
+            (Null, Str(_)) | (Str(_), Null) | (Null, Null) => Ok(Null),
             _ => Err(ValueError::LikeOnNonString {
                 base: self.clone(),
                 pattern: other.clone(),
@@ -2226,11 +2290,12 @@ mod tests {
         );
 
         cast!(Str("2021-05-01".to_owned()) => Date, date.to_owned());
-        cast!(timestamp                    => Date, date);
+        cast!(timestamp.clone()            => Date, date);
         cast!(Null                         => Date, Null);
 
         // Time
         cast!(Str("08:05:30".to_owned()) => Time, Value::Time(NaiveTime::from_hms_opt(8, 5, 30).unwrap()));
+        cast!(timestamp.clone()          => Time, Value::Time(NaiveTime::from_hms_opt(12, 34, 50).unwrap()));
         cast!(Null                       => Time, Null);
 
         // Timestamp
@@ -2259,18 +2324,42 @@ mod tests {
             Str(r#"{"a": 1}"#.to_owned()) => Map,
             Value::parse_json_map(r#"{"a": 1}"#).unwrap()
         );
+        cast!(
+            Value::parse_json_map(r#"{"a": 1}"#).unwrap() => Map,
+            Value::parse_json_map(r#"{"a": 1}"#).unwrap()
+        );
+        cast!(
+            Value::parse_json_map(r#"{"a": 1}"#).unwrap() => Text,
+            Str(r#"{"a":1}"#.to_owned())
+        );
 
         // List
         cast!(
             Str(r#"[1, 2, 3]"#.to_owned()) => List,
             Value::parse_json_list(r#"[1, 2, 3]"#).unwrap()
         );
+        cast!(
+            Value::parse_json_list(r#"[1, 2, 3]"#).unwrap() => List,
+            Value::parse_json_list(r#"[1, 2, 3]"#).unwrap()
+        );
+        cast!(
+            Value::parse_json_list(r#"[1, 2, 3]"#).unwrap() => Text,
+            Str("[1,2,3]".to_owned())
+        );
 
         // Casting error
         assert_eq!(
             Value::Uuid(123).cast(&List),
             Err(ValueError::UnimplementedCast.into())
         );
+        assert_eq!(
+            Value::parse_json_list("[1]").unwrap().cast(&Map),
+            Err(ValueError::UnimplementedCast.into())
+        );
+        assert_eq!(
+            Value::Time(NaiveTime::from_hms_opt(8, 5, 30).unwrap()).cast(&Date),
+            Err(ValueError::ImpossibleCast.into())
+        );
     }
 
     #[test]
@@ -2361,6 +2450,12 @@ mod tests {
         assert!(Decimal(rust_decimal::Decimal::ONE)
             .validate_type(&D::Int)
             .is_err());
+        assert!(Decimal(rust_decimal::Decimal::new(125, 1))
+            .validate_type(&D::DecimalPrecision(3, 1))
+            .is_ok());
+        assert!(Decimal(rust_decimal::Decimal::new(125, 2))
+            .validate_type(&D::DecimalPrecision(3, 1))
+            .is_err());
         assert!(Str("a".to_owned()).validate_type(&D::Text).is_ok());
         assert!(Str("a".to_owned()).validate_type(&D::Int).is_err());
         assert!(bytea.validate_type(&D::Bytea).is_ok());
@@ -2423,6 +2518,42 @@ mod tests {
             Str("abc".to_owned()).unary_minus(),
             Err(ValueError::UnaryMinusOnNonNumeric.into())
         );
+        assert_eq!(
+            I8(i8::MIN).unary_minus(),
+            Err(ValueError::UnaryMinusOverflow.into())
+        );
+        assert_eq!(
+            I64(i64::MIN).unary_minus(),
+            Err(ValueError::UnaryMinusOverflow.into())
+        );
+    }
+
+    #[test]
+    fn coerce_integer() {
+        use crate::ast::DataType as D;
+
+        assert_eq!(I64(100).coerce_integer(&D::Int8), Ok(I8(100)));
+        assert_eq!(I8(-1).coerce_integer(&D::Int128), Ok(I128(-1)));
+        assert_eq!(U16(7).coerce_integer(&D::Uint64), Ok(U64(7)));
+        assert_eq!(I64(1).coerce_integer(&D::Int), Ok(I64(1)));
+        assert_eq!(Null.coerce_integer(&D::Int8), Ok(Null));
+        assert_eq!(F64(1.0).coerce_integer(&D::Int8), Ok(F64(1.0)));
+        assert_eq!(
+            I64(300).coerce_integer(&D::Int8),
+            Err(ValueError::IntegerOutOfRange {
+                data_type: D::Int8,
+                value: "300".to_owned(),
+            }
+            .into())
+        );
+        assert_eq!(
+            I32(-1).coerce_integer(&D::Uint8),
+            Err(ValueError::IntegerOutOfRange {
+                data_type: D::Uint8,
+                value: "-1".to_owned(),
+            }
+            .into())
+        );
     }
 
     #[test]
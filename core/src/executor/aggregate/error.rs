@@ -4,4 +4,7 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 pub enum AggregateError {
     #[error("unreachable rc unwrap failure")]
     UnreachableRcUnwrapFailure,
+
+    #[error("unreachable window function accumulated as an aggregate")]
+    UnreachableWindowFunction,
 }
@@ -9,17 +9,19 @@ use {
         filter::check_expr,
     },
     crate::{
-        ast::{Expr, SelectItem},
-        data::Key,
+        ast::{Aggregate, AggregateFunction, Expr, SelectItem},
+        data::{Key, Value},
         result::Result,
         store::GStore,
     },
     async_recursion::async_recursion,
     futures::stream::{self, Stream, StreamExt, TryStreamExt},
+    im_rc::HashMap,
     std::{convert::identity, rc::Rc},
 };
 
 pub use error::AggregateError;
+pub(crate) use state::AggrValue;
 
 pub struct Aggregator<'a, T: GStore> {
     storage: &'a T,
@@ -94,19 +96,12 @@ impl<'a, T: GStore> Aggregator<'a, T> {
                         .collect::<Result<Vec<Key>>>()?;
 
                     let state = state.apply(index, group, Rc::clone(&project_context));
-                    let state = stream::iter(self.fields)
+                    let state = stream::iter(self.exprs())
                         .map(Ok)
-                        .try_fold(state, |state, field| {
+                        .try_fold(state, |state, expr| {
                             let filter_clone = filter_context.as_ref().map(Rc::clone);
 
-                            async move {
-                                match field {
-                                    SelectItem::Expr { expr, .. } => {
-                                        aggregate(state, filter_clone, expr).await
-                                    }
-                                    _ => Ok(state),
-                                }
-                            }
+                            async move { aggregate(state, filter_clone, expr).await }
                         })
                         .await?;
 
@@ -125,9 +120,12 @@ impl<'a, T: GStore> Aggregator<'a, T> {
         let storage = self.storage;
         let filter_context = self.filter_context.as_ref().map(Rc::clone);
         let having = self.having;
-        let rows = state
-            .export()
-            .await?
+        let rows = state.export().await?;
+        let rows = match rows.is_empty() && self.group_by.is_empty() {
+            true => vec![(Some(self.empty_aggregated()), Some(empty_context()))],
+            false => rows,
+        };
+        let rows = rows
             .into_iter()
             .filter_map(|(aggregated, next)| next.map(|next| (aggregated, next)));
         let rows = stream::iter(rows)
@@ -172,18 +170,39 @@ impl<'a, T: GStore> Aggregator<'a, T> {
         Ok(rows)
     }
 
+    /// Aggregated values of an empty input without GROUP BY, e.g. `COUNT(*)` is 0 and
+    /// `SUM(col)` is NULL.
+    fn empty_aggregated(&self) -> HashMap<&'a Aggregate, Value> {
+        self.exprs()
+            .flat_map(aggregates)
+            .map(|aggr| {
+                let value = match aggr.func {
+                    AggregateFunction::Count(_) => Value::I64(0),
+                    _ => Value::Null,
+                };
+
+                (aggr, value)
+            })
+            .collect()
+    }
+
     fn check_aggregate(&self) -> bool {
         if !self.group_by.is_empty() {
             return true;
         }
 
+        self.exprs().any(check)
+    }
+
+    /// Expressions which can hold aggregates, from both the SELECT list and HAVING.
+    fn exprs(&self) -> impl Iterator<Item = &'a Expr> {
         self.fields
             .iter()
-            .map(|field| match field {
-                SelectItem::Expr { expr, .. } => check(expr),
-                _ => false,
+            .filter_map(|field| match field {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                _ => None,
             })
-            .any(identity)
+            .chain(self.having)
     }
 }
 
@@ -229,11 +248,54 @@ async fn aggregate<'a, T: GStore>(
                 .try_fold(state, aggr)
                 .await
         }
+        Expr::Aggregate(aggr) if aggr.over.is_some() => {
+            stream::iter(aggr.exprs().into_iter().map(Ok))
+                .try_fold(state, |state, expr| {
+                    aggregate(state, filter_context.as_ref().map(Rc::clone), expr)
+                })
+                .await
+        }
         Expr::Aggregate(aggr) => state.accumulate(filter_context, aggr.as_ref()).await,
         _ => Ok(state),
     }
 }
 
+fn empty_context<'a>() -> Rc<RowContext<'a>> {
+    Rc::new(RowContext::RefVecData {
+        columns: &[],
+        values: &[],
+    })
+}
+
+fn aggregates(expr: &Expr) -> Vec<&Aggregate> {
+    match expr {
+        Expr::Between {
+            expr, low, high, ..
+        } => [expr, low, high]
+            .into_iter()
+            .flat_map(|expr| aggregates(expr))
+            .collect(),
+        Expr::BinaryOp { left, right, .. } => [aggregates(left), aggregates(right)].concat(),
+        Expr::UnaryOp { expr, .. } | Expr::Nested(expr) => aggregates(expr),
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => operand
+            .iter()
+            .chain(else_result.iter())
+            .map(AsRef::as_ref)
+            .chain(when_then.iter().flat_map(|(when, then)| [when, then]))
+            .flat_map(aggregates)
+            .collect(),
+        Expr::Aggregate(aggr) if aggr.over.is_some() => {
+            aggr.exprs().into_iter().flat_map(aggregates).collect()
+        }
+        Expr::Aggregate(aggr) => vec![aggr.as_ref()],
+        _ => vec![],
+    }
+}
+
 fn check(expr: &Expr) -> bool {
     match expr {
         Expr::Between {
@@ -257,6 +319,7 @@ fn check(expr: &Expr) -> bool {
                     .map(|expr| check(expr))
                     .unwrap_or(false)
         }
+        Expr::Aggregate(aggr) if aggr.over.is_some() => aggr.exprs().into_iter().any(check),
         Expr::Aggregate(_) => true,
         _ => false,
     }
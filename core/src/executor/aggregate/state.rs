@@ -1,6 +1,7 @@
 use {
+    super::AggregateError,
     crate::{
-        ast::{Aggregate, CountArgExpr, DataType},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, DataType},
         data::{Key, Value},
         executor::{context::RowContext, evaluate::evaluate},
         result::Result,
@@ -17,7 +18,8 @@ type Group = Rc<Vec<Key>>;
 type ValuesMap<'a> = HashMap<&'a Aggregate, Value>;
 type Context<'a> = Rc<RowContext<'a>>;
 
-enum AggrValue {
+#[derive(Clone)]
+pub(crate) enum AggrValue {
     Count {
         wildcard: bool,
         count: i64,
@@ -42,39 +44,46 @@ enum AggrValue {
 }
 
 impl AggrValue {
-    fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
+    pub(crate) fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
         let value = value.clone();
 
-        Ok(match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => AggrValue::Count {
+        Ok(match &aggr.func {
+            AggregateFunction::Count(CountArgExpr::Wildcard) => AggrValue::Count {
                 wildcard: true,
                 count: 1,
             },
-            Aggregate::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
+            AggregateFunction::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
                 wildcard: false,
                 count: i64::from(!value.is_null()),
             },
-            Aggregate::Sum(_) => AggrValue::Sum(value),
-            Aggregate::Min(_) => AggrValue::Min(value),
-            Aggregate::Max(_) => AggrValue::Max(value),
-            Aggregate::Avg(_) => AggrValue::Avg {
+            AggregateFunction::Sum(_) => AggrValue::Sum(value),
+            AggregateFunction::Min(_) => AggrValue::Min(value),
+            AggregateFunction::Max(_) => AggrValue::Max(value),
+            AggregateFunction::Avg(_) => AggrValue::Avg {
                 sum: value,
                 count: 1,
             },
-            Aggregate::Variance(_) => AggrValue::Variance {
+            AggregateFunction::Variance(_) => AggrValue::Variance {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
             },
-            Aggregate::Stdev(_) => AggrValue::Stdev {
+            AggregateFunction::Stdev(_) => AggrValue::Stdev {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
             },
+            AggregateFunction::RowNumber
+            | AggregateFunction::Rank
+            | AggregateFunction::DenseRank
+            | AggregateFunction::Lag { .. }
+            | AggregateFunction::Lead { .. } => {
+                return Err(AggregateError::UnreachableWindowFunction.into());
+            }
         })
     }
 
-    fn accumulate(&self, new_value: &Value) -> Result<Option<Self>> {
+    pub(crate) fn accumulate(&self, new_value: &Value) -> Result<Option<Self>> {
         match self {
             Self::Count { wildcard, count } => {
                 let wildcard = *wildcard;
@@ -89,6 +98,12 @@ impl AggrValue {
                 }
             }
             Self::Sum(value) => Ok(Some(Self::Sum(value.add(new_value)?))),
+            Self::Min(value) if value.is_null() => {
+                Ok((!new_value.is_null()).then(|| Self::Min(new_value.clone())))
+            }
+            Self::Max(value) if value.is_null() => {
+                Ok((!new_value.is_null()).then(|| Self::Max(new_value.clone())))
+            }
             Self::Min(value) => match &value.evaluate_cmp(new_value) {
                 Some(Ordering::Greater) => Ok(Some(Self::Min(new_value.clone()))),
                 _ => Ok(None),
@@ -122,7 +137,7 @@ impl AggrValue {
         }
     }
 
-    async fn export(self) -> Result<Value> {
+    pub(crate) async fn export(self) -> Result<Value> {
         let variance = |sum_square: Value, sum: Value, count: i64| async move {
             let count = Value::I64(count);
             let sum_expr1 = sum_square.multiply(&count)?;
@@ -161,6 +176,8 @@ pub struct State<'a, T: GStore> {
     values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue)>,
     groups: HashSet<Group>,
     contexts: Vector<Rc<RowContext<'a>>>,
+    /// Values already accumulated by DISTINCT aggregates, e.g. `COUNT(DISTINCT id)`
+    distincts: HashSet<(Group, &'a Aggregate, Key)>,
 }
 
 impl<'a, T: GStore> State<'a, T> {
@@ -172,6 +189,7 @@ impl<'a, T: GStore> State<'a, T> {
             values: IndexMap::new(),
             groups: HashSet::new(),
             contexts: Vector::new(),
+            distincts: HashSet::new(),
         }
     }
 
@@ -253,27 +271,34 @@ impl<'a, T: GStore> State<'a, T> {
         filter_context: Option<Rc<RowContext<'a>>>,
         aggr: &'a Aggregate,
     ) -> Result<State<'a, T>> {
-        let value = match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => Value::Null,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
+        let value = match aggr.as_expr() {
+            None => Value::Null,
+            Some(expr) => evaluate(self.storage, filter_context, None, expr)
                 .await?
                 .try_into()?,
         };
-        let aggr_value = match self.get(aggr) {
-            Some((index, _)) if self.index <= *index => None,
+
+        let state = match self.get(aggr) {
+            Some((index, _)) if self.index <= *index => return Ok(self),
+            _ if aggr.distinct => {
+                let key = (Rc::clone(&self.group), aggr, Key::try_from(&value)?);
+                if self.distincts.contains(&key) {
+                    return Ok(self);
+                }
+
+                let distincts = self.distincts.update(key);
+                Self { distincts, ..self }
+            }
+            _ => self,
+        };
+        let aggr_value = match state.get(aggr) {
             Some((_, aggr_value)) => aggr_value.accumulate(&value)?,
             None => Some(AggrValue::new(aggr, &value)?),
         };
 
         match aggr_value {
-            Some(aggr_value) => Ok(self.update(aggr, aggr_value)),
-            None => Ok(self),
+            Some(aggr_value) => Ok(state.update(aggr, aggr_value)),
+            None => Ok(state),
         }
     }
 }
@@ -1,7 +1,7 @@
 use {
-    super::{validate, AlterError},
+    super::{is_check_evaluable, validate, validate_not_referenced_by_view, AlterError},
     crate::{
-        ast::{AlterTableOperation, Expr, Function},
+        ast::{AlterTableOperation, CheckConstraint, Expr, ForeignKey, Function, ToSql},
         data::{Schema, SchemaIndex},
         result::Result,
         store::{GStore, GStoreMut},
@@ -16,11 +16,44 @@ pub async fn alter_table<T: GStore + GStoreMut>(
     match operation {
         AlterTableOperation::RenameTable {
             table_name: new_table_name,
-        } => storage.rename_schema(table_name, new_table_name).await,
+        } => {
+            if storage.fetch_schema(new_table_name).await?.is_some() {
+                return Err(AlterError::TableAlreadyExists(new_table_name.to_owned()).into());
+            }
+
+            let referencing = storage
+                .fetch_all_schemas()
+                .await?
+                .into_iter()
+                .find_map(|schema| {
+                    let foreign_key = schema
+                        .foreign_keys
+                        .into_iter()
+                        .find(|foreign_key| foreign_key.referenced_table_name == table_name)?;
+
+                    Some((schema.table_name, foreign_key))
+                });
+
+            if let Some((referencing_table_name, foreign_key)) = referencing {
+                return Err(AlterError::TableReferenced {
+                    table_name: table_name.to_owned(),
+                    referencing_table_name,
+                    foreign_key_name: foreign_key.name,
+                }
+                .into());
+            }
+
+            validate_not_referenced_by_view(storage, table_name, &[]).await?;
+
+            storage.rename_schema(table_name, new_table_name).await
+        }
         AlterTableOperation::RenameColumn {
             old_column_name,
             new_column_name,
         } => {
+            validate_foreign_key_column(storage, table_name, old_column_name).await?;
+            validate_check_column(storage, table_name, old_column_name).await?;
+
             storage
                 .rename_column(table_name, old_column_name, new_column_name)
                 .await
@@ -46,6 +79,9 @@ pub async fn alter_table<T: GStore + GStoreMut>(
                 .filter(|SchemaIndex { expr, .. }| find_column(expr, column_name))
                 .map(|SchemaIndex { name, .. }| name);
 
+            validate_foreign_key_column(storage, table_name, column_name).await?;
+            validate_check_column(storage, table_name, column_name).await?;
+
             for index_name in indexes {
                 storage.drop_index(table_name, index_name).await?;
             }
@@ -57,6 +93,84 @@ pub async fn alter_table<T: GStore + GStoreMut>(
     }
 }
 
+/// Columns used by foreign keys, on either the referencing or the referenced side, cannot
+/// be renamed or dropped.
+async fn validate_foreign_key_column<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let foreign_key = storage
+        .fetch_all_schemas()
+        .await?
+        .into_iter()
+        .flat_map(|schema| {
+            let is_referencing = schema.table_name == table_name;
+
+            schema
+                .foreign_keys
+                .into_iter()
+                .map(move |foreign_key| (is_referencing, foreign_key))
+        })
+        .find_map(|(is_referencing, foreign_key)| {
+            let ForeignKey {
+                referencing_column_name,
+                referenced_table_name,
+                referenced_column_name,
+                ..
+            } = &foreign_key;
+
+            let found = (is_referencing && referencing_column_name == column_name)
+                || (referenced_table_name == table_name && referenced_column_name == column_name);
+
+            found.then_some(foreign_key)
+        });
+
+    match foreign_key {
+        Some(ForeignKey { name, .. }) => Err(AlterError::ForeignKeyColumnInUse {
+            column_name: column_name.to_owned(),
+            foreign_key_name: name,
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// Columns used by `CHECK` constraints cannot be renamed or dropped.
+async fn validate_check_column<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<()> {
+    let (column_defs, checks) = match storage.fetch_schema(table_name).await? {
+        Some(Schema {
+            column_defs,
+            checks,
+            ..
+        }) => (column_defs, checks),
+        None => return Ok(()),
+    };
+
+    let columns = column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.as_str())
+        .filter(|name| name != &column_name)
+        .collect::<Vec<_>>();
+
+    match checks
+        .iter()
+        .find(|CheckConstraint { expr, .. }| !is_check_evaluable(table_name, columns.clone(), expr))
+    {
+        Some(check) => Err(AlterError::CheckColumnInUse {
+            column_name: column_name.to_owned(),
+            check: check.to_sql(),
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
 fn find_column(expr: &Expr, column_name: &str) -> bool {
     let find = |expr| find_column(expr, column_name);
 
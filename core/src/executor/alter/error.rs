@@ -7,7 +7,7 @@ use {
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum AlterError {
-    // CREATE TABLE
+    // CREATE TABLE, ALTER TABLE RENAME TO
     #[error("table already exists: {0}")]
     TableAlreadyExists(String),
 
@@ -24,10 +24,39 @@ pub enum AlterError {
     #[error("CTAS source table does not exist: {0}")]
     CtasSourceTableNotFound(String),
 
+    // CREATE VIEW, DROP VIEW, REFRESH MATERIALIZED VIEW
+    #[error("view does not exist: {0}")]
+    ViewNotFound(String),
+
+    #[error("materialized view does not exist: {0}")]
+    MaterializedViewNotFound(String),
+
+    #[error("view cannot refer to itself: {0}")]
+    CyclicViewReference(String),
+
+    #[error("'{table_name}' is referenced by view '{view_name}'")]
+    TableReferencedByView {
+        table_name: String,
+        view_name: String,
+    },
+
     // validate column def
     #[error("column '{0}' of data type '{1:?}' is unsupported for unique constraint")]
     UnsupportedDataTypeForUniqueColumn(String, DataType),
 
+    #[error("column '{0}' of data type '{1:?}' is unsupported for collation")]
+    UnsupportedDataTypeForCollation(String, DataType),
+
+    #[error("NOT NULL column cannot have NULL as its default: {0}")]
+    NullDefaultOnNotNullColumn(String),
+
+    // CHECK
+    #[error("CHECK can only refer to the columns of its table: {0}")]
+    UnsupportedCheckExpr(String),
+
+    #[error("column '{column_name}' is used by {check}")]
+    CheckColumnInUse { column_name: String, check: String },
+
     // validate index expr
     #[error("unsupported index expr: {0:#?}")]
     UnsupportedIndexExpr(Expr),
@@ -47,4 +76,44 @@ pub enum AlterError {
 
     #[error("non-default argument should not follow the default argument")]
     NonDefaultArgumentFollowsDefaultArgument,
+
+    // FOREIGN KEY
+    #[error("referencing column not found: {0}")]
+    ReferencingColumnNotFound(String),
+
+    #[error("referenced table not found: {0}")]
+    ReferencedTableNotFound(String),
+
+    #[error("referenced column not found: {0}")]
+    ReferencedColumnNotFound(String),
+
+    #[error("referenced column '{referenced_table_name}.{referenced_column_name}' must be a primary key")]
+    ReferencingNonPrimaryKeyColumn {
+        referenced_table_name: String,
+        referenced_column_name: String,
+    },
+
+    #[error("foreign key column '{referencing_column_name}' of type '{referencing_column_type}' does not match the referenced column '{referenced_column_name}' of type '{referenced_column_type}'")]
+    ForeignKeyDataTypeMismatch {
+        referencing_column_name: String,
+        referencing_column_type: DataType,
+        referenced_column_name: String,
+        referenced_column_type: DataType,
+    },
+
+    #[error("ON DELETE SET NULL requires the foreign key column to be nullable: {0}")]
+    SetNullOnNotNullColumn(String),
+
+    #[error("table '{table_name}' is referenced by foreign key '{foreign_key_name}' of table '{referencing_table_name}'")]
+    TableReferenced {
+        table_name: String,
+        referencing_table_name: String,
+        foreign_key_name: String,
+    },
+
+    #[error("column '{column_name}' is used by foreign key '{foreign_key_name}'")]
+    ForeignKeyColumnInUse {
+        column_name: String,
+        foreign_key_name: String,
+    },
 }
@@ -1,7 +1,7 @@
 use {
     super::{validate_arg_names, validate_default_args, AlterError},
     crate::{
-        ast::{Expr, OperateFunctionArg},
+        ast::{DataType, Expr, OperateFunctionArg},
         data::CustomFunction,
         result::Result,
         store::{GStore, GStoreMut},
@@ -12,6 +12,7 @@ pub async fn insert_function<T: GStore + GStoreMut>(
     storage: &mut T,
     func_name: &str,
     args: &Vec<OperateFunctionArg>,
+    return_type: &Option<DataType>,
     or_replace: bool,
     body: &Expr,
 ) -> Result<()> {
@@ -24,6 +25,7 @@ pub async fn insert_function<T: GStore + GStoreMut>(
             .insert_function(CustomFunction {
                 func_name: func_name.to_owned(),
                 args: args.to_owned(),
+                return_type: return_type.to_owned(),
                 body: body.to_owned(),
             })
             .await?;
@@ -4,13 +4,22 @@ mod function;
 mod index;
 mod table;
 mod validate;
+mod view;
 
-use validate::{validate, validate_arg_names, validate_column_names, validate_default_args};
+use {
+    table::select_with_column_defs,
+    validate::{
+        is_check_evaluable, validate, validate_arg_names, validate_check, validate_column_names,
+        validate_default_args,
+    },
+    view::validate_not_referenced_by_view,
+};
 
 pub use {
     alter_table::alter_table,
     error::AlterError,
     function::{delete_function, insert_function},
     index::create_index,
-    table::{create_table, drop_table},
+    table::{create_table, drop_table, CreateTableOptions},
+    view::{create_view, drop_view, refresh_materialized_view},
 };
@@ -1,95 +1,62 @@
 use {
-    super::{validate, validate_column_names, AlterError},
+    super::{
+        validate, validate_check, validate_column_names, validate_not_referenced_by_view,
+        AlterError,
+    },
     crate::{
-        ast::{ColumnDef, Query, SetExpr, TableFactor, Values},
-        data::{Schema, TableError},
-        executor::{evaluate_stateless, select::select},
+        ast::{
+            CheckConstraint, ColumnDef, ColumnUniqueOption, Expr, ForeignKey, Query,
+            ReferentialAction, SelectItem, SetExpr, TableFactor,
+        },
+        data::{get_alias, Row, Schema},
+        executor::select::select_with_labels,
         prelude::{DataType, Value},
-        result::{Error, Result},
-        store::{GStore, GStoreMut},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::TryStreamExt,
+    std::iter,
 };
 
+pub struct CreateTableOptions<'a> {
+    pub target_table_name: &'a str,
+    pub column_defs: Option<&'a [ColumnDef]>,
+    pub if_not_exists: bool,
+    pub source: &'a Option<Box<Query>>,
+    pub engine: &'a Option<String>,
+    pub foreign_keys: &'a [ForeignKey],
+    pub checks: &'a [CheckConstraint],
+}
+
 pub async fn create_table<T: GStore + GStoreMut>(
     storage: &mut T,
-    target_table_name: &str,
-    column_defs: Option<&[ColumnDef]>,
-    if_not_exists: bool,
-    source: &Option<Box<Query>>,
-    engine: &Option<String>,
+    options: CreateTableOptions<'_>,
 ) -> Result<()> {
-    let target_columns_defs = match source.as_deref() {
-        Some(Query { body, .. }) => match body {
-            SetExpr::Select(select_query) => match &select_query.from.relation {
-                TableFactor::Table { name, .. } => {
-                    let schema = storage.fetch_schema(name).await?;
-                    let Schema {
-                        column_defs: source_column_defs,
-                        ..
-                    } = schema.ok_or_else(|| -> Error {
-                        AlterError::CtasSourceTableNotFound(name.to_owned()).into()
-                    })?;
-
-                    source_column_defs
-                }
-                TableFactor::Series { .. } => {
-                    let column_def = ColumnDef {
-                        name: "N".into(),
-                        data_type: DataType::Int,
-                        nullable: false,
-                        default: None,
-                        unique: None,
-                    };
-
-                    Some(vec![column_def])
-                }
-                _ => {
-                    return Err(Error::Table(TableError::Unreachable));
-                }
-            },
-            SetExpr::Values(Values(values_list)) => {
-                let first_len = values_list[0].len();
-                let mut column_types = vec![None; first_len];
-
-                for exprs in values_list {
-                    for (i, expr) in exprs.iter().enumerate() {
-                        if column_types[i].is_some() {
-                            continue;
-                        }
-
-                        column_types[i] = evaluate_stateless(None, expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .map(|value| value.get_type())?;
-                    }
+    let CreateTableOptions {
+        target_table_name,
+        column_defs,
+        if_not_exists,
+        source,
+        engine,
+        foreign_keys,
+        checks,
+    } = options;
 
-                    if column_types.iter().all(Option::is_some) {
-                        break;
+    let (target_columns_defs, rows) = match source.as_deref() {
+        Some(query) => {
+            if let SetExpr::Select(select) = leftmost_set_expr(&query.body) {
+                if let TableFactor::Table { name, .. } = &select.from.relation {
+                    if storage.fetch_schema(name).await?.is_none() {
+                        return Err(AlterError::CtasSourceTableNotFound(name.to_owned()).into());
                     }
                 }
+            }
 
-                let column_defs = column_types
-                    .iter()
-                    .map(|column_type| match column_type {
-                        Some(column_type) => column_type.to_owned(),
-                        None => DataType::Text,
-                    })
-                    .enumerate()
-                    .map(|(i, data_type)| ColumnDef {
-                        name: format!("column{}", i + 1),
-                        data_type,
-                        nullable: true,
-                        default: None,
-                        unique: None,
-                    })
-                    .collect::<Vec<_>>();
+            let (column_defs, rows) = select_with_column_defs(storage, query).await?;
 
-                Some(column_defs)
-            }
-        },
-        None if column_defs.is_some() => column_defs.map(<[ColumnDef]>::to_vec),
-        None => None,
+            (column_defs, Some(rows))
+        }
+        None => (column_defs.map(<[ColumnDef]>::to_vec), None),
     };
 
     if let Some(column_defs) = target_columns_defs.as_deref() {
@@ -101,11 +68,28 @@ pub async fn create_table<T: GStore + GStoreMut>(
     }
 
     if storage.fetch_schema(target_table_name).await?.is_none() {
+        for foreign_key in foreign_keys {
+            validate_foreign_key(
+                storage,
+                target_table_name,
+                target_columns_defs.as_deref(),
+                foreign_key,
+            )
+            .await?;
+        }
+
+        for check in checks {
+            validate_check(target_table_name, target_columns_defs.as_deref(), check)?;
+        }
+
         let schema = Schema {
             table_name: target_table_name.to_owned(),
             column_defs: target_columns_defs,
             indexes: vec![],
             engine: engine.clone(),
+            foreign_keys: foreign_keys.to_vec(),
+            checks: checks.to_vec(),
+            view: None,
         };
 
         storage.insert_schema(&schema).await?;
@@ -113,19 +97,8 @@ pub async fn create_table<T: GStore + GStoreMut>(
         return Err(AlterError::TableAlreadyExists(target_table_name.to_owned()).into());
     }
 
-    match source {
-        Some(query) => {
-            let rows = select(storage, query, None)
-                .await?
-                .map_ok(Into::into)
-                .try_collect()
-                .await?;
-
-            storage
-                .append_data(target_table_name, rows)
-                .await
-                .map(|_| ())
-        }
+    match rows {
+        Some(rows) => storage.append_data(target_table_name, rows).await,
         None => Ok(()),
     }
 }
@@ -135,6 +108,8 @@ pub async fn drop_table<T: GStore + GStoreMut>(
     table_names: &[String],
     if_exists: bool,
 ) -> Result<()> {
+    let schemas = storage.fetch_all_schemas().await?;
+
     for table_name in table_names {
         let schema = storage.fetch_schema(table_name).await?;
 
@@ -142,8 +117,250 @@ pub async fn drop_table<T: GStore + GStoreMut>(
             schema.ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
         }
 
+        let referencing = schemas
+            .iter()
+            .filter(|schema| !table_names.contains(&schema.table_name))
+            .find_map(|schema| {
+                schema
+                    .foreign_keys
+                    .iter()
+                    .find(|foreign_key| &foreign_key.referenced_table_name == table_name)
+                    .map(|foreign_key| (schema, foreign_key))
+            });
+
+        if let Some((schema, foreign_key)) = referencing {
+            return Err(AlterError::TableReferenced {
+                table_name: table_name.to_owned(),
+                referencing_table_name: schema.table_name.to_owned(),
+                foreign_key_name: foreign_key.name.to_owned(),
+            }
+            .into());
+        }
+
+        validate_not_referenced_by_view(storage, table_name, table_names).await?;
+
         storage.delete_schema(table_name).await?;
     }
 
     Ok(())
 }
+
+async fn validate_foreign_key<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: Option<&[ColumnDef]>,
+    foreign_key: &ForeignKey,
+) -> Result<()> {
+    let ForeignKey {
+        referencing_column_name,
+        referenced_table_name,
+        referenced_column_name,
+        on_delete,
+        ..
+    } = foreign_key;
+
+    let referencing_column_def = column_defs
+        .into_iter()
+        .flatten()
+        .find(|column_def| &column_def.name == referencing_column_name)
+        .ok_or_else(|| AlterError::ReferencingColumnNotFound(referencing_column_name.to_owned()))?;
+
+    let referenced_column_defs = match referenced_table_name == table_name {
+        true => column_defs.map(<[ColumnDef]>::to_vec),
+        false => {
+            storage
+                .fetch_schema(referenced_table_name)
+                .await?
+                .ok_or_else(|| {
+                    AlterError::ReferencedTableNotFound(referenced_table_name.to_owned())
+                })?
+                .column_defs
+        }
+    };
+    let referenced_column_def = referenced_column_defs
+        .iter()
+        .flatten()
+        .find(|column_def| &column_def.name == referenced_column_name)
+        .ok_or_else(|| AlterError::ReferencedColumnNotFound(referenced_column_name.to_owned()))?;
+
+    if referenced_column_def.unique != Some(ColumnUniqueOption { is_primary: true }) {
+        return Err(AlterError::ReferencingNonPrimaryKeyColumn {
+            referenced_table_name: referenced_table_name.to_owned(),
+            referenced_column_name: referenced_column_name.to_owned(),
+        }
+        .into());
+    }
+
+    if referencing_column_def.data_type != referenced_column_def.data_type {
+        return Err(AlterError::ForeignKeyDataTypeMismatch {
+            referencing_column_name: referencing_column_name.to_owned(),
+            referencing_column_type: referencing_column_def.data_type.clone(),
+            referenced_column_name: referenced_column_name.to_owned(),
+            referenced_column_type: referenced_column_def.data_type.clone(),
+        }
+        .into());
+    }
+
+    if on_delete == &ReferentialAction::SetNull && !referencing_column_def.nullable {
+        return Err(AlterError::SetNullOnNotNullColumn(referencing_column_name.to_owned()).into());
+    }
+
+    Ok(())
+}
+
+/// Runs the source query of `CREATE TABLE AS SELECT` or of a materialized view and infers the
+/// columns of its output. Columns taken as they are from a table keep their data type,
+/// nullability and default, the others are typed after their first non-null value, `TEXT` if
+/// there is none.
+pub(super) async fn select_with_column_defs<T: GStore>(
+    storage: &T,
+    query: &Query,
+) -> Result<(Option<Vec<ColumnDef>>, Vec<DataRow>)> {
+    let declared = declared_column_defs(storage, query).await?;
+    let (labels, rows) = select_with_labels(storage, query, None).await?;
+    let rows = rows.try_collect::<Vec<_>>().await?;
+
+    let column_defs = labels.map(|labels| {
+        let declared = declared
+            .filter(|declared| declared.len() == labels.len())
+            .unwrap_or_else(|| vec![None; labels.len()]);
+
+        labels
+            .into_iter()
+            .zip(declared)
+            .enumerate()
+            .map(|(i, (name, declared))| {
+                let mut values = rows.iter().filter_map(|row| match row {
+                    Row::Vec { values, .. } => values.get(i),
+                    Row::Map(_) => None,
+                });
+
+                match declared {
+                    // Outer joins may fill NOT NULL columns with NULL
+                    Some(column_def) => ColumnDef {
+                        name,
+                        nullable: column_def.nullable || values.any(Value::is_null),
+                        unique: None,
+                        ..column_def
+                    },
+                    None => {
+                        let data_type = values.find_map(Value::get_type).unwrap_or(DataType::Text);
+
+                        ColumnDef {
+                            name,
+                            data_type,
+                            nullable: true,
+                            default: None,
+                            unique: None,
+                            collation: None,
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    if let Some(column_defs) = column_defs.as_deref() {
+        validate_column_names(column_defs)?;
+    }
+
+    Ok((column_defs, rows.into_iter().map(Into::into).collect()))
+}
+
+/// Column definitions of the projected columns of the left-most `SELECT` which are table
+/// columns as they are, `None` for the others.
+async fn declared_column_defs<T: GStore>(
+    storage: &T,
+    query: &Query,
+) -> Result<Option<Vec<Option<ColumnDef>>>> {
+    let select = match leftmost_set_expr(&query.body) {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } => return Ok(None),
+    };
+
+    let table_factors = iter::once(&select.from.relation)
+        .chain(select.from.joins.iter().map(|join| &join.relation));
+    let mut tables = Vec::new();
+
+    for table_factor in table_factors {
+        let column_defs = match table_factor {
+            TableFactor::Table { name, .. } => storage
+                .fetch_schema(name)
+                .await?
+                .and_then(|schema| schema.column_defs),
+            TableFactor::Derived { .. }
+            | TableFactor::Series { .. }
+            | TableFactor::Unnest { .. }
+            | TableFactor::Function { .. }
+            | TableFactor::Dictionary { .. } => None,
+        };
+
+        tables.push((get_alias(table_factor), column_defs));
+    }
+
+    let find_table = |alias: &str| {
+        tables
+            .iter()
+            .find(|(name, _)| name.as_str() == alias)
+            .and_then(|(_, column_defs)| column_defs.as_ref())
+    };
+
+    let mut column_defs = Vec::new();
+
+    for select_item in &select.projection {
+        match select_item {
+            SelectItem::Wildcard => {
+                for (_, table_column_defs) in &tables {
+                    match table_column_defs {
+                        Some(table_column_defs) => {
+                            column_defs.extend(table_column_defs.iter().cloned().map(Some))
+                        }
+                        None => return Ok(None),
+                    }
+                }
+            }
+            SelectItem::QualifiedWildcard(alias) => match find_table(alias) {
+                Some(table_column_defs) => {
+                    column_defs.extend(table_column_defs.iter().cloned().map(Some))
+                }
+                None => return Ok(None),
+            },
+            SelectItem::Expr { expr, .. } => {
+                let column_def = match expr {
+                    Expr::Identifier(ident) => {
+                        let mut found = tables
+                            .iter()
+                            .filter_map(|(_, column_defs)| column_defs.as_ref())
+                            .flatten()
+                            .filter(|column_def| &column_def.name == ident);
+
+                        match (found.next(), found.next()) {
+                            (Some(column_def), None) => Some(column_def.clone()),
+                            _ => None,
+                        }
+                    }
+                    Expr::CompoundIdentifier { alias, ident } => find_table(alias)
+                        .and_then(|column_defs| {
+                            column_defs
+                                .iter()
+                                .find(|column_def| &column_def.name == ident)
+                        })
+                        .cloned(),
+                    _ => None,
+                };
+
+                column_defs.push(column_def);
+            }
+        }
+    }
+
+    Ok(Some(column_defs))
+}
+
+/// Column definitions of a compound query come from its left-most branch.
+fn leftmost_set_expr(set_expr: &SetExpr) -> &SetExpr {
+    match set_expr {
+        SetExpr::SetOperation { left, .. } => leftmost_set_expr(left),
+        SetExpr::Select(_) | SetExpr::Values(_) => set_expr,
+    }
+}
@@ -1,10 +1,15 @@
 use {
     super::AlterError,
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, DataType, OperateFunctionArg},
+        ast::{
+            CheckConstraint, ColumnDef, ColumnUniqueOption, DataType, Expr, OperateFunctionArg,
+            ToSql,
+        },
         executor::evaluate_stateless,
+        plan::{check_evaluable, Context},
         result::Result,
     },
+    std::rc::Rc,
 };
 
 pub async fn validate(column_def: &ColumnDef) -> Result<()> {
@@ -13,7 +18,8 @@ pub async fn validate(column_def: &ColumnDef) -> Result<()> {
         default,
         unique,
         name,
-        ..
+        nullable,
+        collation,
     } = column_def;
 
     // unique + data type
@@ -27,13 +33,50 @@ pub async fn validate(column_def: &ColumnDef) -> Result<()> {
         .into());
     }
 
+    // collation + data type
+    if collation.is_some() && !matches!(data_type, DataType::Text) {
+        return Err(AlterError::UnsupportedDataTypeForCollation(
+            name.to_owned(),
+            data_type.clone(),
+        )
+        .into());
+    }
+
     if let Some(expr) = default {
-        evaluate_stateless(None, expr).await?;
+        let evaluated = evaluate_stateless(None, expr).await?;
+
+        if !nullable && evaluated.is_null() {
+            return Err(AlterError::NullDefaultOnNotNullColumn(name.to_owned()).into());
+        }
     }
 
     Ok(())
 }
 
+/// `CHECK` can only refer to the columns of its own table.
+pub fn validate_check(
+    table_name: &str,
+    column_defs: Option<&[ColumnDef]>,
+    check: &CheckConstraint,
+) -> Result<()> {
+    let columns = column_defs
+        .into_iter()
+        .flatten()
+        .map(|column_def| column_def.name.as_str())
+        .collect();
+
+    match is_check_evaluable(table_name, columns, &check.expr) {
+        true => Ok(()),
+        false => Err(AlterError::UnsupportedCheckExpr(check.to_sql()).into()),
+    }
+}
+
+pub fn is_check_evaluable(table_name: &str, columns: Vec<&str>, expr: &Expr) -> bool {
+    let context = Context::new(table_name.to_owned(), columns, None, None);
+
+    check_evaluable(Some(Rc::new(context)), expr)
+}
+
 pub fn validate_column_names(column_defs: &[ColumnDef]) -> Result<()> {
     let duplicate_column_name = column_defs
         .iter()
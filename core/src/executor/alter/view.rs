@@ -0,0 +1,160 @@
+use {
+    super::{select_with_column_defs, AlterError},
+    crate::{
+        ast::{ColumnDef, Query, Statement},
+        data::{Schema, SchemaView, TableError},
+        plan::{fetch_schema_map, fetch_view_map, plan},
+        result::{Error, Result},
+        store::{DataRow, GStore, GStoreMut},
+    },
+};
+
+pub async fn create_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+    query: &Query,
+    or_replace: bool,
+    materialized: bool,
+) -> Result<()> {
+    let replaced = match storage.fetch_schema(view_name).await? {
+        Some(Schema { view: Some(_), .. }) if or_replace => true,
+        Some(_) => return Err(AlterError::TableAlreadyExists(view_name.to_owned()).into()),
+        None => false,
+    };
+
+    let schema_map = fetch_schema_map(storage, &Statement::Query(query.clone())).await?;
+    if schema_map.contains_key(view_name)
+        || fetch_view_map(storage, &schema_map)
+            .await?
+            .contains_key(view_name)
+    {
+        return Err(AlterError::CyclicViewReference(view_name.to_owned()).into());
+    }
+
+    let (column_defs, rows) = match materialized {
+        true => materialize(storage, query).await?,
+        false => (None, Vec::new()),
+    };
+
+    let schema = Schema {
+        table_name: view_name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        engine: None,
+        foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        view: Some(SchemaView {
+            query: query.clone(),
+            materialized,
+        }),
+    };
+
+    if replaced {
+        storage.delete_schema(view_name).await?;
+    }
+
+    storage.insert_schema(&schema).await?;
+
+    match materialized {
+        true => storage.append_data(view_name, rows).await,
+        false => Ok(()),
+    }
+}
+
+pub async fn refresh_materialized_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+) -> Result<()> {
+    let query = match storage.fetch_schema(view_name).await? {
+        Some(Schema {
+            view:
+                Some(SchemaView {
+                    query,
+                    materialized: true,
+                }),
+            ..
+        }) => query,
+        _ => return Err(AlterError::MaterializedViewNotFound(view_name.to_owned()).into()),
+    };
+
+    let (column_defs, rows) = materialize(storage, &query).await?;
+    let schema = Schema {
+        table_name: view_name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        engine: None,
+        foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        view: Some(SchemaView {
+            query,
+            materialized: true,
+        }),
+    };
+
+    storage.delete_schema(view_name).await?;
+    storage.insert_schema(&schema).await?;
+    storage.append_data(view_name, rows).await
+}
+
+async fn materialize<T: GStore>(
+    storage: &T,
+    query: &Query,
+) -> Result<(Option<Vec<ColumnDef>>, Vec<DataRow>)> {
+    match plan(storage, Statement::Query(query.clone())).await? {
+        Statement::Query(query) => select_with_column_defs(storage, &query).await,
+        _ => Err(Error::Table(TableError::Unreachable)),
+    }
+}
+
+pub async fn drop_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for view_name in view_names {
+        match storage.fetch_schema(view_name).await? {
+            Some(Schema { view: Some(_), .. }) => {}
+            None if if_exists => continue,
+            _ => return Err(AlterError::ViewNotFound(view_name.to_owned()).into()),
+        }
+
+        validate_not_referenced_by_view(storage, view_name, view_names).await?;
+
+        storage.delete_schema(view_name).await?;
+    }
+
+    Ok(())
+}
+
+/// Tables and views used by the query of a remaining view cannot be dropped or renamed.
+/// Views listed in `dropped` are going away as well, so they are not counted.
+pub(super) async fn validate_not_referenced_by_view<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    dropped: &[String],
+) -> Result<()> {
+    for Schema {
+        table_name: view_name,
+        view,
+        ..
+    } in storage.fetch_all_schemas().await?
+    {
+        let query = match view {
+            Some(SchemaView { query, .. }) if !dropped.contains(&view_name) => query,
+            _ => continue,
+        };
+
+        if fetch_schema_map(storage, &Statement::Query(query))
+            .await?
+            .contains_key(table_name)
+        {
+            return Err(AlterError::TableReferencedByView {
+                table_name: table_name.to_owned(),
+                view_name,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
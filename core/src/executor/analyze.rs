@@ -0,0 +1,128 @@
+use {
+    super::ExecuteError,
+    crate::{
+        data::{ColumnStatistics, Key, Schema, SchemaView, TableStatistics, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    std::{cmp::Ordering, collections::HashSet},
+};
+
+/// Collects the statistics of the table, or of every table when `table_name` is not given,
+/// and stores them in the storage. Views which are not materialized are skipped.
+pub async fn analyze<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: Option<&str>,
+) -> Result<()> {
+    let schemas = match table_name {
+        Some(table_name) => storage
+            .fetch_schema(table_name)
+            .await?
+            .map(|schema| vec![schema])
+            .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?,
+        None => storage.fetch_all_schemas().await?,
+    };
+
+    for schema in schemas {
+        if matches!(
+            schema.view,
+            Some(SchemaView {
+                materialized: false,
+                ..
+            })
+        ) {
+            continue;
+        }
+
+        let statistics = collect(storage, &schema).await?;
+
+        storage
+            .insert_statistics(&schema.table_name, statistics)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn collect<T: GStore>(storage: &T, schema: &Schema) -> Result<TableStatistics> {
+    let mut columns = schema
+        .column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| ColumnCollector::new(column_def.name.to_owned()))
+        .collect::<Vec<_>>();
+    let mut row_count = 0;
+
+    for item in storage.scan_data(&schema.table_name).await? {
+        let (_, row) = item?;
+        row_count += 1;
+
+        if let DataRow::Vec(values) = row {
+            for (column, value) in columns.iter_mut().zip(values) {
+                column.add(value);
+            }
+        }
+    }
+
+    Ok(TableStatistics {
+        row_count,
+        columns: columns.into_iter().map(ColumnCollector::finish).collect(),
+    })
+}
+
+struct ColumnCollector {
+    name: String,
+    distinct: HashSet<Key>,
+    null_count: usize,
+    /// `None` once two of the values cannot be compared with each other
+    range: Option<Option<(Value, Value)>>,
+}
+
+impl ColumnCollector {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            distinct: HashSet::new(),
+            null_count: 0,
+            range: Some(None),
+        }
+    }
+
+    fn add(&mut self, value: Value) {
+        if value.is_null() {
+            self.null_count += 1;
+
+            return;
+        }
+
+        // lists and maps cannot be keys, so they are told apart by their text
+        let key = Key::try_from(&value).unwrap_or_else(|_| Key::Str(String::from(&value)));
+        self.distinct.insert(key);
+
+        self.range = match self.range.take() {
+            Some(None) => Some(Some((value.clone(), value))),
+            Some(Some((min, max))) => match (value.evaluate_cmp(&min), value.evaluate_cmp(&max)) {
+                (Some(Ordering::Less), Some(_)) => Some(Some((value, max))),
+                (Some(_), Some(Ordering::Greater)) => Some(Some((min, value))),
+                (Some(_), Some(_)) => Some(Some((min, max))),
+                _ => None,
+            },
+            None => None,
+        };
+    }
+
+    fn finish(self) -> ColumnStatistics {
+        let (min, max) = match self.range.flatten() {
+            Some((min, max)) => (Some(min), Some(max)),
+            None => (None, None),
+        };
+
+        ColumnStatistics {
+            name: self.name,
+            distinct_count: self.distinct.len(),
+            null_count: self.null_count,
+            min,
+            max,
+        }
+    }
+}
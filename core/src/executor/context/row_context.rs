@@ -15,6 +15,14 @@ pub enum RowContext<'a> {
         values: &'a [Value],
     },
     RefMapData(&'a HashMap<String, Value>),
+    /// Row joined with `USING` or `NATURAL`, its `columns` are merged into the left side
+    /// so only qualified references resolve to them directly.
+    Using {
+        table_alias: &'a str,
+        row: Cow<'a, Row>,
+        columns: Rc<[String]>,
+        next: Rc<RowContext<'a>>,
+    },
     Bridge {
         left: Rc<RowContext<'a>>,
         right: Rc<RowContext<'a>>,
@@ -30,6 +38,20 @@ impl<'a> RowContext<'a> {
         }
     }
 
+    pub fn using(
+        table_alias: &'a str,
+        row: Cow<'a, Row>,
+        columns: Rc<[String]>,
+        next: Rc<RowContext<'a>>,
+    ) -> Self {
+        Self::Using {
+            table_alias,
+            row,
+            columns,
+            next,
+        }
+    }
+
     pub fn concat(left: Rc<RowContext<'a>>, right: Rc<RowContext<'a>>) -> Self {
         Self::Bridge { left, right }
     }
@@ -44,6 +66,15 @@ impl<'a> RowContext<'a> {
                 next: Some(next),
                 ..
             } => row.get_value(target).or_else(|| next.get_value(target)),
+            Self::Using {
+                row, columns, next, ..
+            } if columns.iter().any(|column| column == target) => next
+                .get_value(target)
+                .filter(|value| !value.is_null())
+                .or_else(|| row.get_value(target)),
+            Self::Using { row, next, .. } => {
+                row.get_value(target).or_else(|| next.get_value(target))
+            }
             Self::Bridge { left, right } => {
                 left.get_value(target).or_else(|| right.get_value(target))
             }
@@ -75,6 +106,17 @@ impl<'a> RowContext<'a> {
             Self::Data {
                 next: Some(next), ..
             } => next.get_alias_value(target_table_alias, target),
+            Self::Using {
+                table_alias,
+                row,
+                next,
+                ..
+            } => match *table_alias == target_table_alias {
+                true => row
+                    .get_value(target)
+                    .or_else(|| next.get_alias_value(target_table_alias, target)),
+                false => next.get_alias_value(target_table_alias, target),
+            },
             Self::Bridge { left, right } => left
                 .get_alias_value(target_table_alias, target)
                 .or_else(|| right.get_alias_value(target_table_alias, target)),
@@ -91,6 +133,10 @@ impl<'a> RowContext<'a> {
             Self::Data {
                 next: Some(next), ..
             } => next.get_alias_entries(alias),
+            Self::Using {
+                table_alias, row, ..
+            } if *table_alias == alias => Some(row.iter().map(|(k, v)| (k, v.clone())).collect()),
+            Self::Using { next, .. } => next.get_alias_entries(alias),
             Self::Bridge { left, right } => left
                 .get_alias_entries(alias)
                 .or_else(|| right.get_alias_entries(alias)),
@@ -112,6 +158,29 @@ impl<'a> RowContext<'a> {
                 .into_iter()
                 .chain(row.iter().map(|(k, v)| (k, v.clone())))
                 .collect(),
+            Self::Using {
+                row, columns, next, ..
+            } => {
+                let mut entries = next.get_all_entries();
+                for column in columns.iter() {
+                    let merged = entries.iter_mut().rev().find(|(key, _)| *key == column);
+
+                    if let (Some((_, merged @ Value::Null)), Some(value)) =
+                        (merged, row.get_value(column))
+                    {
+                        *merged = value.clone();
+                    }
+                }
+
+                entries
+                    .into_iter()
+                    .chain(
+                        row.iter()
+                            .filter(|(k, _)| !columns.contains(k))
+                            .map(|(k, v)| (k, v.clone())),
+                    )
+                    .collect()
+            }
             Self::Bridge { left, right } => {
                 [left.get_all_entries(), right.get_all_entries()].concat()
             }
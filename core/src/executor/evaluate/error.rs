@@ -35,12 +35,36 @@ pub enum EvaluateError {
     #[error("function requires map value: {0}")]
     FunctionRequiresMapValue(String),
 
+    #[error("function requires json text, map or list value: {0}")]
+    FunctionRequiresJsonValue(String),
+
+    #[error("invalid json path: {0}")]
+    InvalidJsonPath(String),
+
     #[error("function requires point value: {0}")]
     FunctionRequiresPointValue(String),
 
+    #[error("function requires bytea value: {0}")]
+    FunctionRequiresByteaValue(String),
+
+    #[error("function requires string or bytea value: {0}")]
+    FunctionRequiresStringOrByteaValue(String),
+
     #[error("function requires date or datetime value: {0}")]
     FunctionRequiresDateOrDateTimeValue(String),
 
+    #[error("unsupported field for DATE_TRUNC: {0}")]
+    UnsupportedDateTruncField(String),
+
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+
+    #[error("unsupported regular expression flag: {0}")]
+    UnsupportedRegexFlag(String),
+
+    #[error("regular expression match requires string operands")]
+    RegexMatchOnNonString,
+
     #[error("function requires one of string, list, map types: {0}")]
     FunctionRequiresStrOrListOrMapValue(String),
 
@@ -110,6 +134,12 @@ pub enum EvaluateError {
     #[error("non-ascii character not allowed")]
     NonAsciiCharacterNotAllowed,
 
+    #[error("unsupported encoding format: {0}")]
+    UnsupportedEncodingFormat(String),
+
+    #[error("failed to decode {text} as {format}")]
+    FailedToDecode { text: String, format: String },
+
     #[error("function requires integer value in range")]
     ChrFunctionRequiresIntegerValueInRange0To255,
 
@@ -110,10 +110,10 @@ where
     match (l, r) {
         (Evaluated::Literal(l), Evaluated::Literal(r)) => literal_op(l, r).map(Evaluated::Literal),
         (Evaluated::Literal(l), Evaluated::Value(r)) => {
-            value_op(&Value::try_from(l)?, r).map(Evaluated::from)
+            value_op(&literal_operand(l, r)?, r).map(Evaluated::from)
         }
         (Evaluated::Value(l), Evaluated::Literal(r)) => {
-            value_op(l, &Value::try_from(r)?).map(Evaluated::from)
+            value_op(l, &literal_operand(r, l)?).map(Evaluated::from)
         }
         (Evaluated::Value(l), Evaluated::Value(r)) => value_op(l, r).map(Evaluated::from),
         (l, r) => Err(EvaluateError::UnsupportedBinaryOperation {
@@ -125,6 +125,36 @@ where
     }
 }
 
+/// Number literals next to a `DECIMAL` value are read as decimals, not as floats, so that
+/// the arithmetic stays exact. Text literals next to a number are read as that number type,
+/// as `CAST` would.
+fn literal_operand(literal: &Literal<'_>, other: &Value) -> Result<Value> {
+    match (literal, other.get_type()) {
+        (Literal::Number(_), Some(data_type @ DataType::Decimal)) => {
+            Value::try_from_literal(&data_type, literal)
+        }
+        (
+            Literal::Text(_),
+            Some(
+                data_type @ (DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int
+                | DataType::Int128
+                | DataType::Uint8
+                | DataType::Uint16
+                | DataType::Uint32
+                | DataType::Uint64
+                | DataType::Uint128
+                | DataType::Float32
+                | DataType::Float
+                | DataType::Decimal),
+            ),
+        ) => Value::try_cast_from_literal(&data_type, literal),
+        _ => Value::try_from(literal),
+    }
+}
+
 pub fn exceptional_int_val_to_eval<'a>(name: String, v: Value) -> Result<Evaluated<'a>> {
     match v {
         Value::Null => Ok(Evaluated::from(Value::Null)),
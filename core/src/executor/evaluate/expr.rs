@@ -1,10 +1,11 @@
 use {
-    super::{EvaluateError, Evaluated},
+    super::{function::compile_regex, EvaluateError, Evaluated},
     crate::{
-        ast::{AstLiteral, BinaryOperator, DataType, UnaryOperator},
+        ast::{AstLiteral, BinaryOperator, Collation, DataType, UnaryOperator},
         data::{Literal, Value},
         result::Result,
     },
+    futures::stream::{Stream, TryStreamExt},
     std::{borrow::Cow, cmp::Ordering},
 };
 
@@ -25,17 +26,22 @@ pub fn binary_op<'a>(
 ) -> Result<Evaluated<'a>> {
     macro_rules! cmp {
         ($expr: expr) => {
-            Ok(Evaluated::from(Value::Bool($expr)))
+            Ok(Evaluated::from(match l.is_null() || r.is_null() {
+                true => Value::Null,
+                false => Value::Bool($expr),
+            }))
         };
     }
 
     macro_rules! cond {
-        (l $op: tt r) => {{
-            let l: bool = l.try_into()?;
-            let r: bool = r.try_into()?;
-            let v = l $op r;
+        ($l: ident, $r: ident => $expr: expr) => {{
+            let $l = nullable_bool(l)?;
+            let $r = nullable_bool(r)?;
 
-            Ok(Evaluated::from(Value::Bool(v)))
+            Ok(Evaluated::from(match $expr {
+                Some(v) => Value::Bool(v),
+                None => Value::Null,
+            }))
         }};
     }
 
@@ -48,15 +54,115 @@ pub fn binary_op<'a>(
         BinaryOperator::StringConcat => l.concat(r),
         BinaryOperator::Eq => cmp!(l.evaluate_eq(&r)),
         BinaryOperator::NotEq => cmp!(!l.evaluate_eq(&r)),
-        BinaryOperator::Lt => cmp!(l.evaluate_cmp(&r) == Some(Ordering::Less)),
-        BinaryOperator::LtEq => cmp!(l.evaluate_cmp(&r) != Some(Ordering::Greater)),
-        BinaryOperator::Gt => cmp!(l.evaluate_cmp(&r) == Some(Ordering::Greater)),
-        BinaryOperator::GtEq => cmp!(l.evaluate_cmp(&r) != Some(Ordering::Less)),
-        BinaryOperator::And => cond!(l && r),
-        BinaryOperator::Or => cond!(l || r),
-        BinaryOperator::Xor => cond!(l ^ r),
+        BinaryOperator::Lt => cmp!(matches!(l.evaluate_cmp(&r), Some(Ordering::Less))),
+        BinaryOperator::LtEq => cmp!(matches!(
+            l.evaluate_cmp(&r),
+            Some(Ordering::Less | Ordering::Equal)
+        )),
+        BinaryOperator::Gt => cmp!(matches!(l.evaluate_cmp(&r), Some(Ordering::Greater))),
+        BinaryOperator::GtEq => cmp!(matches!(
+            l.evaluate_cmp(&r),
+            Some(Ordering::Greater | Ordering::Equal)
+        )),
+        BinaryOperator::And => cond!(l, r => match (l, r) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        }),
+        BinaryOperator::Or => cond!(l, r => match (l, r) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        }),
+        BinaryOperator::Xor => cond!(l, r => l.zip(r).map(|(l, r)| l ^ r)),
         BinaryOperator::BitwiseAnd => l.bitwise_and(&r),
         BinaryOperator::BitwiseShiftLeft => l.bitwise_shift_left(&r),
+        BinaryOperator::Contains => contains(l, r),
+        BinaryOperator::ContainedBy => contains(r, l),
+        BinaryOperator::Arrow => select_key(l, r).map(Evaluated::from),
+        BinaryOperator::LongArrow => select_key(l, r).map(|value| match value {
+            Value::Null => Evaluated::from(Value::Null),
+            value => Evaluated::from(Value::Str(String::from(&value))),
+        }),
+        BinaryOperator::RegexMatch => regex_match(l, r, false),
+        BinaryOperator::RegexIMatch => regex_match(l, r, true),
+        BinaryOperator::RegexNotMatch => regex_match(l, r, false).and_then(not),
+        BinaryOperator::RegexNotIMatch => regex_match(l, r, true).and_then(not),
+    }
+}
+
+/// Evaluates `text ~ pattern`, which is true when the pattern matches any part of the text.
+fn regex_match<'a>(
+    text: Evaluated<'a>,
+    pattern: Evaluated<'a>,
+    case_insensitive: bool,
+) -> Result<Evaluated<'a>> {
+    if text.is_null() || pattern.is_null() {
+        return Ok(Evaluated::from(Value::Null));
+    }
+
+    match (Value::try_from(text)?, Value::try_from(pattern)?) {
+        (Value::Str(text), Value::Str(pattern)) => {
+            let matched = compile_regex(&pattern, case_insensitive)?.is_match(&text);
+
+            Ok(Evaluated::from(Value::Bool(matched)))
+        }
+        _ => Err(EvaluateError::RegexMatchOnNonString.into()),
+    }
+}
+
+fn not(v: Evaluated<'_>) -> Result<Evaluated<'_>> {
+    unary_op(&UnaryOperator::Not, v)
+}
+
+/// Selects the value of a map key or a list index for `doc -> key` and `doc ->> key`.
+/// Text is parsed as a JSON document first.
+fn select_key(doc: Evaluated<'_>, key: Evaluated<'_>) -> Result<Value> {
+    if doc.is_null() || key.is_null() {
+        return Ok(Value::Null);
+    }
+
+    let key = Value::try_from(key)?;
+    let doc = match Value::try_from(doc)? {
+        Value::Str(text) => Value::parse_json(&text)?,
+        doc => doc,
+    };
+
+    doc.selector_by_index(&[key])
+}
+
+/// Evaluates `list @> items`, which is true when every item is found in the list.
+fn contains<'a>(list: Evaluated<'a>, items: Evaluated<'a>) -> Result<Evaluated<'a>> {
+    if list.is_null() || items.is_null() {
+        return Ok(Evaluated::from(Value::Null));
+    }
+
+    match (Value::try_from(list)?, Value::try_from(items)?) {
+        (Value::List(list), Value::List(items)) => {
+            let contained = items
+                .iter()
+                .all(|item| list.iter().any(|value| value.evaluate_eq(item)));
+
+            Ok(Evaluated::from(Value::Bool(contained)))
+        }
+        _ => Err(EvaluateError::ListTypeRequired.into()),
+    }
+}
+
+/// Text under `NOCASE` is folded to lowercase, so that comparing, sorting and matching the
+/// collated operands ignores the letter case.
+pub fn collate<'a>(evaluated: Evaluated<'a>, collation: &Collation) -> Evaluated<'a> {
+    match (collation, evaluated) {
+        (Collation::NoCase, Evaluated::Literal(Literal::Text(v))) => {
+            Evaluated::Literal(Literal::Text(Cow::Owned(v.to_lowercase())))
+        }
+        (Collation::NoCase, Evaluated::StrSlice { source, range }) => {
+            Evaluated::from(Value::Str(source[range].to_lowercase()))
+        }
+        (Collation::NoCase, Evaluated::Value(Value::Str(v))) => {
+            Evaluated::from(Value::Str(v.to_lowercase()))
+        }
+        (_, evaluated) => evaluated,
     }
 }
 
@@ -64,23 +170,77 @@ pub fn unary_op<'a>(op: &UnaryOperator, v: Evaluated<'a>) -> Result<Evaluated<'a
     match op {
         UnaryOperator::Plus => v.unary_plus(),
         UnaryOperator::Minus => v.unary_minus(),
-        UnaryOperator::Not => v.try_into().map(|v: bool| Evaluated::from(Value::Bool(!v))),
+        UnaryOperator::Not => nullable_bool(v).map(|v| {
+            Evaluated::from(match v {
+                Some(v) => Value::Bool(!v),
+                None => Value::Null,
+            })
+        }),
         UnaryOperator::Factorial => v.unary_factorial(),
         UnaryOperator::BitwiseNot => v.unary_bitwise_not(),
     }
 }
 
+pub fn nullable_bool(v: Evaluated<'_>) -> Result<Option<bool>> {
+    match v.is_null() {
+        true => Ok(None),
+        false => v.try_into().map(Some),
+    }
+}
+
+/// Evaluates `target IN (list)`; the result is NULL when the target is NULL, or when no
+/// item matches and the list contains a NULL.
+pub async fn in_list<'a, 'b>(
+    target: Evaluated<'a>,
+    list: impl Stream<Item = Result<Evaluated<'b>>>,
+    negated: bool,
+) -> Result<Evaluated<'a>> {
+    if target.is_null() {
+        return Ok(Evaluated::from(Value::Null));
+    }
+
+    futures::pin_mut!(list);
+
+    let mut unknown = false;
+    while let Some(evaluated) = list.try_next().await? {
+        if evaluated.is_null() {
+            unknown = true;
+        } else if evaluated.evaluate_eq(&target) {
+            return Ok(Evaluated::from(Value::Bool(!negated)));
+        }
+    }
+
+    Ok(Evaluated::from(match unknown {
+        true => Value::Null,
+        false => Value::Bool(negated),
+    }))
+}
+
 pub fn between<'a>(
     target: Evaluated<'a>,
     negated: bool,
     low: Evaluated<'a>,
     high: Evaluated<'a>,
 ) -> Result<Evaluated<'a>> {
-    let v = low.evaluate_cmp(&target) != Some(Ordering::Greater)
-        && target.evaluate_cmp(&high) != Some(Ordering::Greater);
-    let v = negated ^ v;
+    if target.is_null() {
+        return Ok(Evaluated::from(Value::Null));
+    }
+
+    let lte = |l: &Evaluated<'a>, r: &Evaluated<'a>| {
+        (!l.is_null() && !r.is_null())
+            .then(|| matches!(l.evaluate_cmp(r), Some(Ordering::Less | Ordering::Equal)))
+    };
+
+    let v = match (lte(&low, &target), lte(&target, &high)) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    };
 
-    Ok(Evaluated::from(Value::Bool(v)))
+    Ok(Evaluated::from(match v {
+        Some(v) => Value::Bool(negated ^ v),
+        None => Value::Null,
+    }))
 }
 
 pub fn array_index<'a>(obj: Evaluated<'a>, indexes: Vec<Evaluated<'a>>) -> Result<Evaluated<'a>> {
@@ -5,10 +5,14 @@ use {
         data::{Key, Point, Value, ValueError},
         result::{Error, Result},
     },
-    chrono::{Datelike, Duration, Months},
+    base64::{engine::general_purpose::STANDARD as BASE64, Engine},
+    chrono::{Datelike, Duration, Months, NaiveTime, Timelike},
     md5::{Digest, Md5},
     rand::{rngs::StdRng, Rng, SeedableRng},
-    std::ops::ControlFlow,
+    regex::{Captures, Regex, RegexBuilder},
+    rust_decimal::RoundingStrategy,
+    sha2::Sha256,
+    std::{cmp::Ordering, ops::ControlFlow},
     uuid::Uuid,
 };
 
@@ -26,6 +30,21 @@ macro_rules! eval_to_str {
     };
 }
 
+macro_rules! eval_to_bytes {
+    ($name: expr, $evaluated: expr) => {
+        match $evaluated.try_into()? {
+            Value::Str(value) => value.into_bytes(),
+            Value::Bytea(value) => value,
+            Value::Null => {
+                return Ok(Evaluated::from(Value::Null));
+            }
+            _ => {
+                return Err(EvaluateError::FunctionRequiresStringOrByteaValue($name).into());
+            }
+        }
+    };
+}
+
 macro_rules! eval_to_int {
     ($name: expr, $evaluated: expr) => {
         match $evaluated.try_into()? {
@@ -43,7 +62,17 @@ macro_rules! eval_to_int {
 macro_rules! eval_to_float {
     ($name: expr, $evaluated: expr) => {
         match $evaluated.try_into()? {
-            Value::I64(v) => v as f64,
+            value @ (Value::I8(_)
+            | Value::I16(_)
+            | Value::I32(_)
+            | Value::I64(_)
+            | Value::I128(_)
+            | Value::U8(_)
+            | Value::U16(_)
+            | Value::U32(_)
+            | Value::U64(_)
+            | Value::U128(_)
+            | Value::Decimal(_)) => f64::try_from(&value)?,
             Value::F32(v) => v.into(),
             Value::F64(v) => v,
             Value::Null => {
@@ -246,6 +275,92 @@ pub fn replace<'a>(
     Ok(Evaluated::from(Value::Str(value)))
 }
 
+pub fn compile_regex(pattern: &str, case_insensitive: bool) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .map_err(|err| EvaluateError::InvalidRegex(err.to_string()).into())
+}
+
+/// Reads the flags of `REGEXP_*` functions, `i` for case-insensitive matching and `g` for
+/// every match instead of the first one, and returns them as `(case_insensitive, global)`.
+fn regex_flags(flags: &str) -> Result<(bool, bool)> {
+    flags.chars().try_fold(
+        (false, false),
+        |(case_insensitive, global), flag| match flag {
+            'i' => Ok((true, global)),
+            'g' => Ok((case_insensitive, true)),
+            _ => Err(EvaluateError::UnsupportedRegexFlag(flag.to_string()).into()),
+        },
+    )
+}
+
+/// Returns the capture groups of the first match as a list, or the whole match when the
+/// pattern has no groups. With the `g` flag, the lists of every match are returned instead.
+pub fn regexp_matches<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> Result<Evaluated<'a>> {
+    let expr = eval_to_str!(name, expr);
+    let pattern = eval_to_str!(name, pattern);
+    let flags = match flags {
+        Some(flags) => eval_to_str!(name, flags),
+        None => String::new(),
+    };
+
+    let (case_insensitive, global) = regex_flags(&flags)?;
+    let regex = compile_regex(&pattern, case_insensitive)?;
+    let groups = |captures: Captures<'_>| {
+        let skip = usize::from(captures.len() > 1);
+        let groups = captures
+            .iter()
+            .skip(skip)
+            .map(|group| match group {
+                Some(group) => Value::Str(group.as_str().to_owned()),
+                None => Value::Null,
+            })
+            .collect();
+
+        Value::List(groups)
+    };
+
+    let matches = match global {
+        true => Value::List(regex.captures_iter(&expr).map(groups).collect()),
+        false => regex.captures(&expr).map(groups).unwrap_or(Value::Null),
+    };
+
+    Ok(Evaluated::from(matches))
+}
+
+/// Replaces the first match, or every match with the `g` flag. The replacement refers to
+/// capture groups as `$1`, `$2` and so on.
+pub fn regexp_replace<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    pattern: Evaluated<'_>,
+    replacement: Evaluated<'_>,
+    flags: Option<Evaluated<'_>>,
+) -> Result<Evaluated<'a>> {
+    let expr = eval_to_str!(name, expr);
+    let pattern = eval_to_str!(name, pattern);
+    let replacement = eval_to_str!(name, replacement);
+    let flags = match flags {
+        Some(flags) => eval_to_str!(name, flags),
+        None => String::new(),
+    };
+
+    let (case_insensitive, global) = regex_flags(&flags)?;
+    let regex = compile_regex(&pattern, case_insensitive)?;
+    let replaced = match global {
+        true => regex.replace_all(&expr, replacement.as_str()),
+        false => regex.replace(&expr, replacement.as_str()),
+    };
+
+    Ok(Evaluated::from(Value::Str(replaced.into_owned())))
+}
+
 pub fn ascii<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
     let string = eval_to_str!(name, expr);
     let mut iter = string.chars();
@@ -275,23 +390,87 @@ pub fn chr<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
 }
 
 pub fn md5<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    let string = eval_to_str!(name, expr);
+    let bytes = eval_to_bytes!(name, expr);
     let mut hasher = Md5::new();
-    hasher.update(string.as_bytes());
+    hasher.update(bytes);
     let result = hasher.finalize();
     let result = format!("{:x}", result);
 
     Ok(Evaluated::from(Value::Str(result)))
 }
 
+pub fn sha256<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    let bytes = eval_to_bytes!(name, expr);
+    let result = format!("{:x}", Sha256::digest(bytes));
+
+    Ok(Evaluated::from(Value::Str(result)))
+}
+
+pub fn hex<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    let bytes = eval_to_bytes!(name, expr);
+
+    Ok(Evaluated::from(Value::Str(hex::encode(bytes))))
+}
+
+pub fn base64<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    let bytes = eval_to_bytes!(name, expr);
+
+    Ok(Evaluated::from(Value::Str(BASE64.encode(bytes))))
+}
+
+pub fn encode<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    format: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let bytes = match expr.try_into()? {
+        Value::Bytea(bytes) => bytes,
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresByteaValue(name).into()),
+    };
+    let format = eval_to_str!(name, format);
+
+    let encoded = match format.to_lowercase().as_str() {
+        "hex" => hex::encode(bytes),
+        "base64" => BASE64.encode(bytes),
+        _ => return Err(EvaluateError::UnsupportedEncodingFormat(format).into()),
+    };
+
+    Ok(Evaluated::from(Value::Str(encoded)))
+}
+
+pub fn decode<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    format: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let text = eval_to_str!(name, expr);
+    let format = eval_to_str!(name, format);
+
+    let decoded = match format.to_lowercase().as_str() {
+        "hex" => hex::decode(&text).ok(),
+        "base64" => BASE64.decode(&text).ok(),
+        _ => return Err(EvaluateError::UnsupportedEncodingFormat(format).into()),
+    };
+
+    decoded
+        .map(|bytes| Evaluated::from(Value::Bytea(bytes)))
+        .ok_or_else(|| EvaluateError::FailedToDecode { text, format }.into())
+}
+
 // --- float ---
 
 pub fn abs<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
     match n.try_into()? {
-        Value::I8(v) => Ok(Evaluated::from(Value::I8(v.abs()))),
-        Value::I32(v) => Ok(Evaluated::from(Value::I32(v.abs()))),
-        Value::I64(v) => Ok(Evaluated::from(Value::I64(v.abs()))),
-        Value::I128(v) => Ok(Evaluated::from(Value::I128(v.abs()))),
+        value @ (Value::I8(_) | Value::I16(_) | Value::I32(_) | Value::I64(_) | Value::I128(_)) => {
+            match value.evaluate_cmp(&Value::I8(0)) {
+                Some(Ordering::Less) => value.unary_minus().map(Evaluated::from),
+                _ => Ok(Evaluated::from(value)),
+            }
+        }
+        value @ (Value::U8(_) | Value::U16(_) | Value::U32(_) | Value::U64(_) | Value::U128(_)) => {
+            Ok(Evaluated::from(value))
+        }
         Value::Decimal(v) => Ok(Evaluated::from(Value::Decimal(v.abs()))),
         Value::F32(v) => Ok(Evaluated::from(Value::F32(v.abs()))),
         Value::F64(v) => Ok(Evaluated::from(Value::F64(v.abs()))),
@@ -300,9 +479,9 @@ pub fn abs<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
     }
 }
 
-pub fn ifnull<'a>(expr: Evaluated<'a>, then: Evaluated<'a>) -> Result<Evaluated<'a>> {
-    Ok(match expr.is_null() {
-        true => then,
+pub fn nullif<'a>(expr: Evaluated<'a>, value: Evaluated<'a>) -> Result<Evaluated<'a>> {
+    Ok(match expr.evaluate_eq(&value) {
+        true => Evaluated::from(Value::Null),
         false => expr,
     })
 }
@@ -327,7 +506,12 @@ pub fn power<'a>(name: String, expr: Evaluated<'_>, power: Evaluated<'_>) -> Res
 }
 
 pub fn ceil<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).ceil())))
+    match n.try_into()? {
+        Value::Decimal(v) => Ok(Evaluated::from(Value::Decimal(v.ceil()))),
+        v => Ok(Evaluated::from(Value::F64(
+            eval_to_float!(name, Evaluated::from(v)).ceil(),
+        ))),
+    }
 }
 
 pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> Result<Evaluated<'a>> {
@@ -339,12 +523,25 @@ pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> Result<Evaluated<'
     Ok(Evaluated::from(Value::F64(seed)))
 }
 
+/// Halves are rounded away from zero, for both floats and decimals.
 pub fn round<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).round())))
+    match n.try_into()? {
+        Value::Decimal(v) => Ok(Evaluated::from(Value::Decimal(
+            v.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero),
+        ))),
+        v => Ok(Evaluated::from(Value::F64(
+            eval_to_float!(name, Evaluated::from(v)).round(),
+        ))),
+    }
 }
 
 pub fn floor<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).floor())))
+    match n.try_into()? {
+        Value::Decimal(v) => Ok(Evaluated::from(Value::Decimal(v.floor()))),
+        v => Ok(Evaluated::from(Value::F64(
+            eval_to_float!(name, Evaluated::from(v)).floor(),
+        ))),
+    }
 }
 
 pub fn radians<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
@@ -675,22 +872,115 @@ pub fn unwrap<'a>(
     Ok(Evaluated::from(value.selector(&selector)?))
 }
 
+/// Reads the document of `JSON_*` functions, in which text is parsed as JSON.
+fn json_document(name: String, doc: Evaluated<'_>) -> Result<Value> {
+    match doc.try_into()? {
+        Value::Str(text) => Value::parse_json(&text),
+        value @ (Value::Map(_) | Value::List(_) | Value::Null) => Ok(value),
+        _ => Err(EvaluateError::FunctionRequiresJsonValue(name).into()),
+    }
+}
+
+/// Splits a json path such as `$.items[0].name` into its keys and indexes.
+fn json_path(path: &str) -> Result<Vec<String>> {
+    let invalid = || -> Error { EvaluateError::InvalidJsonPath(path.to_owned()).into() };
+    let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+    let mut keys = Vec::new();
+
+    while !rest.is_empty() {
+        let (key, next) = match rest.strip_prefix('.') {
+            Some(member) => member.split_at(member.find(['.', '[']).unwrap_or(member.len())),
+            None => {
+                let index = rest.strip_prefix('[').ok_or_else(invalid)?;
+                let (index, next) = index.split_once(']').ok_or_else(invalid)?;
+
+                if index.parse::<usize>().is_err() {
+                    return Err(invalid());
+                }
+
+                (index, next)
+            }
+        };
+
+        if key.is_empty() {
+            return Err(invalid());
+        }
+
+        keys.push(key.to_owned());
+        rest = next;
+    }
+
+    Ok(keys)
+}
+
+fn json_select(name: String, doc: Evaluated<'_>, path: Evaluated<'_>) -> Result<Value> {
+    let doc = json_document(name.clone(), doc)?;
+    let path = match path.try_into()? {
+        Value::Str(path) => path,
+        Value::Null => return Ok(Value::Null),
+        _ => return Err(EvaluateError::FunctionRequiresStringValue(name).into()),
+    };
+
+    let selected = json_path(&path)?
+        .iter()
+        .try_fold(&doc, |value, key| match value {
+            Value::Map(map) => map.get(key),
+            Value::List(list) => key.parse::<usize>().ok().and_then(|i| list.get(i)),
+            _ => None,
+        })
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    Ok(selected)
+}
+
+pub fn json_extract<'a>(
+    name: String,
+    doc: Evaluated<'_>,
+    path: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    json_select(name, doc, path).map(Evaluated::from)
+}
+
+/// Same as `JSON_EXTRACT`, but returns the selected value as text, like `->>` does.
+pub fn json_value<'a>(
+    name: String,
+    doc: Evaluated<'_>,
+    path: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let value = match json_select(name, doc, path)? {
+        Value::Null => Value::Null,
+        value => Value::Str(String::from(&value)),
+    };
+
+    Ok(Evaluated::from(value))
+}
+
 pub fn generate_uuid<'a>() -> Evaluated<'a> {
     Evaluated::from(Value::Uuid(Uuid::new_v4().as_u128()))
 }
 
 pub fn greatest(name: String, exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'_>> {
+    pick(name, exprs, Ordering::Less)
+}
+
+pub fn least(name: String, exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'_>> {
+    pick(name, exprs, Ordering::Greater)
+}
+
+/// Keeps the first argument and replaces it by each later one which it compares to as `replace_on`.
+fn pick(name: String, exprs: Vec<Evaluated<'_>>, replace_on: Ordering) -> Result<Evaluated<'_>> {
     exprs
         .into_iter()
-        .try_fold(None, |greatest, expr| -> Result<_> {
-            let greatest = match greatest {
-                Some(greatest) => greatest,
+        .try_fold(None, |picked, expr| -> Result<_> {
+            let picked = match picked {
+                Some(picked) => picked,
                 None => return Ok(Some(expr)),
             };
 
-            match greatest.evaluate_cmp(&expr) {
-                Some(std::cmp::Ordering::Less) => Ok(Some(expr)),
-                Some(_) => Ok(Some(greatest)),
+            match picked.evaluate_cmp(&expr) {
+                Some(ordering) if ordering == replace_on => Ok(Some(expr)),
+                Some(_) => Ok(Some(picked)),
                 None => Err(EvaluateError::NonComparableArgumentError(name.to_owned()).into()),
             }
         })?
@@ -738,6 +1028,42 @@ pub fn last_day<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>>
     )))
 }
 
+pub fn date_trunc<'a>(
+    name: String,
+    field: Evaluated<'_>,
+    expr: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let field = eval_to_str!(name, field);
+    let (timestamp, is_date) = match expr.try_into()? {
+        Value::Date(date) => (date.and_time(NaiveTime::default()), true),
+        Value::Timestamp(timestamp) => (timestamp, false),
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresDateOrDateTimeValue(name).into()),
+    };
+
+    let date = timestamp.date();
+    let time = timestamp.time();
+    let midnight = |date: chrono::NaiveDate| date.and_time(NaiveTime::default());
+    let truncated = match field.to_uppercase().as_str() {
+        "YEAR" => midnight(date - Duration::days(date.ordinal0() as i64)),
+        "QUARTER" => {
+            midnight(date - Duration::days(date.day0() as i64) - Months::new(date.month0() % 3))
+        }
+        "MONTH" => midnight(date - Duration::days(date.day0() as i64)),
+        "WEEK" => midnight(date - Duration::days(date.weekday().num_days_from_monday() as i64)),
+        "DAY" => midnight(date),
+        "HOUR" => midnight(date) + Duration::hours(time.hour() as i64),
+        "MINUTE" => midnight(date) + Duration::minutes((time.hour() * 60 + time.minute()) as i64),
+        "SECOND" => midnight(date) + Duration::seconds(time.num_seconds_from_midnight() as i64),
+        _ => return Err(EvaluateError::UnsupportedDateTruncField(field).into()),
+    };
+
+    Ok(Evaluated::from(match is_date {
+        true => Value::Date(truncated.date()),
+        false => Value::Timestamp(truncated),
+    }))
+}
+
 pub fn to_date<'a>(
     name: String,
     expr: Evaluated<'_>,
@@ -867,36 +1193,12 @@ pub fn calc_distance<'a>(x: Evaluated<'_>, y: Evaluated<'_>) -> Result<Evaluated
     Ok(Evaluated::from(Value::F64(Point::calc_distance(&x, &y))))
 }
 
-pub fn coalesce<'a>(exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'a>> {
-    if exprs.is_empty() {
-        return Err((EvaluateError::FunctionRequiresMoreArguments {
-            function_name: "COALESCE".to_owned(),
-            required_minimum: 1,
-            found: exprs.len(),
-        })
-        .into());
-    }
-
-    let control_flow = exprs.into_iter().map(|expr| expr.try_into()).try_for_each(
-        |item: Result<Value>| match item {
-            Ok(value) if value.is_null() => ControlFlow::Continue(()),
-            Ok(value) => ControlFlow::Break(Ok(value)),
-            Err(err) => ControlFlow::Break(Err(err)),
-        },
-    );
-
-    match control_flow {
-        ControlFlow::Break(Ok(value)) => Ok(Evaluated::from(value)),
-        ControlFlow::Break(Err(err)) => Err(err),
-        ControlFlow::Continue(()) => Ok(Evaluated::from(Value::Null)),
-    }
-}
-
 pub fn length<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
     match expr.try_into()? {
         Value::Str(expr) => Ok(Evaluated::from(Value::U64(expr.chars().count() as u64))),
         Value::List(expr) => Ok(Evaluated::from(Value::U64(expr.len() as u64))),
         Value::Map(expr) => Ok(Evaluated::from(Value::U64(expr.len() as u64))),
+        Value::Null => Ok(Evaluated::from(Value::Null)),
         _ => Err(EvaluateError::FunctionRequiresStrOrListOrMapValue(name).into()),
     }
 }
@@ -7,7 +7,7 @@ use {
     super::{context::RowContext, select::select},
     crate::{
         ast::{Aggregate, Expr, Function},
-        data::{CustomFunction, Interval, Literal, Row, Value},
+        data::{CustomFunction, Interval, Literal, NativeFunction, Row, Value},
         mock::MockStorage,
         result::{Error, Result},
         store::GStore,
@@ -15,7 +15,7 @@ use {
     async_recursion::async_recursion,
     chrono::prelude::Utc,
     futures::{
-        future::{ready, try_join_all},
+        future::try_join_all,
         stream::{self, StreamExt, TryStreamExt},
     },
     im_rc::HashMap,
@@ -154,17 +154,10 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             list,
             negated,
         } => {
-            let negated = *negated;
             let target = eval(expr).await?;
+            let list = stream::iter(list).then(eval);
 
-            stream::iter(list)
-                .then(eval)
-                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target)))
-                .try_next()
-                .await
-                .map(|v| v.is_some() ^ negated)
-                .map(Value::Bool)
-                .map(Evaluated::from)
+            expr::in_list(target, list, *negated).await
         }
         Expr::InSubquery {
             expr: target_expr,
@@ -174,28 +167,67 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let storage =
                 storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
             let target = eval(target_expr).await?;
+            let list = select(storage, subquery, context).await?.map(|row| {
+                let value = match row? {
+                    Row::Vec { values, .. } => values,
+                    Row::Map(_) => {
+                        return Err(EvaluateError::SchemalessProjectionForInSubQuery.into());
+                    }
+                }
+                .into_iter()
+                .next()
+                .unwrap_or(Value::Null);
 
-            select(storage, subquery, context)
-                .await?
-                .map(|row| {
-                    let value = match row? {
-                        Row::Vec { values, .. } => values,
-                        Row::Map(_) => {
-                            return Err(EvaluateError::SchemalessProjectionForInSubQuery.into());
-                        }
+                Ok(Evaluated::from(value))
+            });
+
+            expr::in_list(target, list, *negated).await
+        }
+        Expr::AnySubquery {
+            expr: target_expr,
+            op,
+            subquery,
+        }
+        | Expr::AllSubquery {
+            expr: target_expr,
+            op,
+            subquery,
+        } => {
+            let storage =
+                storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
+            let target = eval(target_expr).await?;
+            // ANY is decided by the first TRUE comparison, ALL by the first FALSE one.
+            let decisive = matches!(expr, Expr::AnySubquery { .. });
+
+            let results = select(storage, subquery, context).await?.map(|row| {
+                let value = match row? {
+                    Row::Vec { values, .. } => values,
+                    Row::Map(_) => {
+                        return Err(EvaluateError::SchemalessProjectionForSubQuery.into());
                     }
-                    .into_iter()
-                    .next()
-                    .unwrap_or(Value::Null);
+                }
+                .into_iter()
+                .next()
+                .unwrap_or(Value::Null);
 
-                    Ok(Evaluated::from(value))
-                })
-                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target)))
-                .try_next()
-                .await
-                .map(|v| v.is_some() ^ negated)
-                .map(Value::Bool)
-                .map(Evaluated::from)
+                expr::binary_op(op, target.clone(), Evaluated::from(value))
+                    .and_then(expr::nullable_bool)
+            });
+            futures::pin_mut!(results);
+
+            let mut unknown = false;
+            while let Some(result) = results.try_next().await? {
+                match result {
+                    Some(v) if v == decisive => return Ok(Evaluated::from(Value::Bool(v))),
+                    Some(_) => {}
+                    None => unknown = true,
+                }
+            }
+
+            Ok(Evaluated::from(match unknown {
+                true => Value::Null,
+                false => Value::Bool(!decisive),
+            }))
         }
         Expr::Between {
             expr,
@@ -218,7 +250,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let pattern = eval(pattern).await?;
             let evaluated = target.like(pattern, true)?;
 
-            Ok(match negated {
+            Ok(match *negated && !evaluated.is_null() {
                 true => Evaluated::from(Value::Bool(
                     evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false))),
                 )),
@@ -234,7 +266,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let pattern = eval(pattern).await?;
             let evaluated = target.like(pattern, false)?;
 
-            Ok(match negated {
+            Ok(match *negated && !evaluated.is_null() {
                 true => Evaluated::from(Value::Bool(
                     evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false))),
                 )),
@@ -269,14 +301,18 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             else_result,
         } => {
             let operand = match operand {
-                Some(op) => eval(op).await?,
-                None => Evaluated::from(Value::Bool(true)),
+                Some(op) => Some(eval(op).await?),
+                None => None,
             };
 
             for (when, then) in when_then.iter() {
                 let when = eval(when).await?;
+                let matched = match &operand {
+                    Some(operand) => !operand.is_null() && when.evaluate_eq(operand),
+                    None => expr::nullable_bool(when)? == Some(true),
+                };
 
-                if when.evaluate_eq(&operand) {
+                if matched {
                     return eval(then).await;
                 }
             }
@@ -291,6 +327,18 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let indexes = try_join_all(indexes.iter().map(eval)).await?;
             expr::array_index(obj, indexes)
         }
+        Expr::Array { elem } => try_join_all(elem.iter().map(eval))
+            .await?
+            .into_iter()
+            .map(Value::try_from)
+            .collect::<Result<Vec<_>>>()
+            .map(Value::List)
+            .map(Evaluated::from),
+        Expr::Collate { expr, collation } => {
+            let evaluated = eval(expr).await?;
+
+            Ok(expr::collate(evaluated, collation))
+        }
         Expr::Interval {
             expr,
             leading_field,
@@ -332,12 +380,40 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             f::concat(exprs)
         }
         Function::Custom { name, exprs } => {
+            let storage = storage.ok_or(EvaluateError::UnsupportedCustomFunction)?;
+
+            if let Some(NativeFunction {
+                func_name,
+                arg_types,
+                body,
+            }) = storage.fetch_native_function(name).await?
+            {
+                if exprs.len() != arg_types.len() {
+                    return Err((EvaluateError::FunctionArgsLengthNotWithinRange {
+                        name: func_name,
+                        expected_minimum: arg_types.len(),
+                        expected_maximum: arg_types.len(),
+                        found: exprs.len(),
+                    })
+                    .into());
+                }
+
+                let args: Vec<Value> = stream::iter(arg_types.iter().zip(exprs))
+                    .then(|(data_type, expr)| async {
+                        eval(expr).await?.cast(data_type).and_then(Value::try_from)
+                    })
+                    .try_collect()
+                    .await?;
+
+                return body(args).map(Evaluated::from);
+            }
+
             let CustomFunction {
                 func_name,
                 args,
+                return_type,
                 body,
             } = storage
-                .ok_or(EvaluateError::UnsupportedCustomFunction)?
                 .fetch_function(name)
                 .await?
                 .ok_or_else(|| EvaluateError::UnsupportedFunction(name.to_string()))?;
@@ -376,14 +452,28 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
                     Some(Rc::new(context))
                 })?;
 
-            evaluate_inner(storage, context, None, body).await
+            let evaluated = evaluate_inner(Some(storage), context, None, body).await?;
+
+            match return_type {
+                Some(data_type) => evaluated.cast(data_type),
+                None => Ok(evaluated),
+            }
         }
         Function::ConcatWs { separator, exprs } => {
             let separator = eval(separator).await?;
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             f::concat_ws(name, separator, exprs)
         }
-        Function::IfNull { expr, then } => f::ifnull(eval(expr).await?, eval(then).await?),
+        Function::IfNull { expr, then } => match eval(expr).await? {
+            evaluated if evaluated.is_null() => eval(then).await,
+            evaluated => Ok(evaluated),
+        },
+        Function::NullIf { expr, value } => {
+            let expr = eval(expr).await?;
+            let value = eval(value).await?;
+
+            f::nullif(expr, value)
+        }
         Function::Lower(expr) => f::lower(name, eval(expr).await?),
         Function::Initcap(expr) => f::initcap(name, eval(expr).await?),
         Function::Upper(expr) => f::upper(name, eval(expr).await?),
@@ -413,6 +503,12 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let expr = eval(expr).await?;
             f::last_day(name, expr)
         }
+        Function::DateTrunc { field, expr } => {
+            let field = eval(field).await?;
+            let expr = eval(expr).await?;
+
+            f::date_trunc(name, field, expr)
+        }
         Function::Trim {
             expr,
             filter_chars,
@@ -464,9 +560,54 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             };
             expr.substr(name, start, count)
         }
+        Function::RegexpMatches {
+            expr,
+            pattern,
+            flags,
+        } => {
+            let expr = eval(expr).await?;
+            let pattern = eval(pattern).await?;
+            let flags = match flags {
+                Some(v) => Some(eval(v).await?),
+                None => None,
+            };
+
+            f::regexp_matches(name, expr, pattern, flags)
+        }
+        Function::RegexpReplace {
+            expr,
+            pattern,
+            replacement,
+            flags,
+        } => {
+            let expr = eval(expr).await?;
+            let pattern = eval(pattern).await?;
+            let replacement = eval(replacement).await?;
+            let flags = match flags {
+                Some(v) => Some(eval(v).await?),
+                None => None,
+            };
+
+            f::regexp_replace(name, expr, pattern, replacement, flags)
+        }
         Function::Ascii(expr) => f::ascii(name, eval(expr).await?),
         Function::Chr(expr) => f::chr(name, eval(expr).await?),
         Function::Md5(expr) => f::md5(name, eval(expr).await?),
+        Function::Sha256(expr) => f::sha256(name, eval(expr).await?),
+        Function::Hex(expr) => f::hex(name, eval(expr).await?),
+        Function::Base64(expr) => f::base64(name, eval(expr).await?),
+        Function::Encode { expr, format } => {
+            let expr = eval(expr).await?;
+            let format = eval(format).await?;
+
+            f::encode(name, expr, format)
+        }
+        Function::Decode { expr, format } => {
+            let expr = eval(expr).await?;
+            let format = eval(format).await?;
+
+            f::decode(name, expr, format)
+        }
 
         // --- float ---
         Function::Abs(expr) => f::abs(name, eval(expr).await?),
@@ -560,12 +701,29 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
 
             f::unwrap(name, expr, selector)
         }
+        Function::JsonExtract { expr, path } => {
+            let expr = eval(expr).await?;
+            let path = eval(path).await?;
+
+            f::json_extract(name, expr, path)
+        }
+        Function::JsonValue { expr, path } => {
+            let expr = eval(expr).await?;
+            let path = eval(path).await?;
+
+            f::json_value(name, expr, path)
+        }
         Function::GenerateUuid() => Ok(f::generate_uuid()),
         Function::Greatest(exprs) => {
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             f::greatest(name, exprs)
         }
+        Function::Least(exprs) => {
+            let exprs = stream::iter(exprs).then(eval).try_collect().await?;
+            f::least(name, exprs)
+        }
         Function::Now() => Ok(Evaluated::from(Value::Timestamp(Utc::now().naive_utc()))),
+        Function::CurrentDate() => Ok(Evaluated::from(Value::Date(Utc::now().date_naive()))),
         Function::Format { expr, format } => {
             let expr = eval(expr).await?;
             let format = eval(format).await?;
@@ -617,8 +775,25 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             f::extract(field, expr)
         }
         Function::Coalesce(exprs) => {
-            let exprs = stream::iter(exprs).then(eval).try_collect().await?;
-            f::coalesce(exprs)
+            if exprs.is_empty() {
+                return Err(EvaluateError::FunctionRequiresMoreArguments {
+                    function_name: "COALESCE".to_owned(),
+                    required_minimum: 1,
+                    found: 0,
+                }
+                .into());
+            }
+
+            // arguments after the first non-null one are never evaluated
+            for expr in exprs {
+                let value = Value::try_from(eval(expr).await?)?;
+
+                if !value.is_null() {
+                    return Ok(Evaluated::from(value));
+                }
+            }
+
+            Ok(Evaluated::from(Value::Null))
         }
 
         // --- list ---
@@ -1,13 +1,21 @@
 use {
     super::{
         alter::{
-            alter_table, create_index, create_table, delete_function, drop_table, insert_function,
+            alter_table, create_index, create_table, create_view, delete_function, drop_table,
+            drop_view, insert_function, refresh_materialized_view, CreateTableOptions,
         },
+        analyze::analyze,
+        explain::explain,
         fetch::{fetch, fetch_columns},
+        foreign_key::{delete_referencing, truncate_referencing, validate_referenced},
         insert::insert,
+        limit::Limit,
+        merge::merge,
+        returning::project_returning,
         select::{select, select_with_labels},
+        sort::sort_fetched_rows,
         update::Update,
-        validate::{validate_unique, ColumnValidation},
+        validate::{validate_check, validate_unique, ColumnValidation},
     },
     crate::{
         ast::{
@@ -28,6 +36,12 @@ use {
 pub enum ExecuteError {
     #[error("table not found: {0}")]
     TableNotFound(String),
+
+    #[error("'{0}' is a view, not a table")]
+    TableIsView(String),
+
+    #[error("statements which modify data or schema are not allowed in read-only mode")]
+    ReadOnly,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -42,14 +56,24 @@ pub enum Payload {
     SelectMap(Vec<HashMap<String, Value>>),
     Delete(usize),
     Update(usize),
+    Merge(usize),
     DropTable,
+    DropView,
+    RefreshMaterializedView,
     DropFunction,
+    Truncate,
     AlterTable,
     CreateIndex,
     DropIndex,
     StartTransaction,
     Commit,
     Rollback,
+    Savepoint,
+    RollbackToSavepoint,
+    ReleaseSavepoint,
+    SetTransaction,
+    Flush,
+    Analyze,
     ShowVariable(PayloadVariable),
 }
 
@@ -66,7 +90,14 @@ pub async fn execute<T: GStore + GStoreMut>(
 ) -> Result<Payload> {
     if matches!(
         statement,
-        Statement::StartTransaction | Statement::Rollback | Statement::Commit
+        Statement::StartTransaction
+            | Statement::Rollback
+            | Statement::Commit
+            | Statement::Savepoint { .. }
+            | Statement::RollbackToSavepoint { .. }
+            | Statement::ReleaseSavepoint { .. }
+            | Statement::SetTransaction { .. }
+            | Statement::Flush
     ) {
         return execute_inner(storage, statement).await;
     }
@@ -92,6 +123,8 @@ async fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
 ) -> Result<Payload> {
+    validate_not_view(storage, statement).await?;
+
     match statement {
         //- Modification
         //-- Tables
@@ -101,22 +134,56 @@ async fn execute_inner<T: GStore + GStoreMut>(
             if_not_exists,
             source,
             engine,
+            foreign_keys,
+            checks,
             ..
-        } => create_table(
-            storage,
-            name,
-            columns.as_ref().map(Vec::as_slice),
-            *if_not_exists,
-            source,
-            engine,
-        )
-        .await
-        .map(|_| Payload::Create),
+        } => {
+            let options = CreateTableOptions {
+                target_table_name: name,
+                column_defs: columns.as_ref().map(Vec::as_slice),
+                if_not_exists: *if_not_exists,
+                source,
+                engine,
+                foreign_keys,
+                checks,
+            };
+
+            create_table(storage, options)
+                .await
+                .map(|_| Payload::Create)
+        }
         Statement::DropTable {
             names, if_exists, ..
         } => drop_table(storage, names, *if_exists)
             .await
             .map(|_| Payload::DropTable),
+        Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            query,
+        } => create_view(storage, name, query, *or_replace, *materialized)
+            .await
+            .map(|_| Payload::Create),
+        Statement::DropView { names, if_exists } => drop_view(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropView),
+        Statement::RefreshMaterializedView { name } => refresh_materialized_view(storage, name)
+            .await
+            .map(|_| Payload::RefreshMaterializedView),
+        Statement::Truncate { table_name } => {
+            storage
+                .fetch_schema(table_name)
+                .await?
+                .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+
+            truncate_referencing(storage, table_name).await?;
+
+            storage
+                .truncate_data(table_name)
+                .await
+                .map(|_| Payload::Truncate)
+        }
         Statement::AlterTable { name, operation } => alter_table(storage, name, operation)
             .await
             .map(|_| Payload::AlterTable),
@@ -131,6 +198,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
             .drop_index(table_name, name)
             .await
             .map(|_| Payload::DropIndex),
+        Statement::Analyze { table_name } => analyze(storage, table_name.as_deref())
+            .await
+            .map(|_| Payload::Analyze),
         //- Transaction
         Statement::StartTransaction => storage
             .begin(false)
@@ -138,20 +208,52 @@ async fn execute_inner<T: GStore + GStoreMut>(
             .map(|_| Payload::StartTransaction),
         Statement::Commit => storage.commit().await.map(|_| Payload::Commit),
         Statement::Rollback => storage.rollback().await.map(|_| Payload::Rollback),
+        Statement::Savepoint { name } => storage.savepoint(name).await.map(|_| Payload::Savepoint),
+        Statement::RollbackToSavepoint { name } => storage
+            .rollback_to_savepoint(name)
+            .await
+            .map(|_| Payload::RollbackToSavepoint),
+        Statement::ReleaseSavepoint { name } => storage
+            .release_savepoint(name)
+            .await
+            .map(|_| Payload::ReleaseSavepoint),
+        Statement::SetTransaction { isolation_level } => storage
+            .set_isolation_level(*isolation_level)
+            .await
+            .map(|_| Payload::SetTransaction),
+        Statement::Flush => storage.flush().await.map(|_| Payload::Flush),
         //-- Rows
         Statement::Insert {
             table_name,
             columns,
             source,
-        } => insert(storage, table_name, columns, source)
+            on_conflict,
+            returning,
+        } => {
+            insert(
+                storage,
+                table_name,
+                columns,
+                source,
+                on_conflict.as_ref(),
+                returning,
+            )
             .await
-            .map(Payload::Insert),
+        }
         Statement::Update {
             table_name,
             selection,
             assignments,
+            order_by,
+            limit,
+            returning,
         } => {
-            let Schema { column_defs, .. } = storage
+            let Schema {
+                column_defs,
+                foreign_keys,
+                checks,
+                ..
+            } = storage
                 .fetch_schema(table_name)
                 .await?
                 .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
@@ -169,14 +271,17 @@ async fn execute_inner<T: GStore + GStoreMut>(
 
             let update = Update::new(storage, table_name, assignments, column_defs.as_deref())?;
 
-            let rows = fetch(storage, table_name, all_columns, selection.as_ref())
+            let rows = fetch(storage, table_name, all_columns, selection.as_ref()).await?;
+            let rows = sort_fetched_rows(storage, table_name, order_by, rows).await?;
+            let rows = Limit::new(limit.as_ref(), None)
                 .await?
+                .apply(rows)
                 .and_then(|item| {
                     let update = &update;
                     let (key, row) = item;
 
                     async move {
-                        let row = update.apply(row).await?;
+                        let row = update.apply(row, None).await?;
 
                         Ok((key, row))
                     }
@@ -185,63 +290,86 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 .await?;
 
             if let Some(column_defs) = column_defs {
+                let keys = rows.iter().map(|(key, _)| key.clone()).collect();
                 let column_validation =
-                    ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update);
+                    ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update, keys);
                 let rows = rows.iter().filter_map(|(_, row)| match row {
                     Row::Vec { values, .. } => Some(values.as_slice()),
                     Row::Map(_) => None,
                 });
 
-                validate_unique(storage, table_name, column_validation, rows).await?;
+                validate_unique(storage, table_name, column_validation, rows.clone()).await?;
+                validate_referenced(
+                    storage,
+                    table_name,
+                    &column_defs,
+                    &foreign_keys,
+                    rows.clone(),
+                )
+                .await?;
+                validate_check(storage, table_name, &column_defs, &checks, rows).await?;
             }
 
-            let num_rows = rows.len();
+            let payload = match returning.is_empty() {
+                true => Payload::Update(rows.len()),
+                false => {
+                    let rows = rows.iter().map(|(_, row)| row.clone()).collect();
+
+                    project_returning(storage, table_name, returning, rows).await?
+                }
+            };
             let rows = rows
                 .into_iter()
                 .map(|(key, row)| (key, row.into()))
                 .collect();
 
-            storage
-                .insert_data(table_name, rows)
-                .await
-                .map(|_| Payload::Update(num_rows))
+            storage.insert_data(table_name, rows).await.map(|_| payload)
         }
         Statement::Delete {
             table_name,
             selection,
+            order_by,
+            limit,
+            returning,
         } => {
             let columns = fetch_columns(storage, table_name).await?.map(Rc::from);
-            let keys = fetch(storage, table_name, columns, selection.as_ref())
+            let rows = fetch(storage, table_name, columns, selection.as_ref()).await?;
+            let rows = sort_fetched_rows(storage, table_name, order_by, rows).await?;
+            let (keys, rows): (Vec<_>, Vec<_>) = Limit::new(limit.as_ref(), None)
                 .await?
-                .map_ok(|(key, _)| key)
+                .apply(rows)
                 .try_collect::<Vec<_>>()
-                .await?;
+                .await?
+                .into_iter()
+                .unzip();
 
-            let num_keys = keys.len();
+            let payload = match returning.is_empty() {
+                true => Payload::Delete(keys.len()),
+                false => project_returning(storage, table_name, returning, rows).await?,
+            };
 
-            storage
-                .delete_data(table_name, keys)
-                .await
-                .map(|_| Payload::Delete(num_keys))
+            delete_referencing(storage, table_name, &keys).await?;
+
+            storage.delete_data(table_name, keys).await.map(|_| payload)
         }
 
+        Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+        } => merge(storage, table, source, on, clauses).await,
+
         //- Selection
-        Statement::Query(query) => {
-            let (labels, rows) = select_with_labels(storage, query, None).await?;
+        Statement::Query(query) => select_payload(storage, query).await,
+        Statement::QueryForUpdate { query, .. } => {
+            let mut table_names = Vec::new();
+            collect_table_names(&query.body, &mut table_names);
+            storage.lock_for_update(&table_names).await?;
 
-            match labels {
-                Some(labels) => rows
-                    .map(|row| row?.try_into_vec())
-                    .try_collect::<Vec<_>>()
-                    .await
-                    .map(|rows| Payload::Select { labels, rows }),
-                None => rows
-                    .map(|row| row?.try_into_map())
-                    .try_collect::<Vec<_>>()
-                    .await
-                    .map(Payload::SelectMap),
-            }
+            select_payload(storage, query).await
         }
+        Statement::Explain(query) => explain(storage, query).await,
         Statement::ShowColumns { table_name } => {
             let Schema { column_defs, .. } = storage
                 .fetch_schema(table_name)
@@ -258,7 +386,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
         }
         Statement::ShowIndexes(table_name) => {
             let query = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(crate::ast::Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Dictionary {
@@ -301,7 +431,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::ShowVariable(variable) => match variable {
             Variable::Tables => {
                 let query = Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(crate::ast::Select {
+                        distinct: false,
                         projection: vec![SelectItem::Expr {
                             expr: Expr::Identifier("TABLE_NAME".to_owned()),
                             label: "TABLE_NAME".to_owned(),
@@ -360,8 +492,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
             or_replace,
             name,
             args,
+            return_type,
             return_,
-        } => insert_function(storage, name, args, *or_replace, return_)
+        } => insert_function(storage, name, args, return_type, *or_replace, return_)
             .await
             .map(|_| Payload::Create),
         Statement::DropFunction { if_exists, names } => delete_function(storage, names, *if_exists)
@@ -369,3 +502,78 @@ async fn execute_inner<T: GStore + GStoreMut>(
             .map(|_| Payload::DropFunction),
     }
 }
+
+/// A view has no rows or columns of its own, and the rows of a materialized view are only
+/// replaced by `REFRESH MATERIALIZED VIEW`, so statements working on the stored data of a
+/// table cannot target either.
+async fn validate_not_view<T: GStore>(storage: &T, statement: &Statement) -> Result<()> {
+    let table_names = match statement {
+        Statement::Insert { table_name, .. }
+        | Statement::Update { table_name, .. }
+        | Statement::Delete { table_name, .. }
+        | Statement::Truncate { table_name }
+        | Statement::AlterTable {
+            name: table_name, ..
+        }
+        | Statement::CreateIndex { table_name, .. }
+        | Statement::DropIndex { table_name, .. }
+        | Statement::Analyze {
+            table_name: Some(table_name),
+        }
+        | Statement::Merge {
+            table: TableFactor::Table {
+                name: table_name, ..
+            },
+            ..
+        } => vec![table_name],
+        Statement::DropTable { names, .. } => names.iter().collect(),
+        _ => return Ok(()),
+    };
+
+    for table_name in table_names {
+        if let Some(Schema { view: Some(_), .. }) = storage.fetch_schema(table_name).await? {
+            return Err(ExecuteError::TableIsView(table_name.to_owned()).into());
+        }
+    }
+
+    Ok(())
+}
+
+async fn select_payload<T: GStore>(storage: &T, query: &Query) -> Result<Payload> {
+    let (labels, rows) = select_with_labels(storage, query, None).await?;
+
+    match labels {
+        Some(labels) => rows
+            .map(|row| row?.try_into_vec())
+            .try_collect::<Vec<_>>()
+            .await
+            .map(|rows| Payload::Select { labels, rows }),
+        None => rows
+            .map(|row| row?.try_into_map())
+            .try_collect::<Vec<_>>()
+            .await
+            .map(Payload::SelectMap),
+    }
+}
+
+/// Tables in the FROM clauses of `SELECT .. FOR UPDATE`, whose rows are locked
+fn collect_table_names(set_expr: &SetExpr, table_names: &mut Vec<String>) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let TableWithJoins { relation, joins } = &select.from;
+            let relations =
+                std::iter::once(relation).chain(joins.iter().map(|join| &join.relation));
+
+            for relation in relations {
+                if let TableFactor::Table { name, .. } = relation {
+                    table_names.push(name.to_owned());
+                }
+            }
+        }
+        SetExpr::SetOperation { left, right, .. } => {
+            collect_table_names(left, table_names);
+            collect_table_names(right, table_names);
+        }
+        _ => {}
+    }
+}
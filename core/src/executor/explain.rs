@@ -0,0 +1,177 @@
+use {
+    super::{execute::Payload, select::scan_predicates},
+    crate::{
+        ast::{
+            BinaryOperator, ColumnUniqueOption, Expr, IndexItem, IndexOperator, Join, JoinExecutor,
+            Query, SetExpr, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted,
+        },
+        data::{get_alias, Schema, Value},
+        result::Result,
+        store::GStore,
+    },
+    async_recursion::async_recursion,
+};
+
+/// Access paths which the planned query reads its tables by, a row for each table of the
+/// query in the order of joins. Tables of subqueries are not listed.
+pub async fn explain<T: GStore>(storage: &T, query: &Query) -> Result<Payload> {
+    let labels = ["table", "access", "join"].map(ToOwned::to_owned).to_vec();
+    let rows = explain_set_expr(storage, &query.body).await?;
+
+    Ok(Payload::Select { labels, rows })
+}
+
+#[async_recursion(?Send)]
+async fn explain_set_expr<T: GStore>(storage: &T, set_expr: &SetExpr) -> Result<Vec<Vec<Value>>> {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let TableWithJoins { relation, joins } = &select.from;
+            let predicates = match &select.selection {
+                Some(expr) if joins.is_empty() => scan_predicates(expr),
+                _ => Vec::new(),
+            };
+
+            let mut rows = vec![vec![
+                Value::Str(get_alias(relation).to_owned()),
+                Value::Str(access(storage, relation, &predicates).await?),
+                Value::Null,
+            ]];
+
+            for Join {
+                relation,
+                join_executor,
+                ..
+            } in joins
+            {
+                let join = match join_executor {
+                    JoinExecutor::NestedLoop => "NESTED LOOP",
+                    JoinExecutor::Hash { .. } => "HASH JOIN",
+                };
+
+                rows.push(vec![
+                    Value::Str(get_alias(relation).to_owned()),
+                    Value::Str(access(storage, relation, &[]).await?),
+                    Value::Str(join.to_owned()),
+                ]);
+            }
+
+            Ok(rows)
+        }
+        SetExpr::Values(_) => Ok(vec![vec![
+            Value::Null,
+            Value::Str("VALUES".to_owned()),
+            Value::Null,
+        ]]),
+        SetExpr::SetOperation { left, right, .. } => {
+            let left = explain_set_expr(storage, left).await?;
+            let right = explain_set_expr(storage, right).await?;
+
+            Ok([left, right].concat())
+        }
+    }
+}
+
+async fn access<T: GStore>(
+    storage: &T,
+    table_factor: &TableFactor,
+    predicates: &[(String, IndexOperator, Value)],
+) -> Result<String> {
+    let (name, index) = match table_factor {
+        TableFactor::Table { name, index, .. } => (name, index),
+        TableFactor::Derived { .. } => return Ok("SUBQUERY".to_owned()),
+        TableFactor::Series { .. } => return Ok("SERIES".to_owned()),
+        TableFactor::Unnest { .. } => return Ok("UNNEST".to_owned()),
+        TableFactor::Function { name, .. } => return Ok(format!("FUNCTION {name}")),
+        TableFactor::Dictionary { dict, .. } => return Ok(format!("DICTIONARY {dict}")),
+    };
+    let schema = storage.fetch_schema(name).await?;
+
+    let access = match index {
+        Some(IndexItem::PrimaryKey(expr)) => {
+            let column = schema
+                .and_then(|schema| schema.column_defs)
+                .and_then(|column_defs| {
+                    column_defs.into_iter().find(|column_def| {
+                        column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+                    })
+                })
+                .map(|column_def| Expr::Identifier(column_def.name));
+
+            match column {
+                Some(column) => format!(
+                    "PRIMARY KEY LOOKUP ({})",
+                    comparison(column, BinaryOperator::Eq, expr.clone())
+                ),
+                None => format!("PRIMARY KEY LOOKUP ({})", expr.to_sql_unquoted()),
+            }
+        }
+        Some(IndexItem::NonClustered {
+            name,
+            asc,
+            cmp_expr,
+        }) => {
+            let index_expr = schema.and_then(|Schema { indexes, .. }| {
+                indexes
+                    .into_iter()
+                    .find(|index| &index.name == name)
+                    .map(|index| index.expr)
+            });
+
+            let condition = match (cmp_expr, index_expr) {
+                (Some((op, expr)), Some(index_expr)) => {
+                    format!(
+                        " ({})",
+                        comparison(index_expr, op.clone().into(), expr.clone())
+                    )
+                }
+                (Some((op, expr)), None) => {
+                    format!(
+                        " ({} {})",
+                        BinaryOperator::from(op.clone()).to_sql(),
+                        expr.to_sql_unquoted()
+                    )
+                }
+                (None, _) => String::new(),
+            };
+            let order = match asc {
+                Some(true) => " ORDER ASC",
+                Some(false) => " ORDER DESC",
+                None => "",
+            };
+
+            format!("INDEX SCAN USING {name}{condition}{order}")
+        }
+        None => {
+            let predicates = storage.filtered_scan_predicates(name, predicates).await?;
+            if predicates.is_empty() {
+                return Ok("FULL SCAN".to_owned());
+            }
+
+            let predicates = predicates
+                .iter()
+                .map(|(column, op, value)| {
+                    Expr::try_from(value.clone()).map(|value| {
+                        comparison(
+                            Expr::Identifier(column.to_owned()),
+                            op.clone().into(),
+                            value,
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            format!("FILTERED SCAN ({})", predicates.join(" AND "))
+        }
+    };
+
+    Ok(access)
+}
+
+fn comparison(left: Expr, op: BinaryOperator, right: Expr) -> String {
+    Expr::BinaryOp {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    }
+    .to_sql_unquoted()
+}
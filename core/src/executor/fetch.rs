@@ -4,22 +4,30 @@ use {
         ast::{
             ToSql,
             {
-                ColumnDef, ColumnUniqueOption, Dictionary, Expr, IndexItem, Join, Query, Select,
-                SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, ToSqlUnquoted,
-                Values,
+                BinaryOperator, ColumnDef, ColumnUniqueOption, DataType, Dictionary, Expr,
+                IndexItem, IndexOperator, Join, JoinOperator, Query, Select, SelectItem, SetExpr,
+                TableAlias, TableFactor, TableWithJoins, ToSqlUnquoted, Values,
             },
         },
-        data::{get_alias, get_index, Key, Row, Value},
-        executor::{evaluate::evaluate, select::select},
+        data::{
+            get_alias, get_index, Interval, Key, NativeTableFunction, Row, Schema, SchemaView,
+            Value,
+        },
+        executor::{
+            evaluate::{evaluate, EvaluateError},
+            join::using_columns,
+            select::select,
+        },
+        plan::check_evaluable,
         result::Result,
-        store::{DataRow, GStore},
+        store::{DataRow, GStore, RowIter},
     },
     async_recursion::async_recursion,
     futures::stream::{self, Stream, StreamExt, TryStreamExt},
     iter_enum::Iterator,
     itertools::Itertools,
     serde::Serialize,
-    std::{borrow::Cow, collections::HashMap, fmt::Debug, iter, rc::Rc},
+    std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Debug, iter, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -34,8 +42,30 @@ pub enum FetchError {
     #[error("SERIES has wrong size: {0}")]
     SeriesSizeWrong(i64),
 
+    #[error("SERIES step must not be zero")]
+    SeriesStepZero,
+
+    #[error("UNNEST requires a list value")]
+    UnnestListTypeRequired,
+
+    #[error("table function not found: {0}")]
+    TableFunctionNotFound(String),
+
+    #[error("table function '{name}' returned a row of {found} values for {expected} columns")]
+    TableFunctionRowLengthNotMatching {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+
     #[error("table '{0}' has {1} columns available but {2} column aliases specified")]
     TooManyColumnAliases(String, usize, usize),
+
+    #[error("column '{0}' specified in USING clause does not exist on both sides of the join")]
+    UsingColumnNotFound(String),
+
+    #[error("NATURAL JOIN requires the columns of both sides of the join to be known")]
+    NaturalJoinColumnsUnknown,
 }
 
 pub async fn fetch<'a, T: GStore>(
@@ -45,50 +75,131 @@ pub async fn fetch<'a, T: GStore>(
     where_clause: Option<&'a Expr>,
 ) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
     let columns = columns.unwrap_or_else(|| Rc::from([]));
-    let rows = storage
-        .scan_data(table_name)
-        .await
-        .map(stream::iter)?
-        .try_filter_map(move |(key, data_row)| {
-            let row = match data_row {
-                DataRow::Vec(values) => Row::Vec {
-                    columns: Rc::clone(&columns),
-                    values,
-                },
-                DataRow::Map(values) => Row::Map(values),
-            };
+    let rows: RowIter = match fetch_primary_key(storage, table_name, where_clause).await? {
+        Some(key) => {
+            let row = storage.fetch_data(table_name, &key).await?;
 
-            async move {
-                let expr = match where_clause {
-                    None => {
-                        return Ok(Some((key, row)));
-                    }
-                    Some(expr) => expr,
-                };
+            Box::new(row.map(|row| Ok((key, row))).into_iter())
+        }
+        None => storage.scan_data(table_name).await?,
+    };
+    let rows = stream::iter(rows).try_filter_map(move |(key, data_row)| {
+        let row = match data_row {
+            DataRow::Vec(values) => Row::Vec {
+                columns: Rc::clone(&columns),
+                values,
+            },
+            DataRow::Map(values) => Row::Map(values),
+        };
+
+        async move {
+            let expr = match where_clause {
+                None => {
+                    return Ok(Some((key, row)));
+                }
+                Some(expr) => expr,
+            };
 
-                let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+            let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
 
-                check_expr(storage, Some(Rc::new(context)), None, expr)
-                    .await
-                    .map(|pass| pass.then_some((key, row)))
-            }
-        });
+            check_expr(storage, Some(Rc::new(context)), None, expr)
+                .await
+                .map(|pass| pass.then_some((key, row)))
+        }
+    });
 
     Ok(rows)
 }
 
+/// Key of the row selected by a `pk = <value>` condition of the WHERE clause, which lets
+/// the row be fetched directly instead of scanning the whole table.
+async fn fetch_primary_key<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    where_clause: Option<&Expr>,
+) -> Result<Option<Key>> {
+    let where_clause = match where_clause {
+        Some(where_clause) => where_clause,
+        None => return Ok(None),
+    };
+
+    let primary_key = storage
+        .fetch_schema(table_name)
+        .await?
+        .and_then(|Schema { column_defs, .. }| column_defs)
+        .and_then(|column_defs| {
+            column_defs.into_iter().find(|ColumnDef { unique, .. }| {
+                unique == &Some(ColumnUniqueOption { is_primary: true })
+            })
+        })
+        .map(|ColumnDef { name, .. }| name);
+
+    let value = match primary_key
+        .as_deref()
+        .and_then(|primary_key| find_primary_key_value(table_name, primary_key, where_clause))
+    {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    evaluate(storage, None, None, value)
+        .await
+        .and_then(Value::try_from)
+        .and_then(Key::try_from)
+        .map(Some)
+}
+
+fn find_primary_key_value<'a>(
+    table_name: &str,
+    primary_key: &str,
+    expr: &'a Expr,
+) -> Option<&'a Expr> {
+    let is_primary_key = |expr: &Expr| match expr {
+        Expr::Identifier(ident) => ident == primary_key,
+        Expr::CompoundIdentifier { alias, ident } => alias == table_name && ident == primary_key,
+        _ => false,
+    };
+
+    match expr {
+        Expr::Nested(expr) => find_primary_key_value(table_name, primary_key, expr),
+        Expr::BinaryOp {
+            left: key,
+            op: BinaryOperator::Eq,
+            right: value,
+        }
+        | Expr::BinaryOp {
+            left: value,
+            op: BinaryOperator::Eq,
+            right: key,
+        } if is_primary_key(key) && check_evaluable(None, value) => Some(value),
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => find_primary_key_value(table_name, primary_key, left)
+            .or_else(|| find_primary_key_value(table_name, primary_key, right)),
+        _ => None,
+    }
+}
+
 #[derive(futures_enum::Stream)]
-pub enum Rows<I1, I2, I3, I4> {
+pub enum Rows<I1, I2, I3, I4, I5, I6> {
     Derived(I1),
     Table(I2),
     Series(I3),
-    Dictionary(I4),
+    Unnest(I4),
+    Function(I5),
+    Dictionary(I6),
 }
 
+/// `used_columns` are the columns of a table which the query reads, when they are known, and
+/// `predicates` are the comparisons which rows of the table must pass to pass the WHERE clause
 pub async fn fetch_relation_rows<'a, T: GStore>(
     storage: &'a T,
     table_factor: &'a TableFactor,
     filter_context: &Option<Rc<RowContext<'a>>>,
+    used_columns: Option<&[String]>,
+    predicates: &[(String, IndexOperator, Value)],
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
     let columns = Rc::from(
         fetch_relation_columns(storage, table_factor)
@@ -172,14 +283,23 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                         }))
                     }
                     _ => {
-                        let rows = storage.scan_data(name).await?.map_ok(move |(_, data_row)| {
-                            match data_row {
-                                DataRow::Vec(values) => Row::Vec {
-                                    columns: Rc::clone(&columns),
-                                    values,
-                                },
-                                DataRow::Map(values) => Row::Map(values),
+                        let rows = match (used_columns, predicates) {
+                            (_, [_, ..]) => {
+                                storage
+                                    .scan_data_filtered(name, used_columns, predicates)
+                                    .await?
+                            }
+                            (Some(used_columns), []) => {
+                                storage.scan_data_with_columns(name, used_columns).await?
                             }
+                            (None, []) => storage.scan_data(name).await?,
+                        };
+                        let rows = rows.map_ok(move |(_, data_row)| match data_row {
+                            DataRow::Vec(values) => Row::Vec {
+                                columns: Rc::clone(&columns),
+                                values,
+                            },
+                            DataRow::Map(values) => Row::Map(values),
                         });
 
                         Rows::FullScan(rows)
@@ -189,23 +309,94 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
 
             Ok(Rows::Table(stream::iter(rows)))
         }
-        TableFactor::Series { size, .. } => {
-            let value: Value = evaluate_stateless(None, size).await?.try_into()?;
-            let size: i64 = value.try_into()?;
-            let size = match size {
-                n if n >= 0 => size,
-                n => return Err(FetchError::SeriesSizeWrong(n).into()),
+        TableFactor::Series {
+            start, end, step, ..
+        } => {
+            let end: Value = evaluate_stateless(None, end).await?.try_into()?;
+            let (start, end) = match start {
+                Some(start) => (evaluate_stateless(None, start).await?.try_into()?, end),
+                None => match i64::try_from(end)? {
+                    size if size >= 0 => (Value::I64(1), Value::I64(size)),
+                    size => return Err(FetchError::SeriesSizeWrong(size).into()),
+                },
+            };
+            let step = match step {
+                Some(step) => evaluate_stateless(None, step).await?.try_into()?,
+                None if matches!(start, Value::Date(_) | Value::Timestamp(_)) => {
+                    Value::Interval(Interval::days(1))
+                }
+                None => Value::I64(1),
+            };
+
+            Ok(Rows::Series(stream::iter(series(start, end, step)?)))
+        }
+        TableFactor::Unnest { expr, .. } => {
+            let filter_context = filter_context.as_ref().map(Rc::clone);
+            let values = match evaluate(storage, filter_context, None, expr)
+                .await
+                .and_then(Value::try_from)?
+            {
+                Value::List(values) => values,
+                Value::Null => Vec::new(),
+                _ => return Err(FetchError::UnnestListTypeRequired.into()),
             };
 
-            let columns = Rc::from(vec!["N".to_owned()]);
-            let rows = (1..=size).map(move |v| {
+            let rows = values.into_iter().map(move |value| {
+                Ok(Row::Vec {
+                    columns: Rc::clone(&columns),
+                    values: vec![value],
+                })
+            });
+
+            Ok(Rows::Unnest(stream::iter(rows)))
+        }
+        TableFactor::Function { name, args, .. } => {
+            let NativeTableFunction {
+                func_name,
+                arg_types,
+                body,
+                ..
+            } = fetch_table_function(storage, name).await?;
+
+            if args.len() != arg_types.len() {
+                return Err(EvaluateError::FunctionArgsLengthNotWithinRange {
+                    name: func_name,
+                    expected_minimum: arg_types.len(),
+                    expected_maximum: arg_types.len(),
+                    found: args.len(),
+                }
+                .into());
+            }
+
+            let args: Vec<Value> = stream::iter(arg_types.iter().zip(args))
+                .then(|(data_type, expr)| async move {
+                    let filter_context = filter_context.as_ref().map(Rc::clone);
+
+                    evaluate(storage, filter_context, None, expr)
+                        .await?
+                        .cast(data_type)
+                        .and_then(Value::try_from)
+                })
+                .try_collect()
+                .await?;
+
+            let rows = body(args)?.into_iter().map(move |values| -> Result<Row> {
+                if values.len() != columns.len() {
+                    return Err(FetchError::TableFunctionRowLengthNotMatching {
+                        name: func_name.clone(),
+                        expected: columns.len(),
+                        found: values.len(),
+                    }
+                    .into());
+                }
+
                 Ok(Row::Vec {
                     columns: Rc::clone(&columns),
-                    values: vec![Value::I64(v)],
+                    values,
                 })
             });
 
-            Ok(Rows::Series(stream::iter(rows)))
+            Ok(Rows::Function(stream::iter(rows)))
         }
         TableFactor::Dictionary { dict, .. } => {
             let rows = {
@@ -231,9 +422,16 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                 })
                                 .unwrap_or_default();
 
+                            let object_type = match schema.view {
+                                Some(SchemaView {
+                                    materialized: true, ..
+                                }) => "MATERIALIZED VIEW",
+                                Some(_) => "VIEW",
+                                None => "TABLE",
+                            };
                             let table_rows = HashMap::from([
                                 ("OBJECT_NAME".to_owned(), Value::Str(schema.table_name)),
-                                ("OBJECT_TYPE".to_owned(), Value::Str("TABLE".to_owned())),
+                                ("OBJECT_TYPE".to_owned(), Value::Str(object_type.to_owned())),
                             ])
                             .into_iter()
                             .chain(meta)
@@ -413,6 +611,35 @@ pub async fn fetch_relation_columns<T: GStore>(
             }
         }
         TableFactor::Series { .. } => Ok(Some(vec!["N".to_owned()])),
+        TableFactor::Unnest { alias, .. } => {
+            let column = alias
+                .columns
+                .first()
+                .map_or_else(|| "UNNEST".to_owned(), ToOwned::to_owned);
+
+            Ok(Some(vec![column]))
+        }
+        TableFactor::Function { name, alias, .. } => {
+            let columns = fetch_table_function(storage, name).await?.columns;
+
+            if alias.columns.len() > columns.len() {
+                return Err(FetchError::TooManyColumnAliases(
+                    name.to_owned(),
+                    columns.len(),
+                    alias.columns.len(),
+                )
+                .into());
+            }
+
+            Ok(Some(
+                alias
+                    .columns
+                    .iter()
+                    .chain(&columns[alias.columns.len()..])
+                    .cloned()
+                    .collect(),
+            ))
+        }
         TableFactor::Dictionary { dict, .. } => Ok(Some(match dict {
             Dictionary::GlueObjects => vec![
                 "OBJECT_NAME".to_owned(),
@@ -443,59 +670,68 @@ pub async fn fetch_relation_columns<T: GStore>(
                     columns: alias_columns,
                     name,
                 },
-        } => match body {
-            SetExpr::Select(statement) => {
-                let Select {
-                    from:
-                        TableWithJoins {
-                            relation, joins, ..
-                        },
-                    projection,
-                    ..
-                } = statement.as_ref();
+        } => fetch_set_expr_columns(storage, body, alias_columns, name).await,
+    }
+}
 
-                let labels = fetch_labels(storage, relation, joins, projection).await?;
-                match labels {
-                    None => Ok(None),
-                    Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
-                    Some(labels) if alias_columns.len() > labels.len() => {
-                        Err(FetchError::TooManyColumnAliases(
-                            name.to_string(),
-                            labels.len(),
-                            alias_columns.len(),
-                        )
-                        .into())
-                    }
-                    Some(labels) => Ok(Some(
-                        alias_columns
-                            .iter()
-                            .cloned()
-                            .chain(labels[alias_columns.len()..labels.len()].to_vec())
-                            .collect(),
-                    )),
-                }
-            }
-            SetExpr::Values(Values(values_list)) => {
-                let total_len = values_list[0].len();
-                let alias_len = alias_columns.len();
-                if alias_len > total_len {
-                    return Err(FetchError::TooManyColumnAliases(
-                        name.into(),
-                        total_len,
-                        alias_len,
+#[async_recursion(?Send)]
+async fn fetch_set_expr_columns<T: GStore>(
+    storage: &T,
+    set_expr: &SetExpr,
+    alias_columns: &[String],
+    name: &str,
+) -> Result<Option<Vec<String>>> {
+    match set_expr {
+        SetExpr::Select(statement) => {
+            let Select {
+                from: TableWithJoins {
+                    relation, joins, ..
+                },
+                projection,
+                ..
+            } = statement.as_ref();
+
+            let labels = fetch_labels(storage, relation, joins, projection).await?;
+            match labels {
+                None => Ok(None),
+                Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
+                Some(labels) if alias_columns.len() > labels.len() => {
+                    Err(FetchError::TooManyColumnAliases(
+                        name.to_owned(),
+                        labels.len(),
+                        alias_columns.len(),
                     )
-                    .into());
+                    .into())
                 }
-                let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
-                let labels = alias_columns
-                    .iter()
-                    .cloned()
-                    .chain(labels)
-                    .collect::<Vec<_>>();
-
-                Ok(Some(labels))
+                Some(labels) => Ok(Some(
+                    alias_columns
+                        .iter()
+                        .cloned()
+                        .chain(labels[alias_columns.len()..labels.len()].to_vec())
+                        .collect(),
+                )),
             }
-        },
+        }
+        SetExpr::Values(Values(values_list)) => {
+            let total_len = values_list[0].len();
+            let alias_len = alias_columns.len();
+            if alias_len > total_len {
+                return Err(
+                    FetchError::TooManyColumnAliases(name.into(), total_len, alias_len).into(),
+                );
+            }
+            let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
+            let labels = alias_columns
+                .iter()
+                .cloned()
+                .chain(labels)
+                .collect::<Vec<_>>();
+
+            Ok(Some(labels))
+        }
+        SetExpr::SetOperation { left, .. } => {
+            fetch_set_expr_columns(storage, left, alias_columns, name).await
+        }
     }
 }
 
@@ -519,6 +755,52 @@ async fn fetch_join_columns<'a, T: GStore>(
     Ok((columns.len() == joins.len()).then_some(columns))
 }
 
+/// Values from `start` to `end` by `step`, an empty series when any of them is `NULL`.
+/// Dates stay dates even though adding an interval to a date gives a timestamp.
+fn series(start: Value, end: Value, step: Value) -> Result<impl Iterator<Item = Result<Row>>> {
+    let ascending = match start.add(&step)?.evaluate_cmp(&start) {
+        _ if start.is_null() || end.is_null() || step.is_null() => None,
+        Some(Ordering::Greater) => Some(true),
+        Some(Ordering::Less) => Some(false),
+        Some(Ordering::Equal) | None => return Err(FetchError::SeriesStepZero.into()),
+    };
+    let is_date = matches!(start, Value::Date(_));
+    let columns = Rc::from(vec!["N".to_owned()]);
+
+    let rows = iter::successors(Some(Ok(start)), move |value: &Result<Value>| {
+        value.as_ref().ok().map(|value| value.add(&step))
+    })
+    .take_while(move |value| match (ascending, value) {
+        (Some(ascending), Ok(value)) => matches!(
+            (ascending, value.evaluate_cmp(&end)),
+            (true, Some(Ordering::Less | Ordering::Equal))
+                | (false, Some(Ordering::Greater | Ordering::Equal))
+        ),
+        (Some(_), Err(_)) => true,
+        (None, _) => false,
+    })
+    .map(move |value| {
+        let value = match value? {
+            value @ Value::Timestamp(_) if is_date => value.cast(&DataType::Date)?,
+            value => value,
+        };
+
+        Ok(Row::Vec {
+            columns: Rc::clone(&columns),
+            values: vec![value],
+        })
+    });
+
+    Ok(rows)
+}
+
+async fn fetch_table_function<T: GStore>(storage: &T, name: &str) -> Result<NativeTableFunction> {
+    storage
+        .fetch_native_table_function(name)
+        .await?
+        .ok_or_else(|| FetchError::TableFunctionNotFound(name.to_owned()).into())
+}
+
 pub async fn fetch_labels<T: GStore>(
     storage: &T,
     relation: &TableFactor,
@@ -546,12 +828,31 @@ pub async fn fetch_labels<T: GStore>(
     projection
         .iter()
         .flat_map(|item| match item {
-            SelectItem::Wildcard => {
-                let columns = columns.iter().cloned();
-                let join_columns = join_columns.iter().flat_map(|(_, columns)| columns.clone());
-
-                columns.chain(join_columns).map(Ok).collect()
-            }
+            SelectItem::Wildcard => joins
+                .iter()
+                .zip(join_columns.iter())
+                .try_fold(
+                    columns.clone(),
+                    |labels, (Join { join_operator, .. }, (_, join_columns))| {
+                        let join_constraint = match join_operator {
+                            JoinOperator::Inner(join_constraint)
+                            | JoinOperator::LeftOuter(join_constraint)
+                            | JoinOperator::RightOuter(join_constraint)
+                            | JoinOperator::FullOuter(join_constraint) => join_constraint,
+                        };
+                        let using =
+                            using_columns(join_constraint, Some(&labels), Some(join_columns))?
+                                .unwrap_or_default();
+                        let join_columns = join_columns
+                            .iter()
+                            .filter(|column| !using.contains(column))
+                            .cloned();
+
+                        Ok(labels.into_iter().chain(join_columns).collect())
+                    },
+                )
+                .map(|labels: Vec<String>| labels.into_iter().map(Ok).collect())
+                .unwrap_or_else(|error| vec![Err(error)]),
             SelectItem::QualifiedWildcard(target_table_alias) => {
                 if table_alias == target_table_alias {
                     return columns.iter().cloned().map(Ok).collect();
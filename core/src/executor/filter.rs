@@ -57,7 +57,10 @@ pub async fn check_expr<'a, T: GStore>(
     aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
     expr: &'a Expr,
 ) -> Result<bool> {
-    evaluate(storage, context, aggregated, expr)
-        .await
-        .map(|evaluated| evaluated.try_into())?
+    let evaluated = evaluate(storage, context, aggregated, expr).await?;
+
+    match evaluated.is_null() {
+        true => Ok(false),
+        false => evaluated.try_into(),
+    }
 }
@@ -0,0 +1,272 @@
+use {
+    super::validate::ValidateError,
+    crate::{
+        ast::{ColumnDef, ForeignKey, ReferentialAction},
+        data::{Key, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut, Store},
+    },
+    serde::Serialize,
+    std::{
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+    },
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq)]
+pub enum ForeignKeyError {
+    #[error("conflict! foreign key column not found: {0}")]
+    ConflictOnForeignKeyColumnNotFound(String),
+
+    #[error("foreign key '{foreign_key_name}' violated: '{}' not found in '{referenced_table_name}'", String::from(.value))]
+    ReferencedValueNotFound {
+        foreign_key_name: String,
+        referenced_table_name: String,
+        value: Value,
+    },
+
+    #[error("cannot delete rows of '{table_name}' referenced by foreign key '{foreign_key_name}' of table '{referencing_table_name}'")]
+    RowReferenced {
+        table_name: String,
+        referencing_table_name: String,
+        foreign_key_name: String,
+    },
+}
+
+/// Every non-null foreign key value of the given rows has to exist as the primary key of
+/// the referenced table, or of one of the given rows for a self-referencing table.
+pub async fn validate_referenced<'a, T: Store>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    foreign_keys: &[ForeignKey],
+    rows: impl Iterator<Item = &'a [Value]> + Clone,
+) -> Result<()> {
+    for foreign_key in foreign_keys {
+        let ForeignKey {
+            name,
+            referencing_column_name,
+            referenced_table_name,
+            referenced_column_name,
+            ..
+        } = foreign_key;
+
+        let index = column_index(column_defs, referencing_column_name)?;
+        let own_keys = match referenced_table_name == table_name {
+            true => {
+                let referenced_index = column_index(column_defs, referenced_column_name)?;
+
+                rows.clone()
+                    .filter_map(|values| values.get(referenced_index))
+                    .map(Key::try_from)
+                    .collect::<Result<HashSet<_>>>()?
+            }
+            false => HashSet::new(),
+        };
+
+        for values in rows.clone() {
+            let value = values
+                .get(index)
+                .ok_or(ValidateError::ConflictOnStorageColumnIndex(index))?;
+
+            if value.is_null() {
+                continue;
+            }
+
+            let key = Key::try_from(value)?;
+
+            if own_keys.contains(&key)
+                || storage
+                    .fetch_data(referenced_table_name, &key)
+                    .await?
+                    .is_some()
+            {
+                continue;
+            }
+
+            return Err(ForeignKeyError::ReferencedValueNotFound {
+                foreign_key_name: name.to_owned(),
+                referenced_table_name: referenced_table_name.to_owned(),
+                value: value.clone(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the `ON DELETE` action of every foreign key referencing the rows to delete.
+/// The rows of `table_name` itself are left to the caller.
+pub async fn delete_referencing<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    keys: &[Key],
+) -> Result<()> {
+    let schemas = storage.fetch_all_schemas().await?;
+
+    let mut deleted: HashMap<&str, HashSet<Key>> = HashMap::new();
+    let mut nullified: HashMap<&str, HashMap<Key, Vec<Value>>> = HashMap::new();
+    let mut parents = vec![(table_name, keys.iter().cloned().collect::<HashSet<_>>())];
+
+    deleted.insert(table_name, parents[0].1.clone());
+
+    while let Some((parent_table_name, parent_keys)) = parents.pop() {
+        for Schema {
+            table_name: child_table_name,
+            column_defs,
+            foreign_keys,
+            ..
+        } in schemas.iter()
+        {
+            let child_table_name = child_table_name.as_str();
+
+            for foreign_key in foreign_keys
+                .iter()
+                .filter(|foreign_key| foreign_key.referenced_table_name == parent_table_name)
+            {
+                let index = column_index(
+                    column_defs.as_deref().unwrap_or_default(),
+                    &foreign_key.referencing_column_name,
+                )?;
+
+                let mut referencing = Vec::new();
+
+                for item in storage.scan_data(child_table_name).await? {
+                    let (key, data_row) = item?;
+
+                    if deleted
+                        .get(child_table_name)
+                        .map_or(false, |keys| keys.contains(&key))
+                    {
+                        continue;
+                    }
+
+                    let values = match nullified
+                        .get(child_table_name)
+                        .and_then(|rows| rows.get(&key))
+                    {
+                        Some(values) => values.clone(),
+                        None => match data_row {
+                            DataRow::Vec(values) => values,
+                            DataRow::Map(_) => {
+                                return Err(
+                                    ValidateError::ConflictOnUnexpectedSchemalessRowFound.into()
+                                );
+                            }
+                        },
+                    };
+
+                    let value = values
+                        .get(index)
+                        .ok_or(ValidateError::ConflictOnStorageColumnIndex(index))?;
+
+                    if value.is_null() || !parent_keys.contains(&Key::try_from(value)?) {
+                        continue;
+                    }
+
+                    referencing.push((key, values));
+                }
+
+                if referencing.is_empty() {
+                    continue;
+                }
+
+                match foreign_key.on_delete {
+                    ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                        return Err(ForeignKeyError::RowReferenced {
+                            table_name: parent_table_name.to_owned(),
+                            referencing_table_name: child_table_name.to_owned(),
+                            foreign_key_name: foreign_key.name.to_owned(),
+                        }
+                        .into());
+                    }
+                    ReferentialAction::Cascade => {
+                        let keys = referencing
+                            .into_iter()
+                            .map(|(key, _)| key)
+                            .collect::<HashSet<_>>();
+
+                        if let Some(rows) = nullified.get_mut(child_table_name) {
+                            rows.retain(|key, _| !keys.contains(key));
+                        }
+
+                        deleted
+                            .entry(child_table_name)
+                            .or_default()
+                            .extend(keys.iter().cloned());
+                        parents.push((child_table_name, keys));
+                    }
+                    ReferentialAction::SetNull => {
+                        let rows = nullified.entry(child_table_name).or_default();
+
+                        for (key, mut values) in referencing {
+                            values[index] = Value::Null;
+                            rows.insert(key, values);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(cascaded) = deleted.get_mut(table_name) {
+        cascaded.retain(|key| !keys.contains(key));
+    }
+
+    for (table_name, keys) in deleted {
+        if keys.is_empty() {
+            continue;
+        }
+
+        storage
+            .delete_data(table_name, keys.into_iter().collect())
+            .await?;
+    }
+
+    for (table_name, rows) in nullified {
+        let rows = rows
+            .into_iter()
+            .map(|(key, values)| (key, DataRow::Vec(values)))
+            .collect();
+
+        storage.insert_data(table_name, rows).await?;
+    }
+
+    Ok(())
+}
+
+/// `TRUNCATE` applies the `ON DELETE` actions the same as deleting every row of the table.
+pub async fn truncate_referencing<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+) -> Result<()> {
+    let is_referenced = storage
+        .fetch_all_schemas()
+        .await?
+        .iter()
+        .flat_map(|schema| schema.foreign_keys.iter())
+        .any(|foreign_key| foreign_key.referenced_table_name == table_name);
+
+    if !is_referenced {
+        return Ok(());
+    }
+
+    let keys = storage
+        .scan_data(table_name)
+        .await?
+        .map(|item| item.map(|(key, _)| key))
+        .collect::<Result<Vec<_>>>()?;
+
+    delete_referencing(storage, table_name, &keys).await
+}
+
+fn column_index(column_defs: &[ColumnDef], column_name: &str) -> Result<usize> {
+    column_defs
+        .iter()
+        .position(|column_def| column_def.name == column_name)
+        .ok_or_else(|| {
+            ForeignKeyError::ConflictOnForeignKeyColumnNotFound(column_name.to_owned()).into()
+        })
+}
@@ -1,10 +1,19 @@
 use {
     super::{
+        context::RowContext,
+        execute::Payload,
+        filter::check_expr,
+        foreign_key::validate_referenced,
+        returning::project_returning,
         select::select,
-        validate::{validate_unique, ColumnValidation},
+        update::Update,
+        validate::{validate_check, validate_unique, ColumnValidation, ValidateError},
     },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, Query, SetExpr, Values},
+        ast::{
+            Assignment, ColumnDef, ColumnUniqueOption, Expr, OnConflict, OnConflictAction, Query,
+            SelectItem, SetExpr, Values,
+        },
         data::{Key, Row, Schema, Value},
         executor::{evaluate::evaluate_stateless, limit::Limit},
         result::Result,
@@ -12,7 +21,7 @@ use {
     },
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{fmt::Debug, rc::Rc},
+    std::{borrow::Cow, collections::HashSet, fmt::Debug, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -38,6 +47,12 @@ pub enum InsertError {
 
     #[error("map type required: {0}")]
     MapTypeValueRequired(String),
+
+    #[error("ON CONFLICT requires a primary key: {0}")]
+    OnConflictWithoutPrimaryKey(String),
+
+    #[error("ON CONFLICT target must be the primary key: {0}")]
+    UnsupportedConflictTarget(String),
 }
 
 enum RowsData {
@@ -45,25 +60,97 @@ enum RowsData {
     Insert(Vec<(Key, DataRow)>),
 }
 
+impl RowsData {
+    fn data_rows(&self) -> Vec<&DataRow> {
+        match self {
+            RowsData::Append(rows) => rows.iter().collect(),
+            RowsData::Insert(rows) => rows.iter().map(|(_, row)| row).collect(),
+        }
+    }
+}
+
 pub async fn insert<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
     columns: &[String],
     source: &Query,
-) -> Result<usize> {
-    let Schema { column_defs, .. } = storage
+    on_conflict: Option<&OnConflict>,
+    returning: &[SelectItem],
+) -> Result<Payload> {
+    let Schema {
+        column_defs,
+        foreign_keys,
+        checks,
+        ..
+    } = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
+    let labels = Rc::<[String]>::from(
+        column_defs
+            .iter()
+            .flatten()
+            .map(|column_def| column_def.name.to_owned())
+            .collect::<Vec<_>>(),
+    );
 
-    let rows = match column_defs {
+    let rows = match column_defs.as_deref() {
         Some(column_defs) => {
-            fetch_vec_rows(storage, table_name, column_defs, columns, source).await
+            let rows = fetch_vec_rows(
+                storage,
+                table_name,
+                column_defs,
+                columns,
+                source,
+                on_conflict,
+            )
+            .await?;
+            let data_rows = rows.data_rows();
+            let values = data_rows.iter().filter_map(|data_row| match data_row {
+                DataRow::Vec(values) => Some(values.as_slice()),
+                DataRow::Map(_) => None,
+            });
+
+            validate_referenced(
+                storage,
+                table_name,
+                column_defs,
+                &foreign_keys,
+                values.clone(),
+            )
+            .await?;
+            validate_check(storage, table_name, column_defs, &checks, values).await?;
+
+            Ok(rows)
         }
-        None => fetch_map_rows(storage, source).await.map(RowsData::Append),
+        None if on_conflict.is_some() => {
+            Err(InsertError::OnConflictWithoutPrimaryKey(table_name.to_owned()).into())
+        }
+        None => fetch_map_rows(storage, columns, source)
+            .await
+            .map(RowsData::Append),
     }?;
 
-    match rows {
+    let payload = match returning.is_empty() {
+        true => None,
+        false => {
+            let rows = rows
+                .data_rows()
+                .into_iter()
+                .map(|data_row| match data_row.clone() {
+                    DataRow::Vec(values) => Row::Vec {
+                        columns: Rc::clone(&labels),
+                        values,
+                    },
+                    DataRow::Map(values) => Row::Map(values),
+                })
+                .collect();
+
+            Some(project_returning(storage, table_name, returning, rows).await?)
+        }
+    };
+
+    let num_rows = match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
 
@@ -80,15 +167,18 @@ pub async fn insert<T: GStore + GStoreMut>(
                 .await
                 .map(|_| num_rows)
         }
-    }
+    }?;
+
+    Ok(payload.unwrap_or(Payload::Insert(num_rows)))
 }
 
 async fn fetch_vec_rows<T: GStore>(
     storage: &T,
     table_name: &str,
-    column_defs: Vec<ColumnDef>,
+    column_defs: &[ColumnDef],
     columns: &[String],
     source: &Query,
+    on_conflict: Option<&OnConflict>,
 ) -> Result<RowsData> {
     let labels = Rc::from(
         column_defs
@@ -96,7 +186,7 @@ async fn fetch_vec_rows<T: GStore>(
             .map(|column_def| column_def.name.to_owned())
             .collect::<Vec<_>>(),
     );
-    let column_defs = Rc::from(column_defs);
+    let column_defs = Rc::<[ColumnDef]>::from(column_defs);
     let column_validation = ColumnValidation::All(&column_defs);
 
     #[derive(futures_enum::Stream)]
@@ -124,25 +214,15 @@ async fn fetch_vec_rows<T: GStore>(
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
-                let values = row?.try_into_vec()?;
-
-                column_defs
-                    .iter()
-                    .zip(values.iter())
-                    .try_for_each(|(column_def, value)| {
-                        let ColumnDef {
-                            data_type,
-                            nullable,
-                            ..
-                        } = column_def;
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
+            let rows = select(storage, source, None).await?.then(|row| {
+                let column_defs = Rc::clone(&column_defs);
 
-                        value.validate_type(data_type)?;
-                        value.validate_null(*nullable)
-                    })?;
+                async move {
+                    let values = row?.try_into_vec()?;
 
-                Ok(values)
+                    fill_selected_values(&column_defs, columns, values).await
+                }
             });
 
             Rows::Select(rows)
@@ -151,6 +231,27 @@ async fn fetch_vec_rows<T: GStore>(
     .try_collect::<Vec<Vec<Value>>>()
     .await?;
 
+    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
+        unique == &Some(ColumnUniqueOption { is_primary: true })
+    });
+
+    if let Some(on_conflict) = on_conflict {
+        let primary_key = primary_key
+            .ok_or_else(|| InsertError::OnConflictWithoutPrimaryKey(table_name.to_owned()))?;
+
+        return resolve_conflicts(
+            storage,
+            table_name,
+            &column_defs,
+            labels,
+            primary_key,
+            on_conflict,
+            rows,
+        )
+        .await
+        .map(RowsData::Insert);
+    }
+
     validate_unique(
         storage,
         table_name,
@@ -159,10 +260,6 @@ async fn fetch_vec_rows<T: GStore>(
     )
     .await?;
 
-    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
-        unique == &Some(ColumnUniqueOption { is_primary: true })
-    });
-
     match primary_key {
         Some(i) => rows
             .into_iter()
@@ -178,7 +275,134 @@ async fn fetch_vec_rows<T: GStore>(
     }
 }
 
-async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<DataRow>> {
+/// Splits the rows of `INSERT .. ON CONFLICT` by whether their primary key already exists.
+/// New rows are inserted as they are, existing rows are either kept (`DO NOTHING`) or
+/// updated by the assignments (`DO UPDATE`), where `excluded` refers to the proposed row.
+async fn resolve_conflicts<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    labels: Rc<[String]>,
+    primary_key: usize,
+    on_conflict: &OnConflict,
+    rows: Vec<Vec<Value>>,
+) -> Result<Vec<(Key, DataRow)>> {
+    let OnConflict { columns, action } = on_conflict;
+
+    if !columns.is_empty() && columns != std::slice::from_ref(&column_defs[primary_key].name) {
+        return Err(InsertError::UnsupportedConflictTarget(columns.join(", ")).into());
+    }
+
+    let update = match action {
+        OnConflictAction::DoNothing => None,
+        OnConflictAction::DoUpdate { assignments, .. } => Some(Update::new(
+            storage,
+            table_name,
+            assignments,
+            Some(column_defs),
+        )?),
+    };
+
+    let mut keys = HashSet::new();
+    let mut inserted = Vec::new();
+    let mut updated = Vec::new();
+
+    for values in rows {
+        let key = Key::try_from(&values[primary_key])?;
+
+        if !keys.insert(key.clone()) {
+            match action {
+                OnConflictAction::DoNothing => continue,
+                OnConflictAction::DoUpdate { .. } => {
+                    return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
+                }
+            }
+        }
+
+        let (update, data_row) = match (&update, storage.fetch_data(table_name, &key).await?) {
+            (_, None) => {
+                inserted.push((key, values));
+                continue;
+            }
+            (None, Some(_)) => continue,
+            (Some(update), Some(data_row)) => (update, data_row),
+        };
+
+        let existing = match data_row {
+            DataRow::Vec(values) => Row::Vec {
+                columns: Rc::clone(&labels),
+                values,
+            },
+            DataRow::Map(_) => {
+                return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+            }
+        };
+        let proposed = Row::Vec {
+            columns: Rc::clone(&labels),
+            values,
+        };
+        let excluded = RowContext::new("excluded", Cow::Borrowed(&proposed), None);
+        let excluded = Some(Rc::new(excluded));
+
+        if let OnConflictAction::DoUpdate {
+            selection: Some(expr),
+            ..
+        } = action
+        {
+            let context = RowContext::new(
+                table_name,
+                Cow::Borrowed(&existing),
+                excluded.as_ref().map(Rc::clone),
+            );
+
+            if !check_expr(storage, Some(Rc::new(context)), None, expr).await? {
+                continue;
+            }
+        }
+
+        let values = update.apply(existing, excluded).await?.try_into_vec()?;
+        updated.push((key, values));
+    }
+
+    validate_unique(
+        storage,
+        table_name,
+        ColumnValidation::All(column_defs),
+        inserted.iter().map(|(_, values)| values.as_slice()),
+    )
+    .await?;
+
+    if let OnConflictAction::DoUpdate { assignments, .. } = action {
+        let columns_to_update = assignments
+            .iter()
+            .map(|Assignment { id, .. }| id.to_owned())
+            .collect();
+
+        validate_unique(
+            storage,
+            table_name,
+            ColumnValidation::SpecifiedColumns(
+                column_defs,
+                columns_to_update,
+                updated.iter().map(|(key, _)| key.clone()).collect(),
+            ),
+            updated.iter().map(|(_, values)| values.as_slice()),
+        )
+        .await?;
+    }
+
+    Ok(inserted
+        .into_iter()
+        .chain(updated)
+        .map(|(key, values)| (key, values.into()))
+        .collect())
+}
+
+async fn fetch_map_rows<T: GStore>(
+    storage: &T,
+    columns: &[String],
+    source: &Query,
+) -> Result<Vec<DataRow>> {
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
         Values(I1),
@@ -189,6 +413,17 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
         SetExpr::Values(Values(values_list)) => {
             let limit = Limit::new(source.limit.as_ref(), source.offset.as_ref()).await?;
             let rows = stream::iter(values_list).then(|values| async move {
+                if !columns.is_empty() {
+                    let values = stream::iter(values)
+                        .then(|expr| async move {
+                            Value::try_from(evaluate_stateless(None, expr).await?)
+                        })
+                        .try_collect::<Vec<_>>()
+                        .await?;
+
+                    return document(columns, values);
+                }
+
                 if values.len() > 1 {
                     return Err(InsertError::OnlySingleValueAcceptedForSchemalessRow.into());
                 }
@@ -203,10 +438,14 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
             let rows = select(storage, source, None).await?.map(|row| {
                 let row = row?;
 
+                if !columns.is_empty() {
+                    return document(columns, row.try_into_vec()?).map(Into::into);
+                }
+
                 if let Row::Vec { values, .. } = &row {
                     if values.len() > 1 {
                         return Err(InsertError::OnlySingleValueAcceptedForSchemalessRow.into());
@@ -227,69 +466,130 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
     Ok(rows)
 }
 
-async fn fill_values(
-    column_defs: &[ColumnDef],
-    columns: &[String],
-    values: &[Expr],
-) -> Result<Vec<Value>> {
-    if !columns.is_empty() && values.len() != columns.len() {
+/// Schemaless rows inserted with a column list are stored as documents of those columns.
+fn document(columns: &[String], values: Vec<Value>) -> Result<Row> {
+    if values.len() != columns.len() {
         return Err(InsertError::ColumnAndValuesNotMatched.into());
-    } else if values.len() > column_defs.len() {
-        return Err(InsertError::TooManyValues.into());
     }
 
-    if let Some(wrong_column_name) = columns.iter().find(|column_name| {
-        !column_defs
-            .iter()
-            .any(|column_def| &&column_def.name == column_name)
-    }) {
-        return Err(InsertError::WrongColumnName(wrong_column_name.to_owned()).into());
-    }
+    let values = columns.iter().cloned().zip(values).collect();
 
-    #[derive(iter_enum::Iterator)]
-    enum Columns<I1, I2> {
-        All(I1),
-        Specified(I2),
-    }
+    Ok(Row::Map(values))
+}
 
-    let columns = if columns.is_empty() {
-        Columns::All(column_defs.iter().map(|ColumnDef { name, .. }| name))
-    } else {
-        Columns::Specified(columns.iter())
-    };
+async fn fill_values(
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    values: &[Expr],
+) -> Result<Vec<Value>> {
+    let indexes = column_indexes(column_defs, columns, values.len())?;
+
+    stream::iter(column_defs.iter().zip(indexes))
+        .then(|(column_def, index)| async move {
+            let ColumnDef {
+                data_type,
+                nullable,
+                ..
+            } = column_def;
+
+            match index {
+                Some(i) if !is_default_keyword(&values[i]) => evaluate_stateless(None, &values[i])
+                    .await?
+                    .try_into_value(data_type, *nullable),
+                _ => default_value(column_def).await,
+            }
+        })
+        .try_collect::<Vec<Value>>()
+        .await
+}
 
-    let column_name_value_list = columns.zip(values.iter()).collect::<Vec<(_, _)>>();
+async fn fill_selected_values(
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    values: Vec<Value>,
+) -> Result<Vec<Value>> {
+    let indexes = column_indexes(column_defs, columns, values.len())?;
 
-    let values = stream::iter(column_defs)
-        .then(|column_def| {
-            let column_name_value_list = &column_name_value_list;
+    stream::iter(column_defs.iter().zip(indexes))
+        .then(|(column_def, index)| {
+            let values = &values;
 
             async move {
                 let ColumnDef {
-                    name: def_name,
                     data_type,
                     nullable,
                     ..
                 } = column_def;
 
-                let value = column_name_value_list
-                    .iter()
-                    .find(|(name, _)| name == &def_name)
-                    .map(|(_, value)| value);
-
-                match (value, &column_def.default, nullable) {
-                    (Some(&expr), _, _) | (None, Some(expr), _) => evaluate_stateless(None, expr)
-                        .await?
-                        .try_into_value(data_type, *nullable),
-                    (None, None, true) => Ok(Value::Null),
-                    (None, None, false) => {
-                        Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into())
+                match index {
+                    Some(i) => {
+                        let value = values[i].clone().coerce_integer(data_type)?;
+                        value.validate_type(data_type)?;
+                        value.validate_null(*nullable)?;
+
+                        Ok(value)
                     }
+                    None => default_value(column_def).await,
                 }
             }
         })
         .try_collect::<Vec<Value>>()
-        .await?;
+        .await
+}
+
+/// Position of the given value for each of the target columns, `None` when the column
+/// is not specified and has to be filled with its default.
+pub(super) fn column_indexes(
+    column_defs: &[ColumnDef],
+    columns: &[String],
+    num_values: usize,
+) -> Result<Vec<Option<usize>>> {
+    if !columns.is_empty() && num_values != columns.len() {
+        return Err(InsertError::ColumnAndValuesNotMatched.into());
+    } else if num_values > column_defs.len() {
+        return Err(InsertError::TooManyValues.into());
+    }
+
+    if let Some(wrong_column_name) = columns.iter().find(|column_name| {
+        !column_defs
+            .iter()
+            .any(|column_def| &&column_def.name == column_name)
+    }) {
+        return Err(InsertError::WrongColumnName(wrong_column_name.to_owned()).into());
+    }
+
+    let indexes = column_defs
+        .iter()
+        .enumerate()
+        .map(|(i, ColumnDef { name, .. })| match columns.is_empty() {
+            true => (i < num_values).then_some(i),
+            false => columns.iter().position(|column| column == name),
+        })
+        .collect();
+
+    Ok(indexes)
+}
+
+pub(super) async fn default_value(column_def: &ColumnDef) -> Result<Value> {
+    let ColumnDef {
+        name,
+        data_type,
+        nullable,
+        default,
+        ..
+    } = column_def;
+
+    match (default, nullable) {
+        (Some(expr), _) => evaluate_stateless(None, expr)
+            .await?
+            .try_into_value(data_type, *nullable),
+        (None, true) => Ok(Value::Null),
+        (None, false) => Err(InsertError::LackOfRequiredColumn(name.to_owned()).into()),
+    }
+}
 
-    Ok(values)
+/// `DEFAULT` given as a value of `INSERT` or `UPDATE`, which sqlparser leaves as a plain
+/// identifier.
+pub(super) fn is_default_keyword(expr: &Expr) -> bool {
+    matches!(expr, Expr::Identifier(ident) if ident.eq_ignore_ascii_case("DEFAULT"))
 }
@@ -1,5 +1,5 @@
 use {
-    super::fetch::{fetch_relation_columns, fetch_relation_rows},
+    super::fetch::{fetch_relation_columns, fetch_relation_rows, FetchError},
     crate::{
         ast::{
             Expr, Join as AstJoin, JoinConstraint, JoinExecutor as AstJoinExecutor,
@@ -7,37 +7,51 @@ use {
         },
         data::{get_alias, Key, Row, Value},
         executor::{context::RowContext, evaluate::evaluate, filter::check_expr},
-        result::Result,
+        result::{Error, Result},
         store::GStore,
     },
     futures::{
-        future,
+        future::{self, TryFutureExt},
         stream::{self, empty, once, Stream, StreamExt, TryStreamExt},
     },
     itertools::Itertools,
-    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc},
+    std::{
+        borrow::Cow,
+        cell::RefCell,
+        collections::{HashMap, HashSet},
+        iter,
+        pin::Pin,
+        rc::Rc,
+    },
     utils::OrStream,
 };
 
 pub struct Join<'a, T: GStore> {
     storage: &'a T,
+    relation: &'a TableFactor,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Rc<RowContext<'a>>>,
+    used_columns: Option<Rc<[String]>>,
 }
 
 type JoinItem<'a> = Rc<RowContext<'a>>;
 type Joined<'a> = Pin<Box<dyn Stream<Item = Result<JoinItem<'a>>> + 'a>>;
+type MatchedRows = Rc<RefCell<HashSet<usize>>>;
 
 impl<'a, T: GStore> Join<'a, T> {
     pub fn new(
         storage: &'a T,
+        relation: &'a TableFactor,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Rc<RowContext<'a>>>,
+        used_columns: Option<Rc<[String]>>,
     ) -> Self {
         Self {
             storage,
+            relation,
             join_clauses,
             filter_context,
+            used_columns,
         }
     }
 
@@ -47,12 +61,27 @@ impl<'a, T: GStore> Join<'a, T> {
     ) -> Result<Joined<'a>> {
         let init_rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
 
-        stream::iter(self.join_clauses)
+        stream::iter(self.join_clauses.iter().enumerate())
             .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
+            .try_fold(init_rows, |rows, (i, join_clause)| {
                 let filter_context = self.filter_context.as_ref().map(Rc::clone);
+                let used_columns = self.used_columns.as_ref().map(Rc::clone);
+                let left_relations = self.join_clauses[..i]
+                    .iter()
+                    .map(|AstJoin { relation, .. }| relation);
+                let left_relations = iter::once(self.relation).chain(left_relations).collect();
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
+                async move {
+                    join(
+                        self.storage,
+                        filter_context,
+                        join_clause,
+                        left_relations,
+                        used_columns,
+                        rows,
+                    )
+                    .await
+                }
             })
             .await
     }
@@ -62,6 +91,8 @@ async fn join<'a, T: GStore>(
     storage: &'a T,
     filter_context: Option<Rc<RowContext<'a>>>,
     ast_join: &'a AstJoin,
+    left_relations: Vec<&'a TableFactor>,
+    used_columns: Option<Rc<[String]>>,
     left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
 ) -> Result<Joined<'a>> {
     let AstJoin {
@@ -76,42 +107,63 @@ async fn join<'a, T: GStore>(
         relation,
         filter_context.as_ref().map(Rc::clone),
         join_executor,
+        used_columns.as_deref(),
     )
     .await
     .map(Rc::new)?;
 
-    let (join_operator, where_clause) = match join_operator {
-        AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
-        AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::Inner, Some(where_clause))
-        }
-        AstJoinOperator::LeftOuter(JoinConstraint::None) => (JoinOperator::LeftOuter, None),
-        AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::LeftOuter, Some(where_clause))
+    let (join_operator, join_constraint) = match join_operator {
+        AstJoinOperator::Inner(join_constraint) => (JoinOperator::Inner, join_constraint),
+        AstJoinOperator::LeftOuter(join_constraint) => (JoinOperator::LeftOuter, join_constraint),
+        AstJoinOperator::RightOuter(join_constraint) => (JoinOperator::RightOuter, join_constraint),
+        AstJoinOperator::FullOuter(join_constraint) => (JoinOperator::FullOuter, join_constraint),
+    };
+    let where_clause = match join_constraint {
+        JoinConstraint::On(where_clause) => Some(where_clause),
+        JoinConstraint::Using(_) | JoinConstraint::Natural | JoinConstraint::None => None,
+    };
+
+    let matched_rows: Option<MatchedRows> = match join_operator {
+        JoinOperator::Inner | JoinOperator::LeftOuter => None,
+        JoinOperator::RightOuter | JoinOperator::FullOuter => {
+            Some(Rc::new(RefCell::new(HashSet::new())))
         }
     };
 
     let columns = fetch_relation_columns(storage, relation)
         .await?
-        .map(Rc::from);
-    let rows = left_rows.and_then(move |project_context| {
-        let init_context = {
-            let init_row = match columns.as_ref() {
-                Some(columns) => Row::Vec {
-                    columns: Rc::clone(columns),
-                    values: columns.iter().map(|_| Value::Null).collect(),
-                },
-                None => Row::Map(HashMap::new()),
-            };
+        .map(Rc::<[String]>::from);
+    let using = match join_constraint {
+        JoinConstraint::Using(_) | JoinConstraint::Natural => {
+            let left_columns = stream::iter(left_relations.iter())
+                .then(|left_relation| fetch_relation_columns(storage, left_relation))
+                .try_collect::<Vec<_>>()
+                .await?
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .map(|left_columns| left_columns.concat());
 
-            Rc::new(RowContext::new(
-                table_alias,
-                Cow::Owned(init_row),
-                Some(Rc::clone(&project_context)),
-            ))
-        };
+            using_columns(join_constraint, left_columns.as_deref(), columns.as_deref())?
+                .map(Rc::from)
+        }
+        JoinConstraint::On(_) | JoinConstraint::None => None,
+    };
+    let unmatched_using = using.as_ref().map(Rc::clone);
+    let unmatched_filter_context = filter_context.as_ref().map(Rc::clone);
+    let unmatched_rows = matched_rows.as_ref().map(Rc::clone);
+    let unmatched_used_columns = used_columns.as_ref().map(Rc::clone);
+    let rows = left_rows.and_then(move |project_context| {
+        let init_context = Rc::new(joined_context(
+            table_alias,
+            Cow::Owned(null_row(columns.as_ref())),
+            using.as_ref(),
+            Some(Rc::clone(&project_context)),
+        ));
+        let using = using.as_ref().map(Rc::clone);
         let filter_context = filter_context.as_ref().map(Rc::clone);
         let join_executor = Rc::clone(&join_executor);
+        let matched_rows = matched_rows.as_ref().map(Rc::clone);
+        let used_columns = used_columns.as_ref().map(Rc::clone);
 
         async move {
             let filter_context = match filter_context {
@@ -131,19 +183,28 @@ async fn join<'a, T: GStore>(
             }
             let rows = match join_executor.as_ref() {
                 JoinExecutor::NestedLoop => {
-                    let rows = fetch_relation_rows(storage, relation, &filter_context)
-                        .await?
-                        .and_then(|row| future::ok(Cow::Owned(row)))
-                        .try_filter_map(move |row| {
-                            check_where_clause(
-                                storage,
-                                table_alias,
-                                filter_context.as_ref().map(Rc::clone),
-                                Some(&project_context).map(Rc::clone),
-                                where_clause,
-                                row,
-                            )
-                        });
+                    let rows = fetch_relation_rows(
+                        storage,
+                        relation,
+                        &filter_context,
+                        used_columns.as_deref(),
+                        &[],
+                    )
+                    .await?
+                    .enumerate()
+                    .map(|(index, row)| row.map(|row| (index, Cow::Owned(row))))
+                    .try_filter_map(move |(index, row)| {
+                        check_where_clause(
+                            storage,
+                            table_alias,
+                            filter_context.as_ref().map(Rc::clone),
+                            Some(&project_context).map(Rc::clone),
+                            where_clause,
+                            using.as_ref().map(Rc::clone),
+                            row,
+                        )
+                        .map_ok(mark_matched(matched_rows.as_ref(), index))
+                    });
                     Rows::NestedLoop(rows)
                 }
                 JoinExecutor::Hash {
@@ -164,9 +225,11 @@ async fn join<'a, T: GStore>(
                         None => Rows::Empty(empty()),
                         Some(rows) => {
                             let rows = stream::iter(rows)
-                                .filter_map(|row| {
+                                .filter_map(|(index, row)| {
                                     let filter_context = filter_context.as_ref().map(Rc::clone);
                                     let project_context = Some(&project_context).map(Rc::clone);
+                                    let using = using.as_ref().map(Rc::clone);
+                                    let mark_matched = mark_matched(matched_rows.as_ref(), *index);
 
                                     async {
                                         check_where_clause(
@@ -175,9 +238,11 @@ async fn join<'a, T: GStore>(
                                             filter_context,
                                             project_context,
                                             where_clause,
+                                            using,
                                             Cow::Borrowed(row),
                                         )
                                         .await
+                                        .map(mark_matched)
                                         .transpose()
                                     }
                                 })
@@ -191,8 +256,8 @@ async fn join<'a, T: GStore>(
             };
 
             let rows: Joined = match join_operator {
-                JoinOperator::Inner => Box::pin(rows),
-                JoinOperator::LeftOuter => {
+                JoinOperator::Inner | JoinOperator::RightOuter => Box::pin(rows),
+                JoinOperator::LeftOuter | JoinOperator::FullOuter => {
                     let init_rows = once(async { Ok(init_context) });
 
                     Box::pin(OrStream::new(rows, init_rows))
@@ -202,20 +267,121 @@ async fn join<'a, T: GStore>(
             Ok(rows)
         }
     });
+    let rows = rows.try_flatten();
 
-    Ok(Box::pin(rows.try_flatten()))
+    match unmatched_rows {
+        None => Ok(Box::pin(rows)),
+        Some(matched_rows) => {
+            let unmatched_rows = once(fetch_unmatched_rows(
+                storage,
+                relation,
+                left_relations,
+                unmatched_filter_context,
+                unmatched_using,
+                unmatched_used_columns,
+                matched_rows,
+            ))
+            .try_flatten();
+
+            Ok(Box::pin(rows.chain(unmatched_rows)))
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
 enum JoinOperator {
     Inner,
     LeftOuter,
+    RightOuter,
+    FullOuter,
+}
+
+fn null_row(columns: Option<&Rc<[String]>>) -> Row {
+    match columns {
+        Some(columns) => Row::Vec {
+            columns: Rc::clone(columns),
+            values: columns.iter().map(|_| Value::Null).collect(),
+        },
+        None => Row::Map(HashMap::new()),
+    }
+}
+
+fn mark_matched<'a>(
+    matched_rows: Option<&MatchedRows>,
+    index: usize,
+) -> impl FnOnce(Option<JoinItem<'a>>) -> Option<JoinItem<'a>> {
+    let matched_rows = matched_rows.map(Rc::clone);
+
+    move |joined| {
+        if let (Some(matched_rows), Some(_)) = (matched_rows, &joined) {
+            matched_rows.borrow_mut().insert(index);
+        }
+
+        joined
+    }
+}
+
+/// Emits the right rows which never matched any left row, padding every
+/// left relation with NULL values.
+/// Polled only after the left rows are exhausted, so `matched_rows` is complete.
+async fn fetch_unmatched_rows<'a, T: GStore>(
+    storage: &'a T,
+    relation: &'a TableFactor,
+    left_relations: Vec<&'a TableFactor>,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    using: Option<Rc<[String]>>,
+    used_columns: Option<Rc<[String]>>,
+    matched_rows: MatchedRows,
+) -> Result<Joined<'a>> {
+    let project_context = stream::iter(left_relations)
+        .map(Ok)
+        .try_fold(None, |parent, left_relation| async move {
+            let columns = fetch_relation_columns(storage, left_relation)
+                .await?
+                .map(Rc::from);
+            let context = RowContext::new(
+                get_alias(left_relation),
+                Cow::Owned(null_row(columns.as_ref())),
+                parent,
+            );
+
+            Ok::<_, Error>(Some(Rc::new(context)))
+        })
+        .await?;
+
+    let table_alias = get_alias(relation);
+    let rows = fetch_relation_rows(
+        storage,
+        relation,
+        &filter_context,
+        used_columns.as_deref(),
+        &[],
+    )
+    .await?
+    .enumerate()
+    .filter_map(move |(index, row)| {
+        let matched = matched_rows.borrow().contains(&index);
+
+        future::ready((!matched).then_some(row))
+    })
+    .map_ok(move |row| {
+        let project_context = project_context.as_ref().map(Rc::clone);
+
+        Rc::new(joined_context(
+            table_alias,
+            Cow::Owned(row),
+            using.as_ref(),
+            project_context,
+        ))
+    });
+
+    Ok(Box::pin(rows))
 }
 
 enum JoinExecutor<'a> {
     NestedLoop,
     Hash {
-        rows_map: HashMap<Key, Vec<Row>>,
+        rows_map: HashMap<Key, Vec<(usize, Row)>>,
         value_expr: &'a Expr,
     },
 }
@@ -226,6 +392,7 @@ impl<'a> JoinExecutor<'a> {
         relation: &TableFactor,
         filter_context: Option<Rc<RowContext<'a>>>,
         ast_join_executor: &'a AstJoinExecutor,
+        used_columns: Option<&[String]>,
     ) -> Result<JoinExecutor<'a>> {
         let (key_expr, value_expr, where_clause) = match ast_join_executor {
             AstJoinExecutor::NestedLoop => return Ok(Self::NestedLoop),
@@ -236,9 +403,11 @@ impl<'a> JoinExecutor<'a> {
             } => (key_expr, value_expr, where_clause),
         };
 
-        let rows_map = fetch_relation_rows(storage, relation, &filter_context)
+        let rows_map = fetch_relation_rows(storage, relation, &filter_context, used_columns, &[])
             .await?
-            .try_filter_map(|row| {
+            .enumerate()
+            .map(|(index, row)| row.map(|row| (index, row)))
+            .try_filter_map(|(index, row)| {
                 let filter_context = filter_context.as_ref().map(Rc::clone);
 
                 async move {
@@ -264,8 +433,8 @@ impl<'a> JoinExecutor<'a> {
                     match where_clause {
                         Some(expr) => check_expr(storage, Some(filter_context), None, expr)
                             .await
-                            .map(|pass| pass.then_some((hash_key, row))),
-                        None => Ok(Some((hash_key, row))),
+                            .map(|pass| pass.then_some((hash_key, (index, row)))),
+                        None => Ok(Some((hash_key, (index, row)))),
                     }
                 }
             })
@@ -286,8 +455,25 @@ async fn check_where_clause<'a, 'b, T: GStore>(
     filter_context: Option<Rc<RowContext<'a>>>,
     project_context: Option<Rc<RowContext<'a>>>,
     where_clause: Option<&'a Expr>,
+    using: Option<Rc<[String]>>,
     row: Cow<'b, Row>,
 ) -> Result<Option<Rc<RowContext<'a>>>> {
+    let using_matched = using.as_ref().map_or(true, |columns| {
+        columns.iter().all(|column| {
+            let left = project_context
+                .as_ref()
+                .and_then(|context| context.get_value(column));
+
+            match (left, row.get_value(column)) {
+                (Some(left), Some(right)) => !left.is_null() && left.evaluate_eq(right),
+                _ => false,
+            }
+        })
+    });
+    if !using_matched {
+        return Ok(None);
+    }
+
     let filter_context = RowContext::new(table_alias, Cow::Borrowed(&row), filter_context);
     let filter_context = Some(Rc::new(filter_context));
 
@@ -295,8 +481,66 @@ async fn check_where_clause<'a, 'b, T: GStore>(
         Some(expr) => check_expr(storage, filter_context, None, expr).await?,
         None => true,
     }
-    .then(|| RowContext::new(table_alias, Cow::Owned(row.into_owned()), project_context))
+    .then(|| {
+        joined_context(
+            table_alias,
+            Cow::Owned(row.into_owned()),
+            using.as_ref(),
+            project_context,
+        )
+    })
     .map(Rc::new)
     .map(Ok)
     .transpose()
 }
+
+fn joined_context<'a>(
+    table_alias: &'a str,
+    row: Cow<'a, Row>,
+    using: Option<&Rc<[String]>>,
+    next: Option<Rc<RowContext<'a>>>,
+) -> RowContext<'a> {
+    match (using, next) {
+        (Some(columns), Some(next)) => {
+            RowContext::using(table_alias, row, Rc::clone(columns), next)
+        }
+        (_, next) => RowContext::new(table_alias, row, next),
+    }
+}
+
+/// Resolves the columns merged by a `USING` or `NATURAL` join constraint.
+/// `NATURAL` merges every column the right relation shares with the left ones.
+pub fn using_columns(
+    join_constraint: &JoinConstraint,
+    left_columns: Option<&[String]>,
+    right_columns: Option<&[String]>,
+) -> Result<Option<Vec<String>>> {
+    match join_constraint {
+        JoinConstraint::Using(columns) => {
+            let missing = columns.iter().find(|column| {
+                [left_columns, right_columns]
+                    .into_iter()
+                    .flatten()
+                    .any(|columns| !columns.contains(column))
+            });
+
+            match missing {
+                Some(column) => Err(FetchError::UsingColumnNotFound(column.to_owned()).into()),
+                None => Ok(Some(columns.clone())),
+            }
+        }
+        JoinConstraint::Natural => match (left_columns, right_columns) {
+            (Some(left_columns), Some(right_columns)) => {
+                let columns = right_columns
+                    .iter()
+                    .filter(|column| left_columns.contains(column))
+                    .cloned()
+                    .collect();
+
+                Ok(Some(columns))
+            }
+            _ => Err(FetchError::NaturalJoinColumnsUnknown.into()),
+        },
+        JoinConstraint::On(_) | JoinConstraint::None => Ok(None),
+    }
+}
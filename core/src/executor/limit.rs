@@ -1,10 +1,6 @@
 use {
     super::evaluate::evaluate_stateless,
-    crate::{
-        ast::Expr,
-        data::{Row, Value},
-        result::Result,
-    },
+    crate::{ast::Expr, data::Value, result::Result},
     futures::stream::{Stream, StreamExt},
 };
 
@@ -33,10 +29,10 @@ impl Limit {
         Ok(Self { limit, offset })
     }
 
-    pub fn apply<'a>(
+    pub fn apply<'a, R>(
         &self,
-        rows: impl Stream<Item = Result<Row>> + 'a,
-    ) -> impl Stream<Item = Result<Row>> + 'a {
+        rows: impl Stream<Item = Result<R>> + 'a,
+    ) -> impl Stream<Item = Result<R>> + 'a {
         #[derive(futures_enum::Stream)]
         enum S<S1, S2, S3, S4> {
             Both(S3),
@@ -0,0 +1,333 @@
+use {
+    super::{
+        context::RowContext,
+        evaluate::evaluate,
+        execute::Payload,
+        fetch::{fetch, fetch_relation_rows},
+        filter::check_expr,
+        foreign_key::{delete_referencing, validate_referenced},
+        insert::{column_indexes, default_value, is_default_keyword, InsertError},
+        update::Update,
+        validate::{validate_check, validate_unique, ColumnValidation},
+    },
+    crate::{
+        ast::{Assignment, ColumnDef, ColumnUniqueOption, Expr, MergeClause, TableFactor, ToSql},
+        data::{get_alias, Key, Row, Schema},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+    serde::Serialize,
+    std::{borrow::Cow, collections::HashSet, fmt::Debug, rc::Rc},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum MergeError {
+    #[error("table not found: {0}")]
+    TableNotFound(String),
+
+    #[error("MERGE target must be a table: {0}")]
+    UnsupportedTarget(String),
+
+    #[error("MERGE cannot affect the same target row more than once")]
+    TargetRowMatchedMultipleTimes,
+}
+
+/// Rows of the target table to be written, resolved before touching the storage.
+#[derive(Default)]
+struct Changes {
+    inserted: Vec<(Key, DataRow)>,
+    appended: Vec<DataRow>,
+    updated: Vec<(Key, DataRow)>,
+    deleted: Vec<Key>,
+}
+
+pub async fn merge<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table: &TableFactor,
+    source: &TableFactor,
+    on: &Expr,
+    clauses: &[MergeClause],
+) -> Result<Payload> {
+    let table_name = match table {
+        TableFactor::Table { name, .. } => name,
+        _ => return Err(MergeError::UnsupportedTarget(table.to_sql()).into()),
+    };
+
+    let Changes {
+        inserted,
+        appended,
+        updated,
+        deleted,
+    } = resolve_changes(storage, table_name, table, source, on, clauses).await?;
+    let num_rows = inserted.len() + appended.len() + updated.len() + deleted.len();
+
+    delete_referencing(storage, table_name, &deleted).await?;
+    storage.delete_data(table_name, deleted).await?;
+    storage
+        .insert_data(table_name, inserted.into_iter().chain(updated).collect())
+        .await?;
+    storage.append_data(table_name, appended).await?;
+
+    Ok(Payload::Merge(num_rows))
+}
+
+/// Joins every source row with the target rows satisfying `ON`, then applies the first
+/// `WHEN MATCHED` clause to each matched target row and the first `WHEN NOT MATCHED`
+/// clause to each source row without a match.
+async fn resolve_changes<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    table: &TableFactor,
+    source: &TableFactor,
+    on: &Expr,
+    clauses: &[MergeClause],
+) -> Result<Changes> {
+    let Schema {
+        column_defs,
+        foreign_keys,
+        checks,
+        ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| MergeError::TableNotFound(table_name.to_owned()))?;
+    let column_defs = column_defs.as_deref();
+    let labels = column_defs.map(|column_defs| {
+        column_defs
+            .iter()
+            .map(|column_def| column_def.name.to_owned())
+            .collect::<Rc<[String]>>()
+    });
+    let primary_key = column_defs.and_then(|column_defs| {
+        column_defs.iter().position(|ColumnDef { unique, .. }| {
+            unique == &Some(ColumnUniqueOption { is_primary: true })
+        })
+    });
+
+    let target_alias = get_alias(table);
+    let source_alias = get_alias(source);
+
+    let updates = clauses
+        .iter()
+        .map(|clause| match clause {
+            MergeClause::MatchedUpdate { assignments, .. } => {
+                Update::new(storage, target_alias, assignments, column_defs).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let target_rows = fetch(storage, table_name, labels.clone(), None)
+        .await?
+        .try_collect::<Vec<(Key, Row)>>()
+        .await?;
+    let source_rows = fetch_relation_rows(storage, source, &None, None, &[])
+        .await?
+        .try_collect::<Vec<Row>>()
+        .await?;
+
+    let mut changes = Changes::default();
+    let mut inserted = Vec::new();
+    let mut updated = Vec::new();
+    let mut matched = HashSet::new();
+
+    for source_row in source_rows.iter() {
+        let source_context = RowContext::new(source_alias, Cow::Borrowed(source_row), None);
+        let source_context = Rc::new(source_context);
+
+        let mut is_matched = false;
+
+        for (i, (key, target_row)) in target_rows.iter().enumerate() {
+            let context = RowContext::new(
+                target_alias,
+                Cow::Borrowed(target_row),
+                Some(Rc::clone(&source_context)),
+            );
+            let context = Some(Rc::new(context));
+
+            if !check_expr(storage, context.as_ref().map(Rc::clone), None, on).await? {
+                continue;
+            }
+
+            is_matched = true;
+
+            if !matched.insert(i) {
+                return Err(MergeError::TargetRowMatchedMultipleTimes.into());
+            }
+
+            for (clause, update) in clauses.iter().zip(updates.iter()) {
+                let predicate = match clause {
+                    MergeClause::MatchedUpdate { predicate, .. }
+                    | MergeClause::MatchedDelete { predicate } => predicate,
+                    MergeClause::NotMatched { .. } => continue,
+                };
+
+                if let Some(expr) = predicate {
+                    let context = context.as_ref().map(Rc::clone);
+
+                    if !check_expr(storage, context, None, expr).await? {
+                        continue;
+                    }
+                }
+
+                match update {
+                    Some(update) => {
+                        let next = Some(Rc::clone(&source_context));
+                        let row = update.apply(target_row.clone(), next).await?;
+
+                        updated.push((key.clone(), row));
+                    }
+                    None => changes.deleted.push(key.clone()),
+                }
+
+                break;
+            }
+        }
+
+        if is_matched {
+            continue;
+        }
+
+        for clause in clauses {
+            let (predicate, columns, values) = match clause {
+                MergeClause::NotMatched {
+                    predicate,
+                    columns,
+                    values,
+                } => (predicate, columns, values),
+                _ => continue,
+            };
+
+            if let Some(expr) = predicate {
+                let context = Some(Rc::clone(&source_context));
+
+                if !check_expr(storage, context, None, expr).await? {
+                    continue;
+                }
+            }
+
+            let row = match column_defs {
+                Some(column_defs) => {
+                    let indexes = column_indexes(column_defs, columns, values.len())?;
+                    let mut row = Vec::with_capacity(column_defs.len());
+
+                    for (column_def, index) in column_defs.iter().zip(indexes) {
+                        let ColumnDef {
+                            data_type,
+                            nullable,
+                            ..
+                        } = column_def;
+
+                        let value = match index {
+                            Some(i) if !is_default_keyword(&values[i]) => {
+                                let context = Some(Rc::clone(&source_context));
+
+                                evaluate(storage, context, None, &values[i])
+                                    .await?
+                                    .try_into_value(data_type, *nullable)?
+                            }
+                            _ => default_value(column_def).await?,
+                        };
+
+                        row.push(value);
+                    }
+
+                    DataRow::Vec(row)
+                }
+                None => {
+                    if values.len() > 1 {
+                        return Err(InsertError::OnlySingleValueAcceptedForSchemalessRow.into());
+                    }
+
+                    let context = Some(Rc::clone(&source_context));
+
+                    evaluate(storage, context, None, &values[0])
+                        .await?
+                        .try_into()
+                        .map(DataRow::Map)?
+                }
+            };
+
+            inserted.push(row);
+            break;
+        }
+    }
+
+    if let Some(column_defs) = column_defs {
+        validate_unique(
+            storage,
+            table_name,
+            ColumnValidation::All(column_defs),
+            inserted.iter().filter_map(|row| match row {
+                DataRow::Vec(values) => Some(values.as_slice()),
+                DataRow::Map(_) => None,
+            }),
+        )
+        .await?;
+
+        let columns_to_update = clauses
+            .iter()
+            .flat_map(|clause| match clause {
+                MergeClause::MatchedUpdate { assignments, .. } => assignments.as_slice(),
+                _ => &[],
+            })
+            .map(|Assignment { id, .. }| id.to_owned())
+            .collect();
+
+        validate_unique(
+            storage,
+            table_name,
+            ColumnValidation::SpecifiedColumns(
+                column_defs,
+                columns_to_update,
+                updated.iter().map(|(key, _)| key.clone()).collect(),
+            ),
+            updated.iter().filter_map(|(_, row)| match row {
+                Row::Vec { values, .. } => Some(values.as_slice()),
+                Row::Map(_) => None,
+            }),
+        )
+        .await?;
+
+        let inserted_values = inserted.iter().filter_map(|row| match row {
+            DataRow::Vec(values) => Some(values.as_slice()),
+            DataRow::Map(_) => None,
+        });
+        let updated_values = updated.iter().filter_map(|(_, row)| match row {
+            Row::Vec { values, .. } => Some(values.as_slice()),
+            Row::Map(_) => None,
+        });
+
+        let values = inserted_values.chain(updated_values);
+
+        validate_referenced(
+            storage,
+            table_name,
+            column_defs,
+            &foreign_keys,
+            values.clone(),
+        )
+        .await?;
+        validate_check(storage, table_name, column_defs, &checks, values).await?;
+    }
+
+    changes.updated = updated
+        .into_iter()
+        .map(|(key, row)| (key, row.into()))
+        .collect();
+
+    for row in inserted {
+        match (primary_key, &row) {
+            (Some(i), DataRow::Vec(values)) => {
+                let key = Key::try_from(&values[i])?;
+
+                changes.inserted.push((key, row));
+            }
+            _ => changes.appended.push(row),
+        }
+    }
+
+    Ok(changes)
+}
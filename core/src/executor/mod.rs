@@ -1,17 +1,23 @@
 mod aggregate;
 mod alter;
+mod analyze;
 mod context;
 mod evaluate;
 mod execute;
+mod explain;
 mod fetch;
 mod filter;
+mod foreign_key;
 mod insert;
 mod join;
 mod limit;
+mod merge;
+mod returning;
 mod select;
 mod sort;
 mod update;
 mod validate;
+mod window;
 
 pub use {
     aggregate::AggregateError,
@@ -20,9 +26,12 @@ pub use {
     evaluate::{evaluate_stateless, EvaluateError},
     execute::{execute, ExecuteError, Payload, PayloadVariable},
     fetch::FetchError,
+    foreign_key::ForeignKeyError,
     insert::InsertError,
+    merge::MergeError,
     select::SelectError,
     sort::SortError,
     update::UpdateError,
     validate::ValidateError,
+    window::WindowError,
 };
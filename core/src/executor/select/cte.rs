@@ -0,0 +1,388 @@
+use {
+    super::{row_key, select_with_labels, SelectError},
+    crate::{
+        ast::{
+            ColumnDef, Cte, IndexOperator, Join, Query, SetExpr, SetOperator, TableAlias,
+            TableFactor, TableWithJoins, With,
+        },
+        data::{
+            CustomFunction as StructCustomFunction, Key, NativeFunction, NativeTableFunction, Row,
+            Schema, TableStatistics, Value,
+        },
+        executor::{context::RowContext, fetch::FetchError},
+        prelude::DataType,
+        result::Result,
+        store::{CustomFunction, DataRow, GStore, Index, MetaIter, Metadata, RowIter, Store},
+    },
+    async_trait::async_trait,
+    futures::stream::TryStreamExt,
+    itertools::Itertools,
+    std::{
+        collections::{HashMap, HashSet},
+        rc::Rc,
+    },
+};
+
+/// Storage wrapper which resolves CTE names to their materialized rows
+/// and delegates every other table to the underlying storage.
+pub struct CteStorage<'a> {
+    storage: &'a dyn GStore,
+    tables: HashMap<String, (Schema, Vec<DataRow>)>,
+}
+
+impl<'a> CteStorage<'a> {
+    pub async fn new(
+        storage: &'a dyn GStore,
+        with: &With,
+        filter_context: Option<Rc<RowContext<'_>>>,
+    ) -> Result<CteStorage<'a>> {
+        let With {
+            recursive,
+            cte_tables,
+            max_recursion,
+        } = with;
+
+        let mut cte_storage = Self {
+            storage,
+            tables: HashMap::new(),
+        };
+
+        for Cte { alias, query } in cte_tables {
+            let name = &alias.name;
+            let (labels, rows) = match &query.body {
+                SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all,
+                    left,
+                    right,
+                } if *recursive && !references(left, name) && references(right, name) => {
+                    let (labels, rows) =
+                        select_rows(&cte_storage, branch(left), filter_context.clone()).await?;
+                    let mut keys = HashSet::new();
+                    let mut rows = match all {
+                        true => rows,
+                        false => distinct(rows, &mut keys)?,
+                    };
+                    let schema = cte_schema(alias, labels.as_deref(), &rows)?;
+
+                    let recursive_term = branch(right);
+                    let mut working = rows.clone();
+                    let mut depth = 0;
+                    while !working.is_empty() {
+                        if depth == *max_recursion {
+                            return Err(SelectError::RecursionLimitExceeded {
+                                name: name.to_owned(),
+                                limit: *max_recursion,
+                            }
+                            .into());
+                        }
+                        depth += 1;
+
+                        let working_rows = working.into_iter().map(Into::into).collect();
+                        cte_storage
+                            .tables
+                            .insert(name.to_owned(), (schema.clone(), working_rows));
+
+                        let (next_labels, next) = select_rows(
+                            &cte_storage,
+                            recursive_term.clone(),
+                            filter_context.clone(),
+                        )
+                        .await?;
+
+                        if let (Some(labels), Some(next_labels)) = (&labels, next_labels) {
+                            if labels.len() != next_labels.len() {
+                                return Err(SelectError::SetOperationColumnCountMismatch {
+                                    op: SetOperator::Union.to_string(),
+                                    left: labels.len(),
+                                    right: next_labels.len(),
+                                }
+                                .into());
+                            }
+                        }
+
+                        working = match all {
+                            true => next,
+                            false => distinct(next, &mut keys)?,
+                        };
+                        rows.extend(working.iter().cloned());
+                    }
+
+                    (labels, rows)
+                }
+                body if *recursive && references(body, name) => {
+                    return Err(SelectError::RecursiveCteUnionRequired(name.to_owned()).into());
+                }
+                _ => select_rows(&cte_storage, query.clone(), filter_context.clone()).await?,
+            };
+
+            let schema = cte_schema(alias, labels.as_deref(), &rows)?;
+            let rows = rows.into_iter().map(Into::into).collect();
+            cte_storage.tables.insert(name.to_owned(), (schema, rows));
+        }
+
+        Ok(cte_storage)
+    }
+}
+
+async fn select_rows(
+    storage: &CteStorage<'_>,
+    query: Query,
+    filter_context: Option<Rc<RowContext<'_>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let (labels, rows) = select_with_labels(storage, &query, filter_context).await?;
+    let rows = rows.try_collect().await?;
+
+    Ok((labels, rows))
+}
+
+fn branch(set_expr: &SetExpr) -> Query {
+    Query {
+        with: None,
+        body: set_expr.clone(),
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    }
+}
+
+fn distinct(rows: Vec<Row>, keys: &mut HashSet<Vec<Key>>) -> Result<Vec<Row>> {
+    rows.into_iter()
+        .map(|row| Ok((row_key(&row)?, row)))
+        .filter_map_ok(|(key, row)| keys.insert(key).then_some(row))
+        .collect()
+}
+
+/// Checks whether `set_expr` reads from the table `name` in its `FROM` clauses.
+fn references(set_expr: &SetExpr, name: &str) -> bool {
+    let table_factor_references = |table_factor: &TableFactor| match table_factor {
+        TableFactor::Table {
+            name: table_name, ..
+        } => table_name == name,
+        TableFactor::Derived { subquery, .. } => references(&subquery.body, name),
+        TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Function { .. }
+        | TableFactor::Dictionary { .. } => false,
+    };
+
+    match set_expr {
+        SetExpr::Select(select) => {
+            let TableWithJoins { relation, joins } = &select.from;
+
+            table_factor_references(relation)
+                || joins
+                    .iter()
+                    .any(|Join { relation, .. }| table_factor_references(relation))
+        }
+        SetExpr::Values(_) => false,
+        SetExpr::SetOperation { left, right, .. } => {
+            references(left, name) || references(right, name)
+        }
+    }
+}
+
+fn cte_schema(alias: &TableAlias, labels: Option<&[String]>, rows: &[Row]) -> Result<Schema> {
+    let TableAlias { name, columns } = alias;
+
+    let column_defs = match labels {
+        Some(labels) if columns.len() > labels.len() => {
+            return Err(FetchError::TooManyColumnAliases(
+                name.to_owned(),
+                labels.len(),
+                columns.len(),
+            )
+            .into());
+        }
+        Some(labels) => {
+            let column_defs = columns
+                .iter()
+                .chain(&labels[columns.len()..])
+                .enumerate()
+                .map(|(i, label)| {
+                    let data_type = rows
+                        .iter()
+                        .find_map(|row| match row {
+                            Row::Vec { values, .. } => values.get(i).and_then(Value::get_type),
+                            Row::Map(_) => None,
+                        })
+                        .unwrap_or(DataType::Text);
+
+                    ColumnDef {
+                        name: label.to_owned(),
+                        data_type,
+                        nullable: true,
+                        default: None,
+                        unique: None,
+                        collation: None,
+                    }
+                })
+                .collect();
+
+            Some(column_defs)
+        }
+        None => None,
+    };
+
+    Ok(Schema {
+        table_name: name.to_owned(),
+        column_defs,
+        indexes: Vec::new(),
+        engine: None,
+        foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        view: None,
+    })
+}
+
+#[async_trait(?Send)]
+impl Store for CteStorage<'_> {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        match self.tables.get(table_name) {
+            Some((schema, _)) => Ok(Some(schema.clone())),
+            None => self.storage.fetch_schema(table_name).await,
+        }
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.fetch_all_schemas().await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        match self.tables.get(table_name) {
+            Some((_, rows)) => Ok(match key {
+                Key::I64(i) => usize::try_from(*i).ok().and_then(|i| rows.get(i)).cloned(),
+                _ => None,
+            }),
+            None => self.storage.fetch_data(table_name, key).await,
+        }
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        match self.tables.get(table_name) {
+            Some((_, rows)) => {
+                let rows = rows
+                    .clone()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, row)| Ok((Key::I64(i as i64), row)));
+
+                Ok(Box::new(rows))
+            }
+            None => self.storage.scan_data(table_name).await,
+        }
+    }
+
+    async fn scan_data_with_columns(
+        &self,
+        table_name: &str,
+        columns: &[String],
+    ) -> Result<RowIter> {
+        match self.tables.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_with_columns(table_name, columns)
+                    .await
+            }
+        }
+    }
+
+    async fn scan_data_by_values(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        values: &[(String, Value)],
+    ) -> Result<RowIter> {
+        match self.tables.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_by_values(table_name, columns, values)
+                    .await
+            }
+        }
+    }
+
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        match self.tables.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_filtered(table_name, columns, predicates)
+                    .await
+            }
+        }
+    }
+
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        match self.tables.contains_key(table_name) {
+            true => Ok(Vec::new()),
+            false => {
+                self.storage
+                    .filtered_scan_predicates(table_name, predicates)
+                    .await
+            }
+        }
+    }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        match self.tables.contains_key(table_name) {
+            true => Ok(None),
+            false => self.storage.fetch_statistics(table_name).await,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Index for CteStorage<'_> {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        self.storage
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl Metadata for CteStorage<'_> {
+    async fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.storage.scan_table_meta().await
+    }
+}
+
+#[async_trait(?Send)]
+impl CustomFunction for CteStorage<'_> {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        self.storage.fetch_function(func_name).await
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.storage.fetch_all_functions().await
+    }
+
+    async fn fetch_native_function(&self, func_name: &str) -> Result<Option<NativeFunction>> {
+        self.storage.fetch_native_function(func_name).await
+    }
+
+    async fn fetch_native_table_function(
+        &self,
+        func_name: &str,
+    ) -> Result<Option<NativeTableFunction>> {
+        self.storage.fetch_native_table_function(func_name).await
+    }
+}
@@ -4,4 +4,19 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error("each {op} query must have the same number of columns: {left} and {right}")]
+    SetOperationColumnCountMismatch {
+        op: String,
+        left: usize,
+        right: usize,
+    },
+
+    #[error(
+        "recursive CTE must be of the form 'non-recursive term UNION [ALL] recursive term': {0}"
+    )]
+    RecursiveCteUnionRequired(String),
+
+    #[error("recursive CTE {name} exceeded the maximum recursion depth of {limit}")]
+    RecursionLimitExceeded { name: String, limit: usize },
 }
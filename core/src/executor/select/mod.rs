@@ -1,10 +1,11 @@
+mod cte;
 mod error;
 mod project;
 
-pub use error::SelectError;
+pub use {error::SelectError, project::Project};
 
 use {
-    self::project::Project,
+    self::cte::CteStorage,
     super::{
         aggregate::Aggregator,
         context::{AggregateContext, RowContext},
@@ -14,16 +15,31 @@ use {
         join::Join,
         limit::Limit,
         sort::Sort,
+        window::Window,
     },
     crate::{
-        ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
+        ast::{
+            AstLiteral, BinaryOperator, Expr, IndexOperator, Join as AstJoin, JoinConstraint,
+            JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+            SetOperator, TableWithJoins, Values,
+        },
         data::{get_alias, Key, Row, Value},
+        plan::PlanExpr,
         result::Result,
         store::GStore,
     },
     async_recursion::async_recursion,
-    futures::stream::{self, Stream, StreamExt, TryStreamExt},
-    std::{borrow::Cow, rc::Rc},
+    bigdecimal::ToPrimitive,
+    futures::{
+        future,
+        stream::{self, Stream, StreamExt, TryStreamExt},
+    },
+    itertools::Itertools,
+    std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+        rc::Rc,
+    },
     utils::Vector,
 };
 
@@ -73,17 +89,23 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     let sorted = stream::iter(rows.into_iter())
         .then(|row| async move {
             stream::iter(order_by)
-                .then(|OrderByExpr { expr, asc }| {
-                    let row = Some(&row);
-
-                    async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)
-                            .map(|key| (key, *asc))
-                    }
-                })
+                .then(
+                    |OrderByExpr {
+                         expr,
+                         asc,
+                         nulls_first,
+                     }| {
+                        let row = Some(&row);
+
+                        async move {
+                            evaluate_stateless(row.map(Row::as_context), expr)
+                                .await
+                                .and_then(Value::try_from)
+                                .and_then(Key::try_from)
+                                .map(|key| (key, *asc, *nulls_first))
+                        }
+                    },
+                )
                 .try_collect::<Vec<_>>()
                 .await
                 .map(|keys| (keys, row))
@@ -99,6 +121,273 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     Ok(sorted)
 }
 
+#[derive(futures_enum::Stream)]
+enum Rows<S1, S2> {
+    All(S1),
+    Distinct(S2),
+}
+
+/// Builds the key used to compare rows by their projected values.
+pub(crate) fn row_key(row: &Row) -> Result<Vec<Key>> {
+    match row {
+        Row::Vec { values, .. } => values.iter().map(Key::try_from).collect(),
+        Row::Map(values) => values
+            .iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(k, v)| Ok([Key::Str(k.to_owned()), Key::try_from(v)?]))
+            .flatten_ok()
+            .collect(),
+    }
+}
+
+/// Drops rows whose projected values are equal to a row already emitted, for `SELECT DISTINCT`.
+fn distinct_rows<'a, A: 'a, C: 'a>(
+    rows: impl Stream<Item = Result<(A, C, Row)>> + 'a,
+) -> impl Stream<Item = Result<(A, C, Row)>> + 'a {
+    rows.scan(HashSet::new(), |keys, item| {
+        let item = item.and_then(|(aggregated, context, row)| {
+            let key = row_key(&row)?;
+
+            Ok(keys.insert(key).then_some((aggregated, context, row)))
+        });
+
+        future::ready(Some(item))
+    })
+    .try_filter_map(future::ok)
+}
+
+/// Evaluates each branch of a compound `SELECT` and combines their rows.
+/// Non-`ALL` variants drop duplicate rows, `ALL` variants keep multiset semantics.
+#[async_recursion(?Send)]
+async fn set_expr_rows<'a, T: GStore>(
+    storage: &'a T,
+    body: &'a SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let (op, all, left, right) = match body {
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => (op, *all, left, right),
+        SetExpr::Select(_) | SetExpr::Values(_) => {
+            let query = Query {
+                with: None,
+                body: body.clone(),
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+            };
+            let (labels, rows) = select_with_labels(storage, &query, filter_context).await?;
+            let rows = rows.try_collect().await?;
+
+            return Ok((labels, rows));
+        }
+    };
+
+    let (labels, left_rows) =
+        set_expr_rows(storage, left, filter_context.as_ref().map(Rc::clone)).await?;
+    let (right_labels, right_rows) = set_expr_rows(storage, right, filter_context).await?;
+
+    let right_rows = match (&labels, &right_labels) {
+        (Some(labels), Some(right_labels)) if labels.len() != right_labels.len() => {
+            return Err(SelectError::SetOperationColumnCountMismatch {
+                op: op.to_string(),
+                left: labels.len(),
+                right: right_labels.len(),
+            }
+            .into());
+        }
+        (Some(labels), Some(_)) => {
+            let columns = Rc::from(labels.clone());
+
+            right_rows
+                .into_iter()
+                .map(|row| match row {
+                    Row::Vec { values, .. } => Row::Vec {
+                        columns: Rc::clone(&columns),
+                        values,
+                    },
+                    Row::Map(_) => row,
+                })
+                .collect()
+        }
+        _ => right_rows,
+    };
+
+    let rows = match op {
+        SetOperator::Union => left_rows.into_iter().chain(right_rows).collect(),
+        SetOperator::Intersect | SetOperator::Except => {
+            let mut counts = HashMap::new();
+            for row in &right_rows {
+                *counts.entry(row_key(row)?).or_insert(0_usize) += 1;
+            }
+
+            let intersect = matches!(op, SetOperator::Intersect);
+            left_rows
+                .into_iter()
+                .map(|row| {
+                    let matched = match counts.get_mut(&row_key(&row)?) {
+                        Some(count) if *count > 0 => {
+                            if all {
+                                *count -= 1;
+                            }
+
+                            true
+                        }
+                        _ => false,
+                    };
+
+                    Ok((matched == intersect).then_some(row))
+                })
+                .filter_map_ok(|row| row)
+                .collect::<Result<Vec<_>>>()?
+        }
+    };
+
+    if all {
+        return Ok((labels, rows));
+    }
+
+    let mut keys = HashSet::new();
+    let rows = rows
+        .into_iter()
+        .map(|row| Ok((row_key(&row)?, row)))
+        .filter_ok(|(key, _)| keys.insert(key.clone()))
+        .map_ok(|(_, row)| row)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((labels, rows))
+}
+
+/// Columns which a select reads from its tables, or `None` if it may read any column, such as
+/// with a wildcard, a subquery or a natural join. Columns of every table are put together, so
+/// each table is given the columns of the other tables as well.
+fn used_columns(select: &Select, order_by: &[OrderByExpr]) -> Option<Vec<String>> {
+    fn columns(expr: &Expr) -> Option<Vec<String>> {
+        let exprs = match expr.into() {
+            PlanExpr::None => vec![],
+            PlanExpr::Identifier(ident) | PlanExpr::CompoundIdentifier { ident, .. } => {
+                return Some(vec![ident.to_owned()]);
+            }
+            PlanExpr::Expr(expr) => vec![expr],
+            PlanExpr::TwoExprs(expr, expr2) => vec![expr, expr2],
+            PlanExpr::ThreeExprs(expr, expr2, expr3) => vec![expr, expr2, expr3],
+            PlanExpr::MultiExprs(exprs) => exprs,
+            PlanExpr::Query(_) | PlanExpr::QueryAndExpr { .. } => return None,
+        };
+
+        exprs
+            .into_iter()
+            .map(columns)
+            .collect::<Option<Vec<_>>>()
+            .map(|columns| columns.concat())
+    }
+
+    let mut using_columns = Vec::new();
+    let mut join_exprs = Vec::new();
+    for AstJoin {
+        join_operator,
+        join_executor,
+        ..
+    } in &select.from.joins
+    {
+        let join_constraint = match join_operator {
+            JoinOperator::Inner(join_constraint)
+            | JoinOperator::LeftOuter(join_constraint)
+            | JoinOperator::RightOuter(join_constraint)
+            | JoinOperator::FullOuter(join_constraint) => join_constraint,
+        };
+
+        match join_constraint {
+            JoinConstraint::On(expr) => join_exprs.push(expr),
+            JoinConstraint::Using(columns) => using_columns.extend(columns.iter().cloned()),
+            JoinConstraint::Natural => return None,
+            JoinConstraint::None => {}
+        }
+
+        if let JoinExecutor::Hash {
+            key_expr,
+            value_expr,
+            where_clause,
+        } = join_executor
+        {
+            join_exprs.extend([key_expr, value_expr]);
+            join_exprs.extend(where_clause);
+        }
+    }
+
+    select
+        .projection
+        .iter()
+        .map(|item| match item {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .chain(&select.selection)
+        .chain(&select.group_by)
+        .chain(&select.having)
+        .chain(order_by.iter().map(|order_by| &order_by.expr))
+        .chain(join_exprs)
+        .map(columns)
+        .collect::<Option<Vec<_>>>()
+        .map(|columns| {
+            columns
+                .concat()
+                .into_iter()
+                .chain(using_columns)
+                .unique()
+                .collect()
+        })
+}
+
+/// Comparisons which rows of the table must pass to pass the WHERE clause, taken from
+/// `column <op> literal` conditions of `=`, `<`, `<=`, `>` and `>=` which are joined by AND
+pub(crate) fn scan_predicates(selection: &Expr) -> Vec<(String, IndexOperator, Value)> {
+    let (left, op, right) = match selection {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => return [scan_predicates(left), scan_predicates(right)].concat(),
+        Expr::Nested(expr) => return scan_predicates(expr),
+        Expr::BinaryOp { left, op, right } => (left.as_ref(), op, right.as_ref()),
+        _ => return vec![],
+    };
+    let op = match op {
+        BinaryOperator::Eq => IndexOperator::Eq,
+        BinaryOperator::Gt => IndexOperator::Gt,
+        BinaryOperator::GtEq => IndexOperator::GtEq,
+        BinaryOperator::Lt => IndexOperator::Lt,
+        BinaryOperator::LtEq => IndexOperator::LtEq,
+        _ => return vec![],
+    };
+    let (column, op, literal) = match (left, right) {
+        (
+            Expr::Identifier(column) | Expr::CompoundIdentifier { ident: column, .. },
+            Expr::Literal(literal),
+        ) => (column, op, literal),
+        (
+            Expr::Literal(literal),
+            Expr::Identifier(column) | Expr::CompoundIdentifier { ident: column, .. },
+        ) => (column, op.reverse(), literal),
+        _ => return vec![],
+    };
+    let value = match literal {
+        AstLiteral::Boolean(v) => Some(Value::Bool(*v)),
+        AstLiteral::Number(v) if v.is_integer() => v.to_i64().map(Value::I64),
+        AstLiteral::QuotedString(v) => Some(Value::Str(v.to_owned())),
+        _ => None,
+    };
+
+    value
+        .map(|value| vec![(column.to_owned(), op, value)])
+        .unwrap_or_default()
+}
+
 #[async_recursion(?Send)]
 pub async fn select_with_labels<'a, T: GStore>(
     storage: &'a T,
@@ -106,12 +395,28 @@ pub async fn select_with_labels<'a, T: GStore>(
     filter_context: Option<Rc<RowContext<'a>>>,
 ) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        With(S3),
+    }
+
+    if let Some(with) = &query.with {
+        let storage =
+            CteStorage::new(storage, with, filter_context.as_ref().map(Rc::clone)).await?;
+        let query = Query {
+            with: None,
+            ..query.clone()
+        };
+        let (labels, rows) = select_with_labels(&storage, &query, filter_context).await?;
+        let rows = rows.try_collect::<Vec<_>>().await?;
+        let rows = stream::iter(rows.into_iter().map(Ok));
+
+        return Ok((labels, Row::With(rows)));
     }
 
     let Select {
+        distinct,
         from: table_with_joins,
         selection: where_clause,
         projection,
@@ -128,19 +433,49 @@ pub async fn select_with_labels<'a, T: GStore>(
 
             return Ok((Some(labels), Row::Values(rows)));
         }
+        SetExpr::SetOperation { .. } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let (labels, rows) = set_expr_rows(storage, &query.body, filter_context).await?;
+            let rows = sort_stateless(rows, &query.order_by).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            return Ok((labels, Row::Values(rows)));
+        }
     };
 
     let TableWithJoins { relation, joins } = &table_with_joins;
-    let rows = fetch_relation_rows(storage, relation, &None)
-        .await?
-        .map(move |row| {
-            let row = row?;
-            let alias = get_alias(relation);
+    let used_columns: Option<Rc<[String]>> = match &query.body {
+        SetExpr::Select(select) => used_columns(select, &query.order_by).map(Rc::from),
+        _ => None,
+    };
+    // identifiers of a subquery may refer to the outer query, which the table cannot filter
+    let predicates = match (where_clause, &filter_context) {
+        (Some(expr), None) if joins.is_empty() => scan_predicates(expr),
+        _ => Vec::new(),
+    };
+    let rows = fetch_relation_rows(
+        storage,
+        relation,
+        &None,
+        used_columns.as_deref(),
+        &predicates,
+    )
+    .await?
+    .map(move |row| {
+        let row = row?;
+        let alias = get_alias(relation);
 
-            Ok(RowContext::new(alias, Cow::Owned(row), None))
-        });
+        Ok(RowContext::new(alias, Cow::Owned(row), None))
+    });
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Rc::clone));
+    let join = Join::new(
+        storage,
+        relation,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+        used_columns,
+    );
     let aggregate = Aggregator::new(
         storage,
         projection,
@@ -154,6 +489,12 @@ pub async fn select_with_labels<'a, T: GStore>(
         filter_context.as_ref().map(Rc::clone),
         None,
     ));
+    let window = Window::new(
+        storage,
+        projection,
+        &query.order_by,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
     let sort = Sort::new(
         storage,
@@ -174,6 +515,7 @@ pub async fn select_with_labels<'a, T: GStore>(
     });
 
     let rows = aggregate.apply(rows).await?;
+    let rows = window.apply(rows).await?;
 
     let labels = fetch_labels(storage, relation, joins, projection)
         .await?
@@ -196,6 +538,10 @@ pub async fn select_with_labels<'a, T: GStore>(
         }
     });
 
+    let rows = match distinct {
+        true => Rows::Distinct(distinct_rows(rows)),
+        false => Rows::All(rows),
+    };
     let rows = sort.apply(rows, get_alias(relation)).await?;
     let rows = limit.apply(rows);
     let labels = labels.map(|labels| labels.iter().cloned().collect());
@@ -75,36 +75,45 @@ impl<'a, T: GStore> Sort<'a, T> {
                 let order_by = self.order_by;
                 let order_by = order_by
                     .iter()
-                    .map(|OrderByExpr { expr, asc }| -> Result<_> {
-                        let big_decimal = match expr {
-                            Expr::Literal(AstLiteral::Number(n)) => Some(n),
-                            Expr::UnaryOp {
-                                op: UnaryOperator::Plus,
-                                expr,
-                            } => match expr.as_ref() {
+                    .map(
+                        |OrderByExpr {
+                             expr,
+                             asc,
+                             nulls_first,
+                         }|
+                         -> Result<_> {
+                            let big_decimal = match expr {
                                 Expr::Literal(AstLiteral::Number(n)) => Some(n),
+                                Expr::UnaryOp {
+                                    op: UnaryOperator::Plus,
+                                    expr,
+                                } => match expr.as_ref() {
+                                    Expr::Literal(AstLiteral::Number(n)) => Some(n),
+                                    _ => None,
+                                },
                                 _ => None,
-                            },
-                            _ => None,
-                        };
-
-                        match (big_decimal, &row) {
-                            (Some(n), Row::Vec { values, .. }) => {
-                                let index = n
-                                    .to_usize()
-                                    .ok_or_else(|| -> Error { SortError::Unreachable.into() })?;
-                                let zero_based = index.checked_sub(1).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-                                let value = values.get(zero_based).ok_or_else(|| -> Error {
-                                    SortError::ColumnIndexOutOfRange(index).into()
-                                })?;
-
-                                Ok((SortType::Value(value.clone()), *asc))
+                            };
+
+                            match (big_decimal, &row) {
+                                (Some(n), Row::Vec { values, .. }) => {
+                                    let index = n.to_usize().ok_or_else(|| -> Error {
+                                        SortError::Unreachable.into()
+                                    })?;
+                                    let zero_based =
+                                        index.checked_sub(1).ok_or_else(|| -> Error {
+                                            SortError::ColumnIndexOutOfRange(index).into()
+                                        })?;
+                                    let value =
+                                        values.get(zero_based).ok_or_else(|| -> Error {
+                                            SortError::ColumnIndexOutOfRange(index).into()
+                                        })?;
+
+                                    Ok((SortType::Value(value.clone()), *asc, *nulls_first))
+                                }
+                                _ => Ok((SortType::Expr(expr), *asc, *nulls_first)),
                             }
-                            _ => Ok((SortType::Expr(expr), *asc)),
-                        }
-                    })
+                        },
+                    )
                     .collect::<Result<Vec<_>>>();
 
                 let filter_context = match &self.context {
@@ -124,7 +133,7 @@ impl<'a, T: GStore> Sort<'a, T> {
 
                     let keys = order_by
                         .map(stream::iter)?
-                        .then(|(sort_type, asc)| {
+                        .then(|(sort_type, asc, nulls_first)| {
                             let context = Some(Rc::clone(&filter_context));
                             let aggregated = aggregated.as_ref().map(Rc::clone);
 
@@ -138,7 +147,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                                     }
                                 }
                                 .try_into()
-                                .map(|key| (key, asc))
+                                .map(|key| (key, asc, nulls_first))
                             }
                         })
                         .try_collect::<Vec<_>>()
@@ -150,7 +159,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                     Ok((keys, row))
                 }
             })
-            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, Row)>>()
+            .try_collect::<Vec<(Vec<SortKey>, Row)>>()
             .await
             .map(Vector::from)?
             .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
@@ -161,18 +170,91 @@ impl<'a, T: GStore> Sort<'a, T> {
     }
 }
 
-pub fn sort_by(keys_a: &[(Key, Option<bool>)], keys_b: &[(Key, Option<bool>)]) -> Ordering {
-    let pairs = keys_a
-        .iter()
-        .map(|(a, _)| a)
-        .zip(keys_b.iter())
-        .map(|(a, (b, asc))| (a, b, asc.unwrap_or(true)));
-
-    for (key_a, key_b, asc) in pairs {
-        match (key_a.cmp(key_b), asc) {
-            (Ordering::Equal, _) => continue,
-            (ord, true) => return ord,
-            (ord, false) => return ord.reverse(),
+/// Sorts the rows fetched for `UPDATE` and `DELETE`, keeping the key of each row so that
+/// the sorted rows can still be written back to the storage.
+pub async fn sort_fetched_rows<'a, T: GStore>(
+    storage: &'a T,
+    table_name: &'a str,
+    order_by: &'a [OrderByExpr],
+    rows: impl Stream<Item = Result<(Key, Row)>> + 'a,
+) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
+    #[derive(futures_enum::Stream)]
+    enum Rows<I1, I2> {
+        NonOrderBy(I1),
+        OrderBy(I2),
+    }
+
+    if order_by.is_empty() {
+        return Ok(Rows::NonOrderBy(rows));
+    }
+
+    let rows = rows
+        .and_then(|(key, row)| async move {
+            let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+            let context = Rc::new(context);
+
+            let keys = stream::iter(order_by)
+                .then(
+                    |OrderByExpr {
+                         expr,
+                         asc,
+                         nulls_first,
+                     }| {
+                        let context = Some(Rc::clone(&context));
+
+                        async move {
+                            let value: Value =
+                                evaluate(storage, context, None, expr).await?.try_into()?;
+
+                            Key::try_from(value).map(|key| (key, *asc, *nulls_first))
+                        }
+                    },
+                )
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            drop(context);
+
+            Ok((keys, key, row))
+        })
+        .try_collect::<Vec<(Vec<SortKey>, Key, Row)>>()
+        .await
+        .map(Vector::from)?
+        .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
+        .into_iter()
+        .map(|(_, key, row)| Ok((key, row)));
+
+    Ok(Rows::OrderBy(stream::iter(rows)))
+}
+
+/// Evaluated ORDER BY key paired with its `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` options.
+pub type SortKey = (Key, Option<bool>, Option<bool>);
+
+pub fn sort_by(keys_a: &[SortKey], keys_b: &[SortKey]) -> Ordering {
+    let pairs =
+        keys_a
+            .iter()
+            .map(|(a, ..)| a)
+            .zip(keys_b.iter())
+            .map(|(a, (b, asc, nulls_first))| {
+                let asc = asc.unwrap_or(true);
+
+                (a, b, asc, nulls_first.unwrap_or(!asc))
+            });
+
+    for (key_a, key_b, asc, nulls_first) in pairs {
+        let ord = match (key_a, key_b) {
+            (Key::None, Key::None) => Ordering::Equal,
+            (Key::None, _) if nulls_first => Ordering::Less,
+            (Key::None, _) => Ordering::Greater,
+            (_, Key::None) if nulls_first => Ordering::Greater,
+            (_, Key::None) => Ordering::Less,
+            _ if asc => key_a.cmp(key_b),
+            _ => key_a.cmp(key_b).reverse(),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
         }
     }
 
@@ -2,6 +2,7 @@ use {
     super::{
         context::RowContext,
         evaluate::{evaluate, Evaluated},
+        insert::{default_value, is_default_keyword},
     },
     crate::{
         ast::{Assignment, ColumnDef, ColumnUniqueOption},
@@ -64,8 +65,10 @@ impl<'a, T: GStore> Update<'a, T> {
         })
     }
 
-    pub async fn apply(&self, row: Row) -> Result<Row> {
-        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), None);
+    /// Applies the assignments to `row`, `next` is the context consulted for values which
+    /// are not found in the row itself, e.g. `excluded` of `INSERT .. ON CONFLICT`.
+    pub async fn apply(&self, row: Row, next: Option<Rc<RowContext<'_>>>) -> Result<Row> {
+        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), next);
         let context = Some(Rc::new(context));
 
         let assignments = stream::iter(self.fields.iter())
@@ -77,21 +80,31 @@ impl<'a, T: GStore> Update<'a, T> {
                 let context = context.as_ref().map(Rc::clone);
 
                 async move {
-                    let evaluated = evaluate(self.storage, context, None, value_expr).await?;
-                    let value = match self.column_defs {
-                        Some(column_defs) => {
-                            let ColumnDef {
-                                data_type,
-                                nullable,
-                                ..
-                            } = column_defs
+                    let column_def = self
+                        .column_defs
+                        .map(|column_defs| {
+                            column_defs
                                 .iter()
                                 .find(|column_def| id == &column_def.name)
-                                .ok_or(UpdateError::ConflictOnSchema)?;
+                                .ok_or(UpdateError::ConflictOnSchema)
+                        })
+                        .transpose()?;
+
+                    if let (Some(column_def), true) = (column_def, is_default_keyword(value_expr)) {
+                        return Ok((id.as_ref(), default_value(column_def).await?));
+                    }
 
+                    let evaluated = evaluate(self.storage, context, None, value_expr).await?;
+                    let value = match column_def {
+                        Some(ColumnDef {
+                            data_type,
+                            nullable,
+                            ..
+                        }) => {
                             let value = match evaluated {
                                 Evaluated::Literal(v) => Value::try_from_literal(data_type, &v)?,
                                 Evaluated::Value(v) => {
+                                    let v = v.coerce_integer(data_type)?;
                                     v.validate_type(data_type)?;
                                     v
                                 }
@@ -1,13 +1,14 @@
 use {
+    super::{context::RowContext, filter::check_expr},
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption},
-        data::{Key, Value},
+        ast::{CheckConstraint, ColumnDef, ColumnUniqueOption, Expr, ToSql, UnaryOperator},
+        data::{Key, Row, Value},
         result::Result,
-        store::{DataRow, Store},
+        store::{DataRow, GStore, Store},
     },
     im_rc::HashSet,
     serde::Serialize,
-    std::fmt::Debug,
+    std::{borrow::Cow, fmt::Debug, rc::Rc},
     thiserror::Error as ThisError,
     utils::Vector,
 };
@@ -25,13 +26,16 @@ pub enum ValidateError {
 
     #[error("duplicate entry '{0:?}' for primary_key field")]
     DuplicateEntryOnPrimaryKeyField(Key),
+
+    #[error("check constraint violated: {0}")]
+    CheckConstraintViolated(String),
 }
 
 pub enum ColumnValidation<'column_def> {
     /// `INSERT`
     All(&'column_def [ColumnDef]),
-    /// `UPDATE`
-    SpecifiedColumns(&'column_def [ColumnDef], Vec<String>),
+    /// `UPDATE`, with the keys of the rows being updated
+    SpecifiedColumns(&'column_def [ColumnDef], Vec<String>, Vec<Key>),
 }
 
 #[derive(Debug)]
@@ -94,6 +98,11 @@ pub async fn validate_unique<T: Store>(
         All(Vec<(usize, String)>),
     }
 
+    let updated_keys = match &column_validation {
+        ColumnValidation::All(_) => HashSet::new(),
+        ColumnValidation::SpecifiedColumns(_, _, keys) => keys.iter().cloned().collect(),
+    };
+
     let columns = match &column_validation {
         ColumnValidation::All(column_defs) => {
             let primary_key_index = column_defs
@@ -115,21 +124,25 @@ pub async fn validate_unique<T: Store>(
                 _ => Columns::All(fetch_all_unique_columns(column_defs)),
             }
         }
-        ColumnValidation::SpecifiedColumns(column_defs, specified_columns) => Columns::All(
+        ColumnValidation::SpecifiedColumns(column_defs, specified_columns, _) => Columns::All(
             fetch_specified_unique_columns(column_defs, specified_columns),
         ),
     };
 
     match columns {
         Columns::PrimaryKeyOnly(primary_key_index) => {
+            let mut keys = HashSet::new();
+
             for primary_key in
                 row_iter.filter_map(|row| row.get(primary_key_index).map(Key::try_from))
             {
                 let key = primary_key?;
 
-                if storage.fetch_data(table_name, &key).await?.is_some() {
+                if keys.contains(&key) || storage.fetch_data(table_name, &key).await?.is_some() {
                     return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
                 }
+
+                keys.insert(key);
             }
 
             Ok(())
@@ -142,7 +155,13 @@ pub async fn validate_unique<T: Store>(
 
             let unique_constraints = &unique_constraints;
             storage.scan_data(table_name).await?.try_for_each(|result| {
-                let (_, data_row) = result?;
+                let (key, data_row) = result?;
+
+                // stored values of the updating rows are replaced, not duplicated
+                if updated_keys.contains(&key) {
+                    return Ok(());
+                }
+
                 let values = match data_row {
                     DataRow::Vec(values) => values,
                     DataRow::Map(_) => {
@@ -209,3 +228,45 @@ fn fetch_specified_unique_columns(
         })
         .collect()
 }
+
+/// Rows violate a `CHECK` only when its expression evaluates to false, NULL is accepted.
+pub async fn validate_check<'a, T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    checks: &[CheckConstraint],
+    rows: impl Iterator<Item = &'a [Value]>,
+) -> Result<()> {
+    if checks.is_empty() {
+        return Ok(());
+    }
+
+    let labels = column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Rc<[String]>>();
+    let violations = checks
+        .iter()
+        .map(|check| Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(check.expr.clone()),
+        })
+        .collect::<Vec<_>>();
+
+    for values in rows {
+        let row = Row::Vec {
+            columns: Rc::clone(&labels),
+            values: values.to_vec(),
+        };
+
+        for (check, violation) in checks.iter().zip(violations.iter()) {
+            let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+
+            if check_expr(storage, Some(Rc::new(context)), None, violation).await? {
+                return Err(ValidateError::CheckConstraintViolated(check.to_sql()).into());
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,303 @@
+use {
+    super::{
+        aggregate::AggrValue,
+        context::{AggregateContext, RowContext},
+        evaluate::evaluate,
+        sort::{sort_by, SortKey},
+    },
+    crate::{
+        ast::{Aggregate, AggregateFunction, Expr, OrderByExpr, SelectItem, WindowSpec},
+        data::{Key, Value},
+        plan::PlanExpr,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, Stream, StreamExt, TryStreamExt},
+    im_rc::HashMap,
+    itertools::Itertools,
+    serde::Serialize,
+    std::{cmp::Ordering, collections::HashMap as StdHashMap, fmt::Debug, rc::Rc},
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum WindowError {
+    #[error("offset of {0} must be a non-negative integer")]
+    InvalidOffset(String),
+}
+
+/// Computes window functions, `func() OVER (PARTITION BY .. ORDER BY ..)`, once the rows
+/// are filtered and grouped. The value of each row is added to its aggregated values, so
+/// projection and ORDER BY read window functions the same way as aggregates.
+pub struct Window<'a, T: GStore> {
+    storage: &'a T,
+    windows: Vec<(&'a Aggregate, &'a WindowSpec)>,
+    filter_context: Option<Rc<RowContext<'a>>>,
+}
+
+#[derive(futures_enum::Stream)]
+enum S<T1, T2> {
+    NonWindow(T1),
+    Window(T2),
+}
+
+/// Rows of a partition in the order of `OVER (ORDER BY ..)`, paired with their sort keys
+type Partition = Vec<(Vec<SortKey>, usize)>;
+
+impl<'a, T: GStore> Window<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        fields: &'a [SelectItem],
+        order_by: &'a [OrderByExpr],
+        filter_context: Option<Rc<RowContext<'a>>>,
+    ) -> Self {
+        let windows = fields
+            .iter()
+            .filter_map(|field| match field {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                _ => None,
+            })
+            .chain(order_by.iter().map(|order_by| &order_by.expr))
+            .flat_map(windows)
+            .unique()
+            .collect();
+
+        Self {
+            storage,
+            windows,
+            filter_context,
+        }
+    }
+
+    pub async fn apply(
+        &self,
+        rows: impl Stream<Item = Result<AggregateContext<'a>>>,
+    ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>>> {
+        if self.windows.is_empty() {
+            return Ok(S::NonWindow(rows));
+        }
+
+        let mut rows = rows.try_collect::<Vec<_>>().await?;
+
+        for (aggr, window) in &self.windows {
+            let values = self.compute(aggr, window, &rows).await?;
+
+            for (row, value) in rows.iter_mut().zip(values) {
+                row.aggregated
+                    .get_or_insert_with(HashMap::new)
+                    .insert(*aggr, value);
+            }
+        }
+
+        Ok(S::Window(stream::iter(rows.into_iter().map(Ok))))
+    }
+
+    async fn compute(
+        &self,
+        aggr: &'a Aggregate,
+        window: &'a WindowSpec,
+        rows: &[AggregateContext<'a>],
+    ) -> Result<Vec<Value>> {
+        let mut partitions: StdHashMap<Vec<Key>, Partition> = StdHashMap::new();
+
+        for (index, row) in rows.iter().enumerate() {
+            let partition = stream::iter(&window.partition_by)
+                .then(|expr| self.evaluate(row, expr))
+                .and_then(|value| async move { Key::try_from(value) })
+                .try_collect::<Vec<_>>()
+                .await?;
+            let keys = stream::iter(&window.order_by)
+                .then(
+                    |OrderByExpr {
+                         expr,
+                         asc,
+                         nulls_first,
+                     }| async move {
+                        self.evaluate(row, expr)
+                            .await
+                            .and_then(Key::try_from)
+                            .map(|key| (key, *asc, *nulls_first))
+                    },
+                )
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            partitions.entry(partition).or_default().push((keys, index));
+        }
+
+        let mut values = vec![Value::Null; rows.len()];
+
+        for mut partition in partitions.into_values() {
+            partition.sort_by(|(keys_a, _), (keys_b, _)| sort_by(keys_a, keys_b));
+
+            for (index, value) in self.compute_partition(aggr, rows, &partition).await? {
+                values[index] = value;
+            }
+        }
+
+        Ok(values)
+    }
+
+    async fn compute_partition(
+        &self,
+        aggr: &'a Aggregate,
+        rows: &[AggregateContext<'a>],
+        partition: &Partition,
+    ) -> Result<Vec<(usize, Value)>> {
+        let is_peer =
+            |n: usize| n > 0 && sort_by(&partition[n - 1].0, &partition[n].0) == Ordering::Equal;
+        let indexes = partition.iter().map(|(_, index)| *index);
+
+        match &aggr.func {
+            AggregateFunction::RowNumber => Ok(indexes
+                .enumerate()
+                .map(|(n, index)| (index, Value::I64(n as i64 + 1)))
+                .collect()),
+            AggregateFunction::Rank => {
+                let mut rank = 0;
+
+                Ok(indexes
+                    .enumerate()
+                    .map(|(n, index)| {
+                        if !is_peer(n) {
+                            rank = n + 1;
+                        }
+
+                        (index, Value::I64(rank as i64))
+                    })
+                    .collect())
+            }
+            AggregateFunction::DenseRank => {
+                let mut rank = 0;
+
+                Ok(indexes
+                    .enumerate()
+                    .map(|(n, index)| {
+                        if !is_peer(n) {
+                            rank += 1;
+                        }
+
+                        (index, Value::I64(rank))
+                    })
+                    .collect())
+            }
+            AggregateFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | AggregateFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => {
+                let name = match aggr.func {
+                    AggregateFunction::Lag { .. } => "LAG",
+                    _ => "LEAD",
+                };
+                let mut values = Vec::with_capacity(partition.len());
+
+                for (n, index) in indexes.enumerate() {
+                    let row = &rows[index];
+                    let offset = match offset {
+                        Some(offset) => match self.evaluate(row, offset).await? {
+                            Value::Null => {
+                                values.push((index, Value::Null));
+                                continue;
+                            }
+                            value => i64::try_from(&value)
+                                .ok()
+                                .and_then(|offset| usize::try_from(offset).ok())
+                                .ok_or_else(|| WindowError::InvalidOffset(name.to_owned()))?,
+                        },
+                        None => 1,
+                    };
+                    let target = match aggr.func {
+                        AggregateFunction::Lag { .. } => n.checked_sub(offset),
+                        _ => n.checked_add(offset),
+                    }
+                    .and_then(|target| partition.get(target));
+
+                    let value = match (target, default) {
+                        (Some((_, target)), _) => self.evaluate(&rows[*target], expr).await?,
+                        (None, Some(default)) => self.evaluate(row, default).await?,
+                        (None, None) => Value::Null,
+                    };
+
+                    values.push((index, value));
+                }
+
+                Ok(values)
+            }
+            _ => {
+                let mut state: Option<AggrValue> = None;
+                let mut peers = Vec::new();
+                let mut values = Vec::with_capacity(partition.len());
+
+                // Without ORDER BY every row of a partition is a peer of the others, so the
+                // aggregate covers the whole partition, otherwise it is a running aggregate
+                for (n, index) in indexes.enumerate() {
+                    let value = match aggr.as_expr() {
+                        Some(expr) => self.evaluate(&rows[index], expr).await?,
+                        None => Value::Null,
+                    };
+
+                    state = Some(match state.take() {
+                        Some(state) => state.accumulate(&value)?.unwrap_or(state),
+                        None => AggrValue::new(aggr, &value)?,
+                    });
+                    peers.push(index);
+
+                    if n + 1 < partition.len() && is_peer(n + 1) {
+                        continue;
+                    }
+
+                    if let Some(state) = &state {
+                        let value = state.clone().export().await?;
+
+                        values.extend(peers.drain(..).map(|index| (index, value.clone())));
+                    }
+                }
+
+                Ok(values)
+            }
+        }
+    }
+
+    async fn evaluate(&self, row: &AggregateContext<'a>, expr: &'a Expr) -> Result<Value> {
+        let context = match &self.filter_context {
+            Some(filter_context) => Rc::new(RowContext::concat(
+                Rc::clone(&row.next),
+                Rc::clone(filter_context),
+            )),
+            None => Rc::clone(&row.next),
+        };
+        let aggregated = row.aggregated.clone().map(Rc::new);
+
+        evaluate(self.storage, Some(context), aggregated, expr)
+            .await?
+            .try_into()
+    }
+}
+
+/// Window functions in the expression, excluding the ones inside of subqueries
+fn windows(expr: &Expr) -> Vec<(&Aggregate, &WindowSpec)> {
+    if let Expr::Aggregate(aggr) = expr {
+        if let Some(window) = &aggr.over {
+            return vec![(aggr.as_ref(), window)];
+        }
+    }
+
+    match expr.into() {
+        PlanExpr::None
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. }
+        | PlanExpr::Query(_) => vec![],
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => windows(expr),
+        PlanExpr::TwoExprs(expr, expr2) => [windows(expr), windows(expr2)].concat(),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            [windows(expr), windows(expr2), windows(expr3)].concat()
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().flat_map(windows).collect(),
+    }
+}
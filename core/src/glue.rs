@@ -1,31 +1,149 @@
 use {
     crate::{
-        ast::Statement,
-        executor::{execute, Payload},
+        ast::{DataType, Statement},
+        data::{FunctionRegistry, NativeFunction, NativeTableFunction, Value},
+        executor::{execute, ExecuteError, Payload},
         parse_sql::parse,
         plan::plan,
-        result::Result,
-        store::{GStore, GStoreMut},
+        result::{Error, Result},
+        store::{GStore, GStoreMut, TemporaryStorage, TemporaryTables},
         translate::translate,
     },
     futures::{
         stream::{self, StreamExt},
         TryStreamExt,
     },
+    futures_timer::Delay,
+    std::{sync::Arc, time::Duration},
 };
 
+/// How a statement which fails with `Error::Conflict` is executed again. The statement is
+/// retried up to `max_retries` times, waiting `backoff` before the first retry and twice as
+/// long before each following one. When the retries are exhausted, the conflict error of the
+/// last attempt is returned. `SELECT .. FOR UPDATE NOWAIT` is never retried, and neither are
+/// the other statements run inside of a transaction started by `BEGIN`, whose earlier
+/// statements would not be run again. `SELECT .. FOR UPDATE` is retried until the lock
+/// timeout of `Glue` elapses instead, see `Glue::set_lock_timeout`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff: Duration,
+}
+
+/// default lock timeout of `SELECT .. FOR UPDATE` : 5 seconds
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl Default for RetryPolicy {
+    /// Conflicts are not retried
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff: Duration::from_millis(10),
+        }
+    }
+}
+
 pub struct Glue<T: GStore + GStoreMut> {
     pub storage: T,
+    temporary_tables: TemporaryTables,
+    functions: FunctionRegistry,
+    read_only: bool,
+    retry_policy: RetryPolicy,
+    /// whether a transaction started by `BEGIN` is open
+    in_transaction: bool,
+    lock_timeout: Duration,
 }
 
 impl<T: GStore + GStoreMut> Glue<T> {
     pub fn new(storage: T) -> Self {
-        Self { storage }
+        Self {
+            storage,
+            temporary_tables: TemporaryTables::default(),
+            functions: FunctionRegistry::default(),
+            read_only: false,
+            retry_policy: RetryPolicy::default(),
+            in_transaction: false,
+            lock_timeout: DEFAULT_LOCK_TIMEOUT,
+        }
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// How long `SELECT .. FOR UPDATE` waits for the rows locked by another transaction
+    /// before it fails with the conflict error, 5 seconds by default. The statement is retried
+    /// with the backoff of the retry policy, regardless of its `max_retries`.
+    pub fn set_lock_timeout(&mut self, lock_timeout: Duration) {
+        self.lock_timeout = lock_timeout;
+    }
+
+    /// In read-only mode, statements which modify data or schema fail with
+    /// `ExecuteError::ReadOnly` before the storage is touched. Queries, `SHOW` statements and
+    /// transaction statements are still allowed.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Registers a scalar function implemented in Rust, which SQL calls as `name(...)`.
+    /// Each argument is cast to the data type at the same position of `arg_types`, and the
+    /// number of arguments must match. A function registered under the same name is replaced.
+    pub fn register_function<F>(&self, name: &str, arg_types: Vec<DataType>, func: F)
+    where
+        F: Fn(Vec<Value>) -> Result<Value> + Send + Sync + 'static,
+    {
+        self.functions.insert(NativeFunction {
+            func_name: name.to_owned(),
+            arg_types,
+            body: Arc::new(func),
+        });
+    }
+
+    pub fn unregister_function(&self, name: &str) -> bool {
+        self.functions.remove(name).is_some()
+    }
+
+    /// Registers a table-valued function implemented in Rust, which SQL reads in FROM as
+    /// `name(...)` like a table. Arguments are cast as in `register_function`, and every row
+    /// returned by `func` must have a value for each of `columns`.
+    pub fn register_table_function<F>(
+        &self,
+        name: &str,
+        arg_types: Vec<DataType>,
+        columns: Vec<String>,
+        func: F,
+    ) where
+        F: Fn(Vec<Value>) -> Result<Vec<Vec<Value>>> + Send + Sync + 'static,
+    {
+        self.functions.insert_table_function(NativeTableFunction {
+            func_name: name.to_owned(),
+            arg_types,
+            columns,
+            body: Arc::new(func),
+        });
+    }
+
+    pub fn unregister_table_function(&self, name: &str) -> bool {
+        self.functions.remove_table_function(name).is_some()
+    }
+
+    /// Registry of the native functions, whose clones can be shared with other threads
+    pub fn functions(&self) -> &FunctionRegistry {
+        &self.functions
     }
 
     pub async fn plan<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Statement>> {
         let parsed = parse(sql)?;
-        let storage = &self.storage;
+        let storage = &TemporaryStorage::new(
+            &mut self.storage,
+            &mut self.temporary_tables,
+            &self.functions,
+            None,
+        );
         stream::iter(parsed)
             .map(|p| translate(&p))
             .then(|statement| async move { plan(storage, statement?).await })
@@ -33,8 +151,82 @@ impl<T: GStore + GStoreMut> Glue<T> {
             .await
     }
 
+    pub async fn plan_stmt(&mut self, statement: Statement) -> Result<Statement> {
+        let storage = TemporaryStorage::new(
+            &mut self.storage,
+            &mut self.temporary_tables,
+            &self.functions,
+            None,
+        );
+
+        plan(&storage, statement).await
+    }
+
     pub async fn execute_stmt(&mut self, statement: &Statement) -> Result<Payload> {
-        execute(&mut self.storage, statement).await
+        if self.read_only && !statement.is_read_only() {
+            return Err(ExecuteError::ReadOnly.into());
+        }
+
+        let RetryPolicy {
+            max_retries,
+            backoff,
+        } = self.retry_policy;
+        // `FOR UPDATE` fails before the query runs, so it waits for the lock even inside of a
+        // transaction, at least 1ms at a time so that the waits add up to the lock timeout
+        let (max_retries, backoff, timeout) = match statement {
+            Statement::QueryForUpdate { nowait: true, .. } => (0, backoff, Duration::ZERO),
+            Statement::QueryForUpdate { .. } => (
+                u32::MAX,
+                backoff.max(Duration::from_millis(1)),
+                self.lock_timeout,
+            ),
+            _ if self.in_transaction => (0, backoff, Duration::ZERO),
+            _ => (max_retries, backoff, Duration::MAX),
+        };
+        let mut retries = 0;
+        let mut waited = Duration::ZERO;
+
+        let result = loop {
+            match self.execute_stmt_once(statement).await {
+                Err(Error::Conflict(_)) if retries < max_retries && waited < timeout => {
+                    let delay = backoff
+                        .saturating_mul(1 << retries.min(16))
+                        .min(timeout - waited);
+
+                    Delay::new(delay).await;
+                    retries += 1;
+                    waited += delay;
+                }
+                result => break result,
+            }
+        };
+
+        match result {
+            Ok(Payload::StartTransaction) => self.in_transaction = true,
+            Ok(Payload::Commit | Payload::Rollback) => self.in_transaction = false,
+            _ => {}
+        }
+
+        result
+    }
+
+    async fn execute_stmt_once(&mut self, statement: &Statement) -> Result<Payload> {
+        let created = match statement {
+            Statement::CreateTable {
+                name,
+                temporary: true,
+                ..
+            } => Some(name.as_str()),
+            _ => None,
+        };
+        let mut storage = TemporaryStorage::new(
+            &mut self.storage,
+            &mut self.temporary_tables,
+            &self.functions,
+            created,
+        );
+
+        execute(&mut storage, statement).await
     }
 
     pub async fn execute<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
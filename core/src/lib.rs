@@ -21,7 +21,7 @@ pub mod prelude {
         ast::DataType,
         data::{Key, Value},
         executor::{execute, Payload, PayloadVariable},
-        glue::Glue,
+        glue::{Glue, RetryPolicy},
         parse_sql::parse,
         plan::plan,
         result::{Error, Result},
@@ -152,6 +152,7 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: None,
+                collation: None,
             },
         ))
         .is_err());
@@ -168,6 +169,7 @@ mod tests {
                     value: "true".to_owned(),
                 },
                 asc: None,
+                nulls_first: None,
             },
         ))
         .is_err());
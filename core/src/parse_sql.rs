@@ -7,15 +7,467 @@ use {
             SelectItem as SqlSelectItem, Statement as SqlStatement,
         },
         dialect::PostgreSqlDialect,
+        keywords::Keyword,
         parser::Parser,
-        tokenizer::Tokenizer,
+        tokenizer::{Token, TokenWithLocation, Tokenizer, Word},
     },
+    std::collections::HashMap,
 };
 
 const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 
+/// Statement name `REFRESH MATERIALIZED VIEW` is passed to `translate` with,
+/// see [`rewrite_materialized_views`]
+pub(crate) const REFRESH_MATERIALIZED_VIEW: &str = "REFRESH MATERIALIZED VIEW";
+
+/// Statement names savepoint statements are passed to `translate` with,
+/// see [`rewrite_savepoints`]
+pub(crate) const SAVEPOINT: &str = "SAVEPOINT";
+pub(crate) const ROLLBACK_TO_SAVEPOINT: &str = "ROLLBACK TO SAVEPOINT";
+pub(crate) const RELEASE_SAVEPOINT: &str = "RELEASE SAVEPOINT";
+
+/// Statement name `FLUSH` and `CHECKPOINT` are passed to `translate` with,
+/// see [`rewrite_flush`]
+pub(crate) const FLUSH: &str = "FLUSH";
+
+/// Statement name `ANALYZE` is passed to `translate` with, see [`rewrite_analyze`]
+pub(crate) const ANALYZE: &str = "ANALYZE";
+
 pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
-    Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
+    let tokens = Tokenizer::new(&DIALECT, sql.as_ref())
+        .tokenize_with_location()
+        .map_err(|e| Error::Parser(format!("{:#?}", e)))?;
+
+    Parser::new(&DIALECT)
+        .with_tokens_with_locations(rewrite_analyze(rewrite_flush(rewrite_savepoints(
+            rewrite_materialized_views(wrap_quantified_subqueries(tokens)),
+        ))))
+        .parse_statements()
+        .map_err(|e| Error::Parser(format!("{:#?}", e)))
+}
+
+/// `sqlparser-rs` parses the operand of `ANY` and `ALL` as a plain expression,
+/// so `x > ALL (SELECT ..)` fails to parse unless the subquery is wrapped in an
+/// extra pair of parentheses. This adds those parentheses for comparisons
+/// followed by `ANY (SELECT ..)` or `ALL (SELECT ..)`.
+fn wrap_quantified_subqueries(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let is_significant = |token: &&TokenWithLocation| !matches!(token.token, Token::Whitespace(_));
+    let next_significant = |from: usize| {
+        tokens
+            .iter()
+            .enumerate()
+            .skip(from)
+            .find(|(_, token)| is_significant(token))
+    };
+
+    let mut opens = HashMap::new();
+    let mut closes = HashMap::new();
+    let mut prev: Option<&Token> = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if !is_significant(&token) {
+            continue;
+        }
+
+        let is_comparison = matches!(
+            prev,
+            Some(Token::Eq | Token::Neq | Token::Lt | Token::LtEq | Token::Gt | Token::GtEq)
+        );
+        let is_quantifier = matches!(&token.token, Token::Word(w) if matches!(w.keyword, Keyword::ANY | Keyword::ALL));
+        prev = Some(&token.token);
+
+        if !(is_comparison && is_quantifier) {
+            continue;
+        }
+
+        let lparen = match next_significant(i + 1) {
+            Some((j, token)) if token.token == Token::LParen => j,
+            _ => continue,
+        };
+        let is_query = matches!(
+            next_significant(lparen + 1),
+            Some((_, TokenWithLocation { token: Token::Word(w), .. }))
+                if matches!(w.keyword, Keyword::SELECT | Keyword::WITH)
+        );
+
+        if !is_query {
+            continue;
+        }
+
+        let mut depth = 0;
+        let rparen = tokens
+            .iter()
+            .enumerate()
+            .skip(lparen)
+            .find_map(|(k, token)| {
+                match token.token {
+                    Token::LParen => depth += 1,
+                    Token::RParen => depth -= 1,
+                    _ => {}
+                }
+
+                (depth == 0).then_some(k)
+            });
+
+        if let Some(rparen) = rparen {
+            *opens.entry(lparen).or_insert(0) += 1;
+            *closes.entry(rparen).or_insert(0) += 1;
+        }
+    }
+
+    if opens.is_empty() {
+        return tokens;
+    }
+
+    let mut wrapped = Vec::with_capacity(tokens.len() + opens.len() * 2);
+    for (i, token) in tokens.into_iter().enumerate() {
+        let location = token.location.clone();
+        let paren = |token| TokenWithLocation {
+            token,
+            location: location.clone(),
+        };
+
+        for _ in 0..closes.get(&i).copied().unwrap_or(0) {
+            wrapped.push(paren(Token::RParen));
+        }
+
+        wrapped.push(token);
+
+        for _ in 0..opens.get(&i).copied().unwrap_or(0) {
+            wrapped.push(paren(Token::LParen));
+        }
+    }
+
+    wrapped
+}
+
+/// `sqlparser-rs` parses neither `REFRESH MATERIALIZED VIEW` nor `DROP MATERIALIZED VIEW`.
+/// `REFRESH MATERIALIZED VIEW name` is rewritten into
+/// `EXECUTE "REFRESH MATERIALIZED VIEW" (name)`, which `translate` turns back into a refresh,
+/// and `DROP MATERIALIZED VIEW` into `DROP VIEW`.
+fn rewrite_materialized_views(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let significant = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(i, token)| (i, &token.token))
+        .collect::<Vec<_>>();
+    let is_word = |n: usize, value: &str| {
+        matches!(
+            significant.get(n),
+            Some((_, Token::Word(w))) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value)
+        )
+    };
+
+    let mut replaced = HashMap::new();
+    let mut closes = Vec::new();
+
+    for n in 0..significant.len() {
+        let is_statement_start = n == 0 || significant[n - 1].1 == &Token::SemiColon;
+
+        if !is_statement_start || !is_word(n + 1, "MATERIALIZED") || !is_word(n + 2, "VIEW") {
+            continue;
+        }
+
+        let materialized = significant[n + 1].0;
+
+        if is_word(n, "DROP") {
+            replaced.insert(materialized, None);
+        } else if is_word(n, "REFRESH") {
+            let name = Token::Word(Word {
+                value: REFRESH_MATERIALIZED_VIEW.to_owned(),
+                quote_style: Some('"'),
+                keyword: Keyword::NoKeyword,
+            });
+            let end = significant
+                .iter()
+                .skip(n + 3)
+                .find(|(_, token)| token == &&Token::SemiColon)
+                .map(|(i, _)| *i)
+                .unwrap_or(tokens.len());
+
+            replaced.insert(significant[n].0, Some(Token::make_keyword("EXECUTE")));
+            replaced.insert(materialized, Some(name));
+            replaced.insert(significant[n + 2].0, Some(Token::LParen));
+            closes.push(end);
+        }
+    }
+
+    if replaced.is_empty() {
+        return tokens;
+    }
+
+    let len = tokens.len();
+    let end_location = tokens.last().map(|token| token.location.clone());
+    let mut rewritten = Vec::with_capacity(len + closes.len());
+
+    for (i, TokenWithLocation { token, location }) in tokens.into_iter().enumerate() {
+        if closes.contains(&i) {
+            rewritten.push(TokenWithLocation {
+                token: Token::RParen,
+                location: location.clone(),
+            });
+        }
+
+        let token = match replaced.remove(&i) {
+            Some(Some(token)) => token,
+            Some(None) => continue,
+            None => token,
+        };
+
+        rewritten.push(TokenWithLocation { token, location });
+    }
+
+    if let Some(location) = end_location.filter(|_| closes.contains(&len)) {
+        rewritten.push(TokenWithLocation {
+            token: Token::RParen,
+            location,
+        });
+    }
+
+    rewritten
+}
+
+/// `sqlparser-rs` parses neither `ROLLBACK TO SAVEPOINT` nor `RELEASE SAVEPOINT`.
+/// `SAVEPOINT name`, `ROLLBACK [TRANSACTION | WORK] TO [SAVEPOINT] name` and
+/// `RELEASE [SAVEPOINT] name` are rewritten into `EXECUTE "<statement>" (name)`, which
+/// `translate` turns back into the savepoint statements.
+fn rewrite_savepoints(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let significant = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(i, token)| (i, &token.token))
+        .collect::<Vec<_>>();
+    let is_word = |n: usize, value: &str| {
+        matches!(
+            significant.get(n),
+            Some((_, Token::Word(w))) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value)
+        )
+    };
+
+    // index of the first token, index of the savepoint name and the statement name
+    let mut rewrites = Vec::new();
+
+    for n in 0..significant.len() {
+        let is_statement_start = n == 0 || significant[n - 1].1 == &Token::SemiColon;
+
+        if !is_statement_start {
+            continue;
+        }
+
+        let (statement, name) = if is_word(n, "SAVEPOINT") {
+            (SAVEPOINT, n + 1)
+        } else if is_word(n, "RELEASE") {
+            let name = n + 1 + usize::from(is_word(n + 1, "SAVEPOINT"));
+
+            (RELEASE_SAVEPOINT, name)
+        } else if is_word(n, "ROLLBACK") {
+            let to = n + 1 + usize::from(is_word(n + 1, "TRANSACTION") || is_word(n + 1, "WORK"));
+
+            if !is_word(to, "TO") {
+                continue;
+            }
+
+            let name = to + 1 + usize::from(is_word(to + 1, "SAVEPOINT"));
+
+            (ROLLBACK_TO_SAVEPOINT, name)
+        } else {
+            continue;
+        };
+
+        if let Some((name, Token::Word(_))) = significant.get(name) {
+            rewrites.push((significant[n].0, *name, statement));
+        }
+    }
+
+    if rewrites.is_empty() {
+        return tokens;
+    }
+
+    let mut rewritten = Vec::with_capacity(tokens.len() + rewrites.len() * 3);
+
+    for (i, TokenWithLocation { token, location }) in tokens.into_iter().enumerate() {
+        let rewrite = rewrites
+            .iter()
+            .find(|(start, name, _)| (*start..=*name).contains(&i));
+        let with_location = |token| TokenWithLocation {
+            token,
+            location: location.clone(),
+        };
+
+        match rewrite {
+            Some((start, _, statement)) if i == *start => {
+                let statement = Token::Word(Word {
+                    value: (*statement).to_owned(),
+                    quote_style: Some('"'),
+                    keyword: Keyword::NoKeyword,
+                });
+
+                rewritten.push(with_location(Token::make_keyword("EXECUTE")));
+                rewritten.push(with_location(statement));
+                rewritten.push(with_location(Token::LParen));
+            }
+            Some((_, name, _)) if i == *name => {
+                rewritten.push(with_location(token));
+                rewritten.push(with_location(Token::RParen));
+            }
+            Some(_) => continue,
+            None => rewritten.push(with_location(token)),
+        }
+    }
+
+    rewritten
+}
+
+/// `sqlparser-rs` parses neither `FLUSH` nor `CHECKPOINT`, so a statement of either word
+/// alone is rewritten into `EXECUTE "FLUSH"`, which `translate` turns back into a flush.
+fn rewrite_flush(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let significant = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(i, token)| (i, &token.token))
+        .collect::<Vec<_>>();
+    let is_statement_end = |n: usize| {
+        matches!(
+            significant.get(n),
+            None | Some((_, Token::SemiColon | Token::EOF))
+        )
+    };
+
+    let flushes = significant
+        .iter()
+        .enumerate()
+        .filter(|(n, (_, token))| {
+            let is_statement_start = *n == 0 || significant[n - 1].1 == &Token::SemiColon;
+            let is_flush = matches!(
+                token,
+                Token::Word(w) if w.quote_style.is_none()
+                    && (w.value.eq_ignore_ascii_case("FLUSH")
+                        || w.value.eq_ignore_ascii_case("CHECKPOINT"))
+            );
+
+            is_statement_start && is_flush && is_statement_end(n + 1)
+        })
+        .map(|(_, (i, _))| *i)
+        .collect::<Vec<_>>();
+
+    if flushes.is_empty() {
+        return tokens;
+    }
+
+    let mut rewritten = Vec::with_capacity(tokens.len() + flushes.len());
+
+    for (i, TokenWithLocation { token, location }) in tokens.into_iter().enumerate() {
+        if !flushes.contains(&i) {
+            rewritten.push(TokenWithLocation { token, location });
+            continue;
+        }
+
+        let statement = Token::Word(Word {
+            value: FLUSH.to_owned(),
+            quote_style: Some('"'),
+            keyword: Keyword::NoKeyword,
+        });
+
+        rewritten.push(TokenWithLocation {
+            token: Token::make_keyword("EXECUTE"),
+            location: location.clone(),
+        });
+        rewritten.push(TokenWithLocation {
+            token: statement,
+            location,
+        });
+    }
+
+    rewritten
+}
+
+/// `sqlparser-rs` parses `ANALYZE` only in the form of `ANALYZE TABLE name` followed by the
+/// options of Hive. `ANALYZE` and `ANALYZE [TABLE] name` are rewritten into `EXECUTE "ANALYZE"`
+/// and `EXECUTE "ANALYZE" (name)`, which `translate` turns back into an analyze.
+fn rewrite_analyze(tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+    let significant = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(i, token)| (i, &token.token))
+        .collect::<Vec<_>>();
+    let is_word = |n: usize, value: &str| {
+        matches!(
+            significant.get(n),
+            Some((_, Token::Word(w))) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value)
+        )
+    };
+    let is_statement_end = |n: usize| {
+        matches!(
+            significant.get(n),
+            None | Some((_, Token::SemiColon | Token::EOF))
+        )
+    };
+
+    // index of the `ANALYZE` token and the index of the table name if it is given
+    let mut rewrites = Vec::new();
+
+    for n in 0..significant.len() {
+        let is_statement_start = n == 0 || significant[n - 1].1 == &Token::SemiColon;
+
+        if !is_statement_start || !is_word(n, "ANALYZE") {
+            continue;
+        }
+
+        let name = n + 1 + usize::from(is_word(n + 1, "TABLE"));
+
+        if is_statement_end(n + 1) {
+            rewrites.push((significant[n].0, None));
+        } else if matches!(significant.get(name), Some((_, Token::Word(_))))
+            && is_statement_end(name + 1)
+        {
+            rewrites.push((significant[n].0, Some(significant[name].0)));
+        }
+    }
+
+    if rewrites.is_empty() {
+        return tokens;
+    }
+
+    let mut rewritten = Vec::with_capacity(tokens.len() + rewrites.len() * 3);
+
+    for (i, TokenWithLocation { token, location }) in tokens.into_iter().enumerate() {
+        let rewrite = rewrites
+            .iter()
+            .find(|(start, name)| (*start..=name.unwrap_or(*start)).contains(&i));
+        let with_location = |token| TokenWithLocation {
+            token,
+            location: location.clone(),
+        };
+
+        match rewrite {
+            Some((start, name)) if i == *start => {
+                let statement = Token::Word(Word {
+                    value: ANALYZE.to_owned(),
+                    quote_style: Some('"'),
+                    keyword: Keyword::NoKeyword,
+                });
+
+                rewritten.push(with_location(Token::make_keyword("EXECUTE")));
+                rewritten.push(with_location(statement));
+
+                if name.is_some() {
+                    rewritten.push(with_location(Token::LParen));
+                }
+            }
+            Some((_, Some(name))) if i == *name => {
+                rewritten.push(with_location(token));
+                rewritten.push(with_location(Token::RParen));
+            }
+            Some(_) => continue,
+            None => rewritten.push(with_location(token)),
+        }
+    }
+
+    rewritten
 }
 
 macro_rules! generate_parse_fn {
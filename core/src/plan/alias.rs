@@ -0,0 +1,342 @@
+use {
+    super::{context::Context, expr::PlanExpr, planner::Planner},
+    crate::{
+        ast::{
+            Expr, Function, Query, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+        },
+        data::Schema,
+    },
+    std::{collections::HashMap, iter::once, rc::Rc},
+};
+
+/// Replaces references to projection aliases in GROUP BY and HAVING with the aliased
+/// expressions, e.g. `SELECT price * qty AS total ... HAVING total > 10`.
+///
+/// Columns of the input tables take precedence over aliases, so aliases are only resolved
+/// when the columns of every table in FROM are known from the schema.
+pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
+    let planner = AliasPlanner { schema_map };
+
+    match statement {
+        Statement::Query(query) => {
+            let query = planner.query(None, query);
+
+            Statement::Query(query)
+        }
+        _ => statement,
+    }
+}
+
+struct AliasPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+}
+
+impl<'a> Planner<'a> for AliasPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let body = self.set_expr(outer_context, query.body);
+
+        Query { body, ..query }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a> AliasPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left = self.set_expr(outer_context.as_ref().map(Rc::clone), *left);
+                let right = self.set_expr(outer_context, *right);
+
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+        }
+    }
+
+    fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
+        let relations = once(&select.from.relation)
+            .chain(select.from.joins.iter().map(|join| &join.relation))
+            .collect::<Vec<_>>();
+        let current_context = relations.iter().fold(None, |context, relation| {
+            self.update_context(context, relation)
+        });
+        let known_columns = relations.iter().all(|relation| match relation {
+            TableFactor::Table { name, alias, .. } => {
+                let renamed =
+                    matches!(alias, Some(TableAlias { columns, .. }) if !columns.is_empty());
+                let column_defs = self
+                    .get_schema(name)
+                    .and_then(|schema| schema.column_defs.as_ref());
+
+                !renamed && column_defs.is_some()
+            }
+            _ => false,
+        });
+
+        let find_alias = |ident: &str| -> Option<&Expr> {
+            let is_column = current_context
+                .as_ref()
+                .map(|context| context.contains_column(ident))
+                .unwrap_or(false);
+            if !known_columns || is_column {
+                return None;
+            }
+
+            let mut exprs = select.projection.iter().filter_map(|item| match item {
+                SelectItem::Expr { expr, label } if label == ident => Some(expr),
+                _ => None,
+            });
+
+            match (exprs.next(), exprs.next()) {
+                (Some(expr), None) => Some(expr),
+                _ => None,
+            }
+        };
+
+        let group_by = select
+            .group_by
+            .iter()
+            .map(|expr| match expr {
+                Expr::Identifier(ident) => find_alias(ident)
+                    .filter(|expr| !contains_aggregate(expr))
+                    .unwrap_or(expr)
+                    .clone(),
+                _ => expr.clone(),
+            })
+            .collect();
+        let having = select
+            .having
+            .as_ref()
+            .map(|expr| resolve_alias(expr.clone(), &find_alias));
+
+        let outer_context = Context::concat(current_context, outer_context);
+        let selection = select
+            .selection
+            .map(|expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr));
+        let having = having.map(|expr| self.subquery_expr(outer_context, expr));
+
+        Select {
+            group_by,
+            having,
+            selection,
+            ..select
+        }
+    }
+}
+
+fn resolve_alias<'b>(expr: Expr, find_alias: &impl Fn(&str) -> Option<&'b Expr>) -> Expr {
+    let resolve = |expr: Expr| resolve_alias(expr, find_alias);
+    let resolve_box = |expr: Box<Expr>| Box::new(resolve(*expr));
+
+    match expr {
+        Expr::Identifier(ident) => match find_alias(&ident) {
+            Some(expr) => expr.clone(),
+            None => Expr::Identifier(ident),
+        },
+        Expr::Nested(expr) => Expr::Nested(resolve_box(expr)),
+        Expr::IsNull(expr) => Expr::IsNull(resolve_box(expr)),
+        Expr::IsNotNull(expr) => Expr::IsNotNull(resolve_box(expr)),
+        Expr::UnaryOp { op, expr } => Expr::UnaryOp {
+            op,
+            expr: resolve_box(expr),
+        },
+        Expr::Collate { expr, collation } => Expr::Collate {
+            expr: resolve_box(expr),
+            collation,
+        },
+        Expr::BinaryOp { left, op, right } => Expr::BinaryOp {
+            left: resolve_box(left),
+            op,
+            right: resolve_box(right),
+        },
+        Expr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => Expr::Between {
+            expr: resolve_box(expr),
+            negated,
+            low: resolve_box(low),
+            high: resolve_box(high),
+        },
+        Expr::Like {
+            expr,
+            negated,
+            pattern,
+        } => Expr::Like {
+            expr: resolve_box(expr),
+            negated,
+            pattern: resolve_box(pattern),
+        },
+        Expr::ILike {
+            expr,
+            negated,
+            pattern,
+        } => Expr::ILike {
+            expr: resolve_box(expr),
+            negated,
+            pattern: resolve_box(pattern),
+        },
+        Expr::InList {
+            expr,
+            list,
+            negated,
+        } => Expr::InList {
+            expr: resolve_box(expr),
+            list: list.into_iter().map(resolve).collect(),
+            negated,
+        },
+        Expr::Array { elem } => Expr::Array {
+            elem: elem.into_iter().map(resolve).collect(),
+        },
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => Expr::Case {
+            operand: operand.map(resolve_box),
+            when_then: when_then
+                .into_iter()
+                .map(|(when, then)| (resolve(when), resolve(then)))
+                .collect(),
+            else_result: else_result.map(resolve_box),
+        },
+        Expr::Function(func) => match *func {
+            Function::Cast { expr, data_type } => Expr::Function(Box::new(Function::Cast {
+                expr: resolve(expr),
+                data_type,
+            })),
+            Function::Extract { field, expr } => Expr::Function(Box::new(Function::Extract {
+                field,
+                expr: resolve(expr),
+            })),
+            func => Expr::Function(Box::new(func)),
+        },
+        _ => expr,
+    }
+}
+
+fn contains_aggregate(expr: &Expr) -> bool {
+    if matches!(expr, Expr::Aggregate(_)) {
+        return true;
+    }
+
+    match expr.into() {
+        PlanExpr::None
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. }
+        | PlanExpr::Query(_) => false,
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => contains_aggregate(expr),
+        PlanExpr::TwoExprs(expr, expr2) => contains_aggregate(expr) || contains_aggregate(expr2),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            contains_aggregate(expr) || contains_aggregate(expr2) || contains_aggregate(expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(contains_aggregate),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan,
+        crate::{
+            ast::{SetExpr, Statement, ToSql},
+            mock::{run, MockStorage},
+            parse_sql::{parse, parse_expr},
+            plan::fetch_schema_map,
+            translate::{translate, translate_expr},
+        },
+        futures::executor::block_on,
+    };
+
+    fn plan_alias(storage: &MockStorage, sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = translate(&parsed).unwrap();
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        plan(&schema_map, statement)
+    }
+
+    fn group_by_having(statement: Statement) -> (Vec<String>, Option<String>) {
+        match statement {
+            Statement::Query(query) => match query.body {
+                SetExpr::Select(select) => (
+                    select.group_by.iter().map(ToSql::to_sql).collect(),
+                    select.having.as_ref().map(ToSql::to_sql),
+                ),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn expr(sql: &str) -> String {
+        translate_expr(&parse_expr(sql).expect(sql))
+            .expect(sql)
+            .to_sql()
+    }
+
+    #[test]
+    fn group_by_and_having() {
+        let storage = run("
+            CREATE TABLE Sale (id INTEGER, item TEXT, price INTEGER, qty INTEGER);
+            CREATE TABLE Schemaless;
+        ");
+
+        let test = |sql, group_by: Vec<&str>, having: Option<&str>| {
+            let actual = group_by_having(plan_alias(&storage, sql));
+            let expected = (
+                group_by.into_iter().map(expr).collect::<Vec<_>>(),
+                having.map(expr),
+            );
+
+            assert_eq!(actual, expected, "{sql}");
+        };
+
+        test(
+            "SELECT item AS i, SUM(price) AS total FROM Sale GROUP BY i HAVING total > 10",
+            vec!["item"],
+            Some("SUM(price) > 10"),
+        );
+        test(
+            "SELECT item AS i FROM Sale GROUP BY i HAVING (i = 'a' OR i IS NULL)",
+            vec!["item"],
+            Some("(item = 'a' OR item IS NULL)"),
+        );
+        test(
+            "SELECT price AS qty FROM Sale GROUP BY qty HAVING qty > 1",
+            vec!["qty"],
+            Some("qty > 1"),
+        );
+        test(
+            "SELECT COUNT(*) AS cnt FROM Sale GROUP BY cnt",
+            vec!["cnt"],
+            None,
+        );
+        test(
+            "SELECT id AS a, item AS a FROM Sale GROUP BY a",
+            vec!["a"],
+            None,
+        );
+        test("SELECT id AS i FROM Schemaless GROUP BY i", vec!["i"], None);
+    }
+}
@@ -0,0 +1,517 @@
+use {
+    crate::{
+        ast::{
+            BinaryOperator, Collation, ColumnDef, Cte, Expr, Join, JoinConstraint, JoinOperator,
+            OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins, With,
+        },
+        data::Schema,
+    },
+    std::{collections::HashMap, iter::once},
+};
+
+/// Applies the collations of the columns declared with `COLLATE` to the comparisons they
+/// take part in.
+///
+/// Every operand of `=`, `<`, `LIKE`, `IN (..)`, `BETWEEN` and the others is wrapped in
+/// [`Expr::Collate`] when one of them refers to a collated column or has a `COLLATE` of its
+/// own, and so is an `ORDER BY` item referring to a collated column. Projections are left
+/// as they are, so the stored text is returned unchanged.
+pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
+    let planner = CollationPlanner { schema_map };
+
+    match statement {
+        Statement::Query(query) => Statement::Query(planner.query(&[], query)),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            on_conflict,
+            returning,
+        } => Statement::Insert {
+            table_name,
+            columns,
+            source: planner.query(&[], source),
+            on_conflict,
+            returning,
+        },
+        Statement::Update {
+            table_name,
+            assignments,
+            selection,
+            order_by,
+            limit,
+            returning,
+        } => {
+            let columns = planner.table_columns(&table_name, None);
+            let selection = selection.map(|expr| planner.expr(&columns, expr));
+
+            Statement::Update {
+                table_name,
+                assignments,
+                selection,
+                order_by,
+                limit,
+                returning,
+            }
+        }
+        Statement::Delete {
+            table_name,
+            selection,
+            order_by,
+            limit,
+            returning,
+        } => {
+            let columns = planner.table_columns(&table_name, None);
+            let selection = selection.map(|expr| planner.expr(&columns, expr));
+
+            Statement::Delete {
+                table_name,
+                selection,
+                order_by,
+                limit,
+                returning,
+            }
+        }
+        _ => statement,
+    }
+}
+
+/// Table alias and definition of a column in scope
+type Column<'a> = (String, &'a ColumnDef);
+
+struct CollationPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+}
+
+impl<'a> CollationPlanner<'a> {
+    fn query(&self, outer: &[Column<'a>], query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let with = with.map(|with| With {
+            cte_tables: with
+                .cte_tables
+                .into_iter()
+                .map(|cte| Cte {
+                    query: self.query(outer, cte.query),
+                    ..cte
+                })
+                .collect(),
+            ..with
+        });
+        let columns = match &body {
+            SetExpr::Select(select) => self.select_columns(outer, select),
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } => outer.to_vec(),
+        };
+        let body = self.set_expr(outer, body);
+        let order_by = order_by
+            .into_iter()
+            .map(|order_by_expr| OrderByExpr {
+                expr: match collation(&columns, &order_by_expr.expr) {
+                    Some(collation) => collate(order_by_expr.expr, collation),
+                    None => self.expr(&columns, order_by_expr.expr),
+                },
+                ..order_by_expr
+            })
+            .collect();
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn set_expr(&self, outer: &[Column<'a>], set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => SetExpr::Select(Box::new(self.select(outer, *select))),
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer, *left)),
+                right: Box::new(self.set_expr(outer, *right)),
+            },
+        }
+    }
+
+    fn select(&self, outer: &[Column<'a>], select: Select) -> Select {
+        let columns = self.select_columns(outer, &select);
+        let Select {
+            distinct,
+            projection,
+            from: TableWithJoins { relation, joins },
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let projection = projection
+            .into_iter()
+            .map(|select_item| match select_item {
+                SelectItem::Expr { expr, label } => SelectItem::Expr {
+                    expr: self.expr(&columns, expr),
+                    label,
+                },
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => select_item,
+            })
+            .collect();
+        let joins = joins
+            .into_iter()
+            .map(|join| {
+                let join_constraint = |join_constraint| match join_constraint {
+                    JoinConstraint::On(expr) => JoinConstraint::On(self.expr(&columns, expr)),
+                    JoinConstraint::Using(_) | JoinConstraint::Natural | JoinConstraint::None => {
+                        join_constraint
+                    }
+                };
+                let join_operator = match join.join_operator {
+                    JoinOperator::Inner(constraint) => {
+                        JoinOperator::Inner(join_constraint(constraint))
+                    }
+                    JoinOperator::LeftOuter(constraint) => {
+                        JoinOperator::LeftOuter(join_constraint(constraint))
+                    }
+                    JoinOperator::RightOuter(constraint) => {
+                        JoinOperator::RightOuter(join_constraint(constraint))
+                    }
+                    JoinOperator::FullOuter(constraint) => {
+                        JoinOperator::FullOuter(join_constraint(constraint))
+                    }
+                };
+
+                Join {
+                    join_operator,
+                    ..join
+                }
+            })
+            .collect();
+        let selection = selection.map(|expr| self.expr(&columns, expr));
+        let having = having.map(|expr| self.expr(&columns, expr));
+
+        Select {
+            distinct,
+            projection,
+            from: TableWithJoins { relation, joins },
+            selection,
+            group_by,
+            having,
+        }
+    }
+
+    /// Columns of the tables in FROM followed by the columns of the outer queries, so that
+    /// the nearest table is found first.
+    fn select_columns(&self, outer: &[Column<'a>], select: &Select) -> Vec<Column<'a>> {
+        once(&select.from.relation)
+            .chain(select.from.joins.iter().map(|join| &join.relation))
+            .flat_map(|relation| match relation {
+                TableFactor::Table { name, alias, .. } => self.table_columns(name, alias.as_ref()),
+                _ => Vec::new(),
+            })
+            .chain(outer.iter().cloned())
+            .collect()
+    }
+
+    fn table_columns(&self, table_name: &str, alias: Option<&TableAlias>) -> Vec<Column<'a>> {
+        let column_defs = match self.schema_map.get(table_name) {
+            Some(Schema {
+                column_defs: Some(column_defs),
+                ..
+            }) => column_defs,
+            _ => return Vec::new(),
+        };
+
+        match alias {
+            Some(TableAlias { columns, .. }) if !columns.is_empty() => Vec::new(),
+            Some(TableAlias { name, .. }) => column_defs
+                .iter()
+                .map(|column_def| (name.to_owned(), column_def))
+                .collect(),
+            None => column_defs
+                .iter()
+                .map(|column_def| (table_name.to_owned(), column_def))
+                .collect(),
+        }
+    }
+
+    fn expr(&self, columns: &[Column<'a>], expr: Expr) -> Expr {
+        let plan = |expr| self.expr(columns, expr);
+        let plan_box = |expr: Box<Expr>| Box::new(plan(*expr));
+
+        match expr {
+            Expr::BinaryOp { left, op, right } => {
+                let left = plan(*left);
+                let right = plan(*right);
+                let is_comparison = matches!(
+                    op,
+                    BinaryOperator::Eq
+                        | BinaryOperator::NotEq
+                        | BinaryOperator::Lt
+                        | BinaryOperator::LtEq
+                        | BinaryOperator::Gt
+                        | BinaryOperator::GtEq
+                );
+
+                let collation = match is_comparison {
+                    true => collation_of(columns, [&left, &right]),
+                    false => None,
+                };
+
+                match collation {
+                    Some(collation) => Expr::BinaryOp {
+                        left: Box::new(collate(left, collation)),
+                        op,
+                        right: Box::new(collate(right, collation)),
+                    },
+                    None => Expr::BinaryOp {
+                        left: Box::new(left),
+                        op,
+                        right: Box::new(right),
+                    },
+                }
+            }
+            Expr::Like {
+                expr,
+                negated,
+                pattern,
+            } => {
+                let expr = plan(*expr);
+                let pattern = plan(*pattern);
+
+                match collation_of(columns, [&expr, &pattern]) {
+                    Some(collation) => Expr::Like {
+                        expr: Box::new(collate(expr, collation)),
+                        negated,
+                        pattern: Box::new(collate(pattern, collation)),
+                    },
+                    None => Expr::Like {
+                        expr: Box::new(expr),
+                        negated,
+                        pattern: Box::new(pattern),
+                    },
+                }
+            }
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let expr = plan(*expr);
+                let list = list.into_iter().map(plan).collect::<Vec<_>>();
+
+                match collation_of(columns, once(&expr).chain(&list)) {
+                    Some(collation) => Expr::InList {
+                        expr: Box::new(collate(expr, collation)),
+                        list: list
+                            .into_iter()
+                            .map(|expr| collate(expr, collation))
+                            .collect(),
+                        negated,
+                    },
+                    None => Expr::InList {
+                        expr: Box::new(expr),
+                        list,
+                        negated,
+                    },
+                }
+            }
+            Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                let expr = plan(*expr);
+                let low = plan(*low);
+                let high = plan(*high);
+
+                match collation_of(columns, [&expr, &low, &high]) {
+                    Some(collation) => Expr::Between {
+                        expr: Box::new(collate(expr, collation)),
+                        negated,
+                        low: Box::new(collate(low, collation)),
+                        high: Box::new(collate(high, collation)),
+                    },
+                    None => Expr::Between {
+                        expr: Box::new(expr),
+                        negated,
+                        low: Box::new(low),
+                        high: Box::new(high),
+                    },
+                }
+            }
+            Expr::ILike {
+                expr,
+                negated,
+                pattern,
+            } => Expr::ILike {
+                expr: plan_box(expr),
+                negated,
+                pattern: plan_box(pattern),
+            },
+            Expr::Nested(expr) => Expr::Nested(plan_box(expr)),
+            Expr::UnaryOp { op, expr } => Expr::UnaryOp {
+                op,
+                expr: plan_box(expr),
+            },
+            Expr::IsNull(expr) => Expr::IsNull(plan_box(expr)),
+            Expr::IsNotNull(expr) => Expr::IsNotNull(plan_box(expr)),
+            Expr::Case {
+                operand,
+                when_then,
+                else_result,
+            } => Expr::Case {
+                operand: operand.map(plan_box),
+                when_then: when_then
+                    .into_iter()
+                    .map(|(when, then)| (plan(when), plan(then)))
+                    .collect(),
+                else_result: else_result.map(plan_box),
+            },
+            Expr::Subquery(query) => Expr::Subquery(Box::new(self.query(columns, *query))),
+            Expr::Exists { subquery, negated } => Expr::Exists {
+                subquery: Box::new(self.query(columns, *subquery)),
+                negated,
+            },
+            Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => Expr::InSubquery {
+                expr: plan_box(expr),
+                subquery: Box::new(self.query(columns, *subquery)),
+                negated,
+            },
+            _ => expr,
+        }
+    }
+}
+
+/// Collation shared by the operands of a comparison, taken from the first operand with one
+fn collation_of<'b>(
+    columns: &[Column<'_>],
+    operands: impl IntoIterator<Item = &'b Expr>,
+) -> Option<Collation> {
+    operands
+        .into_iter()
+        .find_map(|operand| collation(columns, operand))
+}
+
+fn collation(columns: &[Column<'_>], expr: &Expr) -> Option<Collation> {
+    match expr {
+        Expr::Collate { collation, .. } => Some(*collation),
+        Expr::Nested(expr) => collation(columns, expr),
+        Expr::Identifier(ident) => columns
+            .iter()
+            .find(|(_, column_def)| &column_def.name == ident)
+            .and_then(|(_, column_def)| column_def.collation),
+        Expr::CompoundIdentifier { alias, ident } => columns
+            .iter()
+            .find(|(table_alias, column_def)| table_alias == alias && &column_def.name == ident)
+            .and_then(|(_, column_def)| column_def.collation),
+        _ => None,
+    }
+}
+
+/// Operands with a `COLLATE` of their own keep it.
+fn collate(expr: Expr, collation: Collation) -> Expr {
+    match expr {
+        Expr::Collate { .. } => expr,
+        _ => Expr::Collate {
+            expr: Box::new(expr),
+            collation,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan,
+        crate::{
+            ast::Statement,
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::fetch_schema_map,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn plan_collation(storage: &MockStorage, sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = translate(&parsed).unwrap();
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        plan(&schema_map, statement)
+    }
+
+    fn translated(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).unwrap()
+    }
+
+    #[test]
+    fn collate() {
+        let storage = run("
+            CREATE TABLE Foo (id INTEGER, name TEXT COLLATE NOCASE, code TEXT);
+            CREATE TABLE Bar (id INTEGER, name TEXT);
+        ");
+        let test = |sql, expected| assert_eq!(plan_collation(&storage, sql), translated(expected));
+
+        test(
+            "SELECT name FROM Foo WHERE name = 'a'",
+            "SELECT name FROM Foo WHERE name COLLATE NOCASE = 'a' COLLATE NOCASE",
+        );
+        test(
+            "SELECT * FROM Foo WHERE code = 'a' AND id > 1",
+            "SELECT * FROM Foo WHERE code = 'a' AND id > 1",
+        );
+        test(
+            "SELECT * FROM Foo WHERE 'a' = code COLLATE NOCASE",
+            "SELECT * FROM Foo WHERE 'a' COLLATE NOCASE = code COLLATE NOCASE",
+        );
+        test(
+            "SELECT * FROM Foo f WHERE f.name LIKE 'a%' OR f.name IN ('b', code)",
+            "SELECT * FROM Foo f WHERE f.name COLLATE NOCASE LIKE 'a%' COLLATE NOCASE
+                OR f.name COLLATE NOCASE IN ('b' COLLATE NOCASE, code COLLATE NOCASE)",
+        );
+        test(
+            "SELECT * FROM Foo ORDER BY name, code",
+            "SELECT * FROM Foo ORDER BY name COLLATE NOCASE, code",
+        );
+        test(
+            "SELECT * FROM Bar WHERE name = 'a'",
+            "SELECT * FROM Bar WHERE name = 'a'",
+        );
+
+        // the nearest table is found first
+        test(
+            "SELECT * FROM Foo WHERE EXISTS (SELECT * FROM Bar WHERE name = 'a')",
+            "SELECT * FROM Foo WHERE EXISTS (SELECT * FROM Bar WHERE name = 'a')",
+        );
+        test(
+            "SELECT * FROM Bar WHERE EXISTS (SELECT * FROM Foo WHERE Bar.name = 'a')",
+            "SELECT * FROM Bar WHERE EXISTS (SELECT * FROM Foo WHERE Bar.name = 'a')",
+        );
+
+        test(
+            "DELETE FROM Foo WHERE name = 'a'",
+            "DELETE FROM Foo WHERE name COLLATE NOCASE = 'a' COLLATE NOCASE",
+        );
+    }
+}
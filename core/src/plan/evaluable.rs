@@ -38,24 +38,38 @@ pub fn check_expr(context: Option<Rc<Context<'_>>>, expr: &Expr) -> bool {
     }
 }
 
+fn check_set_expr(context: Option<Rc<Context<'_>>>, set_expr: &SetExpr) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => check_select(context, select),
+        SetExpr::Values(Values(rows)) => rows
+            .iter()
+            .flatten()
+            .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
+            .all(identity),
+        SetExpr::SetOperation { left, right, .. } => {
+            check_set_expr(context.as_ref().map(Rc::clone), left) && check_set_expr(context, right)
+        }
+    }
+}
+
 fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
     let Query {
+        with,
         body,
         order_by,
         limit,
         offset,
     } = query;
 
-    let body = match body {
-        SetExpr::Select(select) => check_select(context.as_ref().map(Rc::clone), select),
-        SetExpr::Values(Values(rows)) => rows
-            .iter()
-            .flatten()
-            .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
-            .all(identity),
-    };
+    let with = with
+        .iter()
+        .flat_map(|with| &with.cte_tables)
+        .all(|cte| check_query(context.as_ref().map(Rc::clone), &cte.query));
+    if !with {
+        return false;
+    }
 
-    if !body {
+    if !check_set_expr(context.as_ref().map(Rc::clone), body) {
         return false;
     }
 
@@ -77,6 +91,7 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
 
 fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
     let Select {
+        distinct: _,
         projection,
         from,
         selection,
@@ -116,11 +131,15 @@ fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
 
             match join_operator {
                 JoinOperator::Inner(JoinConstraint::On(expr))
-                | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => {
                     check_expr(context.as_ref().map(Rc::clone), expr)
                 }
-                JoinOperator::Inner(JoinConstraint::None)
-                | JoinOperator::LeftOuter(JoinConstraint::None) => true,
+                JoinOperator::Inner(_)
+                | JoinOperator::LeftOuter(_)
+                | JoinOperator::RightOuter(_)
+                | JoinOperator::FullOuter(_) => true,
             }
         })
         .all(identity)
@@ -144,6 +163,8 @@ fn check_table_factor(context: Option<Rc<Context<'_>>>, table_factor: &TableFact
             .unwrap_or_else(|| name),
         TableFactor::Derived { alias, .. }
         | TableFactor::Series { alias, .. }
+        | TableFactor::Unnest { alias, .. }
+        | TableFactor::Function { alias, .. }
         | TableFactor::Dictionary { alias, .. } => &alias.name,
     };
 
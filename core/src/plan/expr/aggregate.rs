@@ -1,24 +1,66 @@
-use crate::ast::{Aggregate, CountArgExpr, Expr};
+use crate::ast::{Aggregate, AggregateFunction, CountArgExpr, Expr};
 
 impl Aggregate {
     pub fn as_expr(&self) -> Option<&Expr> {
-        match self {
-            Aggregate::Count(CountArgExpr::Wildcard) => None,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => Some(expr),
+        match &self.func {
+            AggregateFunction::Count(CountArgExpr::Wildcard)
+            | AggregateFunction::RowNumber
+            | AggregateFunction::Rank
+            | AggregateFunction::DenseRank => None,
+            AggregateFunction::Count(CountArgExpr::Expr(expr))
+            | AggregateFunction::Sum(expr)
+            | AggregateFunction::Max(expr)
+            | AggregateFunction::Min(expr)
+            | AggregateFunction::Avg(expr)
+            | AggregateFunction::Variance(expr)
+            | AggregateFunction::Stdev(expr)
+            | AggregateFunction::Lag { expr, .. }
+            | AggregateFunction::Lead { expr, .. } => Some(expr),
         }
     }
+
+    /// `ROW_NUMBER`, `RANK`, `DENSE_RANK`, `LAG` and `LEAD`, which cannot be used without `OVER`
+    pub fn is_window_function(&self) -> bool {
+        matches!(
+            self.func,
+            AggregateFunction::RowNumber
+                | AggregateFunction::Rank
+                | AggregateFunction::DenseRank
+                | AggregateFunction::Lag { .. }
+                | AggregateFunction::Lead { .. }
+        )
+    }
+
+    /// Every expression inside, including the offset and default of `LAG` and `LEAD` and
+    /// the expressions of `OVER`
+    pub fn exprs(&self) -> Vec<&Expr> {
+        let (offset, default) = match &self.func {
+            AggregateFunction::Lag {
+                offset, default, ..
+            }
+            | AggregateFunction::Lead {
+                offset, default, ..
+            } => (offset.as_ref(), default.as_ref()),
+            _ => (None, None),
+        };
+
+        self.as_expr()
+            .into_iter()
+            .chain(offset)
+            .chain(default)
+            .chain(self.over.iter().flat_map(|over| {
+                over.partition_by
+                    .iter()
+                    .chain(over.order_by.iter().map(|order_by| &order_by.expr))
+            }))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        ast::{Aggregate, Expr},
+        ast::{Aggregate, AstLiteral, Expr},
         parse_sql::parse_expr,
         translate::translate_expr,
     };
@@ -60,5 +102,39 @@ mod tests {
         let actual = parse("VARIANCE(id)");
         let expected = Expr::Identifier("id".to_owned());
         assert_eq!(actual.as_expr(), Some(&expected));
+
+        let actual = parse("COUNT(DISTINCT id)");
+        let expected = Expr::Identifier("id".to_owned());
+        assert_eq!(actual.as_expr(), Some(&expected));
+
+        assert_eq!(parse("ROW_NUMBER() OVER ()").as_expr(), None);
+
+        let actual = parse("LAG(id, 2) OVER (ORDER BY num)");
+        let expected = Expr::Identifier("id".to_owned());
+        assert_eq!(actual.as_expr(), Some(&expected));
+    }
+
+    #[test]
+    fn exprs() {
+        let ident = |name: &str| Expr::Identifier(name.to_owned());
+
+        assert_eq!(parse("COUNT(*)").exprs(), Vec::<&Expr>::new());
+        assert_eq!(parse("SUM(id)").exprs(), vec![&ident("id")]);
+        assert_eq!(
+            parse("SUM(id) OVER (PARTITION BY team ORDER BY num)").exprs(),
+            vec![&ident("id"), &ident("team"), &ident("num")]
+        );
+        assert_eq!(
+            parse("LEAD(id, num, 0) OVER ()").exprs(),
+            vec![
+                &ident("id"),
+                &ident("num"),
+                &Expr::Literal(AstLiteral::Number(0.into()))
+            ]
+        );
+        assert_eq!(
+            parse("RANK() OVER (ORDER BY num)").exprs(),
+            vec![&ident("num")]
+        );
     }
 }
@@ -17,9 +17,11 @@ impl Function {
         }
 
         match self {
-            Self::Now() | Function::Pi() | Function::GenerateUuid() | Self::Rand(None) => {
-                Exprs::Empty(empty())
-            }
+            Self::Now()
+            | Self::CurrentDate()
+            | Function::Pi()
+            | Function::GenerateUuid()
+            | Self::Rand(None) => Exprs::Empty(empty()),
             Self::Lower(expr)
             | Self::Length(expr)
             | Self::Initcap(expr)
@@ -46,6 +48,9 @@ impl Function {
             | Self::Ascii(expr)
             | Self::Chr(expr)
             | Self::Md5(expr)
+            | Self::Sha256(expr)
+            | Self::Hex(expr)
+            | Self::Base64(expr)
             | Self::LastDay(expr)
             | Self::Ltrim { expr, chars: None }
             | Self::Rtrim { expr, chars: None }
@@ -103,6 +108,14 @@ impl Function {
                 expr,
                 format: expr2,
             }
+            | Self::Encode {
+                expr,
+                format: expr2,
+            }
+            | Self::Decode {
+                expr,
+                format: expr2,
+            }
             | Self::ToDate {
                 expr,
                 format: expr2,
@@ -115,6 +128,10 @@ impl Function {
                 expr,
                 format: expr2,
             }
+            | Self::DateTrunc {
+                field: expr,
+                expr: expr2,
+            }
             | Self::Power { expr, power: expr2 }
             | Self::Ltrim {
                 expr,
@@ -131,6 +148,7 @@ impl Function {
                 count: None,
             }
             | Self::IfNull { expr, then: expr2 }
+            | Self::NullIf { expr, value: expr2 }
             | Self::Unwrap {
                 expr,
                 selector: expr2,
@@ -152,6 +170,13 @@ impl Function {
                 order: Some(expr2),
             }
             | Self::Take { expr, size: expr2 }
+            | Self::JsonExtract { expr, path: expr2 }
+            | Self::JsonValue { expr, path: expr2 }
+            | Self::RegexpMatches {
+                expr,
+                pattern: expr2,
+                flags: None,
+            }
             | Self::Point { x: expr, y: expr2 }
             | Self::CalcDistance {
                 geometry1: expr,
@@ -192,6 +217,17 @@ impl Function {
                 begin_index: expr2,
                 end_index: expr3,
                 values: None,
+            }
+            | Self::RegexpMatches {
+                expr,
+                pattern: expr2,
+                flags: Some(expr3),
+            }
+            | Self::RegexpReplace {
+                expr,
+                pattern: expr2,
+                replacement: expr3,
+                flags: None,
             } => Exprs::Triple([expr, expr2, expr3].into_iter()),
             Self::Custom { name: _, exprs } => Exprs::VariableArgs(exprs.iter()),
             Self::Coalesce(exprs) => Exprs::VariableArgs(exprs.iter()),
@@ -199,13 +235,19 @@ impl Function {
             Self::ConcatWs { separator, exprs } => {
                 Exprs::VariableArgsWithSingle(once(separator).chain(exprs.iter()))
             }
-            Self::Greatest(exprs) => Exprs::VariableArgs(exprs.iter()),
+            Self::Greatest(exprs) | Self::Least(exprs) => Exprs::VariableArgs(exprs.iter()),
             Self::Entries(expr) => Exprs::Single([expr].into_iter()),
             Self::Splice {
                 list_data: expr,
                 begin_index: expr2,
                 end_index: expr3,
                 values: Some(expr4),
+            }
+            | Self::RegexpReplace {
+                expr,
+                pattern: expr2,
+                replacement: expr3,
+                flags: Some(expr4),
             } => Exprs::Quadruple([expr, expr2, expr3, expr4].into_iter()),
         }
     }
@@ -312,6 +354,7 @@ mod tests {
         test("REPEAT(column, 2)", &["column", "2"]);
         test(r#"UNWRAP(field, "foo.1")"#, &["field", r#""foo.1""#]);
         test(r#"SKIP(list, 2)"#, &[r#""list""#, r#"2"#]);
+        test("REGEXP_MATCHES(line, 'a+')", &["line", "'a+'"]);
 
         // Triple
         test(
@@ -327,12 +370,18 @@ mod tests {
             &[r#"'   >++++("<   '"#, "3", "11"],
         );
         test(r#"SPLICE(list, 2, 4)"#, &["list", "2", "4"]);
+        test("REGEXP_MATCHES(line, 'a+', 'i')", &["line", "'a+'", "'i'"]);
+        test("REGEXP_REPLACE(line, 'a+', 'b')", &["line", "'a+'", "'b'"]);
 
         // Quadruple
         test(
             r#"SPLICE(list, 3, 5, values)"#,
             &["list", "3", "5", "values"],
         );
+        test(
+            "REGEXP_REPLACE(line, 'a+', 'b', 'g')",
+            &["line", "'a+'", "'b'", "'g'"],
+        );
 
         //VariableArgs
         test(r#"COALESCE("test")"#, &[r#""test""#]);
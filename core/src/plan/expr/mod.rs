@@ -31,7 +31,11 @@ impl<'a> From<&'a Expr> for PlanExpr<'a> {
             | Expr::UnaryOp { expr, .. }
             | Expr::IsNull(expr)
             | Expr::IsNotNull(expr)
-            | Expr::Interval { expr, .. } => PlanExpr::Expr(expr),
+            | Expr::Interval { expr, .. }
+            | Expr::Collate { expr, .. } => PlanExpr::Expr(expr),
+            Expr::Aggregate(aggregate) if aggregate.over.is_some() => {
+                PlanExpr::MultiExprs(aggregate.exprs())
+            }
             Expr::Aggregate(aggregate) => match aggregate.as_expr() {
                 Some(expr) => PlanExpr::Expr(expr),
                 None => PlanExpr::None,
@@ -69,12 +73,23 @@ impl<'a> From<&'a Expr> for PlanExpr<'a> {
                 let exprs = indexes.iter().chain(once(obj.as_ref())).collect();
                 PlanExpr::MultiExprs(exprs)
             }
+            Expr::Array { elem } => PlanExpr::MultiExprs(elem.iter().collect()),
             Expr::Function(function) => PlanExpr::MultiExprs(function.as_exprs().collect()),
             Expr::Subquery(subquery) | Expr::Exists { subquery, .. } => PlanExpr::Query(subquery),
             Expr::InSubquery {
                 expr,
                 subquery: query,
                 ..
+            }
+            | Expr::AnySubquery {
+                expr,
+                subquery: query,
+                ..
+            }
+            | Expr::AllSubquery {
+                expr,
+                subquery: query,
+                ..
             } => PlanExpr::QueryAndExpr { expr, query },
         }
     }
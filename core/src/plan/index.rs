@@ -1,8 +1,9 @@
 use {
     crate::{
         ast::{
-            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, OrderByExpr,
-            Query, Select, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, Join,
+            JoinOperator, OrderByExpr, Query, Select, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins,
         },
         data::{Schema, SchemaIndex, SchemaIndexOrd, TableError},
         result::{Error, Result},
@@ -36,6 +37,14 @@ impl Indexes {
                     return false;
                 }
 
+                let asc = target.asc.unwrap_or(true);
+                if target
+                    .nulls_first
+                    .map_or(false, |nulls_first| nulls_first == asc)
+                {
+                    return false;
+                }
+
                 matches!(
                     (target.asc, order),
                     (_, SchemaIndexOrd::Both)
@@ -50,6 +59,7 @@ impl Indexes {
 
 fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Query> {
     let Query {
+        with,
         body,
         order_by,
         limit,
@@ -60,19 +70,69 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         SetExpr::Select(select) => select,
         SetExpr::Values(_) => {
             return Ok(Query {
+                with,
                 body,
                 order_by,
                 limit,
                 offset,
             });
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let plan_branch = |body| {
+                let query = Query {
+                    with: None,
+                    body,
+                    order_by: Vec::new(),
+                    limit: None,
+                    offset: None,
+                };
+
+                plan_query(schema_map, query).map(|query| Box::new(query.body))
+            };
+
+            return Ok(Query {
+                with,
+                body: SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: plan_branch(*left)?,
+                    right: plan_branch(*right)?,
+                },
+                order_by,
+                limit,
+                offset,
+            });
+        }
     };
 
-    let TableWithJoins { relation, .. } = &select.from;
+    let TableWithJoins { relation, joins } = &select.from;
+    // RIGHT and FULL joins emit unmatched right rows after the left scan,
+    // so neither filtering nor ordering can be pushed into the left index.
+    if joins.iter().any(|Join { join_operator, .. }| {
+        matches!(
+            join_operator,
+            JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+        )
+    }) {
+        return Ok(Query {
+            with,
+            body: SetExpr::Select(select),
+            order_by,
+            limit,
+            offset,
+        });
+    }
+
     let table_name = match relation {
         TableFactor::Table { name, .. } => name,
         TableFactor::Derived { .. } => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -82,6 +142,14 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         TableFactor::Series {
             alias: TableAlias { name, .. },
             ..
+        }
+        | TableFactor::Unnest {
+            alias: TableAlias { name, .. },
+            ..
+        }
+        | TableFactor::Function {
+            alias: TableAlias { name, .. },
+            ..
         } => name,
         TableFactor::Dictionary {
             alias: TableAlias { name, .. },
@@ -93,6 +161,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         Some(Schema { indexes, .. }) => Indexes(indexes.clone()),
         None => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -114,6 +183,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
     match index {
         index if index.is_some() => {
             let Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -126,6 +196,8 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
                 TableFactor::Table { name, alias, .. } => (name, alias),
                 TableFactor::Derived { .. }
                 | TableFactor::Series { .. }
+                | TableFactor::Unnest { .. }
+                | TableFactor::Function { .. }
                 | TableFactor::Dictionary { .. } => {
                     return Err(Error::Table(TableError::Unreachable));
                 }
@@ -137,6 +209,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             let select = Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -145,6 +218,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             Ok(Query {
+                with,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vector::from(order_by).pop().0.into(),
                 limit,
@@ -155,6 +229,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             let select = plan_select(schema_map, &indexes, *select)?;
             let body = SetExpr::Select(Box::new(select));
             let query = Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -172,6 +247,7 @@ fn plan_select(
     select: Select,
 ) -> Result<Select> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
@@ -183,6 +259,7 @@ fn plan_select(
         Some(expr) => expr,
         None => {
             return Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -194,6 +271,7 @@ fn plan_select(
 
     match plan_index(schema_map, indexes, selection)? {
         Planned::Expr(selection) => Ok(Select {
+            distinct,
             projection,
             from,
             selection: Some(selection),
@@ -211,6 +289,8 @@ fn plan_select(
                 TableFactor::Table { name, alias, .. } => (name, alias),
                 TableFactor::Derived { .. }
                 | TableFactor::Series { .. }
+                | TableFactor::Unnest { .. }
+                | TableFactor::Function { .. }
                 | TableFactor::Dictionary { .. } => {
                     return Err(Error::Table(TableError::Unreachable));
                 }
@@ -227,6 +307,7 @@ fn plan_select(
             };
 
             Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -276,6 +357,14 @@ fn plan_index(
                 negated,
             })
             .map(Planned::Expr),
+        Expr::AnySubquery { expr, op, subquery } => plan_query(schema_map, *subquery)
+            .map(Box::new)
+            .map(|subquery| Expr::AnySubquery { expr, op, subquery })
+            .map(Planned::Expr),
+        Expr::AllSubquery { expr, op, subquery } => plan_query(schema_map, *subquery)
+            .map(Box::new)
+            .map(|subquery| Expr::AllSubquery { expr, op, subquery })
+            .map(Planned::Expr),
         Expr::BinaryOp {
             left,
             op: BinaryOperator::And,
@@ -362,6 +451,12 @@ fn plan_index(
             op: BinaryOperator::Eq,
             right,
         } => Ok(search_index_op(indexes, IndexOperator::Eq, left, right)),
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } => Ok(search_between(indexes, expr, low, high)),
         _ => Ok(Planned::Expr(selection)),
     }
 }
@@ -433,6 +528,34 @@ fn search_index_op(
     }
 }
 
+/// `expr BETWEEN low AND high` scans the index from `low` and keeps `expr <= high` as the
+/// remaining selection.
+fn search_between(indexes: &Indexes, expr: Box<Expr>, low: Box<Expr>, high: Box<Expr>) -> Planned {
+    match search_index_op(indexes, IndexOperator::GtEq, expr.clone(), low.clone()) {
+        Planned::IndexedExpr {
+            index_name,
+            index_op,
+            index_value_expr,
+            ..
+        } => Planned::IndexedExpr {
+            index_name,
+            index_op,
+            index_value_expr,
+            selection: Some(Expr::BinaryOp {
+                left: expr,
+                op: BinaryOperator::LtEq,
+                right: high,
+            }),
+        },
+        Planned::Expr(_) => Planned::Expr(Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        }),
+    }
+}
+
 fn is_stateless(expr: &Expr) -> bool {
     match expr {
         Expr::Literal(AstLiteral::Null) => false,
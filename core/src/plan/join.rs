@@ -3,7 +3,7 @@ use {
     crate::{
         ast::{
             BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
-            SetExpr, Statement, TableWithJoins,
+            SetExpr, Statement, TableFactor, TableWithJoins,
         },
         data::Schema,
     },
@@ -31,22 +31,17 @@ struct JoinPlanner<'a> {
 impl<'a> Planner<'a> for JoinPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
         let Query {
+            with,
             body,
             order_by,
             limit,
             offset,
         } = query;
 
-        let body = match body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => body,
-        };
+        let body = self.set_expr(outer_context, body);
 
         Query {
+            with,
             body,
             order_by,
             limit,
@@ -60,8 +55,36 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 }
 
 impl<'a> JoinPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left = self.set_expr(outer_context.as_ref().map(Rc::clone), *left);
+                let right = self.set_expr(outer_context, *right);
+
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+        }
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
         let Select {
+            distinct,
             projection,
             from,
             selection,
@@ -73,6 +96,7 @@ impl<'a> JoinPlanner<'a> {
         let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
 
         Select {
+            distinct,
             projection,
             from,
             selection,
@@ -116,7 +140,14 @@ impl<'a> JoinPlanner<'a> {
             join_executor,
         } = join;
 
-        if matches!(join_executor, JoinExecutor::Hash { .. }) {
+        // UNNEST and table functions may refer to the preceding tables, so they are fetched
+        // again for each joined row
+        if matches!(join_executor, JoinExecutor::Hash { .. })
+            || matches!(
+                relation,
+                TableFactor::Unnest { .. } | TableFactor::Function { .. }
+            )
+        {
             let context = self.update_context(inner_context, &relation);
             let join = Join {
                 relation,
@@ -130,13 +161,36 @@ impl<'a> JoinPlanner<'a> {
         enum JoinOp {
             Inner,
             LeftOuter,
+            RightOuter,
+            FullOuter,
         }
 
+        let merged_column = match &join_operator {
+            JoinOperator::Inner(join_constraint)
+            | JoinOperator::LeftOuter(join_constraint)
+            | JoinOperator::RightOuter(join_constraint)
+            | JoinOperator::FullOuter(join_constraint) => {
+                self.merged_column(inner_context.as_ref(), &relation, join_constraint)
+            }
+        };
+
         let (join_op, expr) = match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr)) => (JoinOp::Inner, expr),
             JoinOperator::LeftOuter(JoinConstraint::On(expr)) => (JoinOp::LeftOuter, expr),
-            JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => {
+            JoinOperator::RightOuter(JoinConstraint::On(expr)) => (JoinOp::RightOuter, expr),
+            JoinOperator::FullOuter(JoinConstraint::On(expr)) => (JoinOp::FullOuter, expr),
+            JoinOperator::Inner(_)
+            | JoinOperator::LeftOuter(_)
+            | JoinOperator::RightOuter(_)
+            | JoinOperator::FullOuter(_) => {
+                let join_executor = match merged_column {
+                    Some(column) => JoinExecutor::Hash {
+                        key_expr: Expr::Identifier(column.clone()),
+                        value_expr: Expr::Identifier(column),
+                        where_clause: None,
+                    },
+                    None => join_executor,
+                };
                 let context = self.update_context(inner_context, &relation);
                 let join = Join {
                     relation,
@@ -161,6 +215,10 @@ impl<'a> JoinPlanner<'a> {
             (JoinOp::Inner, None) => JoinOperator::Inner(JoinConstraint::None),
             (JoinOp::LeftOuter, Some(expr)) => JoinOperator::LeftOuter(JoinConstraint::On(expr)),
             (JoinOp::LeftOuter, None) => JoinOperator::LeftOuter(JoinConstraint::None),
+            (JoinOp::RightOuter, Some(expr)) => JoinOperator::RightOuter(JoinConstraint::On(expr)),
+            (JoinOp::RightOuter, None) => JoinOperator::RightOuter(JoinConstraint::None),
+            (JoinOp::FullOuter, Some(expr)) => JoinOperator::FullOuter(JoinConstraint::On(expr)),
+            (JoinOp::FullOuter, None) => JoinOperator::FullOuter(JoinConstraint::None),
         };
 
         let context = self.update_context(inner_context, &relation);
@@ -173,6 +231,36 @@ impl<'a> JoinPlanner<'a> {
         (context, join)
     }
 
+    /// Picks a column merged by `USING` or `NATURAL` to key the hash join with,
+    /// the executor still compares every merged column of the matched rows.
+    fn merged_column(
+        &self,
+        inner_context: Option<&Rc<Context<'a>>>,
+        relation: &TableFactor,
+        join_constraint: &JoinConstraint,
+    ) -> Option<String> {
+        let inner_context = inner_context?;
+        let current_context = self.update_context(None, relation)?;
+        let columns = match current_context.as_ref() {
+            Context::Data { columns, .. } => columns,
+            Context::Bridge { .. } => return None,
+        };
+        let is_shared = |column: &str| {
+            columns.iter().any(|c| *c == column) && inner_context.contains_column(column)
+        };
+
+        match join_constraint {
+            JoinConstraint::Using(using) => {
+                using.first().filter(|column| is_shared(column)).cloned()
+            }
+            JoinConstraint::Natural => columns
+                .iter()
+                .find(|column| inner_context.contains_column(column))
+                .map(|column| column.to_string()),
+            JoinConstraint::On(_) | JoinConstraint::None => None,
+        }
+    }
+
     fn join_expr(
         &self,
         outer_context: Option<Rc<Context<'a>>>,
@@ -368,6 +456,30 @@ impl<'a> JoinPlanner<'a> {
                 });
                 (JoinExecutor::NestedLoop, expr)
             }
+            Expr::AnySubquery { expr, op, subquery } => {
+                let context = Context::concat(current_context, inner_context);
+                let context = Context::concat(context, outer_context);
+
+                let subquery = self.query(context, *subquery);
+                let expr = Some(Expr::AnySubquery {
+                    expr,
+                    op,
+                    subquery: Box::new(subquery),
+                });
+                (JoinExecutor::NestedLoop, expr)
+            }
+            Expr::AllSubquery { expr, op, subquery } => {
+                let context = Context::concat(current_context, inner_context);
+                let context = Context::concat(context, outer_context);
+
+                let subquery = self.query(context, *subquery);
+                let expr = Some(Expr::AllSubquery {
+                    expr,
+                    op,
+                    subquery: Box::new(subquery),
+                });
+                (JoinExecutor::NestedLoop, expr)
+            }
             Expr::Exists { subquery, negated } => {
                 let context = Context::concat(current_context, inner_context);
                 let context = Context::concat(context, outer_context);
@@ -422,12 +534,15 @@ mod tests {
     use {
         super::plan,
         crate::{
-            ast::{DateTimeField, Statement},
+            ast::{
+                DateTimeField, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, SetExpr,
+                Statement, TableFactor,
+            },
             ast_builder::{col, exists, num, subquery, table, Build, QueryNode},
             mock::{run, MockStorage},
-            parse_sql::parse,
+            parse_sql::{parse, parse_expr},
             plan::fetch_schema_map,
-            translate::translate,
+            translate::{translate, translate_expr},
         },
         futures::executor::block_on,
     };
@@ -946,4 +1061,83 @@ mod tests {
         );
         test!(actual, expected, "case expr:\n{sql}");
     }
+
+    #[test]
+    fn hash_join_outer_and_merged_columns() {
+        let storage = run("
+            CREATE TABLE Player (
+                id INTEGER,
+                name TEXT
+            );
+            CREATE TABLE PlayerItem (
+                id INTEGER,
+                user_id INTEGER,
+                amount INTEGER
+            );
+        ");
+
+        let joins = |sql: &str| match plan_join(&storage, sql) {
+            Statement::Query(Query {
+                body: SetExpr::Select(select),
+                ..
+            }) => select.from.joins,
+            _ => unreachable!("{sql}"),
+        };
+        let expr = |sql: &str| translate_expr(&parse_expr(sql).expect(sql)).expect(sql);
+        let join = |join_operator, key_expr: &str, value_expr: &str| Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator,
+            join_executor: JoinExecutor::Hash {
+                key_expr: expr(key_expr),
+                value_expr: expr(value_expr),
+                where_clause: None,
+            },
+        };
+
+        let sql = "
+            SELECT * FROM Player
+            RIGHT JOIN PlayerItem ON PlayerItem.user_id = Player.id AND Player.name = 'a'
+        ";
+        let expected = join(
+            JoinOperator::RightOuter(JoinConstraint::On(expr("Player.name = 'a'"))),
+            "PlayerItem.user_id",
+            "Player.id",
+        );
+        assert_eq!(joins(sql), vec![expected], "right join:\n{sql}");
+
+        let sql = "SELECT * FROM Player FULL JOIN PlayerItem ON PlayerItem.user_id = Player.id";
+        let expected = join(
+            JoinOperator::FullOuter(JoinConstraint::None),
+            "PlayerItem.user_id",
+            "Player.id",
+        );
+        assert_eq!(joins(sql), vec![expected], "full join:\n{sql}");
+
+        let sql = "SELECT * FROM Player JOIN PlayerItem USING (id)";
+        let expected = join(
+            JoinOperator::Inner(JoinConstraint::Using(vec!["id".to_owned()])),
+            "id",
+            "id",
+        );
+        assert_eq!(joins(sql), vec![expected], "using:\n{sql}");
+
+        let sql = "SELECT * FROM Player JOIN PlayerItem USING (name)";
+        let expected = Join {
+            join_executor: JoinExecutor::NestedLoop,
+            ..join(
+                JoinOperator::Inner(JoinConstraint::Using(vec!["name".to_owned()])),
+                "name",
+                "name",
+            )
+        };
+        assert_eq!(joins(sql), vec![expected], "using unknown column:\n{sql}");
+
+        let sql = "SELECT * FROM Player NATURAL LEFT JOIN PlayerItem";
+        let expected = join(JoinOperator::LeftOuter(JoinConstraint::Natural), "id", "id");
+        assert_eq!(joins(sql), vec![expected], "natural join:\n{sql}");
+    }
 }
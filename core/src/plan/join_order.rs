@@ -0,0 +1,299 @@
+use {
+    super::expr::PlanExpr,
+    crate::{
+        ast::{
+            BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
+            SelectItem, SetExpr, Statement, TableFactor, TableWithJoins,
+        },
+        data::{Schema, TableStatistics},
+        result::Result,
+        store::Store,
+    },
+    std::collections::HashMap,
+};
+
+pub async fn fetch_statistics_map<T: Store + ?Sized>(
+    storage: &T,
+    schema_map: &HashMap<String, Schema>,
+) -> Result<HashMap<String, TableStatistics>> {
+    let mut statistics_map = HashMap::new();
+
+    for table_name in schema_map.keys() {
+        if let Some(statistics) = storage.fetch_statistics(table_name).await? {
+            statistics_map.insert(table_name.to_owned(), statistics);
+        }
+    }
+
+    Ok(statistics_map)
+}
+
+/// Reorders the inner joins of tables by the row counts collected by `ANALYZE`.
+///
+/// The largest table is read first and each of the following tables is the largest one
+/// which is joined to the tables read so far, so the smaller tables end up on the build
+/// side of hash joins. `ON` conditions are split by `AND` and each part is moved to the
+/// first join where every table it refers to is read.
+///
+/// Joins are kept as written unless every table of `FROM` has statistics and every join is
+/// an inner join with `ON` or without a condition. `SELECT *` is kept as well, since its
+/// columns follow the order of the tables.
+pub fn plan(
+    schema_map: &HashMap<String, Schema>,
+    statistics_map: &HashMap<String, TableStatistics>,
+    statement: Statement,
+) -> Statement {
+    let planner = JoinOrderPlanner {
+        schema_map,
+        statistics_map,
+    };
+
+    match statement {
+        Statement::Query(query) => Statement::Query(planner.query(query)),
+        _ => statement,
+    }
+}
+
+struct JoinOrderPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+    statistics_map: &'a HashMap<String, TableStatistics>,
+}
+
+struct Relation<'a> {
+    factor: &'a TableFactor,
+    alias: &'a str,
+    schema: &'a Schema,
+    row_count: usize,
+}
+
+impl<'a> JoinOrderPlanner<'a> {
+    fn query(&self, query: Query) -> Query {
+        let body = self.set_expr(query.body);
+
+        Query { body, ..query }
+    }
+
+    fn set_expr(&self, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let from = self.table_with_joins(&select);
+
+                match from {
+                    Some(from) => SetExpr::Select(Box::new(Select { from, ..*select })),
+                    None => SetExpr::Select(select),
+                }
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(*left)),
+                right: Box::new(self.set_expr(*right)),
+            },
+        }
+    }
+
+    /// Returns the reordered `FROM`, or `None` when the joins are kept as written.
+    fn table_with_joins(&self, select: &Select) -> Option<TableWithJoins> {
+        let TableWithJoins { relation, joins } = &select.from;
+
+        if joins.is_empty()
+            || select
+                .projection
+                .iter()
+                .any(|item| matches!(item, SelectItem::Wildcard))
+        {
+            return None;
+        }
+
+        let mut conditions = Vec::new();
+        let mut relations = vec![self.relation(relation)?];
+
+        for join in joins {
+            match join {
+                Join {
+                    relation,
+                    join_operator: JoinOperator::Inner(join_constraint),
+                    join_executor: JoinExecutor::NestedLoop,
+                } => {
+                    match join_constraint {
+                        JoinConstraint::On(expr) => split_and(expr, &mut conditions),
+                        JoinConstraint::None => {}
+                        JoinConstraint::Using(_) | JoinConstraint::Natural => return None,
+                    }
+
+                    relations.push(self.relation(relation)?);
+                }
+                _ => return None,
+            }
+        }
+
+        let conditions = conditions
+            .into_iter()
+            .map(|expr| {
+                let mut tables = Vec::new();
+
+                refer(&relations, expr, &mut tables).map(|_| (expr, tables))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let order = join_order(&relations, &conditions);
+        if order.iter().copied().eq(0..relations.len()) {
+            return None;
+        }
+
+        let mut attached = vec![false; conditions.len()];
+        let joins = order
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(position, &i)| {
+                let read = &order[..=position];
+                let expr = conditions
+                    .iter()
+                    .zip(attached.iter_mut())
+                    .filter(|((_, tables), attached)| {
+                        !**attached && tables.iter().all(|table| read.contains(table))
+                    })
+                    .map(|((expr, _), attached)| {
+                        *attached = true;
+
+                        (*expr).clone()
+                    })
+                    .reduce(|left, right| Expr::BinaryOp {
+                        left: Box::new(left),
+                        op: BinaryOperator::And,
+                        right: Box::new(right),
+                    });
+                let join_constraint = match expr {
+                    Some(expr) => JoinConstraint::On(expr),
+                    None => JoinConstraint::None,
+                };
+
+                Join {
+                    relation: relations[i].factor.clone(),
+                    join_operator: JoinOperator::Inner(join_constraint),
+                    join_executor: JoinExecutor::NestedLoop,
+                }
+            })
+            .collect();
+
+        Some(TableWithJoins {
+            relation: relations[order[0]].factor.clone(),
+            joins,
+        })
+    }
+
+    fn relation<'b>(&self, factor: &'b TableFactor) -> Option<Relation<'b>>
+    where
+        'a: 'b,
+    {
+        match factor {
+            TableFactor::Table {
+                name,
+                alias,
+                index: None,
+            } => Some(Relation {
+                factor,
+                alias: alias.as_ref().map_or(name, |alias| &alias.name),
+                schema: self.schema_map.get(name)?,
+                row_count: self.statistics_map.get(name)?.row_count,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn split_and<'a>(expr: &'a Expr, conditions: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_and(left, conditions);
+            split_and(right, conditions);
+        }
+        Expr::Nested(expr) => split_and(expr, conditions),
+        _ => conditions.push(expr),
+    }
+}
+
+/// Collects the positions of the tables which `expr` refers to, `None` is returned when
+/// one of the references cannot be resolved to a single table of `FROM`.
+fn refer(relations: &[Relation<'_>], expr: &Expr, tables: &mut Vec<usize>) -> Option<()> {
+    match PlanExpr::from(expr) {
+        PlanExpr::None => Some(()),
+        PlanExpr::Identifier(ident) => {
+            let mut found = relations
+                .iter()
+                .map(|relation| relation.schema.column_defs.as_deref())
+                .enumerate()
+                .filter_map(|(i, column_defs)| match column_defs {
+                    Some(column_defs) => column_defs
+                        .iter()
+                        .any(|column_def| column_def.name == ident)
+                        .then_some(Some(i)),
+                    None => Some(None),
+                });
+
+            match (found.next(), found.next()) {
+                (Some(Some(i)), None) => {
+                    tables.push(i);
+
+                    Some(())
+                }
+                _ => None,
+            }
+        }
+        PlanExpr::CompoundIdentifier { alias, .. } => {
+            let i = relations
+                .iter()
+                .position(|relation| relation.alias == alias)?;
+            tables.push(i);
+
+            Some(())
+        }
+        PlanExpr::Expr(expr) => refer(relations, expr, tables),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            refer(relations, expr, tables)?;
+            refer(relations, expr2, tables)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            refer(relations, expr, tables)?;
+            refer(relations, expr2, tables)?;
+            refer(relations, expr3, tables)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs
+            .into_iter()
+            .try_for_each(|expr| refer(relations, expr, tables)),
+        PlanExpr::Query(_) | PlanExpr::QueryAndExpr { .. } => None,
+    }
+}
+
+/// Starts from the largest table and picks the largest of the tables joined to the ones
+/// read so far, a table which is not joined to them is only picked when no other is left.
+fn join_order(relations: &[Relation<'_>], conditions: &[(&Expr, Vec<usize>)]) -> Vec<usize> {
+    let mut remaining = (0..relations.len()).collect::<Vec<_>>();
+    remaining.sort_by(|a, b| relations[*b].row_count.cmp(&relations[*a].row_count));
+
+    let mut order = vec![remaining.remove(0)];
+    while !remaining.is_empty() {
+        let joined = remaining.iter().position(|i| {
+            conditions.iter().any(|(_, tables)| {
+                tables.contains(i)
+                    && tables
+                        .iter()
+                        .any(|table| table != i && order.contains(table))
+            })
+        });
+
+        order.push(remaining.remove(joined.unwrap_or(0)));
+    }
+
+    order
+}
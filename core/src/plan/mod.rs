@@ -1,24 +1,71 @@
+mod alias;
+mod collation;
 mod context;
 mod error;
 mod evaluable;
 mod expr;
 mod index;
 mod join;
+mod join_order;
 mod planner;
 mod primary_key;
 mod schema;
 mod validate;
+mod view;
 
 use crate::{ast::Statement, result::Result, store::Store};
 
+pub(crate) use {context::Context, evaluable::check_expr as check_evaluable, expr::PlanExpr};
+
 pub use {
-    self::validate::validate, error::*, index::plan as plan_index, join::plan as plan_join,
-    primary_key::plan as plan_primary_key, schema::fetch_schema_map,
+    self::validate::validate,
+    alias::plan as plan_alias,
+    collation::plan as plan_collation,
+    error::*,
+    index::plan as plan_index,
+    join::plan as plan_join,
+    join_order::{fetch_statistics_map, plan as plan_join_order},
+    primary_key::plan as plan_primary_key,
+    schema::fetch_schema_map,
+    view::{fetch_view_map, plan as plan_view},
 };
 
 pub async fn plan<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
+    match statement {
+        Statement::QueryForUpdate { query, nowait } => {
+            plan_statement(storage, Statement::Query(query)).await.map(
+                |statement| match statement {
+                    Statement::Query(query) => Statement::QueryForUpdate { query, nowait },
+                    statement => statement,
+                },
+            )
+        }
+        Statement::Explain(query) => plan_statement(storage, Statement::Query(query)).await.map(
+            |statement| match statement {
+                Statement::Query(query) => Statement::Explain(query),
+                statement => statement,
+            },
+        ),
+        statement => plan_statement(storage, statement).await,
+    }
+}
+
+async fn plan_statement<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
     let schema_map = fetch_schema_map(storage, &statement).await?;
+    let view_map = fetch_view_map(storage, &schema_map).await?;
+    let (schema_map, statement) = match view_map.is_empty() {
+        true => (schema_map, statement),
+        false => {
+            let statement = plan_view(&view_map, statement);
+
+            (fetch_schema_map(storage, &statement).await?, statement)
+        }
+    };
     validate(&schema_map, &statement)?;
+    let statement = plan_alias(&schema_map, statement);
+    let statement = plan_collation(&schema_map, statement);
+    let statistics_map = fetch_statistics_map(storage, &schema_map).await?;
+    let statement = plan_join_order(&schema_map, &statistics_map, statement);
     let statement = plan_primary_key(&schema_map, statement);
     let statement = plan_index(&schema_map, statement)?;
     let statement = plan_join(&schema_map, statement);
@@ -59,6 +59,20 @@ pub trait Planner<'a> {
                     negated,
                 }
             }
+            Expr::AnySubquery { expr, op, subquery } => {
+                let expr =
+                    Box::new(self.subquery_expr(outer_context.as_ref().map(Rc::clone), *expr));
+                let subquery = Box::new(self.query(outer_context, *subquery));
+
+                Expr::AnySubquery { expr, op, subquery }
+            }
+            Expr::AllSubquery { expr, op, subquery } => {
+                let expr =
+                    Box::new(self.subquery_expr(outer_context.as_ref().map(Rc::clone), *expr));
+                let subquery = Box::new(self.query(outer_context, *subquery));
+
+                Expr::AllSubquery { expr, op, subquery }
+            }
             Expr::Between {
                 expr,
                 negated,
@@ -119,6 +133,10 @@ pub trait Planner<'a> {
                 expr: Box::new(self.subquery_expr(outer_context, *expr)),
             },
             Expr::Nested(expr) => Expr::Nested(Box::new(self.subquery_expr(outer_context, *expr))),
+            Expr::Collate { expr, collation } => Expr::Collate {
+                expr: Box::new(self.subquery_expr(outer_context, *expr)),
+                collation,
+            },
             Expr::Case {
                 operand,
                 when_then,
@@ -153,6 +171,12 @@ pub trait Planner<'a> {
                 let obj = Box::new(self.subquery_expr(outer_context, *obj));
                 Expr::ArrayIndex { obj, indexes }
             }
+            Expr::Array { elem } => Expr::Array {
+                elem: elem
+                    .into_iter()
+                    .map(|expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr))
+                    .collect(),
+            },
             Expr::Interval {
                 expr,
                 leading_field,
@@ -190,6 +214,8 @@ pub trait Planner<'a> {
             }
             TableFactor::Derived { .. }
             | TableFactor::Series { .. }
+            | TableFactor::Unnest { .. }
+            | TableFactor::Function { .. }
             | TableFactor::Dictionary { .. } => return next,
         };
 
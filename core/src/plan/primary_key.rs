@@ -2,8 +2,8 @@ use {
     super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
     crate::{
         ast::{
-            BinaryOperator, Expr, IndexItem, Query, Select, SetExpr, Statement, TableFactor,
-            TableWithJoins,
+            BinaryOperator, Expr, IndexItem, Join, JoinOperator, Query, Select, SetExpr, Statement,
+            TableFactor, TableWithJoins,
         },
         data::Schema,
     },
@@ -29,14 +29,7 @@ struct PrimaryKeyPlanner<'a> {
 
 impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
-        let body = match query.body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => query.body,
-        };
+        let body = self.set_expr(outer_context, query.body);
 
         Query { body, ..query }
     }
@@ -55,19 +48,83 @@ enum PrimaryKey {
 }
 
 impl<'a> PrimaryKeyPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left = self.set_expr(outer_context.as_ref().map(Rc::clone), *left);
+                let right = self.set_expr(outer_context, *right);
+
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+        }
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
-        let current_context = self.update_context(None, &select.from.relation);
+        // Unmatched right rows of RIGHT and FULL joins are not filtered by a key lookup
+        // on the left table, so the selection has to stay in the WHERE clause.
+        if select.from.joins.iter().any(|Join { join_operator, .. }| {
+            matches!(
+                join_operator,
+                JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+            )
+        }) {
+            return select;
+        }
+
+        let from_context = self.update_context(None, &select.from.relation);
+        let joined_context = select.from.joins.iter().fold(None, |context, join| {
+            self.update_context(context, &join.relation)
+        });
         let current_context = select
             .from
             .joins
             .iter()
-            .fold(current_context, |context, join| {
+            .fold(from_context.as_ref().map(Rc::clone), |context, join| {
                 self.update_context(context, &join.relation)
             });
 
+        // Key lookup is only applied to the FROM relation, so the key has to refer to it
+        // and not to a joined table which may share the name of the column or the table.
+        let check_primary_key = |key: &Expr| {
+            let from_context = match from_context.as_ref() {
+                Some(from_context) => from_context,
+                None => return false,
+            };
+
+            match key {
+                Expr::Identifier(ident) => {
+                    from_context.contains_primary_key(ident)
+                        && !joined_context
+                            .as_ref()
+                            .map(|context| context.contains_column(ident))
+                            .unwrap_or(false)
+                }
+                Expr::CompoundIdentifier { alias, ident } => {
+                    from_context.contains_alias(alias) && from_context.contains_primary_key(ident)
+                }
+                _ => false,
+            }
+        };
+
         let (index, selection) = select
             .selection
-            .map(|expr| self.expr(outer_context, current_context, expr))
+            .map(|expr| self.expr(outer_context, current_context, &check_primary_key, expr))
             .map(|primary_key| match primary_key {
                 PrimaryKey::Found { index_item, expr } => (Some(index_item), expr),
                 PrimaryKey::NotFound(expr) => (None, Some(expr)),
@@ -102,21 +159,9 @@ impl<'a> PrimaryKeyPlanner<'a> {
         &self,
         outer_context: Option<Rc<Context<'a>>>,
         current_context: Option<Rc<Context<'a>>>,
+        check_primary_key: &impl Fn(&Expr) -> bool,
         expr: Expr,
     ) -> PrimaryKey {
-        let check_primary_key = |key: &Expr| {
-            let key = match key {
-                Expr::Identifier(ident) => ident,
-                Expr::CompoundIdentifier { ident, .. } => ident,
-                _ => return false,
-            };
-
-            current_context
-                .as_ref()
-                .map(|context| context.contains_primary_key(key))
-                .unwrap_or(false)
-        };
-
         match expr {
             Expr::BinaryOp {
                 left: key,
@@ -146,6 +191,7 @@ impl<'a> PrimaryKeyPlanner<'a> {
                 let primary_key = self.expr(
                     outer_context.as_ref().map(Rc::clone),
                     current_context.as_ref().map(Rc::clone),
+                    check_primary_key,
                     *left,
                 );
 
@@ -168,7 +214,7 @@ impl<'a> PrimaryKeyPlanner<'a> {
                     PrimaryKey::NotFound(expr) => expr,
                 };
 
-                match self.expr(outer_context, current_context, *right) {
+                match self.expr(outer_context, current_context, check_primary_key, *right) {
                     PrimaryKey::Found { index_item, expr } => {
                         let expr = match expr {
                             Some(right) => Expr::BinaryOp {
@@ -195,14 +241,18 @@ impl<'a> PrimaryKeyPlanner<'a> {
                     }
                 }
             }
-            Expr::Nested(expr) => match self.expr(outer_context, current_context, *expr) {
-                PrimaryKey::Found { index_item, expr } => {
-                    let expr = expr.map(Box::new).map(Expr::Nested);
+            Expr::Nested(expr) => {
+                match self.expr(outer_context, current_context, check_primary_key, *expr) {
+                    PrimaryKey::Found { index_item, expr } => {
+                        let expr = expr.map(Box::new).map(Expr::Nested);
 
-                    PrimaryKey::Found { index_item, expr }
+                        PrimaryKey::Found { index_item, expr }
+                    }
+                    PrimaryKey::NotFound(expr) => {
+                        PrimaryKey::NotFound(Expr::Nested(Box::new(expr)))
+                    }
                 }
-                PrimaryKey::NotFound(expr) => PrimaryKey::NotFound(Expr::Nested(Box::new(expr))),
-            },
+            }
             _ => {
                 let outer_context = Context::concat(current_context, outer_context);
                 let expr = self.subquery_expr(outer_context, expr);
@@ -220,8 +270,8 @@ mod tests {
         crate::{
             ast::{
                 AstLiteral, BinaryOperator, Expr, IndexItem, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
-                TableWithJoins, Values,
+                JoinOperator, Query, Select, SelectItem, SetExpr, Statement, TableAlias,
+                TableFactor, TableWithJoins, Values,
             },
             mock::{run, MockStorage},
             parse_sql::{parse, parse_expr},
@@ -241,6 +291,7 @@ mod tests {
 
     fn select(select: Select) -> Statement {
         Statement::Query(Query {
+            with: None,
             body: SetExpr::Select(Box::new(select)),
             limit: None,
             offset: None,
@@ -266,6 +317,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE id = 1;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -284,6 +336,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE 1 = id;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -302,6 +355,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE id = 1 AND True;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -326,6 +380,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -359,6 +414,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -391,6 +447,7 @@ mod tests {
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = 1";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -417,6 +474,7 @@ mod tests {
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = Badge.user_id";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -440,6 +498,53 @@ mod tests {
         });
         assert_eq!(actual, expected, "join but no primary key:\n{sql}");
 
+        let self_join = |index, selection| {
+            select(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Player".to_owned(),
+                        alias: Some(TableAlias {
+                            name: "a".to_owned(),
+                            columns: Vec::new(),
+                        }),
+                        index,
+                    },
+                    joins: vec![Join {
+                        relation: TableFactor::Table {
+                            name: "Player".to_owned(),
+                            alias: Some(TableAlias {
+                                name: "b".to_owned(),
+                                columns: Vec::new(),
+                            }),
+                            index: None,
+                        },
+                        join_operator: JoinOperator::Inner(JoinConstraint::None),
+                        join_executor: JoinExecutor::NestedLoop,
+                    }],
+                },
+                selection,
+                group_by: Vec::new(),
+                having: None,
+            })
+        };
+
+        let sql = "SELECT * FROM Player a JOIN Player b WHERE a.id = 1";
+        let actual = plan(&storage, sql);
+        let expected = self_join(Some(IndexItem::PrimaryKey(expr("1"))), None);
+        assert_eq!(actual, expected, "self join on from alias:\n{sql}");
+
+        let sql = "SELECT * FROM Player a JOIN Player b WHERE b.id = 1";
+        let actual = plan(&storage, sql);
+        let expected = self_join(None, Some(expr("b.id = 1")));
+        assert_eq!(actual, expected, "self join on joined alias:\n{sql}");
+
+        let sql = "SELECT * FROM Player a JOIN Player b WHERE id = 1";
+        let actual = plan(&storage, sql);
+        let expected = self_join(None, Some(expr("id = 1")));
+        assert_eq!(actual, expected, "self join on ambiguous column:\n{sql}");
+
         let sql = "
             SELECT * FROM Player
             WHERE name IN (
@@ -448,7 +553,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -468,6 +575,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -502,7 +610,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("name".to_owned()),
                         label: "name".to_owned(),
@@ -525,6 +635,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -553,7 +664,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("id".to_owned()),
                         label: "id".to_owned(),
@@ -576,6 +689,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -605,12 +719,16 @@ mod tests {
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::Number(1.into()))),
             }),
+            order_by: Vec::new(),
+            limit: None,
+            returning: Vec::new(),
         };
         assert_eq!(actual, expected, "delete statement:\n{sql}");
 
         let sql = "VALUES (1), (2);";
         let actual = plan(&storage, sql);
         let expected = Statement::Query(Query {
+            with: None,
             body: SetExpr::Values(Values(vec![
                 vec![Expr::Literal(AstLiteral::Number(1.into()))],
                 vec![Expr::Literal(AstLiteral::Number(2.into()))],
@@ -624,6 +742,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE (name);";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
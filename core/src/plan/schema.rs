@@ -63,18 +63,20 @@ pub async fn fetch_schema_map<T: Store>(
     }
 }
 
-async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<String, Schema>> {
+#[async_recursion(?Send)]
+pub(super) async fn scan_query<T: Store>(
+    storage: &T,
+    query: &Query,
+) -> Result<HashMap<String, Schema>> {
     let Query {
+        with,
         body,
         limit,
         offset,
         ..
     } = query;
 
-    let schema_list = match body {
-        SetExpr::Select(select) => scan_select(storage, select).await?,
-        SetExpr::Values(_) => HashMap::new(),
-    };
+    let schema_list = scan_set_expr(storage, body).await?;
 
     let schema_list = match (limit, offset) {
         (Some(limit), Some(offset)) => schema_list
@@ -89,11 +91,43 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
         (None, None) => schema_list,
     };
 
+    let cte_tables = with.iter().flat_map(|with| &with.cte_tables);
+    let mut schema_list = stream::iter(cte_tables.clone())
+        .then(|cte| scan_query(storage, &cte.query))
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .flatten()
+        .chain(schema_list)
+        .collect::<HashMap<_, _>>();
+
+    // CTE names shadow stored tables, so their schemas must not guide planning
+    for cte in cte_tables {
+        schema_list.remove(&cte.alias.name);
+    }
+
     Ok(schema_list)
 }
 
+#[async_recursion(?Send)]
+async fn scan_set_expr<T: Store>(
+    storage: &T,
+    set_expr: &SetExpr,
+) -> Result<HashMap<String, Schema>> {
+    match set_expr {
+        SetExpr::Select(select) => scan_select(storage, select).await,
+        SetExpr::Values(_) => Ok(HashMap::new()),
+        SetExpr::SetOperation { left, right, .. } => Ok(scan_set_expr(storage, left)
+            .await?
+            .into_iter()
+            .chain(scan_set_expr(storage, right).await?)
+            .collect()),
+    }
+}
+
 async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<String, Schema>> {
     let Select {
+        distinct: _,
         projection,
         from,
         selection,
@@ -155,13 +189,17 @@ async fn scan_join<T: Store>(storage: &T, join: &Join) -> Result<HashMap<String,
     let schema_list = scan_table_factor(storage, relation).await?;
     let schema_list = match join_operator {
         JoinOperator::Inner(JoinConstraint::On(expr))
-        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
             .await?
             .into_iter()
             .chain(schema_list)
             .collect(),
-        JoinOperator::Inner(JoinConstraint::None)
-        | JoinOperator::LeftOuter(JoinConstraint::None) => schema_list,
+        JoinOperator::Inner(_)
+        | JoinOperator::LeftOuter(_)
+        | JoinOperator::RightOuter(_)
+        | JoinOperator::FullOuter(_) => schema_list,
     };
 
     Ok(schema_list)
@@ -182,6 +220,14 @@ async fn scan_table_factor<T: Store>(
             Ok(schema_list)
         }
         TableFactor::Derived { subquery, .. } => scan_query(storage, subquery).await,
+        TableFactor::Unnest { expr, .. } => scan_expr(storage, expr).await,
+        TableFactor::Function { args, .. } => Ok(stream::iter(args)
+            .then(|expr| scan_expr(storage, expr))
+            .try_collect::<Vec<HashMap<String, Schema>>>()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect()),
         TableFactor::Series { .. } | TableFactor::Dictionary { .. } => Ok(HashMap::new()),
     }
 }
@@ -1,7 +1,10 @@
 use {
     super::PlanError,
     crate::{
-        ast::{Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins},
+        ast::{
+            Expr, Join, JoinConstraint, JoinOperator, Query, SelectItem, SetExpr, Statement,
+            TableFactor, TableWithJoins,
+        },
         data::Schema,
         result::Result,
     },
@@ -65,6 +68,44 @@ impl<'a> Context<'a> {
         }
     }
 
+    fn contains(&self, column_name: &str) -> bool {
+        match self {
+            Context::Data { labels, next } => {
+                labels
+                    .as_ref()
+                    .map(|labels| labels.contains(&column_name))
+                    .unwrap_or(false)
+                    || next
+                        .as_ref()
+                        .map(|next| next.contains(column_name))
+                        .unwrap_or(false)
+            }
+            Context::Bridge { left, right } => {
+                left.contains(column_name) || right.contains(column_name)
+            }
+        }
+    }
+
+    /// Drops the columns which `USING` or `NATURAL` merged into the left side of a join.
+    fn exclude(&self, merged: &impl Fn(&str) -> bool) -> Self {
+        match self {
+            Context::Data { labels, next } => Context::Data {
+                labels: labels.as_ref().map(|labels| {
+                    labels
+                        .iter()
+                        .copied()
+                        .filter(|label| !merged(label))
+                        .collect()
+                }),
+                next: next.as_ref().map(|next| Rc::new(next.exclude(merged))),
+            },
+            Context::Bridge { left, right } => Context::Bridge {
+                left: Rc::new(left.exclude(merged)),
+                right: Rc::new(right.exclude(merged)),
+            },
+        }
+    }
+
     fn validate_duplicated(&self, column_name: &str) -> Result<()> {
         fn validate(context: &Context, column_name: &str) -> Result<bool> {
             let (left, right) = match context {
@@ -115,14 +156,44 @@ fn contextualize_query<'a>(schema_map: &'a SchemaMap, query: &'a Query) -> Optio
         SetExpr::Select(select) => {
             let TableWithJoins { relation, joins } = &select.from;
             let by_table = contextualize_table_factor(schema_map, relation);
-            let by_joins = joins
-                .iter()
-                .map(|Join { relation, .. }| contextualize_table_factor(schema_map, relation))
-                .fold(None, Context::concat);
 
-            Context::concat(by_table, by_joins)
+            joins.iter().fold(
+                by_table,
+                |context,
+                 Join {
+                     relation,
+                     join_operator,
+                     ..
+                 }| {
+                    let join_constraint = match join_operator {
+                        JoinOperator::Inner(join_constraint)
+                        | JoinOperator::LeftOuter(join_constraint)
+                        | JoinOperator::RightOuter(join_constraint)
+                        | JoinOperator::FullOuter(join_constraint) => join_constraint,
+                    };
+                    let by_join = contextualize_table_factor(schema_map, relation);
+                    let by_join = match join_constraint {
+                        JoinConstraint::Using(columns) => by_join.map(|by_join| {
+                            Rc::new(by_join.exclude(&|label: &str| {
+                                columns.iter().any(|column| column == label)
+                            }))
+                        }),
+                        JoinConstraint::Natural => by_join.map(|by_join| {
+                            Rc::new(by_join.exclude(&|label: &str| {
+                                context
+                                    .as_ref()
+                                    .map(|context| context.contains(label))
+                                    .unwrap_or(false)
+                            }))
+                        }),
+                        JoinConstraint::On(_) | JoinConstraint::None => by_join,
+                    };
+
+                    Context::concat(context, by_join)
+                },
+            )
         }
-        SetExpr::Values(_) => None,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } => None,
     }
 }
 
@@ -136,7 +207,10 @@ fn contextualize_table_factor<'a>(
             schema.map(|schema| Rc::from(Context::new(get_labels(schema), None)))
         }
         TableFactor::Derived { subquery, .. } => contextualize_query(schema_map, subquery),
-        TableFactor::Series { .. } | TableFactor::Dictionary { .. } => None,
+        TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Function { .. }
+        | TableFactor::Dictionary { .. } => None,
     }
     .map(Rc::from)
 }
@@ -165,6 +239,10 @@ mod tests {
             ("SELECT * FROM (SELECT * FROM Users) AS Sub", true),
             ("SELECT * FROM SERIES(3)", true),
             ("SELECT id FROM Users A JOIN Users B on A.id = B.id", false),
+            ("SELECT id FROM Users A JOIN Users B USING (id)", true),
+            ("SELECT id FROM Users A JOIN Users B USING (name)", false),
+            ("SELECT id FROM Users A JOIN Users B USING (id, name)", true),
+            ("SELECT id FROM Users A NATURAL JOIN Users B", true),
             (
                 "INSERT INTO Users SELECT id FROM Users A JOIN Users B on A.id = B.id",
                 false,
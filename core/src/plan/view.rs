@@ -0,0 +1,345 @@
+use {
+    super::{context::Context, planner::Planner, schema::scan_query},
+    crate::{
+        ast::{
+            Cte, Join, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableAlias,
+            TableFactor, TableWithJoins, Values, With,
+        },
+        data::{Schema, SchemaView},
+        result::Result,
+        store::Store,
+    },
+    std::{collections::HashMap, rc::Rc},
+};
+
+/// Collects the stored queries of the views among the given schemas, including the views
+/// referred to by those queries in turn. Materialized views are scanned like tables.
+pub async fn fetch_view_map<T: Store>(
+    storage: &T,
+    schema_map: &HashMap<String, Schema>,
+) -> Result<HashMap<String, Query>> {
+    let mut schemas = schema_map.values().cloned().collect::<Vec<_>>();
+    let mut view_map = HashMap::new();
+
+    while let Some(Schema {
+        table_name, view, ..
+    }) = schemas.pop()
+    {
+        let query = match view {
+            Some(SchemaView {
+                query,
+                materialized: false,
+            }) if !view_map.contains_key(&table_name) => query,
+            _ => continue,
+        };
+
+        schemas.extend(scan_query(storage, &query).await?.into_values());
+        view_map.insert(table_name, query);
+    }
+
+    Ok(view_map)
+}
+
+/// Replaces every view in `FROM` with its stored query as a derived table named after the
+/// view, so the later planners and the executor only ever see tables.
+pub fn plan(view_map: &HashMap<String, Query>, statement: Statement) -> Statement {
+    let planner = ViewPlanner {
+        view_map: view_map
+            .iter()
+            .map(|(name, query)| (name.as_str(), query))
+            .collect(),
+    };
+
+    match statement {
+        Statement::Query(query) => Statement::Query(planner.query(None, query)),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            on_conflict,
+            returning,
+        } => Statement::Insert {
+            table_name,
+            columns,
+            source: planner.query(None, source),
+            on_conflict,
+            returning,
+        },
+        Statement::CreateTable {
+            if_not_exists,
+            temporary,
+            name,
+            columns,
+            source,
+            engine,
+            foreign_keys,
+            checks,
+        } => Statement::CreateTable {
+            if_not_exists,
+            temporary,
+            name,
+            columns,
+            source: source.map(|query| Box::new(planner.query(None, *query))),
+            engine,
+            foreign_keys,
+            checks,
+        },
+        _ => statement,
+    }
+}
+
+struct ViewPlanner<'b> {
+    view_map: HashMap<&'b str, &'b Query>,
+}
+
+impl<'a, 'b> Planner<'a> for ViewPlanner<'b> {
+    fn query(&self, _: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        // CTE names shadow views of the same name
+        let cte_names = with
+            .iter()
+            .flat_map(|with| &with.cte_tables)
+            .map(|cte| cte.alias.name.as_str())
+            .collect::<Vec<_>>();
+        let planner = self.exclude(&cte_names);
+
+        let with = with.map(|with| {
+            let cte_tables = with
+                .cte_tables
+                .into_iter()
+                .map(|cte| Cte {
+                    query: planner.query(None, cte.query),
+                    ..cte
+                })
+                .collect();
+
+            With { cte_tables, ..with }
+        });
+        let body = planner.set_expr(body);
+        let order_by = order_by
+            .into_iter()
+            .map(|order_by_expr| OrderByExpr {
+                expr: planner.subquery_expr(None, order_by_expr.expr),
+                ..order_by_expr
+            })
+            .collect();
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, _: &str) -> Option<&'a Schema> {
+        None
+    }
+}
+
+impl<'b> ViewPlanner<'b> {
+    fn exclude(&self, names: &[&str]) -> ViewPlanner<'b> {
+        let view_map = self
+            .view_map
+            .iter()
+            .filter(|(name, _)| !names.iter().any(|excluded| excluded == *name))
+            .map(|(name, query)| (*name, *query))
+            .collect();
+
+        ViewPlanner { view_map }
+    }
+
+    fn set_expr(&self, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => SetExpr::Select(Box::new(self.select(*select))),
+            SetExpr::Values(Values(rows)) => {
+                let rows = rows
+                    .into_iter()
+                    .map(|exprs| {
+                        exprs
+                            .into_iter()
+                            .map(|expr| self.subquery_expr(None, expr))
+                            .collect()
+                    })
+                    .collect();
+
+                SetExpr::Values(Values(rows))
+            }
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(*left)),
+                right: Box::new(self.set_expr(*right)),
+            },
+        }
+    }
+
+    fn select(&self, select: Select) -> Select {
+        let Select {
+            distinct,
+            projection,
+            from: TableWithJoins { relation, joins },
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let projection = projection
+            .into_iter()
+            .map(|select_item| match select_item {
+                SelectItem::Expr { expr, label } => SelectItem::Expr {
+                    expr: self.subquery_expr(None, expr),
+                    label,
+                },
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => select_item,
+            })
+            .collect();
+        let from = TableWithJoins {
+            relation: self.table_factor(relation),
+            joins: joins
+                .into_iter()
+                .map(|join| Join {
+                    relation: self.table_factor(join.relation),
+                    ..join
+                })
+                .collect(),
+        };
+        let selection = selection.map(|expr| self.subquery_expr(None, expr));
+        let group_by = group_by
+            .into_iter()
+            .map(|expr| self.subquery_expr(None, expr))
+            .collect();
+        let having = having.map(|expr| self.subquery_expr(None, expr));
+
+        Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        match table_factor {
+            TableFactor::Table { name, alias, index } => {
+                let query = match self.view_map.get(name.as_str()) {
+                    Some(query) => Query::clone(query),
+                    None => return TableFactor::Table { name, alias, index },
+                };
+
+                // A view is not expanded again inside its own query
+                let subquery = self.exclude(&[name.as_str()]).query(None, query);
+                let alias = alias.unwrap_or(TableAlias {
+                    name,
+                    columns: Vec::new(),
+                });
+
+                TableFactor::Derived { subquery, alias }
+            }
+            TableFactor::Derived { subquery, alias } => TableFactor::Derived {
+                subquery: self.query(None, subquery),
+                alias,
+            },
+            TableFactor::Unnest { alias, expr } => TableFactor::Unnest {
+                alias,
+                expr: self.subquery_expr(None, expr),
+            },
+            TableFactor::Function { name, args, alias } => TableFactor::Function {
+                name,
+                args: args
+                    .into_iter()
+                    .map(|expr| self.subquery_expr(None, expr))
+                    .collect(),
+                alias,
+            },
+            TableFactor::Series { .. } | TableFactor::Dictionary { .. } => table_factor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{fetch_view_map, plan},
+        crate::{
+            ast::Statement,
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::fetch_schema_map,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn plan_view(storage: &MockStorage, sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = translate(&parsed).unwrap();
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+        let view_map = block_on(fetch_view_map(storage, &schema_map)).unwrap();
+
+        plan(&view_map, statement)
+    }
+
+    fn translated(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).unwrap()
+    }
+
+    #[test]
+    fn expand() {
+        let storage = run("
+            CREATE TABLE Foo (id INTEGER, name TEXT);
+            CREATE VIEW Bar AS SELECT id FROM Foo WHERE id > 1;
+            CREATE VIEW Baz AS SELECT * FROM Bar;
+        ");
+        let test = |sql, expected| assert_eq!(plan_view(&storage, sql), translated(expected));
+
+        test("SELECT * FROM Foo", "SELECT * FROM Foo");
+        test(
+            "SELECT * FROM Bar",
+            "SELECT * FROM (SELECT id FROM Foo WHERE id > 1) AS Bar",
+        );
+        test(
+            "SELECT b.id FROM Bar AS b",
+            "SELECT b.id FROM (SELECT id FROM Foo WHERE id > 1) AS b",
+        );
+        test(
+            "SELECT * FROM Baz",
+            "SELECT * FROM (SELECT * FROM (SELECT id FROM Foo WHERE id > 1) AS Bar) AS Baz",
+        );
+        test(
+            "SELECT * FROM Foo JOIN Bar ON Foo.id = Bar.id",
+            "SELECT * FROM Foo JOIN (SELECT id FROM Foo WHERE id > 1) AS Bar ON Foo.id = Bar.id",
+        );
+        test(
+            "SELECT * FROM Foo WHERE id IN (SELECT id FROM Bar)",
+            "SELECT * FROM Foo WHERE id IN (SELECT id FROM (SELECT id FROM Foo WHERE id > 1) AS Bar)",
+        );
+        test(
+            "INSERT INTO Foo SELECT id, 'a' FROM Bar",
+            "INSERT INTO Foo SELECT id, 'a' FROM (SELECT id FROM Foo WHERE id > 1) AS Bar",
+        );
+
+        // CTE names shadow views
+        test(
+            "WITH Bar AS (SELECT 1 AS id) SELECT * FROM Bar",
+            "WITH Bar AS (SELECT 1 AS id) SELECT * FROM Bar",
+        );
+    }
+}
@@ -7,11 +7,11 @@ pub use crate::{
         TableError, ValueError,
     },
     executor::{
-        AggregateError, AlterError, EvaluateError, ExecuteError, FetchError, InsertError,
-        SelectError, SortError, UpdateError, ValidateError,
+        AggregateError, AlterError, EvaluateError, ExecuteError, FetchError, ForeignKeyError,
+        InsertError, MergeError, SelectError, SortError, UpdateError, ValidateError, WindowError,
     },
     plan::PlanError,
-    store::{AlterTableError, IndexError},
+    store::{AlterTableError, ConflictError, IndexError},
     translate::TranslateError,
 };
 
@@ -33,6 +33,8 @@ pub enum Error {
     AlterTable(#[from] AlterTableError),
     #[error("index: {0}")]
     Index(#[from] IndexError),
+    #[error("conflict: {0}")]
+    Conflict(#[from] ConflictError),
     #[error("execute: {0}")]
     Execute(#[from] ExecuteError),
     #[error("alter: {0}")]
@@ -47,10 +49,16 @@ pub enum Error {
     Aggregate(#[from] AggregateError),
     #[error("sort: {0}")]
     Sort(#[from] SortError),
+    #[error("window: {0}")]
+    Window(#[from] WindowError),
     #[error("insert: {0}")]
     Insert(#[from] InsertError),
+    #[error("foreign-key: {0}")]
+    ForeignKey(#[from] ForeignKeyError),
     #[error("update: {0}")]
     Update(#[from] UpdateError),
+    #[error("merge: {0}")]
+    Merge(#[from] MergeError),
     #[error("table: {0}")]
     Table(#[from] TableError),
     #[error("validate: {0}")]
@@ -28,6 +28,9 @@ pub enum AlterTableError {
 
     #[error("Schemaless table does not support ALTER TABLE: {0}")]
     SchemalessTableFound(String),
+
+    #[error("Temporary table does not support ALTER TABLE: {0}")]
+    TemporaryTableFound(String),
 }
 
 #[async_trait(?Send)]
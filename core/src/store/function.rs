@@ -1,6 +1,6 @@
 use {
     crate::{
-        data::CustomFunction as StructCustomFunction,
+        data::{CustomFunction as StructCustomFunction, NativeFunction, NativeTableFunction},
         result::{Error, Result},
     },
     async_trait::async_trait,
@@ -18,6 +18,19 @@ pub trait CustomFunction {
             "[Storage] CustomFunction is not supported".to_owned(),
         ))
     }
+
+    /// Native functions are registered on `Glue` rather than stored, so storages do not
+    /// need to implement this.
+    async fn fetch_native_function(&self, _func_name: &str) -> Result<Option<NativeFunction>> {
+        Ok(None)
+    }
+
+    async fn fetch_native_table_function(
+        &self,
+        _func_name: &str,
+    ) -> Result<Option<NativeTableFunction>> {
+        Ok(None)
+    }
 }
 
 #[async_trait(?Send)]
@@ -22,6 +22,9 @@ pub enum IndexError {
     #[error("index name does not exist: {0}")]
     IndexNameDoesNotExist(String),
 
+    #[error("temporary table does not support index: {0}")]
+    TemporaryTableFound(String),
+
     #[error("conflict - table not found: {0}")]
     ConflictTableNotFound(String),
 
@@ -3,6 +3,7 @@ mod data_row;
 mod function;
 mod index;
 mod metadata;
+mod temporary;
 mod transaction;
 
 pub trait GStore: Store + Index + Metadata + CustomFunction {}
@@ -23,13 +24,16 @@ pub use {
     function::{CustomFunction, CustomFunctionMut},
     index::{Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
-    transaction::Transaction,
+    transaction::{ConflictError, Transaction},
 };
 
+pub(crate) use temporary::{TemporaryStorage, TemporaryTables};
+
 use {
     crate::{
-        data::{Key, Schema},
-        result::Result,
+        ast::IndexOperator,
+        data::{Key, Schema, TableStatistics, Value},
+        result::{Error, Result},
     },
     async_trait::async_trait,
 };
@@ -46,6 +50,75 @@ pub trait Store {
     async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>>;
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter>;
+
+    /// Scans the table for a query which reads only `columns` of it. Columnar storages can
+    /// skip reading the other columns and leave them NULL, the default implementation reads
+    /// every column with `scan_data`.
+    async fn scan_data_with_columns(
+        &self,
+        table_name: &str,
+        _columns: &[String],
+    ) -> Result<RowIter> {
+        self.scan_data(table_name).await
+    }
+
+    /// Scans the table for a query whose WHERE clause requires each column of `values` to
+    /// equal its value. Storages which filter natively can return only the matching rows, the
+    /// WHERE clause is still evaluated on every returned row. `columns` is the same as of
+    /// `scan_data_with_columns`, which the default implementation calls when it is given.
+    async fn scan_data_by_values(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        _values: &[(String, Value)],
+    ) -> Result<RowIter> {
+        match columns {
+            Some(columns) => self.scan_data_with_columns(table_name, columns).await,
+            None => self.scan_data(table_name).await,
+        }
+    }
+
+    /// Scans the table for a query whose WHERE clause requires each `column <op> value` of
+    /// `predicates` to hold. Storages which can skip data, e.g. by key ranges or statistics,
+    /// may return only a part of the rows which includes every matching row, as the WHERE
+    /// clause is still evaluated on every returned row. The default implementation passes the
+    /// equality predicates to `scan_data_by_values`.
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        let values = predicates
+            .iter()
+            .filter(|(_, op, _)| op == &IndexOperator::Eq)
+            .map(|(column, _, value)| (column.to_owned(), value.clone()))
+            .collect::<Vec<_>>();
+
+        match (columns, values.as_slice()) {
+            (_, [_, ..]) => self.scan_data_by_values(table_name, columns, &values).await,
+            (Some(columns), []) => self.scan_data_with_columns(table_name, columns).await,
+            (None, []) => self.scan_data(table_name).await,
+        }
+    }
+
+    /// The predicates of `scan_data_filtered` which the storage uses to read fewer rows than
+    /// the whole table, `EXPLAIN` shows them as a filtered scan. The default scan methods read
+    /// every row, so none of the predicates are used unless the storage overrides this along
+    /// with `scan_data_filtered` or `scan_data_by_values`.
+    async fn filtered_scan_predicates(
+        &self,
+        _table_name: &str,
+        _predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        Ok(Vec::new())
+    }
+
+    /// Statistics of the table stored by the last `ANALYZE`, `None` if it has never been
+    /// analyzed or the storage does not keep statistics.
+    async fn fetch_statistics(&self, _table_name: &str) -> Result<Option<TableStatistics>> {
+        Ok(None)
+    }
 }
 
 /// By implementing `StoreMut` trait,
@@ -61,4 +134,33 @@ pub trait StoreMut {
     async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()>;
 
     async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()>;
+
+    /// Removes every row of the table, used by `TRUNCATE TABLE`.
+    ///
+    /// The default implementation scans the table and deletes the rows by their keys,
+    /// storages which are able to drop the whole table data at once should override it.
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()>
+    where
+        Self: Sized + Store,
+    {
+        let keys = self
+            .scan_data(table_name)
+            .await?
+            .map(|item| item.map(|(key, _)| key))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.delete_data(table_name, keys).await
+    }
+
+    /// Stores the statistics collected by `ANALYZE`, replacing the previous ones of the
+    /// table. Storages which keep statistics should also remove them in `delete_schema`.
+    async fn insert_statistics(
+        &mut self,
+        _table_name: &str,
+        _statistics: TableStatistics,
+    ) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] StoreMut::insert_statistics is not supported".to_owned(),
+        ))
+    }
 }
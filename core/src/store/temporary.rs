@@ -0,0 +1,417 @@
+use {
+    super::{
+        AlterTable, AlterTableError, CustomFunction, CustomFunctionMut, DataRow, Index, IndexError,
+        IndexMut, MetaIter, Metadata, RowIter, Store, StoreMut, Transaction,
+    },
+    crate::{
+        ast::{ColumnDef, IndexOperator, IsolationLevel, OrderByExpr},
+        data::{
+            CustomFunction as StructCustomFunction, FunctionRegistry, Key, NativeFunction,
+            NativeTableFunction, Schema, TableStatistics, Value,
+        },
+        result::Result,
+    },
+    async_trait::async_trait,
+    std::collections::{BTreeMap, HashMap},
+};
+
+/// Tables created by `CREATE TEMPORARY TABLE`, owned by `Glue` and dropped along with it
+#[derive(Debug, Default)]
+pub struct TemporaryTables {
+    id_counter: i64,
+    items: HashMap<String, (Schema, BTreeMap<Key, DataRow>)>,
+}
+
+/// Storage wrapper which keeps temporary tables in memory and delegates every other table
+/// to the underlying storage. `created` is the name of the table which the statement being
+/// executed creates by `CREATE TEMPORARY TABLE`. It also serves the native scalar and table
+/// functions registered on `Glue`.
+///
+/// Temporary tables are not affected by transactions of the underlying storage.
+pub struct TemporaryStorage<'a, T> {
+    storage: &'a mut T,
+    tables: &'a mut TemporaryTables,
+    functions: &'a FunctionRegistry,
+    created: Option<&'a str>,
+}
+
+impl<'a, T> TemporaryStorage<'a, T> {
+    pub fn new(
+        storage: &'a mut T,
+        tables: &'a mut TemporaryTables,
+        functions: &'a FunctionRegistry,
+        created: Option<&'a str>,
+    ) -> Self {
+        Self {
+            storage,
+            tables,
+            functions,
+            created,
+        }
+    }
+
+    fn is_temporary(&self, table_name: &str) -> bool {
+        self.tables.items.contains_key(table_name) || self.created == Some(table_name)
+    }
+
+    fn validate_alter_table(&self, table_name: &str) -> Result<()> {
+        match self.tables.items.contains_key(table_name) {
+            true => Err(AlterTableError::TemporaryTableFound(table_name.to_owned()).into()),
+            false => Ok(()),
+        }
+    }
+
+    fn validate_index(&self, table_name: &str) -> Result<()> {
+        match self.tables.items.contains_key(table_name) {
+            true => Err(IndexError::TemporaryTableFound(table_name.to_owned()).into()),
+            false => Ok(()),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Store> Store for TemporaryStorage<'_, T> {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        match self.tables.items.get(table_name) {
+            Some((schema, _)) => Ok(Some(schema.clone())),
+            None => self.storage.fetch_schema(table_name).await,
+        }
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut schemas = self.storage.fetch_all_schemas().await?;
+        schemas.extend(self.tables.items.values().map(|(schema, _)| schema.clone()));
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        match self.tables.items.get(table_name) {
+            Some((_, rows)) => Ok(rows.get(key).cloned()),
+            None => self.storage.fetch_data(table_name, key).await,
+        }
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        match self.tables.items.get(table_name) {
+            Some((_, rows)) => Ok(Box::new(rows.clone().into_iter().map(Ok))),
+            None => self.storage.scan_data(table_name).await,
+        }
+    }
+
+    async fn scan_data_with_columns(
+        &self,
+        table_name: &str,
+        columns: &[String],
+    ) -> Result<RowIter> {
+        match self.tables.items.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_with_columns(table_name, columns)
+                    .await
+            }
+        }
+    }
+
+    async fn scan_data_by_values(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        values: &[(String, Value)],
+    ) -> Result<RowIter> {
+        match self.tables.items.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_by_values(table_name, columns, values)
+                    .await
+            }
+        }
+    }
+
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        match self.tables.items.contains_key(table_name) {
+            true => self.scan_data(table_name).await,
+            false => {
+                self.storage
+                    .scan_data_filtered(table_name, columns, predicates)
+                    .await
+            }
+        }
+    }
+
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        match self.tables.items.contains_key(table_name) {
+            true => Ok(Vec::new()),
+            false => {
+                self.storage
+                    .filtered_scan_predicates(table_name, predicates)
+                    .await
+            }
+        }
+    }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        match self.tables.items.contains_key(table_name) {
+            true => Ok(None),
+            false => self.storage.fetch_statistics(table_name).await,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Store + StoreMut> StoreMut for TemporaryStorage<'_, T> {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        if !self.is_temporary(&schema.table_name) {
+            return self.storage.insert_schema(schema).await;
+        }
+
+        self.tables
+            .items
+            .insert(schema.table_name.clone(), (schema.clone(), BTreeMap::new()));
+
+        Ok(())
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        match self.tables.items.remove(table_name) {
+            Some(_) => Ok(()),
+            None => self.storage.delete_schema(table_name).await,
+        }
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let TemporaryTables { id_counter, items } = &mut *self.tables;
+
+        match items.get_mut(table_name) {
+            Some((_, data)) => {
+                for row in rows {
+                    *id_counter += 1;
+
+                    data.insert(Key::I64(*id_counter), row);
+                }
+
+                Ok(())
+            }
+            None => self.storage.append_data(table_name, rows).await,
+        }
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        match self.tables.items.get_mut(table_name) {
+            Some((_, data)) => {
+                data.extend(rows);
+
+                Ok(())
+            }
+            None => self.storage.insert_data(table_name, rows).await,
+        }
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        match self.tables.items.get_mut(table_name) {
+            Some((_, data)) => {
+                for key in keys {
+                    data.remove(&key);
+                }
+
+                Ok(())
+            }
+            None => self.storage.delete_data(table_name, keys).await,
+        }
+    }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()>
+    where
+        Self: Sized + Store,
+    {
+        match self.tables.items.get_mut(table_name) {
+            Some((_, data)) => {
+                data.clear();
+
+                Ok(())
+            }
+            None => self.storage.truncate_data(table_name).await,
+        }
+    }
+
+    /// Statistics of temporary tables are not kept, they are dropped along with `Glue`.
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        match self.tables.items.contains_key(table_name) {
+            true => Ok(()),
+            false => self.storage.insert_statistics(table_name, statistics).await,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Index> Index for TemporaryStorage<'_, T> {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        self.storage
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: IndexMut> IndexMut for TemporaryStorage<'_, T> {
+    async fn create_index(
+        &mut self,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+    ) -> Result<()> {
+        self.validate_index(table_name)?;
+
+        self.storage
+            .create_index(table_name, index_name, column)
+            .await
+    }
+
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        self.validate_index(table_name)?;
+
+        self.storage.drop_index(table_name, index_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: AlterTable> AlterTable for TemporaryStorage<'_, T> {
+    async fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        self.validate_alter_table(table_name)?;
+
+        self.storage.rename_schema(table_name, new_table_name).await
+    }
+
+    async fn rename_column(
+        &mut self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> Result<()> {
+        self.validate_alter_table(table_name)?;
+
+        self.storage
+            .rename_column(table_name, old_column_name, new_column_name)
+            .await
+    }
+
+    async fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        self.validate_alter_table(table_name)?;
+
+        self.storage.add_column(table_name, column_def).await
+    }
+
+    async fn drop_column(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        self.validate_alter_table(table_name)?;
+
+        self.storage
+            .drop_column(table_name, column_name, if_exists)
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Transaction> Transaction for TemporaryStorage<'_, T> {
+    async fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        self.storage.begin(autocommit).await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        self.storage.rollback().await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        self.storage.commit().await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        self.storage.savepoint(name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        self.storage.rollback_to_savepoint(name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        self.storage.release_savepoint(name).await
+    }
+
+    async fn set_isolation_level(&mut self, isolation_level: IsolationLevel) -> Result<()> {
+        self.storage.set_isolation_level(isolation_level).await
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.storage.flush().await
+    }
+
+    async fn lock_for_update(&mut self, table_names: &[String]) -> Result<()> {
+        self.storage.lock_for_update(table_names).await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: Metadata> Metadata for TemporaryStorage<'_, T> {
+    async fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.storage.scan_table_meta().await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: CustomFunction> CustomFunction for TemporaryStorage<'_, T> {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        self.storage.fetch_function(func_name).await
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.storage.fetch_all_functions().await
+    }
+
+    async fn fetch_native_function(&self, func_name: &str) -> Result<Option<NativeFunction>> {
+        Ok(self.functions.get(func_name))
+    }
+
+    async fn fetch_native_table_function(
+        &self,
+        func_name: &str,
+    ) -> Result<Option<NativeTableFunction>> {
+        Ok(self.functions.get_table_function(func_name))
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: CustomFunctionMut> CustomFunctionMut for TemporaryStorage<'_, T> {
+    async fn insert_function(&mut self, func: StructCustomFunction) -> Result<()> {
+        self.storage.insert_function(func).await
+    }
+
+    async fn delete_function(&mut self, func_name: &str) -> Result<()> {
+        self.storage.delete_function(func_name).await
+    }
+}
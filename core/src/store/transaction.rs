@@ -1,8 +1,22 @@
 use {
-    crate::result::{Error, Result},
+    crate::{
+        ast::IsolationLevel,
+        result::{Error, Result},
+    },
     async_trait::async_trait,
+    serde::Serialize,
+    std::fmt::Debug,
+    thiserror::Error as ThisError,
 };
 
+/// Errors of statements which failed because of another transaction, the statement can
+/// succeed when it is executed again, see `RetryPolicy` of `Glue`
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum ConflictError {
+    #[error("database is locked by another transaction")]
+    Locked,
+}
+
 #[async_trait(?Send)]
 pub trait Transaction {
     async fn begin(&mut self, autocommit: bool) -> Result<bool> {
@@ -22,4 +36,45 @@ pub trait Transaction {
     async fn commit(&mut self) -> Result<()> {
         Ok(())
     }
+
+    async fn savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Transaction::savepoint is not supported".to_owned(),
+        ))
+    }
+
+    async fn rollback_to_savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Transaction::rollback_to_savepoint is not supported".to_owned(),
+        ))
+    }
+
+    async fn release_savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Transaction::release_savepoint is not supported".to_owned(),
+        ))
+    }
+
+    /// Sets the isolation level of the transactions which are started afterwards.
+    /// A storage may run a level by a stronger one, but never by a weaker one.
+    async fn set_isolation_level(&mut self, _isolation_level: IsolationLevel) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Transaction::set_isolation_level is not supported".to_owned(),
+        ))
+    }
+
+    /// Persists the changes made so far, storages which write changes through have nothing
+    /// to flush
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Locks the rows of `SELECT .. FOR UPDATE` until the transaction ends, so that the other
+    /// transactions cannot modify them. `table_names` are the tables in its FROM clauses, a
+    /// storage without row-level locks may lock more than the rows, e.g. the whole tables.
+    async fn lock_for_update(&mut self, _table_names: &[String]) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Transaction::lock_for_update is not supported".to_owned(),
+        ))
+    }
 }
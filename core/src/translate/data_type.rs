@@ -1,9 +1,12 @@
 use {
-    super::TranslateError,
-    crate::{ast::DataType, result::Result},
+    super::{translate_object_name, TranslateError},
+    crate::{
+        ast::{Collation, DataType},
+        result::Result,
+    },
     sqlparser::ast::{
         DataType as SqlDataType, ExactNumberInfo as SqlExactNumberInfo,
-        TimezoneInfo as SqlTimezoneInfo,
+        ObjectName as SqlObjectName, TimezoneInfo as SqlTimezoneInfo,
     },
 };
 
@@ -19,7 +22,13 @@ pub fn translate_data_type(sql_data_type: &SqlDataType) -> Result<DataType> {
         SqlDataType::Time(None, SqlTimezoneInfo::None) => Ok(DataType::Time),
         SqlDataType::Interval => Ok(DataType::Interval),
         SqlDataType::Uuid => Ok(DataType::Uuid),
-        SqlDataType::Decimal(SqlExactNumberInfo::None) => Ok(DataType::Decimal),
+        SqlDataType::Decimal(info) | SqlDataType::Numeric(info) => match info {
+            SqlExactNumberInfo::None => Ok(DataType::Decimal),
+            SqlExactNumberInfo::Precision(precision) => translate_decimal(*precision, 0),
+            SqlExactNumberInfo::PrecisionAndScale(precision, scale) => {
+                translate_decimal(*precision, *scale)
+            }
+        },
         SqlDataType::Custom(name, _idents) => {
             let name = name.0.get(0).map(|v| v.value.to_uppercase());
 
@@ -45,3 +54,23 @@ pub fn translate_data_type(sql_data_type: &SqlDataType) -> Result<DataType> {
         _ => Err(TranslateError::UnsupportedDataType(sql_data_type.to_string()).into()),
     }
 }
+
+pub fn translate_collation(sql_collation: &SqlObjectName) -> Result<Collation> {
+    let collation = translate_object_name(sql_collation)?;
+
+    match collation.to_uppercase().as_str() {
+        "BINARY" => Ok(Collation::Binary),
+        "NOCASE" => Ok(Collation::NoCase),
+        _ => Err(TranslateError::UnsupportedCollation(collation).into()),
+    }
+}
+
+/// Precision is limited to the 28 significant digits a `Decimal` value can hold.
+fn translate_decimal(precision: u64, scale: u64) -> Result<DataType> {
+    match (u32::try_from(precision), u32::try_from(scale)) {
+        (Ok(precision @ 1..=28), Ok(scale)) if scale <= precision => {
+            Ok(DataType::DecimalPrecision(precision, scale))
+        }
+        _ => Err(TranslateError::InvalidDecimalPrecision { precision, scale }.into()),
+    }
+}
@@ -1,15 +1,22 @@
 use {
     super::{
-        data_type::translate_data_type, expr::translate_expr, translate_object_name, TranslateError,
+        data_type::{translate_collation, translate_data_type},
+        expr::translate_expr,
+        translate_object_name, TranslateError,
     },
     crate::{
-        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, OperateFunctionArg},
+        ast::{
+            AlterTableOperation, CheckConstraint, ColumnDef, ColumnUniqueOption, ForeignKey,
+            OperateFunctionArg, ReferentialAction,
+        },
         result::Result,
     },
     sqlparser::ast::{
         AlterTableOperation as SqlAlterTableOperation, ColumnDef as SqlColumnDef,
-        ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef,
-        OperateFunctionArg as SqlOperateFunctionArg,
+        ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef, Expr as SqlExpr,
+        Ident as SqlIdent, ObjectName as SqlObjectName,
+        OperateFunctionArg as SqlOperateFunctionArg, ReferentialAction as SqlReferentialAction,
+        TableConstraint as SqlTableConstraint,
     },
 };
 
@@ -53,8 +60,8 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
     let SqlColumnDef {
         name,
         data_type,
+        collation,
         options,
-        ..
     } = sql_column_def;
 
     let (nullable, default, unique) = options.iter().try_fold(
@@ -87,9 +94,148 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
         nullable,
         default,
         unique,
+        collation: collation.as_ref().map(translate_collation).transpose()?,
+    })
+}
+
+/// Column definitions and constraints of `CREATE TABLE`, where a column level
+/// `REFERENCES` or `CHECK` is handled the same as its table level constraint.
+pub fn translate_create_table_columns(
+    sql_column_defs: &[SqlColumnDef],
+    sql_constraints: &[SqlTableConstraint],
+) -> Result<(Vec<ColumnDef>, Vec<ForeignKey>, Vec<CheckConstraint>)> {
+    let mut column_defs = Vec::with_capacity(sql_column_defs.len());
+    let mut foreign_keys = Vec::new();
+    let mut checks = Vec::new();
+
+    for sql_column_def in sql_column_defs {
+        let (constraints, options): (Vec<_>, Vec<_>) =
+            sql_column_def.options.iter().cloned().partition(
+                |SqlColumnOptionDef { option, .. }| {
+                    matches!(
+                        option,
+                        SqlColumnOption::ForeignKey { .. } | SqlColumnOption::Check(_)
+                    )
+                },
+            );
+
+        for SqlColumnOptionDef { name, option } in constraints.iter() {
+            match option {
+                SqlColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                } => {
+                    let foreign_key = translate_foreign_key(
+                        name,
+                        &[sql_column_def.name.clone()],
+                        foreign_table,
+                        referred_columns,
+                        on_delete,
+                        on_update,
+                    )?;
+
+                    foreign_keys.push(foreign_key);
+                }
+                SqlColumnOption::Check(expr) => checks.push(translate_check(name, expr)?),
+                _ => {}
+            }
+        }
+
+        let sql_column_def = SqlColumnDef {
+            options,
+            ..sql_column_def.clone()
+        };
+
+        column_defs.push(translate_column_def(&sql_column_def)?);
+    }
+
+    for sql_constraint in sql_constraints {
+        match sql_constraint {
+            SqlTableConstraint::ForeignKey {
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+                on_delete,
+                on_update,
+            } => {
+                let foreign_key = translate_foreign_key(
+                    name,
+                    columns,
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                )?;
+
+                foreign_keys.push(foreign_key);
+            }
+            SqlTableConstraint::Check { name, expr } => checks.push(translate_check(name, expr)?),
+            _ => {}
+        }
+    }
+
+    Ok((column_defs, foreign_keys, checks))
+}
+
+fn translate_check(name: &Option<SqlIdent>, expr: &SqlExpr) -> Result<CheckConstraint> {
+    Ok(CheckConstraint {
+        name: name.as_ref().map(|name| name.value.to_owned()),
+        expr: translate_expr(expr)?,
     })
 }
 
+fn translate_foreign_key(
+    name: &Option<SqlIdent>,
+    columns: &[SqlIdent],
+    foreign_table: &SqlObjectName,
+    referred_columns: &[SqlIdent],
+    on_delete: &Option<SqlReferentialAction>,
+    on_update: &Option<SqlReferentialAction>,
+) -> Result<ForeignKey> {
+    let (referencing_column_name, referenced_column_name) = match (columns, referred_columns) {
+        ([column], [referred_column]) => {
+            (column.value.to_owned(), referred_column.value.to_owned())
+        }
+        ([column], []) => {
+            return Err(TranslateError::ReferencedColumnRequired(column.value.to_owned()).into())
+        }
+        _ => return Err(TranslateError::CompositeForeignKeyNotSupported.into()),
+    };
+    let referenced_table_name = translate_object_name(foreign_table)?;
+    let name = match name {
+        Some(name) => name.value.to_owned(),
+        None => {
+            format!("FK_{referencing_column_name}-{referenced_table_name}_{referenced_column_name}")
+        }
+    };
+
+    Ok(ForeignKey {
+        name,
+        referencing_column_name,
+        referenced_table_name,
+        referenced_column_name,
+        on_delete: translate_referential_action(on_delete)?,
+        on_update: translate_referential_action(on_update)?,
+    })
+}
+
+fn translate_referential_action(
+    sql_referential_action: &Option<SqlReferentialAction>,
+) -> Result<ReferentialAction> {
+    match sql_referential_action {
+        None | Some(SqlReferentialAction::NoAction) => Ok(ReferentialAction::NoAction),
+        Some(SqlReferentialAction::Restrict) => Ok(ReferentialAction::Restrict),
+        Some(SqlReferentialAction::Cascade) => Ok(ReferentialAction::Cascade),
+        Some(SqlReferentialAction::SetNull) => Ok(ReferentialAction::SetNull),
+        Some(action) => {
+            Err(TranslateError::UnsupportedReferentialAction(action.to_string()).into())
+        }
+    }
+}
+
 pub fn translate_operate_function_arg(arg: &SqlOperateFunctionArg) -> Result<OperateFunctionArg> {
     let name = arg
         .name
@@ -2,15 +2,24 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum TranslateError {
-    #[error("unimplemented - select on two or more than tables are not supported")]
-    TooManyTables,
-
-    #[error("unimplemented - select distinct is not supported")]
-    SelectDistinctNotSupported,
+    #[error("unimplemented - select distinct on is not supported")]
+    SelectDistinctOnNotSupported,
 
     #[error("unimplemented - composite index is not supported")]
     CompositeIndexNotSupported,
 
+    #[error("unimplemented - composite foreign key is not supported")]
+    CompositeForeignKeyNotSupported,
+
+    #[error("foreign key on column '{0}' must specify the referenced column")]
+    ReferencedColumnRequired(String),
+
+    #[error("unsupported referential action: {0}")]
+    UnsupportedReferentialAction(String),
+
+    #[error("unimplemented - column list of view is not supported")]
+    ViewColumnListNotSupported,
+
     #[error("unimplemented - join on update not supported")]
     JoinOnUpdateNotSupported,
 
@@ -54,12 +63,27 @@ pub enum TranslateError {
     #[error("empty function body is not supported")]
     UnsupportedEmptyFunctionBody,
 
+    #[error("SELECT * with no tables specified is not valid")]
+    WildcardWithoutFrom,
+
+    #[error("MERGE accepts a single row of values to insert: {0}")]
+    MergeInsertRequiresSingleRow(String),
+
+    #[error("unsupported ON clause of INSERT: {0}")]
+    UnsupportedOnInsert(String),
+
     #[error("wildcard function arg is not accepted")]
     WildcardFunctionArgNotAccepted,
 
     #[error("qualified wildcard is not supported - COUNT({0})")]
     QualifiedWildcardInCountNotSupported(String),
 
+    #[error("wildcard is not supported with DISTINCT - COUNT(DISTINCT *)")]
+    DistinctWildcardInCountNotSupported,
+
+    #[error("DISTINCT is only supported in aggregate functions: {0}")]
+    DistinctOnNonAggregateFunction(String),
+
     #[error("order by - NULLS (FIRST | LAST) is not supported")]
     OrderByNullsFirstOrLastNotSupported,
 
@@ -78,6 +102,12 @@ pub enum TranslateError {
     #[error("unsupported data type: {0}")]
     UnsupportedDataType(String),
 
+    #[error("unsupported collation: {0}")]
+    UnsupportedCollation(String),
+
+    #[error("invalid precision and scale of decimal: ({precision}, {scale})")]
+    InvalidDecimalPrecision { precision: u64, scale: u64 },
+
     #[error("unsupported datetime field: {0}")]
     UnsupportedDateTimeField(String),
 
@@ -96,15 +126,18 @@ pub enum TranslateError {
     #[error("unsupported query set expr: {0}")]
     UnsupportedQuerySetExpr(String),
 
+    #[error("unsupported FROM clause in CTE: {0}")]
+    UnsupportedCteFrom(String),
+
     #[error("unsupported query table factor: {0}")]
     UnsupportedQueryTableFactor(String),
 
-    #[error("unsupported join constraint: {0}")]
-    UnsupportedJoinConstraint(String),
-
     #[error("unsupported join operator: {0}")]
     UnsupportedJoinOperator(String),
 
+    #[error("unsupported join in comma separated table list: {0}")]
+    UnsupportedJoinAfterCommaJoin(String),
+
     #[error("unsupported column option: {0}")]
     UnsupportedColumnOption(String),
 
@@ -114,6 +147,12 @@ pub enum TranslateError {
     #[error("unsupported table factor: {0}")]
     UnsupportedTableFactor(String),
 
+    #[error("unsupported lock clause: {0}")]
+    UnsupportedLockClause(String),
+
+    #[error("unsupported explain: {0}")]
+    UnsupportedExplain(String),
+
     #[error("Every derived table must have its own alias")]
     LackOfAlias,
 
@@ -134,4 +173,13 @@ pub enum TranslateError {
 
     #[error("cannot drop primary index")]
     CannotDropPrimary,
+
+    #[error("window function {0} requires an OVER clause")]
+    WindowFunctionRequiresOver(String),
+
+    #[error("unsupported window function: {0}")]
+    UnsupportedWindowFunction(String),
+
+    #[error("unsupported window frame: {0}")]
+    UnsupportedWindowFrame(String),
 }
@@ -1,7 +1,7 @@
 use {
     super::{
         ast_literal::{translate_ast_literal, translate_datetime_field},
-        data_type::translate_data_type,
+        data_type::{translate_collation, translate_data_type},
         function::{
             translate_cast, translate_ceil, translate_extract, translate_floor, translate_function,
             translate_position,
@@ -10,12 +10,13 @@ use {
         translate_idents, translate_query, TranslateError,
     },
     crate::{
-        ast::{Expr, OrderByExpr},
+        ast::{BinaryOperator, Expr, OrderByExpr, Query},
         result::Result,
         translate::function::translate_trim,
     },
     sqlparser::ast::{
-        DateTimeField as SqlDateTimeField, Expr as SqlExpr, Interval as SqlInterval,
+        Array as SqlArray, BinaryOperator as SqlBinaryOperator, DateTimeField as SqlDateTimeField,
+        Expr as SqlExpr, Interval as SqlInterval, JsonOperator as SqlJsonOperator,
         OrderByExpr as SqlOrderByExpr,
     },
 };
@@ -90,11 +91,36 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             negated: *negated,
             pattern: translate_expr(pattern).map(Box::new)?,
         }),
-        SqlExpr::BinaryOp { left, op, right } => Ok(Expr::BinaryOp {
-            left: translate_expr(left).map(Box::new)?,
-            op: translate_binary_operator(op)?,
-            right: translate_expr(right).map(Box::new)?,
-        }),
+        SqlExpr::BinaryOp { left, op, right } => match right.as_ref() {
+            SqlExpr::AnyOp(subquery) => translate_quantified(sql_expr, left, op, subquery)
+                .map(|(expr, op, subquery)| Expr::AnySubquery { expr, op, subquery }),
+            SqlExpr::AllOp(subquery) => translate_quantified(sql_expr, left, op, subquery)
+                .map(|(expr, op, subquery)| Expr::AllSubquery { expr, op, subquery }),
+            _ => Ok(Expr::BinaryOp {
+                left: translate_expr(left).map(Box::new)?,
+                op: translate_binary_operator(op)?,
+                right: translate_expr(right).map(Box::new)?,
+            }),
+        },
+        SqlExpr::JsonAccess {
+            left,
+            operator,
+            right,
+        } => {
+            let op = match operator {
+                SqlJsonOperator::AtArrow => BinaryOperator::Contains,
+                SqlJsonOperator::ArrowAt => BinaryOperator::ContainedBy,
+                SqlJsonOperator::Arrow => BinaryOperator::Arrow,
+                SqlJsonOperator::LongArrow => BinaryOperator::LongArrow,
+                _ => return Err(TranslateError::UnsupportedExpr(sql_expr.to_string()).into()),
+            };
+
+            Ok(Expr::BinaryOp {
+                left: translate_expr(left).map(Box::new)?,
+                op,
+                right: translate_expr(right).map(Box::new)?,
+            })
+        }
         SqlExpr::UnaryOp { op, expr } => Ok(Expr::UnaryOp {
             op: translate_unary_operator(op)?,
             expr: translate_expr(expr).map(Box::new)?,
@@ -160,7 +186,14 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             obj: translate_expr(obj).map(Box::new)?,
             indexes: indexes.iter().map(translate_expr).collect::<Result<_>>()?,
         }),
+        SqlExpr::Array(SqlArray { elem, .. }) => Ok(Expr::Array {
+            elem: elem.iter().map(translate_expr).collect::<Result<_>>()?,
+        }),
         SqlExpr::Position { expr, r#in } => translate_position(expr, r#in),
+        SqlExpr::Collate { expr, collation } => Ok(Expr::Collate {
+            expr: translate_expr(expr).map(Box::new)?,
+            collation: translate_collation(collation)?,
+        }),
         SqlExpr::Interval(SqlInterval {
             value,
             leading_field,
@@ -183,6 +216,32 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
     }
 }
 
+fn translate_quantified(
+    sql_expr: &SqlExpr,
+    left: &SqlExpr,
+    op: &SqlBinaryOperator,
+    subquery: &SqlExpr,
+) -> Result<(Box<Expr>, BinaryOperator, Box<Query>)> {
+    let unsupported = || TranslateError::UnsupportedExpr(sql_expr.to_string()).into();
+
+    let subquery = match subquery {
+        SqlExpr::Subquery(subquery) => translate_query(subquery).map(Box::new)?,
+        _ => return Err(unsupported()),
+    };
+    let op = match translate_binary_operator(op)? {
+        op @ (BinaryOperator::Eq
+        | BinaryOperator::NotEq
+        | BinaryOperator::Lt
+        | BinaryOperator::LtEq
+        | BinaryOperator::Gt
+        | BinaryOperator::GtEq) => op,
+        _ => return Err(unsupported()),
+    };
+    let expr = translate_expr(left).map(Box::new)?;
+
+    Ok((expr, op, subquery))
+}
+
 pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<OrderByExpr> {
     let SqlOrderByExpr {
         expr,
@@ -190,12 +249,9 @@ pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<Ord
         nulls_first,
     } = sql_order_by_expr;
 
-    if nulls_first.is_some() {
-        return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
-    }
-
     Ok(OrderByExpr {
         expr: translate_expr(expr)?,
         asc: *asc,
+        nulls_first: *nulls_first,
     })
 }
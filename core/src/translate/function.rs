@@ -1,17 +1,17 @@
 use {
     super::{
         ast_literal::{translate_datetime_field, translate_trim_where_field},
-        expr::translate_expr,
+        expr::{translate_expr, translate_order_by_expr},
         translate_data_type, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{Aggregate, AggregateFunction, CountArgExpr, Expr, Function, WindowSpec},
         result::Result,
     },
     sqlparser::ast::{
         DataType, DateTimeField as SqlDateTimeField, Expr as SqlExpr, Function as SqlFunction,
         FunctionArg as SqlFunctionArg, FunctionArgExpr as SqlFunctionArgExpr,
-        TrimWhereField as SqlTrimWhereField,
+        TrimWhereField as SqlTrimWhereField, WindowSpec as SqlWindowSpec,
     },
 };
 
@@ -131,15 +131,21 @@ fn translate_function_one_arg<T: FnOnce(Expr) -> Function>(
         .map(Expr::Function)
 }
 
-fn translate_aggregate_one_arg<T: FnOnce(Expr) -> Aggregate>(
+fn translate_aggregate_one_arg<T: FnOnce(Expr) -> AggregateFunction>(
     func: T,
     args: Vec<&SqlExpr>,
     name: String,
+    distinct: bool,
 ) -> Result<Expr> {
     check_len(name, args.len(), 1)?;
 
     translate_expr(args[0])
         .map(func)
+        .map(|func| Aggregate {
+            func,
+            distinct,
+            over: None,
+        })
         .map(Box::new)
         .map(Expr::Aggregate)
 }
@@ -178,7 +184,67 @@ pub fn translate_function_arg_exprs(
 }
 
 pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
-    let SqlFunction { name, args, .. } = sql_function;
+    let expr = translate_function_call(sql_function)?;
+    let over = match &sql_function.over {
+        Some(over) => translate_window_spec(over)?,
+        None => {
+            return match expr {
+                Expr::Aggregate(aggregate) if aggregate.is_window_function() => {
+                    let name = translate_object_name(&sql_function.name)?.to_uppercase();
+
+                    Err(TranslateError::WindowFunctionRequiresOver(name).into())
+                }
+                expr => Ok(expr),
+            };
+        }
+    };
+
+    match expr {
+        Expr::Aggregate(aggregate) if !aggregate.distinct => {
+            Ok(Expr::Aggregate(Box::new(Aggregate {
+                over: Some(over),
+                ..*aggregate
+            })))
+        }
+        _ => {
+            let name = translate_object_name(&sql_function.name)?.to_uppercase();
+
+            Err(TranslateError::UnsupportedWindowFunction(name).into())
+        }
+    }
+}
+
+fn translate_window_spec(sql_window_spec: &SqlWindowSpec) -> Result<WindowSpec> {
+    let SqlWindowSpec {
+        partition_by,
+        order_by,
+        window_frame,
+    } = sql_window_spec;
+
+    if let Some(window_frame) = window_frame {
+        return Err(TranslateError::UnsupportedWindowFrame(window_frame.to_string()).into());
+    }
+
+    Ok(WindowSpec {
+        partition_by: partition_by
+            .iter()
+            .map(translate_expr)
+            .collect::<Result<_>>()?,
+        order_by: order_by
+            .iter()
+            .map(translate_order_by_expr)
+            .collect::<Result<_>>()?,
+    })
+}
+
+fn translate_function_call(sql_function: &SqlFunction) -> Result<Expr> {
+    let SqlFunction {
+        name,
+        args,
+        distinct,
+        ..
+    } = sql_function;
+    let distinct = *distinct;
     let name = translate_object_name(name)?.to_uppercase();
 
     let function_arg_exprs = args
@@ -202,21 +268,64 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
 
                 return Err(TranslateError::QualifiedWildcardInCountNotSupported(idents).into());
             }
+            SqlFunctionArgExpr::Wildcard if distinct => {
+                return Err(TranslateError::DistinctWildcardInCountNotSupported.into());
+            }
             SqlFunctionArgExpr::Wildcard => CountArgExpr::Wildcard,
         };
+        let func = AggregateFunction::Count(count_arg);
 
-        return Ok(Expr::Aggregate(Box::new(Aggregate::Count(count_arg))));
+        return Ok(Expr::Aggregate(Box::new(Aggregate {
+            func,
+            distinct,
+            over: None,
+        })));
     }
 
     let args = translate_function_arg_exprs(function_arg_exprs)?;
 
     match name.as_str() {
-        "SUM" => translate_aggregate_one_arg(Aggregate::Sum, args, name),
-        "MIN" => translate_aggregate_one_arg(Aggregate::Min, args, name),
-        "MAX" => translate_aggregate_one_arg(Aggregate::Max, args, name),
-        "AVG" => translate_aggregate_one_arg(Aggregate::Avg, args, name),
-        "VARIANCE" => translate_aggregate_one_arg(Aggregate::Variance, args, name),
-        "STDEV" => translate_aggregate_one_arg(Aggregate::Stdev, args, name),
+        "SUM" => translate_aggregate_one_arg(AggregateFunction::Sum, args, name, distinct),
+        "MIN" => translate_aggregate_one_arg(AggregateFunction::Min, args, name, distinct),
+        "MAX" => translate_aggregate_one_arg(AggregateFunction::Max, args, name, distinct),
+        "AVG" => translate_aggregate_one_arg(AggregateFunction::Avg, args, name, distinct),
+        "VARIANCE" => {
+            translate_aggregate_one_arg(AggregateFunction::Variance, args, name, distinct)
+        }
+        "STDEV" => translate_aggregate_one_arg(AggregateFunction::Stdev, args, name, distinct),
+        "ROW_NUMBER" | "RANK" | "DENSE_RANK" if !distinct => {
+            check_len(name.clone(), args.len(), 0)?;
+
+            let func = match name.as_str() {
+                "ROW_NUMBER" => AggregateFunction::RowNumber,
+                "RANK" => AggregateFunction::Rank,
+                _ => AggregateFunction::DenseRank,
+            };
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::new(func))))
+        }
+        "LAG" | "LEAD" if !distinct => {
+            check_len_range(name.clone(), args.len(), 1, 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let offset = args.get(1).map(|arg| translate_expr(arg)).transpose()?;
+            let default = args.get(2).map(|arg| translate_expr(arg)).transpose()?;
+            let func = match name.as_str() {
+                "LAG" => AggregateFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                },
+                _ => AggregateFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                },
+            };
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::new(func))))
+        }
+        _ if distinct => Err(TranslateError::DistinctOnNonAggregateFunction(name).into()),
         "COALESCE" => {
             let exprs = args
                 .into_iter()
@@ -276,6 +385,12 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let then = translate_expr(args[1])?;
             Ok(Expr::Function(Box::new(Function::IfNull { expr, then })))
         }
+        "NULLIF" => {
+            check_len(name, args.len(), 2)?;
+            let expr = translate_expr(args[0])?;
+            let value = translate_expr(args[1])?;
+            Ok(Expr::Function(Box::new(Function::NullIf { expr, value })))
+        }
         "RIGHT" => {
             check_len(name, args.len(), 2)?;
 
@@ -365,7 +480,8 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         "RADIANS" => translate_function_one_arg(Function::Radians, args, name),
         "DEGREES" => translate_function_one_arg(Function::Degrees, args, name),
         "PI" => translate_function_zero_arg(Function::Pi(), args, name),
-        "NOW" => translate_function_zero_arg(Function::Now(), args, name),
+        "NOW" | "CURRENT_TIMESTAMP" => translate_function_zero_arg(Function::Now(), args, name),
+        "CURRENT_DATE" => translate_function_zero_arg(Function::CurrentDate(), args, name),
         "GCD" => {
             check_len(name, args.len(), 2)?;
 
@@ -381,6 +497,17 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
 
             Ok(Expr::Function(Box::new(Function::LastDay(expr))))
         }
+        "DATE_TRUNC" => {
+            check_len(name, args.len(), 2)?;
+
+            let field = translate_expr(args[0])?;
+            let expr = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::DateTrunc {
+                field,
+                expr,
+            })))
+        }
         "LCM" => {
             check_len(name, args.len(), 2)?;
 
@@ -460,6 +587,57 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 count,
             })))
         }
+        "JSON_EXTRACT" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let path = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::JsonExtract {
+                expr,
+                path,
+            })))
+        }
+        "JSON_VALUE" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let path = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::JsonValue { expr, path })))
+        }
+        "REGEXP_MATCHES" => {
+            check_len_range(name, args.len(), 2, 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let pattern = translate_expr(args[1])?;
+            let flags = (args.len() > 2)
+                .then(|| translate_expr(args[2]))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::RegexpMatches {
+                expr,
+                pattern,
+                flags,
+            })))
+        }
+        "REGEXP_REPLACE" => {
+            check_len_range(name, args.len(), 3, 4)?;
+
+            let expr = translate_expr(args[0])?;
+            let pattern = translate_expr(args[1])?;
+            let replacement = translate_expr(args[2])?;
+            let flags = (args.len() > 3)
+                .then(|| translate_expr(args[3]))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::RegexpReplace {
+                expr,
+                pattern,
+                replacement,
+                flags,
+            })))
+        }
         "UNWRAP" => {
             check_len(name, args.len(), 2)?;
 
@@ -473,7 +651,9 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         }
         "ABS" => translate_function_one_arg(Function::Abs, args, name),
         "SIGN" => translate_function_one_arg(Function::Sign, args, name),
-        "GENERATE_UUID" => translate_function_zero_arg(Function::GenerateUuid(), args, name),
+        "GENERATE_UUID" | "UUID" => {
+            translate_function_zero_arg(Function::GenerateUuid(), args, name)
+        }
         "FORMAT" => {
             check_len(name, args.len(), 2)?;
 
@@ -528,6 +708,40 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let expr = translate_expr(args[0])?;
             Ok(Expr::Function(Box::new(Function::Md5(expr))))
         }
+        "SHA256" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0])?;
+            Ok(Expr::Function(Box::new(Function::Sha256(expr))))
+        }
+        "HEX" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0])?;
+            Ok(Expr::Function(Box::new(Function::Hex(expr))))
+        }
+        "BASE64" => {
+            check_len(name, args.len(), 1)?;
+
+            let expr = translate_expr(args[0])?;
+            Ok(Expr::Function(Box::new(Function::Base64(expr))))
+        }
+        "ENCODE" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let format = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Encode { expr, format })))
+        }
+        "DECODE" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let format = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Decode { expr, format })))
+        }
         "LENGTH" => {
             check_len(name, args.len(), 1)?;
 
@@ -625,6 +839,14 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 .collect::<Result<Vec<_>>>()?;
             Ok(Expr::Function(Box::new(Function::Greatest(exprs))))
         }
+        "LEAST" => {
+            check_len_min(name, args.len(), 2)?;
+            let exprs = args
+                .into_iter()
+                .map(translate_expr)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Expr::Function(Box::new(Function::Least(exprs))))
+        }
         "VALUES" => {
             check_len(name, args.len(), 1)?;
 
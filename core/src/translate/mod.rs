@@ -12,38 +12,86 @@ pub use self::{
     ddl::{translate_column_def, translate_operate_function_arg},
     error::TranslateError,
     expr::{translate_expr, translate_order_by_expr},
-    query::{alias_or_name, translate_query, translate_select_item},
+    query::{alias_or_name, translate_query, translate_select_item, translate_table_factor},
 };
 
 use {
     crate::{
-        ast::{Assignment, Statement, Variable},
+        ast::{
+            Assignment, IsolationLevel, MergeClause, OnConflict, OnConflictAction, SelectItem,
+            Statement, Variable,
+        },
+        parse_sql::{
+            parse_expr, ANALYZE, FLUSH, REFRESH_MATERIALIZED_VIEW, RELEASE_SAVEPOINT,
+            ROLLBACK_TO_SAVEPOINT, SAVEPOINT,
+        },
         result::Result,
     },
-    ddl::translate_alter_table_operation,
+    ddl::{translate_alter_table_operation, translate_create_table_columns},
     sqlparser::ast::{
-        Assignment as SqlAssignment, Ident as SqlIdent, ObjectName as SqlObjectName,
-        ObjectType as SqlObjectType, Statement as SqlStatement, TableFactor, TableWithJoins,
+        Assignment as SqlAssignment, ConflictTarget as SqlConflictTarget, DoUpdate as SqlDoUpdate,
+        Expr as SqlExpr, FunctionDefinition as SqlFunctionDefinition, Ident as SqlIdent,
+        LockClause as SqlLockClause, LockType as SqlLockType, MergeClause as SqlMergeClause,
+        NonBlock as SqlNonBlock, ObjectName as SqlObjectName, ObjectType as SqlObjectType,
+        OnConflict as SqlOnConflict, OnConflictAction as SqlOnConflictAction,
+        OnInsert as SqlOnInsert, SelectItem as SqlSelectItem, Statement as SqlStatement,
+        TableFactor, TableWithJoins, TransactionIsolationLevel as SqlTransactionIsolationLevel,
+        TransactionMode as SqlTransactionMode,
     },
 };
 
 pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
     match sql_statement {
-        SqlStatement::Query(query) => translate_query(query).map(Statement::Query),
+        SqlStatement::Query(query) => match query.locks.as_slice() {
+            [] => translate_query(query).map(Statement::Query),
+            [SqlLockClause {
+                lock_type: SqlLockType::Update,
+                of: None,
+                nonblock,
+            }] if !matches!(nonblock, Some(SqlNonBlock::SkipLocked)) => {
+                let nowait = nonblock.is_some();
+
+                translate_query(query).map(|query| Statement::QueryForUpdate { query, nowait })
+            }
+            locks => Err(TranslateError::UnsupportedLockClause(
+                locks
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+            .into()),
+        },
+        SqlStatement::Explain {
+            analyze: false,
+            statement,
+            ..
+        } => match translate(statement)? {
+            Statement::Query(query) => Ok(Statement::Explain(query)),
+            _ => Err(TranslateError::UnsupportedExplain(sql_statement.to_string()).into()),
+        },
+        SqlStatement::Explain { .. } => {
+            Err(TranslateError::UnsupportedExplain(sql_statement.to_string()).into())
+        }
         SqlStatement::Insert {
             table_name,
             columns,
             source,
+            on,
+            returning,
             ..
         } => Ok(Statement::Insert {
             table_name: translate_object_name(table_name)?,
             columns: translate_idents(columns),
             source: translate_query(source)?,
+            on_conflict: on.as_ref().map(translate_on_insert).transpose()?,
+            returning: translate_returning(returning)?,
         }),
         SqlStatement::Update {
             table,
             assignments,
             selection,
+            returning,
             ..
         } => Ok(Statement::Update {
             table_name: translate_table_with_join(table)?,
@@ -52,9 +100,15 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .map(translate_assignment)
                 .collect::<Result<_>>()?,
             selection: selection.as_ref().map(translate_expr).transpose()?,
+            order_by: Vec::new(),
+            limit: None,
+            returning: translate_returning(returning)?,
         }),
         SqlStatement::Delete {
-            from, selection, ..
+            from,
+            selection,
+            returning,
+            ..
         } => {
             let table_name = from
                 .iter()
@@ -65,25 +119,44 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             Ok(Statement::Delete {
                 table_name,
                 selection: selection.as_ref().map(translate_expr).transpose()?,
+                order_by: Vec::new(),
+                limit: None,
+                returning: translate_returning(returning)?,
             })
         }
+        SqlStatement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } => Ok(Statement::Merge {
+            table: translate_table_factor(table)?,
+            source: translate_table_factor(source)?,
+            on: translate_expr(on)?,
+            clauses: clauses
+                .iter()
+                .map(translate_merge_clause)
+                .collect::<Result<_>>()?,
+        }),
         SqlStatement::CreateTable {
             if_not_exists,
+            temporary,
             name,
             columns,
+            constraints,
             query,
             engine,
             ..
         } => {
-            let columns = columns
-                .iter()
-                .map(translate_column_def)
-                .collect::<Result<Vec<_>>>()?;
+            let (columns, foreign_keys, checks) =
+                translate_create_table_columns(columns, constraints)?;
 
             let columns = (!columns.is_empty()).then_some(columns);
 
             Ok(Statement::CreateTable {
                 if_not_exists: *if_not_exists,
+                temporary: *temporary,
                 name: translate_object_name(name)?,
                 columns,
                 source: match query {
@@ -91,6 +164,8 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                     None => None,
                 },
                 engine: engine.clone(),
+                foreign_keys,
+                checks,
             })
         }
         SqlStatement::AlterTable {
@@ -111,6 +186,99 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .map(translate_object_name)
                 .collect::<Result<Vec<_>>>()?,
         }),
+        SqlStatement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            ..
+        } => {
+            if !columns.is_empty() {
+                return Err(TranslateError::ViewColumnListNotSupported.into());
+            }
+
+            Ok(Statement::CreateView {
+                or_replace: *or_replace,
+                materialized: *materialized,
+                name: translate_object_name(name)?,
+                query: translate_query(query).map(Box::new)?,
+            })
+        }
+        SqlStatement::Execute {
+            name, parameters, ..
+        } if name.value == REFRESH_MATERIALIZED_VIEW => {
+            let name = match parameters.as_slice() {
+                [SqlExpr::Identifier(ident)] => ident.value.to_owned(),
+                [SqlExpr::CompoundIdentifier(idents)] => {
+                    translate_object_name(&SqlObjectName(idents.to_owned()))?
+                }
+                _ => {
+                    return Err(
+                        TranslateError::UnsupportedStatement(sql_statement.to_string()).into(),
+                    )
+                }
+            };
+
+            Ok(Statement::RefreshMaterializedView { name })
+        }
+        SqlStatement::Execute {
+            name, parameters, ..
+        } if [SAVEPOINT, ROLLBACK_TO_SAVEPOINT, RELEASE_SAVEPOINT]
+            .contains(&name.value.as_str()) =>
+        {
+            let savepoint = match parameters.as_slice() {
+                [SqlExpr::Identifier(ident)] => ident.value.to_owned(),
+                _ => {
+                    return Err(
+                        TranslateError::UnsupportedStatement(sql_statement.to_string()).into(),
+                    )
+                }
+            };
+
+            Ok(match name.value.as_str() {
+                SAVEPOINT => Statement::Savepoint { name: savepoint },
+                ROLLBACK_TO_SAVEPOINT => Statement::RollbackToSavepoint { name: savepoint },
+                _ => Statement::ReleaseSavepoint { name: savepoint },
+            })
+        }
+        SqlStatement::Execute {
+            name, parameters, ..
+        } if name.value == FLUSH && parameters.is_empty() => Ok(Statement::Flush),
+        SqlStatement::Execute {
+            name, parameters, ..
+        } if name.value == ANALYZE => {
+            let table_name = match parameters.as_slice() {
+                [] => None,
+                [SqlExpr::Identifier(ident)] => Some(ident.value.to_owned()),
+                _ => {
+                    return Err(
+                        TranslateError::UnsupportedStatement(sql_statement.to_string()).into(),
+                    )
+                }
+            };
+
+            Ok(Statement::Analyze { table_name })
+        }
+        SqlStatement::Drop {
+            object_type: SqlObjectType::View,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropView {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
+        SqlStatement::Truncate {
+            table_name,
+            partitions: None,
+            ..
+        } => Ok(Statement::Truncate {
+            table_name: translate_object_name(table_name)?,
+        }),
         SqlStatement::DropFunction {
             if_exists,
             func_desc,
@@ -138,10 +306,16 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 return Err(TranslateError::ReservedIndexName(name).into());
             };
 
+            let column = translate_order_by_expr(&columns[0])?;
+
+            if column.nulls_first.is_some() {
+                return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
+            }
+
             Ok(Statement::CreateIndex {
                 name,
                 table_name: translate_object_name(table_name)?,
-                column: translate_order_by_expr(&columns[0])?,
+                column,
             })
         }
         SqlStatement::Drop {
@@ -170,6 +344,21 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
         SqlStatement::StartTransaction { .. } => Ok(Statement::StartTransaction),
         SqlStatement::Commit { .. } => Ok(Statement::Commit),
         SqlStatement::Rollback { .. } => Ok(Statement::Rollback),
+        SqlStatement::SetTransaction { modes, .. } => match modes.as_slice() {
+            [SqlTransactionMode::IsolationLevel(isolation_level)] => {
+                let isolation_level = match isolation_level {
+                    SqlTransactionIsolationLevel::ReadUncommitted => {
+                        IsolationLevel::ReadUncommitted
+                    }
+                    SqlTransactionIsolationLevel::ReadCommitted => IsolationLevel::ReadCommitted,
+                    SqlTransactionIsolationLevel::RepeatableRead => IsolationLevel::RepeatableRead,
+                    SqlTransactionIsolationLevel::Serializable => IsolationLevel::Serializable,
+                };
+
+                Ok(Statement::SetTransaction { isolation_level })
+            }
+            _ => Err(TranslateError::UnsupportedStatement(sql_statement.to_string()).into()),
+        },
         SqlStatement::ShowTables {
             filter: None,
             db_name: None,
@@ -207,6 +396,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             or_replace,
             name,
             args,
+            return_type,
             params,
             ..
         } => {
@@ -218,16 +408,24 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                         .collect::<Result<Vec<_>>>()
                 })
                 .transpose()?;
+            let return_ = match (&params.return_, &params.as_) {
+                (Some(expr), _) => translate_expr(expr)?,
+                (
+                    None,
+                    Some(
+                        SqlFunctionDefinition::SingleQuotedDef(body)
+                        | SqlFunctionDefinition::DoubleDollarDef(body),
+                    ),
+                ) => translate_expr(&parse_expr(body)?)?,
+                (None, None) => return Err(TranslateError::UnsupportedEmptyFunctionBody.into()),
+            };
+
             Ok(Statement::CreateFunction {
                 or_replace: *or_replace,
                 name: translate_object_name(name)?,
                 args: args.unwrap_or_default(),
-                return_: params
-                    .return_
-                    .as_ref()
-                    .map(translate_expr)
-                    .transpose()?
-                    .ok_or(TranslateError::UnsupportedEmptyFunctionBody)?,
+                return_type: return_type.as_ref().map(translate_data_type).transpose()?,
+                return_,
             })
         }
         _ => Err(TranslateError::UnsupportedStatement(sql_statement.to_string()).into()),
@@ -253,6 +451,98 @@ pub fn translate_assignment(sql_assignment: &SqlAssignment) -> Result<Assignment
     })
 }
 
+fn translate_merge_clause(sql_merge_clause: &SqlMergeClause) -> Result<MergeClause> {
+    let translate_predicate =
+        |predicate: &Option<SqlExpr>| predicate.as_ref().map(translate_expr).transpose();
+
+    match sql_merge_clause {
+        SqlMergeClause::MatchedUpdate {
+            predicate,
+            assignments,
+        } => Ok(MergeClause::MatchedUpdate {
+            predicate: translate_predicate(predicate)?,
+            assignments: assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+        }),
+        SqlMergeClause::MatchedDelete(predicate) => Ok(MergeClause::MatchedDelete {
+            predicate: translate_predicate(predicate)?,
+        }),
+        SqlMergeClause::NotMatched {
+            predicate,
+            columns,
+            values,
+        } => {
+            let values = match values.rows.as_slice() {
+                [values] => values.iter().map(translate_expr).collect::<Result<_>>()?,
+                _ => {
+                    return Err(TranslateError::MergeInsertRequiresSingleRow(
+                        sql_merge_clause.to_string(),
+                    )
+                    .into());
+                }
+            };
+
+            Ok(MergeClause::NotMatched {
+                predicate: translate_predicate(predicate)?,
+                columns: translate_idents(columns),
+                values,
+            })
+        }
+    }
+}
+
+fn translate_returning(sql_returning: &Option<Vec<SqlSelectItem>>) -> Result<Vec<SelectItem>> {
+    sql_returning
+        .iter()
+        .flatten()
+        .map(translate_select_item)
+        .collect()
+}
+
+fn translate_on_insert(sql_on_insert: &SqlOnInsert) -> Result<OnConflict> {
+    let SqlOnConflict {
+        conflict_target,
+        action,
+    } = match sql_on_insert {
+        SqlOnInsert::OnConflict(on_conflict) => on_conflict,
+        _ => {
+            return Err(TranslateError::UnsupportedOnInsert(
+                sql_on_insert.to_string().trim_start().to_owned(),
+            )
+            .into());
+        }
+    };
+
+    let columns = match conflict_target {
+        Some(SqlConflictTarget::Columns(columns)) => translate_idents(columns),
+        Some(SqlConflictTarget::OnConstraint(_)) => {
+            return Err(TranslateError::UnsupportedOnInsert(
+                sql_on_insert.to_string().trim_start().to_owned(),
+            )
+            .into());
+        }
+        None => Vec::new(),
+    };
+
+    let action = match action {
+        SqlOnConflictAction::DoNothing => OnConflictAction::DoNothing,
+        SqlOnConflictAction::DoUpdate(SqlDoUpdate {
+            assignments,
+            selection,
+        }) => OnConflictAction::DoUpdate {
+            assignments: assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+            selection: selection.as_ref().map(translate_expr).transpose()?,
+        },
+    };
+
+    Ok(OnConflict { columns, action })
+}
+
 fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
     if !table.joins.is_empty() {
         return Err(TranslateError::JoinOnUpdateNotSupported.into());
@@ -263,9 +553,11 @@ fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
     }
 }
 
+/// Object names can be qualified with a single namespace, e.g. `sled.users`,
+/// which is kept in the name and left to the storage to resolve.
 fn translate_object_name(sql_object_name: &SqlObjectName) -> Result<String> {
     let sql_object_name = &sql_object_name.0;
-    if sql_object_name.len() > 1 {
+    if sql_object_name.len() > 2 {
         let compound_object_name = translate_idents(sql_object_name).join(".");
         return Err(TranslateError::CompoundObjectNotSupported(compound_object_name).into());
     }
@@ -39,6 +39,10 @@ pub fn translate_binary_operator(
         SqlBinaryOperator::Xor => Ok(BinaryOperator::Xor),
         SqlBinaryOperator::BitwiseAnd => Ok(BinaryOperator::BitwiseAnd),
         SqlBinaryOperator::PGBitwiseShiftLeft => Ok(BinaryOperator::BitwiseShiftLeft),
+        SqlBinaryOperator::PGRegexMatch => Ok(BinaryOperator::RegexMatch),
+        SqlBinaryOperator::PGRegexIMatch => Ok(BinaryOperator::RegexIMatch),
+        SqlBinaryOperator::PGRegexNotMatch => Ok(BinaryOperator::RegexNotMatch),
+        SqlBinaryOperator::PGRegexNotIMatch => Ok(BinaryOperator::RegexNotIMatch),
         _ => Err(TranslateError::UnsupportedBinaryOperator(sql_binary_operator.to_string()).into()),
     }
 }
@@ -5,22 +5,25 @@ use {
     },
     crate::{
         ast::{
-            AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            AstLiteral, Cte, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator,
+            Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+            TableWithJoins, Values, With, DEFAULT_MAX_RECURSION,
         },
         result::Result,
     },
     sqlparser::ast::{
-        Expr as SqlExpr, FunctionArg as SqlFunctionArg, Join as SqlJoin,
-        JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator, Query as SqlQuery,
-        Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
+        Cte as SqlCte, Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
+        Join as SqlJoin, JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator,
+        Query as SqlQuery, Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
+        SetOperator as SqlSetOperator, SetQuantifier as SqlSetQuantifier,
         TableAlias as SqlTableAlias, TableFactor as SqlTableFactor,
-        TableWithJoins as SqlTableWithJoins,
+        TableWithJoins as SqlTableWithJoins, With as SqlWith,
     },
 };
 
 pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     let SqlQuery {
+        with,
         body,
         order_by,
         limit,
@@ -28,6 +31,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         ..
     } = sql_query;
 
+    let with = with.as_ref().map(translate_with).transpose()?;
     let body = translate_set_expr(body)?;
     let order_by = order_by
         .iter()
@@ -41,6 +45,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         .transpose()?;
 
     Ok(Query {
+        with,
         body,
         order_by,
         limit,
@@ -48,6 +53,36 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     })
 }
 
+fn translate_with(sql_with: &SqlWith) -> Result<With> {
+    let SqlWith {
+        recursive,
+        cte_tables,
+    } = sql_with;
+
+    let cte_tables = cte_tables
+        .iter()
+        .map(|SqlCte { alias, query, from }| {
+            if let Some(from) = from {
+                return Err(TranslateError::UnsupportedCteFrom(from.value.to_owned()).into());
+            }
+
+            Ok(Cte {
+                alias: TableAlias {
+                    name: alias.name.value.to_owned(),
+                    columns: translate_idents(&alias.columns),
+                },
+                query: translate_query(query)?,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(With {
+        recursive: *recursive,
+        cte_tables,
+        max_recursion: DEFAULT_MAX_RECURSION,
+    })
+}
+
 fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
     match sql_set_expr {
         SqlSetExpr::Select(select) => translate_select(select).map(Box::new).map(SetExpr::Select),
@@ -57,6 +92,43 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Union => SetOperator::Union,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+                SqlSetOperator::Except => SetOperator::Except,
+            };
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                SqlSetQuantifier::ByName | SqlSetQuantifier::AllByName => {
+                    return Err(
+                        TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                    );
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all,
+                left: translate_set_expr(left).map(Box::new)?,
+                right: translate_set_expr(right).map(Box::new)?,
+            })
+        }
+        SqlSetExpr::Query(query)
+            if query.with.is_none()
+                && query.order_by.is_empty()
+                && query.limit.is_none()
+                && query.offset.is_none()
+                && query.fetch.is_none() =>
+        {
+            translate_set_expr(&query.body)
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
@@ -72,29 +144,53 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         ..
     } = sql_select;
 
-    if from.len() > 1 {
-        return Err(TranslateError::TooManyTables.into());
-    }
+    let distinct = match distinct {
+        Some(SqlDistinct::Distinct) => true,
+        Some(SqlDistinct::On(_)) => {
+            return Err(TranslateError::SelectDistinctOnNotSupported.into());
+        }
+        None => false,
+    };
 
-    if distinct.is_some() {
-        return Err(TranslateError::SelectDistinctNotSupported.into());
-    }
+    let from = match from.split_first() {
+        Some((sql_table_with_joins, sql_cross_joined)) => {
+            let TableWithJoins { relation, joins } =
+                translate_table_with_joins(sql_table_with_joins)?;
+            let cross_joins = sql_cross_joined
+                .iter()
+                .map(translate_cross_joined)
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .flatten();
+            let joins = joins.into_iter().chain(cross_joins).collect();
 
-    let from = match from.get(0) {
-        Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
+            TableWithJoins { relation, joins }
+        }
+        None if projection.iter().any(|item| {
+            matches!(
+                item,
+                SqlSelectItem::Wildcard(_) | SqlSelectItem::QualifiedWildcard(..)
+            )
+        }) =>
+        {
+            return Err(TranslateError::WildcardWithoutFrom.into());
+        }
         None => TableWithJoins {
             relation: TableFactor::Series {
                 alias: TableAlias {
                     name: "Series".to_owned(),
                     columns: Vec::new(),
                 },
-                size: Expr::Literal(AstLiteral::Number(1.into())),
+                start: None,
+                end: Expr::Literal(AstLiteral::Number(1.into())),
+                step: None,
             },
             joins: vec![],
         },
     };
 
     Ok(Select {
+        distinct,
         projection: projection
             .iter()
             .map(translate_select_item)
@@ -144,6 +240,31 @@ fn translate_table_with_joins(sql_table_with_joins: &SqlTableWithJoins) -> Resul
     })
 }
 
+/// Translates a comma separated item of the `FROM` list into joins which
+/// produce its cartesian product with the tables listed before it.
+fn translate_cross_joined(sql_table_with_joins: &SqlTableWithJoins) -> Result<Vec<Join>> {
+    let TableWithJoins { relation, joins } = translate_table_with_joins(sql_table_with_joins)?;
+
+    let outer_join = joins
+        .iter()
+        .find_map(|Join { join_operator, .. }| match join_operator {
+            JoinOperator::RightOuter(_) => Some("RIGHT OUTER JOIN"),
+            JoinOperator::FullOuter(_) => Some("FULL OUTER JOIN"),
+            JoinOperator::Inner(_) | JoinOperator::LeftOuter(_) => None,
+        });
+    if let Some(join_operator) = outer_join {
+        return Err(TranslateError::UnsupportedJoinAfterCommaJoin(join_operator.to_owned()).into());
+    }
+
+    let cross_join = Join {
+        relation,
+        join_operator: JoinOperator::Inner(JoinConstraint::None),
+        join_executor: JoinExecutor::NestedLoop,
+    };
+
+    Ok(std::iter::once(cross_join).chain(joins).collect())
+}
+
 fn translate_table_alias(alias: &Option<SqlTableAlias>) -> Option<TableAlias> {
     alias
         .as_ref()
@@ -153,8 +274,8 @@ fn translate_table_alias(alias: &Option<SqlTableAlias>) -> Option<TableAlias> {
         })
 }
 
-fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFactor> {
-    let translate_table_args = |args: &Vec<SqlFunctionArg>| -> Result<Expr> {
+pub fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFactor> {
+    let translate_table_args = |args: &Vec<SqlFunctionArg>| -> Result<Vec<Expr>> {
         let function_arg_exprs = args
             .iter()
             .map(|arg| match arg {
@@ -165,10 +286,16 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
             })
             .collect::<Result<Vec<_>>>()?;
 
-        match translate_function_arg_exprs(function_arg_exprs)?.get(0) {
-            Some(expr) => Ok(translate_expr(expr)?),
-            None => Err(TranslateError::LackOfArgs.into()),
-        }
+        translate_function_arg_exprs(function_arg_exprs)?
+            .into_iter()
+            .map(translate_expr)
+            .collect()
+    };
+    let translate_table_arg = |args: &Vec<SqlFunctionArg>| -> Result<Expr> {
+        translate_table_args(args)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| TranslateError::LackOfArgs.into())
     };
 
     match sql_table_factor {
@@ -179,9 +306,42 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
             let alias = translate_table_alias(alias);
 
             match (object_name.as_str(), args) {
-                ("SERIES", Some(args)) => Ok(TableFactor::Series {
+                ("SERIES" | "GENERATE_SERIES", Some(args)) => {
+                    let expected_minimum = match object_name.as_str() {
+                        "SERIES" => 1,
+                        _ => 2,
+                    };
+                    let args = translate_table_args(args)?;
+
+                    if args.is_empty() {
+                        return Err(TranslateError::LackOfArgs.into());
+                    } else if !(expected_minimum..=3).contains(&args.len()) {
+                        return Err(TranslateError::FunctionArgsLengthNotWithinRange {
+                            name: object_name,
+                            expected_minimum,
+                            expected_maximum: 3,
+                            found: args.len(),
+                        }
+                        .into());
+                    }
+
+                    let mut args = args.into_iter();
+                    let (start, end, step) = match (args.next(), args.next(), args.next()) {
+                        (Some(end), None, _) => (None, end, None),
+                        (start, Some(end), step) => (start, end, step),
+                        (None, None, _) => return Err(TranslateError::LackOfArgs.into()),
+                    };
+
+                    Ok(TableFactor::Series {
+                        alias: alias_or_name(alias, object_name),
+                        start,
+                        end,
+                        step,
+                    })
+                }
+                ("UNNEST", Some(args)) => Ok(TableFactor::Unnest {
                     alias: alias_or_name(alias, object_name),
-                    size: translate_table_args(args)?,
+                    expr: translate_table_arg(args)?,
                 }),
                 ("GLUE_OBJECTS", _) => Ok(TableFactor::Dictionary {
                     dict: Dictionary::GlueObjects,
@@ -199,7 +359,25 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
                     dict: Dictionary::GlueTableColumns,
                     alias: alias_or_name(alias, object_name),
                 }),
+                (_, Some(args)) => {
+                    let name = translate_object_name(name)?;
+
+                    Ok(TableFactor::Function {
+                        alias: alias_or_name(alias, name.clone()),
+                        name,
+                        args: translate_table_args(args)?,
+                    })
+                }
                 _ => {
+                    // namespaced tables are referred to by their own name, e.g. `users` of `sled.users`
+                    let alias = match (alias, name.0.as_slice()) {
+                        (None, [_, table_name]) => Some(TableAlias {
+                            name: table_name.value.to_owned(),
+                            columns: Vec::new(),
+                        }),
+                        (alias, _) => alias,
+                    };
+
                     Ok(TableFactor::Table {
                         name: translate_object_name(name)?,
                         alias,
@@ -243,12 +421,8 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
     let translate_constraint = |sql_join_constraint: &SqlJoinConstraint| match sql_join_constraint {
         SqlJoinConstraint::On(expr) => translate_expr(expr).map(JoinConstraint::On),
         SqlJoinConstraint::None => Ok(JoinConstraint::None),
-        SqlJoinConstraint::Using(_) => {
-            Err(TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into())
-        }
-        SqlJoinConstraint::Natural => {
-            Err(TranslateError::UnsupportedJoinConstraint("NATURAL".to_owned()).into())
-        }
+        SqlJoinConstraint::Using(idents) => Ok(JoinConstraint::Using(translate_idents(idents))),
+        SqlJoinConstraint::Natural => Ok(JoinConstraint::Natural),
     };
 
     let join_operator = match sql_join_operator {
@@ -258,6 +432,13 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
         SqlJoinOperator::LeftOuter(sql_join_constraint) => {
             translate_constraint(sql_join_constraint).map(JoinOperator::LeftOuter)
         }
+        SqlJoinOperator::RightOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::RightOuter)
+        }
+        SqlJoinOperator::FullOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::FullOuter)
+        }
+        SqlJoinOperator::CrossJoin => Ok(JoinOperator::Inner(JoinConstraint::None)),
         _ => {
             Err(TranslateError::UnsupportedJoinOperator(format!("{:?}", sql_join_operator)).into())
         }
@@ -18,6 +18,8 @@ fn convert_payload(payload: Payload) -> Json {
     match payload {
         Payload::Create => json!({ "type": "CREATE TABLE" }),
         Payload::DropTable => json!({ "type": "DROP TABLE" }),
+        Payload::DropView => json!({ "type": "DROP VIEW" }),
+        Payload::RefreshMaterializedView => json!({ "type": "REFRESH MATERIALIZED VIEW" }),
         Payload::Select { labels, rows } => {
             let rows = rows
                 .into_iter()
@@ -98,6 +100,12 @@ fn convert_payload(payload: Payload) -> Json {
         Payload::StartTransaction => json!({ "type": "BEGIN" }),
         Payload::Commit => json!({ "type": "COMMIT" }),
         Payload::Rollback => json!({ "type": "ROLLBACK" }),
+        Payload::Savepoint => json!({ "type": "SAVEPOINT" }),
+        Payload::RollbackToSavepoint => json!({ "type": "ROLLBACK TO SAVEPOINT" }),
+        Payload::ReleaseSavepoint => json!({ "type": "RELEASE SAVEPOINT" }),
+        Payload::SetTransaction => json!({ "type": "SET TRANSACTION" }),
+        Payload::Flush => json!({ "type": "FLUSH" }),
+        Payload::Analyze => json!({ "type": "ANALYZE" }),
         Payload::ShowVariable(PayloadVariable::Version(version)) => {
             json!({
                 "type": "SHOW VERSION",
@@ -111,6 +119,11 @@ fn convert_payload(payload: Payload) -> Json {
             })
         }
         Payload::DropFunction => json!({ "type": "DROP FUNCTION" }),
+        Payload::Merge(num) => json!({
+            "type": "MERGE",
+            "affected": num
+        }),
+        Payload::Truncate => json!({ "type": "TRUNCATE TABLE" }),
         Payload::ShowVariable(PayloadVariable::Functions(function_names)) => {
             json!({
                 "type": "SHOW FUNCTIONS",
@@ -21,6 +21,30 @@ pub use sled_storage;
 #[cfg(feature = "json-storage")]
 pub use json_storage;
 
+#[cfg(feature = "csv-storage")]
+pub use csv_storage;
+
+#[cfg(feature = "mongo-storage")]
+pub use mongo_storage;
+
+#[cfg(feature = "object-store-storage")]
+pub use object_store_storage;
+
+#[cfg(feature = "parquet-storage")]
+pub use parquet_storage;
+
+#[cfg(feature = "redis-storage")]
+pub use redis_storage;
+
+#[cfg(feature = "compressed-storage")]
+pub use compressed_storage;
+
+#[cfg(feature = "encrypted-storage")]
+pub use encrypted_storage;
+
+#[cfg(feature = "sqlite-storage")]
+pub use sqlite_storage;
+
 #[cfg(feature = "composite-storage")]
 pub use composite_storage;
 
@@ -48,6 +72,30 @@ pub mod prelude {
     #[cfg(feature = "json-storage")]
     pub use json_storage::JsonStorage;
 
+    #[cfg(feature = "csv-storage")]
+    pub use csv_storage::CsvStorage;
+
+    #[cfg(feature = "mongo-storage")]
+    pub use mongo_storage::MongoStorage;
+
+    #[cfg(feature = "object-store-storage")]
+    pub use object_store_storage::ObjectStoreStorage;
+
+    #[cfg(feature = "parquet-storage")]
+    pub use parquet_storage::ParquetStorage;
+
+    #[cfg(feature = "redis-storage")]
+    pub use redis_storage::RedisStorage;
+
+    #[cfg(feature = "compressed-storage")]
+    pub use compressed_storage::{CompressedStorage, Compression};
+
+    #[cfg(feature = "encrypted-storage")]
+    pub use encrypted_storage::EncryptedStorage;
+
+    #[cfg(feature = "sqlite-storage")]
+    pub use sqlite_storage::SqliteStorage;
+
     #[cfg(feature = "composite-storage")]
     pub use composite_storage::CompositeStorage;
 
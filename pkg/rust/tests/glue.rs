@@ -80,3 +80,54 @@ fn memory_basic() {
 
     block_on(basic(glue));
 }
+
+async fn temporary<T: GStore + GStoreMut>(mut glue: Glue<T>) {
+    assert_eq!(
+        glue.execute("CREATE TEMPORARY TABLE api_temp (id INTEGER)")
+            .await,
+        Ok(vec![Payload::Create])
+    );
+    assert_eq!(
+        glue.execute("INSERT INTO api_temp VALUES (1)").await,
+        Ok(vec![Payload::Insert(1)])
+    );
+    assert_eq!(
+        glue.execute("SELECT id FROM api_temp").await,
+        Ok(vec![Payload::Select {
+            labels: vec![String::from("id")],
+            rows: vec![vec![Value::I64(1)]]
+        }])
+    );
+
+    let storage = glue.storage;
+    assert_eq!(storage.fetch_schema("api_temp").await, Ok(None));
+
+    let mut glue = Glue::new(storage);
+    assert!(glue.execute("SELECT id FROM api_temp").await.is_err());
+}
+
+#[cfg(feature = "sled-storage")]
+#[test]
+fn sled_temporary() {
+    use sled_storage::{sled, SledStorage};
+
+    let config = sled::Config::default()
+        .path("data/using_temporary")
+        .temporary(true);
+
+    let storage = SledStorage::try_from(config).unwrap();
+    let glue = Glue::new(storage);
+
+    block_on(temporary(glue));
+}
+
+#[cfg(feature = "memory-storage")]
+#[test]
+fn memory_temporary() {
+    use memory_storage::MemoryStorage;
+
+    let storage = MemoryStorage::default();
+    let glue = Glue::new(storage);
+
+    block_on(temporary(glue));
+}
@@ -0,0 +1,72 @@
+use {
+    super::CompositeStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::ColumnDef,
+        error::{Error, Result},
+        store::AlterTable,
+    },
+};
+
+#[async_trait(?Send)]
+impl AlterTable for CompositeStorage {
+    async fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+        let new_table_name = match (
+            self.split_namespace(table_name),
+            self.split_namespace(new_table_name),
+        ) {
+            (Some((engine, _)), Some((new_engine, new_table_name))) if engine == new_engine => {
+                new_table_name
+            }
+            (_, Some(_)) => {
+                return Err(Error::StorageMsg(format!(
+                    "table cannot be moved to another storage: {new_table_name}"
+                )));
+            }
+            (_, None) => new_table_name,
+        };
+
+        self.fetch_storage_mut(table_name)
+            .await?
+            .rename_schema(inner_table_name, new_table_name)
+            .await
+    }
+
+    async fn rename_column(
+        &mut self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
+        self.fetch_storage_mut(table_name)
+            .await?
+            .rename_column(inner_table_name, old_column_name, new_column_name)
+            .await
+    }
+
+    async fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
+        self.fetch_storage_mut(table_name)
+            .await?
+            .add_column(inner_table_name, column_def)
+            .await
+    }
+
+    async fn drop_column(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
+        self.fetch_storage_mut(table_name)
+            .await?
+            .drop_column(inner_table_name, column_name, if_exists)
+            .await
+    }
+}
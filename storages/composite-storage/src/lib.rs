@@ -1,5 +1,6 @@
 #![deny(clippy::str_to_string)]
 
+mod alter_table;
 mod store;
 mod store_mut;
 mod transaction;
@@ -55,7 +56,24 @@ impl CompositeStorage {
         self.default_engine = None;
     }
 
+    /// Splits a namespaced table name such as `sled.users` into the registered engine and the
+    /// table name in its storage, names whose prefix is not an engine are left as they are.
+    fn split_namespace<'a>(&self, table_name: &'a str) -> Option<(&'a str, &'a str)> {
+        table_name
+            .split_once('.')
+            .filter(|(engine, _)| self.storages.contains_key(*engine))
+    }
+
+    fn inner_table_name<'a>(&self, table_name: &'a str) -> &'a str {
+        self.split_namespace(table_name)
+            .map_or(table_name, |(_, table_name)| table_name)
+    }
+
     async fn fetch_engine(&self, table_name: &str) -> Result<String> {
+        if let Some((engine, _)) = self.split_namespace(table_name) {
+            return Ok(engine.to_owned());
+        }
+
         self.fetch_schema(table_name)
             .await?
             .and_then(|Schema { engine, .. }| engine)
@@ -86,7 +104,6 @@ impl CompositeStorage {
     }
 }
 
-impl gluesql_core::store::AlterTable for CompositeStorage {}
 impl gluesql_core::store::Index for CompositeStorage {}
 impl gluesql_core::store::IndexMut for CompositeStorage {}
 impl Metadata for CompositeStorage {}
@@ -3,7 +3,8 @@ use {
     async_trait::async_trait,
     futures::stream::{self, StreamExt, TryStreamExt},
     gluesql_core::{
-        data::{Key, Schema},
+        ast::IndexOperator,
+        data::{Key, Schema, TableStatistics, Value},
         error::Result,
         store::{DataRow, RowIter, Store},
     },
@@ -25,6 +26,19 @@ impl Store for CompositeStorage {
     }
 
     async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        if let Some((engine, inner_table_name)) = self.split_namespace(table_name) {
+            let schema = self.storages[engine]
+                .fetch_schema(inner_table_name)
+                .await?
+                .map(|schema| Schema {
+                    table_name: table_name.to_owned(),
+                    engine: Some(engine.to_owned()),
+                    ..schema
+                });
+
+            return Ok(schema);
+        }
+
         for storage in self.storages.values() {
             let schema = storage.fetch_schema(table_name).await?;
 
@@ -39,14 +53,67 @@ impl Store for CompositeStorage {
     async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
         self.fetch_storage(table_name)
             .await?
-            .fetch_data(table_name, key)
+            .fetch_data(self.inner_table_name(table_name), key)
             .await
     }
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
         self.fetch_storage(table_name)
             .await?
-            .scan_data(table_name)
+            .scan_data(self.inner_table_name(table_name))
+            .await
+    }
+
+    async fn scan_data_with_columns(
+        &self,
+        table_name: &str,
+        columns: &[String],
+    ) -> Result<RowIter> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_data_with_columns(self.inner_table_name(table_name), columns)
+            .await
+    }
+
+    async fn scan_data_by_values(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        values: &[(String, Value)],
+    ) -> Result<RowIter> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_data_by_values(self.inner_table_name(table_name), columns, values)
+            .await
+    }
+
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_data_filtered(self.inner_table_name(table_name), columns, predicates)
+            .await
+    }
+
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        self.fetch_storage(table_name)
+            .await?
+            .filtered_scan_predicates(self.inner_table_name(table_name), predicates)
+            .await
+    }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        self.fetch_storage(table_name)
+            .await?
+            .fetch_statistics(self.inner_table_name(table_name))
             .await
     }
 }
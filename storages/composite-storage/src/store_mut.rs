@@ -2,7 +2,7 @@ use {
     super::CompositeStorage,
     async_trait::async_trait,
     gluesql_core::{
-        data::{Key, Schema},
+        data::{Key, Schema, TableStatistics},
         error::{Error, Result},
         store::{DataRow, StoreMut},
     },
@@ -11,6 +11,23 @@ use {
 #[async_trait(?Send)]
 impl StoreMut for CompositeStorage {
     async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        if let Some((engine, table_name)) = self.split_namespace(&schema.table_name) {
+            if matches!(&schema.engine, Some(schema_engine) if schema_engine != engine) {
+                return Err(Error::StorageMsg(format!(
+                    "engine does not match with the namespace of table: {}",
+                    schema.table_name
+                )));
+            }
+
+            let schema = Schema {
+                table_name: table_name.to_owned(),
+                engine: Some(engine.to_owned()),
+                ..schema.clone()
+            };
+
+            return self.insert_schema(&schema).await;
+        }
+
         let storage = schema
             .engine
             .as_ref()
@@ -35,30 +52,51 @@ impl StoreMut for CompositeStorage {
     }
 
     async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
         self.fetch_storage_mut(table_name)
             .await?
-            .delete_schema(table_name)
+            .delete_schema(inner_table_name)
             .await
     }
 
     async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
         self.fetch_storage_mut(table_name)
             .await?
-            .append_data(table_name, rows)
+            .append_data(inner_table_name, rows)
             .await
     }
 
     async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
         self.fetch_storage_mut(table_name)
             .await?
-            .insert_data(table_name, rows)
+            .insert_data(inner_table_name, rows)
             .await
     }
 
     async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
+        self.fetch_storage_mut(table_name)
+            .await?
+            .delete_data(inner_table_name, keys)
+            .await
+    }
+
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        let inner_table_name = self.inner_table_name(table_name);
+
         self.fetch_storage_mut(table_name)
             .await?
-            .delete_data(table_name, keys)
+            .insert_statistics(inner_table_name, statistics)
             .await
     }
 }
@@ -38,4 +38,12 @@ impl Transaction for CompositeStorage {
 
         Ok(())
     }
+
+    async fn flush(&mut self) -> Result<()> {
+        for storage in self.storages.values_mut() {
+            storage.flush().await?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,92 @@
+use {
+    gluesql_composite_storage::CompositeStorage,
+    gluesql_core::{
+        error::FetchError,
+        prelude::{Error, Glue, Payload, Value::*},
+    },
+    memory_storage::MemoryStorage,
+    sled_storage::SledStorage,
+    std::fs,
+    test_suite::*,
+};
+
+#[tokio::test]
+async fn namespace() {
+    let memory_storage = MemoryStorage::default();
+    let sled_storage = {
+        let path = "data/namespace";
+        fs::remove_dir_all(path).unwrap_or(());
+
+        SledStorage::new(path).unwrap()
+    };
+
+    let mut storage = CompositeStorage::new();
+    storage.push("MEMORY", memory_storage);
+    storage.push("SLED", sled_storage);
+
+    let mut glue = Glue::new(storage);
+    macro_rules! run {
+        ($sql: literal) => {
+            glue.execute($sql)
+                .await
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap()
+        };
+    }
+
+    run!("CREATE TABLE MEMORY.users (id INTEGER PRIMARY KEY, name TEXT);");
+    run!("CREATE TABLE SLED.events (id INTEGER, user_id INTEGER, kind TEXT);");
+    run!("INSERT INTO MEMORY.users VALUES (1, 'Alice'), (2, 'Bob');");
+    run!("INSERT INTO SLED.events VALUES (1, 1, 'login'), (2, 2, 'login'), (3, 1, 'logout');");
+
+    assert_eq!(
+        run!(
+            "SELECT events.id, users.name, events.kind
+            FROM SLED.events
+            JOIN MEMORY.users ON users.id = events.user_id
+            ORDER BY events.id;"
+        ),
+        select!(
+            id  | name               | kind
+            I64 | Str                | Str;
+            1     "Alice".to_owned()   "login".to_owned();
+            2     "Bob".to_owned()     "login".to_owned();
+            3     "Alice".to_owned()   "logout".to_owned()
+        )
+    );
+    assert_eq!(
+        run!("SELECT U.name FROM MEMORY.users AS U WHERE U.id = 2;"),
+        select!(name Str; "Bob".to_owned())
+    );
+
+    // tables are kept in their own storage without the namespace
+    assert_eq!(
+        run!("SELECT COUNT(*) AS cnt FROM events;"),
+        select!(cnt I64; 3)
+    );
+    assert_eq!(
+        run!("DELETE FROM SLED.events WHERE kind = 'logout';"),
+        Payload::Delete(1)
+    );
+    assert_eq!(
+        run!("SELECT COUNT(*) AS cnt FROM SLED.events;"),
+        select!(cnt I64; 2)
+    );
+
+    assert_eq!(
+        glue.execute("CREATE TABLE SLED.logs (id INTEGER) ENGINE = MEMORY;")
+            .await
+            .unwrap_err(),
+        Error::StorageMsg(
+            "engine does not match with the namespace of table: SLED.logs".to_owned()
+        ),
+    );
+
+    run!("DROP TABLE MEMORY.users;");
+    assert_eq!(
+        glue.execute("SELECT * FROM users;").await.unwrap_err(),
+        FetchError::TableNotFound("users".to_owned()).into(),
+    );
+}
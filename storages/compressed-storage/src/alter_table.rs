@@ -0,0 +1,37 @@
+use {
+    super::CompressedStorage,
+    async_trait::async_trait,
+    gluesql_core::{error::Result, store::AlterTable},
+};
+
+/// Only the changes which leave the rows as they are can be passed to the inner storage,
+/// adding or dropping a column is not supported.
+///
+/// Compressions are configured by table name, so renaming a table moves its compression to
+/// the new name, and the renamed table keeps being written with it.
+#[async_trait(?Send)]
+impl<T: AlterTable> AlterTable for CompressedStorage<T> {
+    async fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        self.inner.rename_schema(table_name, new_table_name).await?;
+
+        match self.compressions.remove(table_name) {
+            Some(compression) => self.set_compression(new_table_name, compression),
+            None => {
+                self.remove_compression(new_table_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn rename_column(
+        &mut self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> Result<()> {
+        self.inner
+            .rename_column(table_name, old_column_name, new_column_name)
+            .await
+    }
+}
@@ -0,0 +1,26 @@
+use {gluesql_core::error::Error, thiserror::Error};
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<CompressedStorageError> for Error {
+    fn from(error: CompressedStorageError) -> Self {
+        Error::StorageMsg(error.to_string())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum CompressedStorageError {
+    #[error("row is not a compressed payload")]
+    InvalidPayload,
+
+    #[error("unknown compression of row: {0}")]
+    UnknownCompression(u8),
+}
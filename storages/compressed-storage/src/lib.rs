@@ -0,0 +1,117 @@
+#![deny(clippy::str_to_string)]
+
+mod alter_table;
+pub mod error;
+mod store;
+mod store_mut;
+mod transaction;
+
+use {
+    error::{CompressedStorageError, ResultExt},
+    gluesql_core::{
+        data::Value,
+        error::Result,
+        store::{CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata},
+    },
+    std::collections::HashMap,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    /// zstd with the given compression level, `0` picks the default level of zstd
+    Zstd(i32),
+    Lz4,
+}
+
+impl Compression {
+    fn tag(&self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Zstd(_) => 1,
+            Compression::Lz4 => 2,
+        }
+    }
+}
+
+/// Storage wrapper which compresses rows before they are passed to the inner storage.
+///
+/// Each row is stored as a single `BYTEA` value which starts with the tag of its compression,
+/// so the compression of a table can be changed at any time without rewriting the rows. The
+/// inner storage has to keep rows as they are given, e.g. `SledStorage` and `MemoryStorage`.
+pub struct CompressedStorage<T> {
+    inner: T,
+    pub default_compression: Compression,
+    pub compressions: HashMap<String, Compression>,
+}
+
+impl<T> CompressedStorage<T> {
+    pub fn new(inner: T, default_compression: Compression) -> Self {
+        Self {
+            inner,
+            default_compression,
+            compressions: HashMap::new(),
+        }
+    }
+
+    pub fn set_compression<U: Into<String>>(&mut self, table_name: U, compression: Compression) {
+        self.compressions.insert(table_name.into(), compression);
+    }
+
+    pub fn remove_compression<U: AsRef<str>>(&mut self, table_name: U) -> Option<Compression> {
+        self.compressions.remove(table_name.as_ref())
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn compress(&self, table_name: &str, row: DataRow) -> Result<DataRow> {
+        let compression = self
+            .compressions
+            .get(table_name)
+            .unwrap_or(&self.default_compression);
+        let data = bincode::serialize(&row).map_storage_err()?;
+        let data = match compression {
+            Compression::None => data,
+            Compression::Zstd(level) => {
+                zstd::encode_all(data.as_slice(), *level).map_storage_err()?
+            }
+            Compression::Lz4 => lz4_flex::compress_prepend_size(&data),
+        };
+
+        let payload = std::iter::once(compression.tag()).chain(data).collect();
+
+        Ok(DataRow::Vec(vec![Value::Bytea(payload)]))
+    }
+}
+
+fn decompress(row: DataRow) -> Result<DataRow> {
+    let payload = match &row {
+        DataRow::Vec(values) => match values.as_slice() {
+            [Value::Bytea(payload)] => payload.split_first(),
+            _ => None,
+        },
+        DataRow::Map(_) => None,
+    };
+    let (tag, data) = payload.ok_or(CompressedStorageError::InvalidPayload)?;
+
+    let data = match tag {
+        0 => data.to_vec(),
+        1 => zstd::decode_all(data).map_storage_err()?,
+        2 => lz4_flex::decompress_size_prepended(data).map_storage_err()?,
+        _ => return Err(CompressedStorageError::UnknownCompression(*tag).into()),
+    };
+
+    bincode::deserialize(&data).map_storage_err()
+}
+
+impl<T> CustomFunction for CompressedStorage<T> {}
+impl<T> CustomFunctionMut for CompressedStorage<T> {}
+impl<T> Index for CompressedStorage<T> {}
+impl<T> IndexMut for CompressedStorage<T> {}
+impl<T> Metadata for CompressedStorage<T> {}
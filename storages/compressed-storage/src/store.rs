@@ -0,0 +1,42 @@
+use {
+    super::{decompress, CompressedStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema, TableStatistics},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl<T: Store> Store for CompressedStorage<T> {
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.inner.fetch_all_schemas().await
+    }
+
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.inner.fetch_schema(table_name).await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.inner
+            .fetch_data(table_name, key)
+            .await?
+            .map(decompress)
+            .transpose()
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let rows = self.inner.scan_data(table_name).await?.map(|item| {
+            let (key, row) = item?;
+
+            decompress(row).map(|row| (key, row))
+        });
+
+        Ok(Box::new(rows))
+    }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        self.inner.fetch_statistics(table_name).await
+    }
+}
@@ -0,0 +1,50 @@
+use {
+    super::CompressedStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema, TableStatistics},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+};
+
+#[async_trait(?Send)]
+impl<T: StoreMut> StoreMut for CompressedStorage<T> {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.inner.insert_schema(schema).await
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.inner.delete_schema(table_name).await
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let rows = rows
+            .into_iter()
+            .map(|row| self.compress(table_name, row))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner.append_data(table_name, rows).await
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let rows = rows
+            .into_iter()
+            .map(|(key, row)| self.compress(table_name, row).map(|row| (key, row)))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner.insert_data(table_name, rows).await
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        self.inner.delete_data(table_name, keys).await
+    }
+
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        self.inner.insert_statistics(table_name, statistics).await
+    }
+}
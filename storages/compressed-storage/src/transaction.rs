@@ -0,0 +1,44 @@
+use {
+    super::CompressedStorage,
+    async_trait::async_trait,
+    gluesql_core::{ast::IsolationLevel, error::Result, store::Transaction},
+};
+
+#[async_trait(?Send)]
+impl<T: Transaction> Transaction for CompressedStorage<T> {
+    async fn begin(&mut self, autocommit: bool) -> Result<bool> {
+        self.inner.begin(autocommit).await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        self.inner.rollback().await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        self.inner.commit().await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        self.inner.savepoint(name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        self.inner.rollback_to_savepoint(name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        self.inner.release_savepoint(name).await
+    }
+
+    async fn set_isolation_level(&mut self, isolation_level: IsolationLevel) -> Result<()> {
+        self.inner.set_isolation_level(isolation_level).await
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.inner.flush().await
+    }
+
+    async fn lock_for_update(&mut self, table_names: &[String]) -> Result<()> {
+        self.inner.lock_for_update(table_names).await
+    }
+}
@@ -0,0 +1,27 @@
+use {
+    async_trait::async_trait,
+    gluesql_compressed_storage::{CompressedStorage, Compression},
+    gluesql_core::prelude::Glue,
+    memory_storage::MemoryStorage,
+    test_suite::*,
+};
+
+struct CompressedTester {
+    glue: Glue<CompressedStorage<MemoryStorage>>,
+}
+
+#[async_trait(?Send)]
+impl Tester<CompressedStorage<MemoryStorage>> for CompressedTester {
+    async fn new(_: &str) -> Self {
+        let storage = CompressedStorage::new(MemoryStorage::default(), Compression::Zstd(0));
+        let glue = Glue::new(storage);
+
+        CompressedTester { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<CompressedStorage<MemoryStorage>> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(tokio::test, CompressedTester);
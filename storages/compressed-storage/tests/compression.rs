@@ -0,0 +1,122 @@
+use {
+    gluesql_compressed_storage::{CompressedStorage, Compression},
+    gluesql_core::{
+        prelude::{Glue, Value::*},
+        store::{DataRow, Store},
+    },
+    memory_storage::MemoryStorage,
+    test_suite::*,
+};
+
+async fn payload_sizes(storage: &CompressedStorage<MemoryStorage>, table_name: &str) -> Vec<usize> {
+    storage
+        .inner()
+        .scan_data(table_name)
+        .await
+        .unwrap()
+        .map(|item| match item.unwrap().1 {
+            DataRow::Vec(values) => match values.as_slice() {
+                [Bytea(payload)] => payload.len(),
+                _ => panic!("unexpected row: {values:?}"),
+            },
+            DataRow::Map(_) => panic!("unexpected schemaless row"),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn compression() {
+    let mut storage = CompressedStorage::new(MemoryStorage::default(), Compression::None);
+    storage.set_compression("Zstd", Compression::Zstd(3));
+    storage.set_compression("Lz4", Compression::Lz4);
+
+    let mut glue = Glue::new(storage);
+    for table_name in ["Plain", "Zstd", "Lz4"] {
+        glue.execute(format!(
+            "CREATE TABLE {table_name} (id INTEGER, body TEXT);"
+        ))
+        .await
+        .unwrap();
+        glue.execute(format!(
+            "INSERT INTO {table_name} VALUES (1, '{}');",
+            "glue ".repeat(200)
+        ))
+        .await
+        .unwrap();
+    }
+
+    for table_name in ["Plain", "Zstd", "Lz4"] {
+        assert_eq!(
+            glue.execute(format!("SELECT id, LENGTH(body) AS len FROM {table_name};"))
+                .await
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap(),
+            select!(id | len I64 | I64; 1 1000)
+        );
+    }
+
+    let plain = payload_sizes(&glue.storage, "Plain").await[0];
+    assert!(plain > 1000);
+    assert!(payload_sizes(&glue.storage, "Zstd").await[0] < plain / 10);
+    assert!(payload_sizes(&glue.storage, "Lz4").await[0] < plain / 10);
+
+    // rows keep their own compression, so changing it leaves the written rows readable
+    glue.storage.set_compression("Plain", Compression::Lz4);
+    glue.execute("INSERT INTO Plain VALUES (2, 'short');")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute("SELECT id, LENGTH(body) AS len FROM Plain;")
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(
+            id  | len
+            I64 | I64;
+            1     1000;
+            2     5
+        )
+    );
+}
+
+#[tokio::test]
+async fn rename_table() {
+    let mut storage = CompressedStorage::new(MemoryStorage::default(), Compression::None);
+    storage.set_compression("Logs", Compression::Zstd(3));
+    storage.set_compression("Archive", Compression::Lz4);
+
+    let mut glue = Glue::new(storage);
+    glue.execute("CREATE TABLE Logs (id INTEGER, body TEXT);")
+        .await
+        .unwrap();
+    glue.execute("ALTER TABLE Logs RENAME TO Archive;")
+        .await
+        .unwrap();
+
+    // the renamed table keeps its compression instead of the one of its new name
+    assert_eq!(glue.storage.compressions.get("Logs"), None);
+    assert_eq!(
+        glue.storage.compressions.get("Archive"),
+        Some(&Compression::Zstd(3))
+    );
+
+    glue.execute(format!(
+        "INSERT INTO Archive VALUES (1, '{}');",
+        "glue ".repeat(200)
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        glue.execute("SELECT id, LENGTH(body) AS len FROM Archive;")
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(id | len I64 | I64; 1 1000)
+    );
+}
@@ -53,6 +53,7 @@ impl CsvStorage {
                             name: header.to_string(),
                             data_type: DataType::Text,
                             unique: None,
+                            collation: None,
                             default: None,
                             nullable: true,
                         })
@@ -66,6 +67,9 @@ impl CsvStorage {
                 column_defs,
                 indexes: Vec::new(),
                 engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                view: None,
             };
 
             return Ok(Some((schema, true)));
@@ -34,6 +34,11 @@ impl Store for CsvStorage {
                     .and_then(OsStr::to_str)
                     .map_storage_err(CsvStorageError::FileNotFound)?;
 
+                // types and temporary files sit next to the data file of their table
+                if table_name.ends_with(".types") || table_name.ends_with(".tmp") {
+                    return Ok(None);
+                }
+
                 self.fetch_schema(table_name)?
                     .map(|(schema, _)| schema)
                     .map_storage_err(CsvStorageError::TableDoesNotExist)
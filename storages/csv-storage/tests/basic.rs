@@ -55,4 +55,22 @@ async fn basic() {
         "David".to_owned()    35
     };
     assert_eq!(actual, expected);
+
+    let actual = glue
+        .execute("SELECT TABLE_NAME FROM GLUE_TABLES")
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    let expected = select! {
+        TABLE_NAME
+        Str;
+        "Book".to_owned();
+        "City".to_owned();
+        "Employee".to_owned();
+        "Grocery".to_owned();
+        "Student".to_owned()
+    };
+    assert_eq!(actual, expected);
 }
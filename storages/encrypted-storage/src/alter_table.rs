@@ -0,0 +1,118 @@
+use {
+    super::EncryptedStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::ColumnDef,
+        data::{Key, Value},
+        error::Result,
+        executor::evaluate_stateless,
+        store::{AlterTable, DataRow, Store, StoreMut},
+    },
+};
+
+impl<T: Store> EncryptedStorage<T> {
+    async fn fetch_rows(&self, table_name: &str) -> Result<Vec<(Key, DataRow)>> {
+        self.scan_data(table_name).await?.collect()
+    }
+}
+
+/// Rows are bound to the name of their table and their values are only visible after they
+/// are decrypted, so renaming a table and adding or dropping a column encrypt every row of
+/// the table again. The inner storage changes the schema first.
+#[async_trait(?Send)]
+impl<T: AlterTable + Store + StoreMut> AlterTable for EncryptedStorage<T> {
+    async fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        let rows = self.fetch_rows(table_name).await?;
+        self.inner.rename_schema(table_name, new_table_name).await?;
+
+        self.insert_data(new_table_name, rows).await
+    }
+
+    async fn rename_column(
+        &mut self,
+        table_name: &str,
+        old_column_name: &str,
+        new_column_name: &str,
+    ) -> Result<()> {
+        self.inner
+            .rename_column(table_name, old_column_name, new_column_name)
+            .await
+    }
+
+    async fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let rows = self.fetch_rows(table_name).await?;
+        self.inner.add_column(table_name, column_def).await?;
+
+        let ColumnDef {
+            data_type,
+            nullable,
+            default,
+            ..
+        } = column_def;
+
+        // the inner storage fails without a default value when the column is not nullable
+        let value = match default {
+            Some(expr) => evaluate_stateless(None, expr)
+                .await?
+                .try_into_value(data_type, *nullable)?,
+            None => Value::Null,
+        };
+        let rows = rows
+            .into_iter()
+            .map(|(key, row)| match row {
+                DataRow::Vec(mut values) => {
+                    values.push(value.clone());
+
+                    (key, DataRow::Vec(values))
+                }
+                DataRow::Map(_) => (key, row),
+            })
+            .collect();
+
+        self.insert_data(table_name, rows).await
+    }
+
+    async fn drop_column(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let column_index = self
+            .inner
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .and_then(|column_defs| {
+                column_defs
+                    .iter()
+                    .position(|column_def| column_def.name == column_name)
+            });
+        let rows = match column_index {
+            Some(_) => self.fetch_rows(table_name).await?,
+            None => Vec::new(),
+        };
+
+        self.inner
+            .drop_column(table_name, column_name, if_exists)
+            .await?;
+
+        let column_index = match column_index {
+            Some(column_index) => column_index,
+            None => return Ok(()),
+        };
+        let rows = rows
+            .into_iter()
+            .map(|(key, row)| match row {
+                DataRow::Vec(mut values) if values.len() > column_index => {
+                    values.remove(column_index);
+
+                    (key, DataRow::Vec(values))
+                }
+                _ => (key, row),
+            })
+            .collect();
+
+        self.insert_data(table_name, rows).await
+    }
+}
@@ -0,0 +1,29 @@
+use {gluesql_core::error::Error, thiserror::Error};
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<EncryptedStorageError> for Error {
+    fn from(error: EncryptedStorageError) -> Self {
+        Error::StorageMsg(error.to_string())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum EncryptedStorageError {
+    #[error("failed to encrypt row")]
+    EncryptionFailed,
+
+    #[error("failed to decrypt row, the key is wrong or the row is corrupted")]
+    DecryptionFailed,
+
+    #[error("row is not an encrypted payload")]
+    InvalidPayload,
+}
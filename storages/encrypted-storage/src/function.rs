@@ -0,0 +1,31 @@
+use {
+    super::EncryptedStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::CustomFunction as StructCustomFunction,
+        error::Result,
+        store::{CustomFunction, CustomFunctionMut},
+    },
+};
+
+#[async_trait(?Send)]
+impl<T: CustomFunction> CustomFunction for EncryptedStorage<T> {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        self.inner.fetch_function(func_name).await
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.inner.fetch_all_functions().await
+    }
+}
+
+#[async_trait(?Send)]
+impl<T: CustomFunctionMut> CustomFunctionMut for EncryptedStorage<T> {
+    async fn insert_function(&mut self, func: StructCustomFunction) -> Result<()> {
+        self.inner.insert_function(func).await
+    }
+
+    async fn delete_function(&mut self, func_name: &str) -> Result<()> {
+        self.inner.delete_function(func_name).await
+    }
+}
@@ -0,0 +1,133 @@
+#![deny(clippy::str_to_string)]
+
+mod alter_table;
+pub mod error;
+mod function;
+mod metadata;
+mod store;
+mod store_mut;
+mod transaction;
+
+use {
+    aes_gcm::{
+        aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+        Aes256Gcm, Nonce,
+    },
+    error::{EncryptedStorageError, ResultExt},
+    gluesql_core::{
+        data::{Key, Value},
+        error::Result,
+        store::{DataRow, Index, IndexMut},
+    },
+};
+
+/// Size of the nonce which is stored in front of each encrypted row
+const NONCE_SIZE: usize = 12;
+
+/// Tags of the payloads, which tell what the ciphertext is bound to. Rows appended without a
+/// key are bound only to their table, since the inner storage gives them their keys.
+const TABLE_BOUND: u8 = 0;
+const KEY_BOUND: u8 = 1;
+
+/// Storage wrapper which encrypts rows with AES-256-GCM before they are passed to the inner
+/// storage.
+///
+/// Each row is stored as a single `BYTEA` value, so the inner storage has to keep rows as they
+/// are given, e.g. `SledStorage` and `MemoryStorage`. Schemas and keys are not encrypted.
+///
+/// The table name and the row key are authenticated along with each row, so a row copied
+/// to another table or key of the inner storage fails to decrypt. Rows of tables without a
+/// primary key are only bound to their table until they are written again by `UPDATE`.
+pub struct EncryptedStorage<T> {
+    inner: T,
+    cipher: Aes256Gcm,
+}
+
+impl<T> EncryptedStorage<T> {
+    pub fn new(inner: T, key: &[u8; 32]) -> Self {
+        let cipher = Aes256Gcm::new(key.into());
+
+        Self { inner, cipher }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Associated data which binds a row to its table, and to its key when the key is given
+fn associated_data(table_name: &str, key: Option<&Key>) -> Result<Vec<u8>> {
+    let mut aad = (table_name.len() as u64).to_be_bytes().to_vec();
+    aad.extend(table_name.as_bytes());
+
+    if let Some(key) = key {
+        aad.extend(key.to_cmp_be_bytes()?);
+    }
+
+    Ok(aad)
+}
+
+fn encrypt(
+    cipher: &Aes256Gcm,
+    table_name: &str,
+    key: Option<&Key>,
+    row: DataRow,
+) -> Result<DataRow> {
+    let plaintext = bincode::serialize(&row).map_storage_err()?;
+    let aad = associated_data(table_name, key)?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: &plaintext,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| EncryptedStorageError::EncryptionFailed)?;
+    let tag = match key {
+        Some(_) => KEY_BOUND,
+        None => TABLE_BOUND,
+    };
+    let payload = [&[tag][..], nonce.as_slice(), ciphertext.as_slice()].concat();
+
+    Ok(DataRow::Vec(vec![Value::Bytea(payload)]))
+}
+
+fn decrypt(cipher: &Aes256Gcm, table_name: &str, key: &Key, row: DataRow) -> Result<DataRow> {
+    let payload = match &row {
+        DataRow::Vec(values) => match values.as_slice() {
+            [Value::Bytea(payload)] if payload.len() > NONCE_SIZE => payload,
+            _ => return Err(EncryptedStorageError::InvalidPayload.into()),
+        },
+        DataRow::Map(_) => return Err(EncryptedStorageError::InvalidPayload.into()),
+    };
+
+    let (tag, payload) = payload.split_at(1);
+    let aad = match tag[0] {
+        TABLE_BOUND => associated_data(table_name, None)?,
+        KEY_BOUND => associated_data(table_name, Some(key))?,
+        _ => return Err(EncryptedStorageError::InvalidPayload.into()),
+    };
+    let (nonce, ciphertext) = payload.split_at(NONCE_SIZE);
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| EncryptedStorageError::DecryptionFailed)?;
+
+    bincode::deserialize(&plaintext).map_storage_err()
+}
+
+/// Indexes are not forwarded, since the inner storage only sees the encrypted payloads and
+/// an index of the plain values would leak them.
+impl<T> Index for EncryptedStorage<T> {}
+impl<T> IndexMut for EncryptedStorage<T> {}
@@ -0,0 +1,15 @@
+use {
+    super::EncryptedStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        error::Result,
+        store::{MetaIter, Metadata},
+    },
+};
+
+#[async_trait(?Send)]
+impl<T: Metadata> Metadata for EncryptedStorage<T> {
+    async fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.inner.scan_table_meta().await
+    }
+}
@@ -0,0 +1,40 @@
+use {
+    super::{decrypt, EncryptedStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl<T: Store> Store for EncryptedStorage<T> {
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.inner.fetch_all_schemas().await
+    }
+
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.inner.fetch_schema(table_name).await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.inner
+            .fetch_data(table_name, key)
+            .await?
+            .map(|row| decrypt(&self.cipher, table_name, key, row))
+            .transpose()
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let cipher = self.cipher.clone();
+        let table_name = table_name.to_owned();
+        let rows = self.inner.scan_data(&table_name).await?.map(move |item| {
+            let (key, row) = item?;
+
+            decrypt(&cipher, &table_name, &key, row).map(|row| (key, row))
+        });
+
+        Ok(Box::new(rows))
+    }
+}
@@ -0,0 +1,30 @@
+use {
+    async_trait::async_trait, gluesql_core::prelude::Glue,
+    gluesql_encrypted_storage::EncryptedStorage, memory_storage::MemoryStorage, test_suite::*,
+};
+
+struct EncryptedTester {
+    glue: Glue<EncryptedStorage<MemoryStorage>>,
+}
+
+#[async_trait(?Send)]
+impl Tester<EncryptedStorage<MemoryStorage>> for EncryptedTester {
+    async fn new(_: &str) -> Self {
+        let storage = EncryptedStorage::new(MemoryStorage::default(), &[7; 32]);
+        let glue = Glue::new(storage);
+
+        EncryptedTester { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<EncryptedStorage<MemoryStorage>> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(tokio::test, EncryptedTester);
+
+generate_alter_table_tests!(tokio::test, EncryptedTester);
+
+generate_metadata_table_tests!(tokio::test, EncryptedTester);
+
+generate_custom_function_tests!(tokio::test, EncryptedTester);
@@ -0,0 +1,117 @@
+use {
+    gluesql_core::{
+        prelude::{Error, Glue, Value::*},
+        store::{DataRow, Store, StoreMut},
+    },
+    gluesql_encrypted_storage::EncryptedStorage,
+    memory_storage::MemoryStorage,
+    test_suite::*,
+};
+
+#[tokio::test]
+async fn encryption() {
+    let storage = EncryptedStorage::new(MemoryStorage::default(), &[1; 32]);
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Secret (id INTEGER PRIMARY KEY, memo TEXT);")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Secret VALUES (1, 'open sesame'), (2, 'swordfish');")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        glue.execute("SELECT memo FROM Secret WHERE id = 2;")
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(memo Str; "swordfish".to_owned())
+    );
+
+    // the inner storage only holds the encrypted payloads
+    let inner = glue.storage.into_inner();
+    let rows = inner
+        .scan_data("Secret")
+        .await
+        .unwrap()
+        .map(|item| item.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 2);
+    for row in rows {
+        let payload = match row {
+            DataRow::Vec(values) => match values.as_slice() {
+                [Bytea(payload)] => payload.clone(),
+                _ => panic!("unexpected row: {values:?}"),
+            },
+            DataRow::Map(_) => panic!("unexpected schemaless row"),
+        };
+
+        let text = String::from_utf8_lossy(&payload);
+        assert!(!text.contains("open sesame") && !text.contains("swordfish"));
+    }
+
+    // rows cannot be read back with another key
+    let mut glue = Glue::new(EncryptedStorage::new(inner, &[2; 32]));
+    assert_eq!(
+        glue.execute("SELECT * FROM Secret;").await.unwrap_err(),
+        Error::StorageMsg(
+            "failed to decrypt row, the key is wrong or the row is corrupted".to_owned()
+        ),
+    );
+}
+
+#[tokio::test]
+async fn moved_rows() {
+    let storage = EncryptedStorage::new(MemoryStorage::default(), &[1; 32]);
+    let mut glue = Glue::new(storage);
+
+    glue.execute(
+        "
+        CREATE TABLE Secret (id INTEGER PRIMARY KEY, memo TEXT);
+        CREATE TABLE Copied (id INTEGER PRIMARY KEY, memo TEXT);
+        INSERT INTO Secret VALUES (1, 'open sesame'), (2, 'swordfish');
+        ",
+    )
+    .await
+    .unwrap();
+
+    // rows are copied to another table and swapped between keys of the inner storage
+    let mut inner = glue.storage.into_inner();
+    let rows = inner
+        .scan_data("Secret")
+        .await
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    inner.insert_data("Copied", rows.clone()).await.unwrap();
+    inner
+        .insert_data("Secret", vec![(rows[0].0.clone(), rows[1].1.clone())])
+        .await
+        .unwrap();
+
+    let mut glue = Glue::new(EncryptedStorage::new(inner, &[1; 32]));
+    let decryption_failed = Error::StorageMsg(
+        "failed to decrypt row, the key is wrong or the row is corrupted".to_owned(),
+    );
+    assert_eq!(
+        glue.execute("SELECT * FROM Copied;").await.unwrap_err(),
+        decryption_failed,
+    );
+    assert_eq!(
+        glue.execute("SELECT memo FROM Secret WHERE id = 1;")
+            .await
+            .unwrap_err(),
+        decryption_failed,
+    );
+    assert_eq!(
+        glue.execute("SELECT memo FROM Secret WHERE id = 2;")
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap(),
+        select!(memo Str; "swordfish".to_owned())
+    );
+}
@@ -20,6 +20,11 @@ impl AlterTable for MemoryStorage {
         item.schema.table_name = new_table_name.to_owned();
         self.items.insert(new_table_name.to_owned(), item);
 
+        if let Some(statistics) = self.statistics.remove(table_name) {
+            self.statistics
+                .insert(new_table_name.to_owned(), statistics);
+        }
+
         Ok(())
     }
 
@@ -9,7 +9,7 @@ use {
     async_trait::async_trait,
     gluesql_core::{
         chrono::Utc,
-        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        data::{CustomFunction as StructCustomFunction, Key, Schema, TableStatistics, Value},
         error::Result,
         store::{CustomFunction, CustomFunctionMut, DataRow, RowIter, Store, StoreMut},
     },
@@ -32,6 +32,7 @@ pub struct MemoryStorage {
     pub items: HashMap<String, Item>,
     pub metadata: HashMap<String, HashMap<String, Value>>,
     pub functions: HashMap<String, StructCustomFunction>,
+    pub statistics: HashMap<String, TableStatistics>,
 }
 
 #[async_trait(?Send)]
@@ -93,6 +94,10 @@ impl Store for MemoryStorage {
 
         Ok(rows)
     }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        Ok(self.statistics.get(table_name).cloned())
+    }
 }
 
 #[async_trait(?Send)]
@@ -118,6 +123,7 @@ impl StoreMut for MemoryStorage {
     async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
         self.items.remove(table_name);
         self.metadata.remove(table_name);
+        self.statistics.remove(table_name);
 
         Ok(())
     }
@@ -153,4 +159,22 @@ impl StoreMut for MemoryStorage {
 
         Ok(())
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        if let Some(item) = self.items.get_mut(table_name) {
+            item.rows.clear();
+        }
+
+        Ok(())
+    }
+
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        self.statistics.insert(table_name.to_owned(), statistics);
+
+        Ok(())
+    }
 }
@@ -84,6 +84,24 @@ async fn memory_storage_index() {
     );
 }
 
+#[tokio::test]
+async fn memory_storage_explain() {
+    use gluesql_core::prelude::{Glue, Value::*};
+
+    let storage = MemoryStorage::default();
+    let mut glue = Glue::new(storage);
+
+    // MemoryStorage reads every row whatever the comparisons are
+    exec!(glue "CREATE TABLE Item (id INTEGER PRIMARY KEY, qty INTEGER);");
+    test!(
+        glue "EXPLAIN SELECT qty FROM Item WHERE id > 1;",
+        Ok(vec![select_with_null!(
+            table                  | access                        | join;
+            Str("Item".to_owned())   Str("FULL SCAN".to_owned())     Null
+        )])
+    );
+}
+
 #[tokio::test]
 async fn memory_storage_transaction() {
     use gluesql_core::prelude::{Error, Glue, Payload};
@@ -96,3 +114,94 @@ async fn memory_storage_transaction() {
     test!(glue "COMMIT", Ok(vec![Payload::Commit]));
     test!(glue "ROLLBACK", Ok(vec![Payload::Rollback]));
 }
+
+#[tokio::test]
+async fn memory_storage_analyze() {
+    use gluesql_core::{
+        data::{ColumnStatistics, TableStatistics},
+        error::ExecuteError,
+        prelude::{Glue, Payload, Value::*},
+        store::Store,
+    };
+
+    let storage = MemoryStorage::default();
+    let mut glue = Glue::new(storage);
+
+    exec!(glue "CREATE TABLE Small (id INTEGER, name TEXT);");
+    exec!(glue "CREATE TABLE Big (id INTEGER, small_id INTEGER);");
+    exec!(glue "INSERT INTO Small VALUES (1, 'a'), (2, NULL);");
+    exec!(glue "INSERT INTO Big VALUES (1, 1), (2, 1), (3, 2), (4, 2);");
+
+    test!(glue "ANALYZE TABLE Small;", Ok(vec![Payload::Analyze]));
+    assert_eq!(
+        glue.storage.fetch_statistics("Small").await,
+        Ok(Some(TableStatistics {
+            row_count: 2,
+            columns: vec![
+                ColumnStatistics {
+                    name: "id".to_owned(),
+                    distinct_count: 2,
+                    null_count: 0,
+                    min: Some(I64(1)),
+                    max: Some(I64(2)),
+                },
+                ColumnStatistics {
+                    name: "name".to_owned(),
+                    distinct_count: 1,
+                    null_count: 1,
+                    min: Some(Str("a".to_owned())),
+                    max: Some(Str("a".to_owned())),
+                },
+            ],
+        }))
+    );
+    assert_eq!(glue.storage.fetch_statistics("Big").await, Ok(None));
+
+    let explain = "
+        EXPLAIN SELECT Small.name, Big.id
+        FROM Small
+        JOIN Big ON Big.small_id = Small.id
+    ";
+    test!(
+        glue explain,
+        Ok(vec![select_with_null!(
+            table           | access                   | join;
+            Str("Small".to_owned()) Str("FULL SCAN".to_owned()) Null;
+            Str("Big".to_owned())   Str("FULL SCAN".to_owned()) Str("HASH JOIN".to_owned())
+        )])
+    );
+
+    // the larger table is read first and the smaller one is built into the hash table
+    test!(glue "ANALYZE;", Ok(vec![Payload::Analyze]));
+    test!(
+        glue explain,
+        Ok(vec![select_with_null!(
+            table           | access                   | join;
+            Str("Big".to_owned())   Str("FULL SCAN".to_owned()) Null;
+            Str("Small".to_owned()) Str("FULL SCAN".to_owned()) Str("HASH JOIN".to_owned())
+        )])
+    );
+    test!(
+        glue "
+            SELECT Small.name, Big.id
+            FROM Small
+            JOIN Big ON Big.small_id = Small.id
+            ORDER BY Big.id
+        ",
+        Ok(vec![select_with_null!(
+            name                  | id;
+            Str("a".to_owned())     I64(1);
+            Str("a".to_owned())     I64(2);
+            Null                    I64(3);
+            Null                    I64(4)
+        )])
+    );
+
+    test!(
+        glue "ANALYZE Missing;",
+        Err(ExecuteError::TableNotFound("Missing".to_owned()).into())
+    );
+
+    exec!(glue "DROP TABLE Big;");
+    assert_eq!(glue.storage.fetch_statistics("Big").await, Ok(None));
+}
@@ -0,0 +1,181 @@
+use {
+    crate::error::MongoStorageError,
+    gluesql_core::{
+        ast::{ColumnDef, ColumnUniqueOption, DataType},
+        chrono::NaiveDateTime,
+        data::{Key, Value},
+        error::Result,
+        store::DataRow,
+    },
+    mongodb::bson::{oid::ObjectId, spec::BinarySubtype, Binary, Bson, Document},
+    std::collections::HashMap,
+};
+
+pub fn value_to_bson(value: Value) -> Bson {
+    match value {
+        Value::Null => Bson::Null,
+        Value::Bool(v) => Bson::Boolean(v),
+        Value::I8(v) => Bson::Int32(v.into()),
+        Value::I16(v) => Bson::Int32(v.into()),
+        Value::I32(v) => Bson::Int32(v),
+        Value::I64(v) => Bson::Int64(v),
+        Value::U8(v) => Bson::Int32(v.into()),
+        Value::U16(v) => Bson::Int32(v.into()),
+        Value::U32(v) => Bson::Int64(v.into()),
+        Value::F32(v) => Bson::Double(v.into()),
+        Value::F64(v) => Bson::Double(v),
+        Value::Str(v) => Bson::String(v),
+        Value::Bytea(bytes) => Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes,
+        }),
+        Value::Map(values) => Bson::Document(
+            values
+                .into_iter()
+                .map(|(key, value)| (key, value_to_bson(value)))
+                .collect(),
+        ),
+        Value::List(values) => Bson::Array(values.into_iter().map(value_to_bson).collect()),
+        // the other types are kept as text and cast back to the column type when read
+        value => Bson::String(String::from(value)),
+    }
+}
+
+pub fn bson_to_value(bson: Bson) -> Value {
+    match bson {
+        Bson::Null | Bson::Undefined => Value::Null,
+        Bson::Boolean(v) => Value::Bool(v),
+        Bson::Int32(v) => Value::I64(v.into()),
+        Bson::Int64(v) => Value::I64(v),
+        Bson::Double(v) => Value::F64(v),
+        Bson::String(v) => Value::Str(v),
+        Bson::Binary(Binary { bytes, .. }) => Value::Bytea(bytes),
+        Bson::ObjectId(v) => Value::Str(v.to_hex()),
+        Bson::DateTime(v) => NaiveDateTime::from_timestamp_millis(v.timestamp_millis())
+            .map(Value::Timestamp)
+            .unwrap_or(Value::Null),
+        Bson::Document(document) => Value::Map(document_to_map(document)),
+        Bson::Array(values) => Value::List(values.into_iter().map(bson_to_value).collect()),
+        bson => Value::Str(bson.to_string()),
+    }
+}
+
+/// Whether values of the data type are stored as BSON values of their own type, so that
+/// MongoDB compares them the same way as GlueSQL does
+pub fn is_native(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int
+            | DataType::Uint8
+            | DataType::Uint16
+            | DataType::Uint32
+            | DataType::Float32
+            | DataType::Float
+            | DataType::Text
+            | DataType::Bytea
+    )
+}
+
+/// `_id` of a document, keys of 12 bytes are the ones read from object ids
+pub fn key_to_bson(key: Key) -> Bson {
+    match key {
+        Key::Bytea(bytes) => match <[u8; 12]>::try_from(bytes.as_slice()) {
+            Ok(bytes) => Bson::ObjectId(ObjectId::from_bytes(bytes)),
+            Err(_) => value_to_bson(Value::Bytea(bytes)),
+        },
+        key => value_to_bson(Value::from(key)),
+    }
+}
+
+fn bson_to_key(bson: Bson) -> Result<Key> {
+    match bson {
+        Bson::ObjectId(v) => Ok(Key::Bytea(v.bytes().to_vec())),
+        bson => Key::try_from(bson_to_value(bson)),
+    }
+}
+
+fn document_to_map(document: Document) -> HashMap<String, Value> {
+    document
+        .into_iter()
+        .map(|(key, bson)| (key, bson_to_value(bson)))
+        .collect()
+}
+
+pub fn document_to_row(
+    mut document: Document,
+    column_defs: Option<&[ColumnDef]>,
+) -> Result<(Key, DataRow)> {
+    let id = document.get("_id").cloned().unwrap_or(Bson::Null);
+    let column_defs = match column_defs {
+        Some(column_defs) => column_defs,
+        None => {
+            let row = DataRow::Map(document_to_map(document));
+
+            return Ok((bson_to_key(id)?, row));
+        }
+    };
+
+    let mut key: Option<Key> = None;
+    let values = column_defs
+        .iter()
+        .map(|column_def| {
+            let value = bson_to_value(document.remove(&column_def.name).unwrap_or(Bson::Null));
+            let value = match value.get_type() {
+                Some(data_type) if data_type != column_def.data_type => {
+                    value.cast(&column_def.data_type)?
+                }
+                _ => value,
+            };
+
+            if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
+                key = Key::try_from(&value).map(Some)?;
+            }
+
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let key = match key {
+        Some(key) => key,
+        None => bson_to_key(id)?,
+    };
+
+    Ok((key, DataRow::Vec(values)))
+}
+
+pub fn row_to_document(
+    key: Key,
+    row: DataRow,
+    column_defs: Option<&[ColumnDef]>,
+) -> Result<Document> {
+    let mut document = Document::new();
+    document.insert("_id", key_to_bson(key));
+
+    match (row, column_defs) {
+        (DataRow::Vec(values), Some(column_defs)) => {
+            for (column_def, value) in column_defs.iter().zip(values) {
+                document.insert(column_def.name.clone(), value_to_bson(value));
+            }
+        }
+        (DataRow::Map(values), None) => {
+            for (key, value) in values {
+                // `_id` is given by the key of the row
+                if key != "_id" {
+                    document.insert(key, value_to_bson(value));
+                }
+            }
+        }
+        (DataRow::Vec(_), None) => {
+            return Err(MongoStorageError::UnreachableVecTypeDataRowTypeFound.into());
+        }
+        (DataRow::Map(_), Some(_)) => {
+            return Err(MongoStorageError::UnreachableMapTypeDataRowFound.into());
+        }
+    }
+
+    Ok(document)
+}
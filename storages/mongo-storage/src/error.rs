@@ -0,0 +1,32 @@
+use {gluesql_core::error::Error, thiserror::Error};
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+impl From<MongoStorageError> for Error {
+    fn from(error: MongoStorageError) -> Self {
+        Error::StorageMsg(error.to_string())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MongoStorageError {
+    #[error("schema document of table {0} is invalid")]
+    InvalidSchemaDocument(String),
+
+    #[error("table does not exist: {0}")]
+    TableDoesNotExist(String),
+
+    #[error("unreachable map type data row found")]
+    UnreachableMapTypeDataRowFound,
+
+    #[error("unreachable vector data row type found")]
+    UnreachableVecTypeDataRowTypeFound,
+}
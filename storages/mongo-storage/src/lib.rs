@@ -0,0 +1,117 @@
+#![deny(clippy::str_to_string)]
+
+mod convert;
+pub mod error;
+mod store;
+mod store_mut;
+
+use {
+    error::{MongoStorageError, ResultExt},
+    futures::TryStreamExt,
+    gluesql_core::{
+        data::Schema,
+        error::Result,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, RowIter,
+            Transaction,
+        },
+    },
+    mongodb::{
+        bson::{doc, Document},
+        options::FindOptions,
+        Client, Collection, Database,
+    },
+};
+
+/// Collection which keeps the schemas of the tables created through GlueSQL
+const SCHEMA_COLLECTION: &str = "__gluesql_schema";
+
+/// Storage over the collections of a MongoDB database.
+///
+/// Collections without a schema of their own are schemaless tables, which read each
+/// document as a row of its fields including `_id`. Tables created through GlueSQL keep
+/// their schema in the `__gluesql_schema` collection.
+pub struct MongoStorage {
+    pub db: Database,
+}
+
+impl MongoStorage {
+    pub async fn new(conn_str: &str, db_name: &str) -> Result<Self> {
+        let client = Client::with_uri_str(conn_str).await.map_storage_err()?;
+
+        Ok(Self {
+            db: client.database(db_name),
+        })
+    }
+
+    fn collection(&self, table_name: &str) -> Collection<Document> {
+        self.db.collection(table_name)
+    }
+
+    fn schema_collection(&self) -> Collection<Document> {
+        self.db.collection(SCHEMA_COLLECTION)
+    }
+
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let document = self
+            .schema_collection()
+            .find_one(doc! { "_id": table_name }, None)
+            .await
+            .map_storage_err()?;
+
+        if let Some(document) = document {
+            let schema = document
+                .get_str("schema")
+                .map_err(|_| MongoStorageError::InvalidSchemaDocument(table_name.to_owned()))?;
+
+            return serde_json::from_str(schema).map(Some).map_storage_err();
+        }
+
+        let exists = !self
+            .db
+            .list_collection_names(doc! { "name": table_name })
+            .await
+            .map_storage_err()?
+            .is_empty();
+
+        Ok(exists.then(|| Schema {
+            table_name: table_name.to_owned(),
+            column_defs: None,
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
+        }))
+    }
+
+    async fn scan_data(&self, table_name: &str, filter: Option<Document>) -> Result<RowIter> {
+        let schema = match self.fetch_schema(table_name).await? {
+            Some(schema) => schema,
+            None => return Ok(Box::new(std::iter::empty())),
+        };
+
+        let options = FindOptions::builder().sort(doc! { "_id": 1 }).build();
+        let rows = self
+            .collection(table_name)
+            .find(filter, options)
+            .await
+            .map_storage_err()?
+            .try_collect::<Vec<_>>()
+            .await
+            .map_storage_err()?
+            .into_iter()
+            .map(|document| convert::document_to_row(document, schema.column_defs.as_deref()))
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(rows.into_iter()))
+    }
+}
+
+impl AlterTable for MongoStorage {}
+impl CustomFunction for MongoStorage {}
+impl CustomFunctionMut for MongoStorage {}
+impl Index for MongoStorage {}
+impl IndexMut for MongoStorage {}
+impl Transaction for MongoStorage {}
+impl Metadata for MongoStorage {}
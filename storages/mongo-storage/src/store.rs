@@ -0,0 +1,133 @@
+use {
+    crate::{
+        convert::{self, is_native, key_to_bson, value_to_bson},
+        error::ResultExt,
+        MongoStorage, SCHEMA_COLLECTION,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::IndexOperator,
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+    mongodb::bson::{doc, Document},
+};
+
+#[async_trait(?Send)]
+impl Store for MongoStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.fetch_schema(table_name).await
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let table_names = self
+            .db
+            .list_collection_names(None)
+            .await
+            .map_storage_err()?;
+
+        let mut schemas = Vec::new();
+        for table_name in table_names {
+            if table_name == SCHEMA_COLLECTION || table_name.starts_with("system.") {
+                continue;
+            }
+
+            schemas.extend(self.fetch_schema(&table_name).await?);
+        }
+
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let column_defs = match self.fetch_schema(table_name).await? {
+            Some(schema) => schema.column_defs,
+            None => return Ok(None),
+        };
+
+        self.collection(table_name)
+            .find_one(doc! { "_id": key_to_bson(key.clone()) }, None)
+            .await
+            .map_storage_err()?
+            .map(|document| convert::document_to_row(document, column_defs.as_deref()))
+            .transpose()
+            .map(|row| row.map(|(_, row)| row))
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        self.scan_data(table_name, None).await
+    }
+
+    /// Pushes the values down as a query filter. A field of another BSON type than its value
+    /// still passes the filter, as GlueSQL may cast one of them to compare.
+    async fn scan_data_by_values(
+        &self,
+        table_name: &str,
+        _columns: Option<&[String]>,
+        values: &[(String, Value)],
+    ) -> Result<RowIter> {
+        let column_defs = match self.fetch_schema(table_name).await? {
+            Some(schema) => schema.column_defs,
+            None => return Ok(Box::new(std::iter::empty())),
+        };
+
+        let conditions = values
+            .iter()
+            .filter(|(column, _)| match &column_defs {
+                Some(column_defs) => column_defs.iter().any(|column_def| {
+                    &column_def.name == column && is_native(&column_def.data_type)
+                }),
+                None => true,
+            })
+            .map(|(column, value)| {
+                let column = column.as_str();
+                let bson_type = match value {
+                    Value::Bool(_) => "bool",
+                    Value::I64(_) => "number",
+                    _ => "string",
+                };
+
+                doc! {
+                    "$or": [
+                        { column: value_to_bson(value.clone()) },
+                        { column: { "$not": { "$type": bson_type } } },
+                    ]
+                }
+            })
+            .collect::<Vec<Document>>();
+
+        let filter = (!conditions.is_empty()).then(|| doc! { "$and": conditions });
+
+        self.scan_data(table_name, filter).await
+    }
+
+    /// Equalities are sent to MongoDB by `scan_data_by_values`
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        let column_defs = match self.fetch_schema(table_name).await? {
+            Some(schema) => schema.column_defs,
+            None => return Ok(Vec::new()),
+        };
+
+        let predicates = predicates
+            .iter()
+            .filter(|(column, op, _)| {
+                op == &IndexOperator::Eq
+                    && match &column_defs {
+                        Some(column_defs) => column_defs.iter().any(|column_def| {
+                            &column_def.name == column && is_native(&column_def.data_type)
+                        }),
+                        None => true,
+                    }
+            })
+            .cloned()
+            .collect();
+
+        Ok(predicates)
+    }
+}
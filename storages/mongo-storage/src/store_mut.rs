@@ -0,0 +1,116 @@
+use {
+    crate::{
+        convert::{key_to_bson, row_to_document},
+        error::{MongoStorageError, ResultExt},
+        MongoStorage,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::ColumnDef,
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+    mongodb::{
+        bson::{doc, oid::ObjectId},
+        options::ReplaceOptions,
+    },
+};
+
+#[async_trait(?Send)]
+impl StoreMut for MongoStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let table_name = schema.table_name.as_str();
+        let value = serde_json::to_string(schema).map_storage_err()?;
+        let options = ReplaceOptions::builder().upsert(true).build();
+
+        self.schema_collection()
+            .replace_one(
+                doc! { "_id": table_name },
+                doc! { "_id": table_name, "schema": value },
+                options,
+            )
+            .await
+            .map(|_| ())
+            .map_storage_err()
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.schema_collection()
+            .delete_one(doc! { "_id": table_name }, None)
+            .await
+            .map_storage_err()?;
+
+        self.collection(table_name)
+            .drop(None)
+            .await
+            .map_storage_err()
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let column_defs = self.column_defs(table_name).await?;
+        let documents = rows
+            .into_iter()
+            .map(|row| {
+                let key = Key::Bytea(ObjectId::new().bytes().to_vec());
+
+                row_to_document(key, row, column_defs.as_deref())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.collection(table_name)
+            .insert_many(documents, None)
+            .await
+            .map(|_| ())
+            .map_storage_err()
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let column_defs = self.column_defs(table_name).await?;
+        let collection = self.collection(table_name);
+
+        for (key, row) in rows {
+            let filter = doc! { "_id": key_to_bson(key.clone()) };
+            let document = row_to_document(key, row, column_defs.as_deref())?;
+            let options = ReplaceOptions::builder().upsert(true).build();
+
+            collection
+                .replace_one(filter, document, options)
+                .await
+                .map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let ids = keys.into_iter().map(key_to_bson).collect::<Vec<_>>();
+
+        self.collection(table_name)
+            .delete_many(doc! { "_id": { "$in": ids } }, None)
+            .await
+            .map(|_| ())
+            .map_storage_err()
+    }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        self.collection(table_name)
+            .delete_many(doc! {}, None)
+            .await
+            .map(|_| ())
+            .map_storage_err()
+    }
+}
+
+impl MongoStorage {
+    async fn column_defs(&self, table_name: &str) -> Result<Option<Vec<ColumnDef>>> {
+        self.fetch_schema(table_name)
+            .await?
+            .map(|schema| schema.column_defs)
+            .ok_or_else(|| MongoStorageError::TableDoesNotExist(table_name.to_owned()).into())
+    }
+}
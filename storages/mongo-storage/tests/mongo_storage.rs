@@ -0,0 +1,31 @@
+#![cfg(feature = "test-mongo")]
+
+use {
+    async_trait::async_trait, gluesql_core::prelude::Glue, gluesql_mongo_storage::MongoStorage,
+    std::env, test_suite::*,
+};
+
+struct MongoTester {
+    glue: Glue<MongoStorage>,
+}
+
+#[async_trait(?Send)]
+impl Tester<MongoStorage> for MongoTester {
+    async fn new(namespace: &str) -> Self {
+        let url = env::var("MONGO_URL").unwrap_or_else(|_| "mongodb://127.0.0.1:27017".to_owned());
+        let storage = MongoStorage::new(&url, namespace)
+            .await
+            .expect("MongoStorage::new");
+
+        storage.db.drop(None).await.expect("drop database");
+
+        let glue = Glue::new(storage);
+        MongoTester { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<MongoStorage> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(tokio::test, MongoTester);
@@ -0,0 +1,55 @@
+#![cfg(feature = "test-mongo")]
+
+use {
+    gluesql_core::prelude::{Glue, Value::*},
+    gluesql_mongo_storage::MongoStorage,
+    std::env,
+    test_suite::*,
+};
+
+#[tokio::test]
+async fn pushdown() {
+    let url = env::var("MONGO_URL").unwrap_or_else(|_| "mongodb://127.0.0.1:27017".to_owned());
+    let storage = MongoStorage::new(&url, "pushdown").await.unwrap();
+    storage.db.drop(None).await.unwrap();
+
+    let mut glue = Glue::new(storage);
+    glue.execute(
+        r#"
+        CREATE TABLE Event;
+        INSERT INTO Event VALUES
+            ('{"id": 1, "kind": "click", "ok": true}'),
+            ('{"id": 2, "kind": "view", "ok": true}'),
+            ('{"id": 3, "kind": "scroll", "ok": false}'),
+            ('{"id": 4, "kind": "click", "ok": false}');
+        "#,
+    )
+    .await
+    .unwrap();
+
+    macro_rules! test {
+        ($sql: literal, $expected: expr) => {
+            let actual = glue
+                .execute($sql)
+                .await
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap();
+            assert_eq!(actual, $expected, "{}", $sql);
+        };
+    }
+
+    test!(
+        "SELECT id FROM Event WHERE kind = 'click' AND ok = TRUE",
+        select!(id I64; 1)
+    );
+    test!(
+        "SELECT id FROM Event WHERE (id = 2 OR id = 3) AND kind = 'view'",
+        select!(id I64; 2)
+    );
+    test!(
+        "SELECT COUNT(*) AS cnt FROM Event WHERE ok = FALSE",
+        select!(cnt I64; 2)
+    );
+}
@@ -0,0 +1,235 @@
+#![deny(clippy::str_to_string)]
+
+pub mod error;
+mod store;
+mod store_mut;
+
+pub use object_store;
+
+use {
+    bytes::Bytes,
+    error::{ObjectStoreStorageError, ResultExt},
+    gluesql_core::{
+        data::Key,
+        error::Result,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
+            Transaction,
+        },
+    },
+    object_store::{path::Path, Error as ObjectStoreError, ObjectStore},
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    std::{
+        cell::RefCell,
+        collections::{BTreeMap, HashMap},
+        sync::Arc,
+    },
+};
+
+/// Storage for append-mostly tables on an object store such as S3.
+///
+/// Every write statement adds one segment object to its table instead of rewriting the
+/// table, and reading a table replays its segments in order. A later segment overrides the
+/// rows of the same keys, and deleted rows are kept as tombstones until [`compact`] merges
+/// the segments. The manifest of each table lists its segments and is cached locally, so a
+/// table is expected to have a single writer.
+///
+/// ```text
+/// {prefix}/{table}/schema.json
+/// {prefix}/{table}/manifest.json
+/// {prefix}/{table}/segments/00000000000000000000.json
+/// ```
+///
+/// [`compact`]: ObjectStoreStorage::compact
+pub struct ObjectStoreStorage {
+    pub store: Arc<dyn ObjectStore>,
+    pub prefix: Path,
+    manifests: RefCell<HashMap<String, Manifest>>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct Manifest {
+    segments: Vec<u64>,
+    next_segment: u64,
+    next_id: i64,
+}
+
+/// Rows written by a single statement, `None` marks a deleted row
+type Segment = Vec<(Key, Option<DataRow>)>;
+
+impl ObjectStoreStorage {
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: &str) -> Self {
+        Self {
+            store,
+            prefix: Path::from(prefix),
+            manifests: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Merges the segments of the table into a single segment without tombstones
+    pub async fn compact(&mut self, table_name: &str) -> Result<()> {
+        let manifest = self.manifest(table_name).await?;
+        if manifest.segments.len() <= 1 {
+            return Ok(());
+        }
+
+        let rows = self.scan_rows(table_name).await?;
+        let segment = rows
+            .into_iter()
+            .map(|(key, row)| (key, Some(row)))
+            .collect();
+        let prev_segments = manifest.segments.clone();
+        let manifest = Manifest {
+            segments: Vec::new(),
+            ..manifest
+        };
+
+        self.write_segment(table_name, manifest, segment).await?;
+        self.delete_segments(table_name, &prev_segments).await
+    }
+
+    fn table_path(&self, table_name: &str) -> Path {
+        self.prefix.child(table_name)
+    }
+
+    fn schema_path(&self, table_name: &str) -> Path {
+        self.table_path(table_name).child("schema.json")
+    }
+
+    fn manifest_path(&self, table_name: &str) -> Path {
+        self.table_path(table_name).child("manifest.json")
+    }
+
+    fn segment_path(&self, table_name: &str, segment: u64) -> Path {
+        self.table_path(table_name)
+            .child("segments")
+            .child(format!("{segment:020}.json"))
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &Path) -> Result<Option<T>> {
+        let bytes = match self.store.get(path).await {
+            Ok(result) => result.bytes().await.map_storage_err()?,
+            Err(ObjectStoreError::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(e).map_storage_err(),
+        };
+
+        serde_json::from_slice(&bytes).map(Some).map_storage_err()
+    }
+
+    async fn put_json<T: Serialize>(&self, path: &Path, value: &T) -> Result<()> {
+        let bytes = serde_json::to_vec(value).map_storage_err()?;
+
+        self.store
+            .put(path, Bytes::from(bytes))
+            .await
+            .map(|_| ())
+            .map_storage_err()
+    }
+
+    async fn manifest(&self, table_name: &str) -> Result<Manifest> {
+        if let Some(manifest) = self.manifests.borrow().get(table_name) {
+            return Ok(manifest.clone());
+        }
+
+        let manifest = self
+            .get_json(&self.manifest_path(table_name))
+            .await?
+            .unwrap_or_default();
+
+        self.manifests
+            .borrow_mut()
+            .insert(table_name.to_owned(), manifest.clone());
+
+        Ok(manifest)
+    }
+
+    async fn write_manifest(&self, table_name: &str, manifest: Manifest) -> Result<()> {
+        self.put_json(&self.manifest_path(table_name), &manifest)
+            .await?;
+        self.manifests
+            .borrow_mut()
+            .insert(table_name.to_owned(), manifest);
+
+        Ok(())
+    }
+
+    /// Puts the segment first, so a failed write leaves an unlisted object behind instead of
+    /// a manifest which refers to a missing segment
+    async fn write_segment(
+        &self,
+        table_name: &str,
+        mut manifest: Manifest,
+        segment: Segment,
+    ) -> Result<()> {
+        let segment_id = manifest.next_segment;
+
+        self.put_json(&self.segment_path(table_name, segment_id), &segment)
+            .await?;
+
+        manifest.segments.push(segment_id);
+        manifest.next_segment += 1;
+
+        self.write_manifest(table_name, manifest).await
+    }
+
+    async fn delete_segments(&self, table_name: &str, segments: &[u64]) -> Result<()> {
+        for segment in segments {
+            self.store
+                .delete(&self.segment_path(table_name, *segment))
+                .await
+                .map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn read_segment(&self, table_name: &str, segment_id: u64) -> Result<Segment> {
+        let path = self.segment_path(table_name, segment_id);
+
+        self.get_json::<Segment>(&path)
+            .await?
+            .ok_or_else(|| ObjectStoreStorageError::SegmentNotFound(path.to_string()).into())
+    }
+
+    /// Reads the segments newest first and stops at the first one which has the key, so a
+    /// lookup does not replay the whole table
+    async fn fetch_row(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let manifest = self.manifest(table_name).await?;
+
+        for segment_id in manifest.segments.into_iter().rev() {
+            let segment = self.read_segment(table_name, segment_id).await?;
+
+            if let Some((_, row)) = segment.into_iter().rev().find(|(k, _)| k == key) {
+                return Ok(row);
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn scan_rows(&self, table_name: &str) -> Result<BTreeMap<Key, DataRow>> {
+        let manifest = self.manifest(table_name).await?;
+        let mut rows = BTreeMap::new();
+
+        for segment_id in manifest.segments {
+            let segment = self.read_segment(table_name, segment_id).await?;
+
+            for (key, row) in segment {
+                match row {
+                    Some(row) => rows.insert(key, row),
+                    None => rows.remove(&key),
+                };
+            }
+        }
+
+        Ok(rows)
+    }
+}
+
+impl AlterTable for ObjectStoreStorage {}
+impl CustomFunction for ObjectStoreStorage {}
+impl CustomFunctionMut for ObjectStoreStorage {}
+impl Index for ObjectStoreStorage {}
+impl IndexMut for ObjectStoreStorage {}
+impl Transaction for ObjectStoreStorage {}
+impl Metadata for ObjectStoreStorage {}
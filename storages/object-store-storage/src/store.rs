@@ -0,0 +1,46 @@
+use {
+    crate::{error::ResultExt, ObjectStoreStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl Store for ObjectStoreStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.get_json(&self.schema_path(table_name)).await
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let tables = self
+            .store
+            .list_with_delimiter(Some(&self.prefix))
+            .await
+            .map_storage_err()?
+            .common_prefixes;
+
+        let mut schemas = Vec::new();
+        for table in tables {
+            if let Some(table_name) = table.filename() {
+                schemas.extend(self.fetch_schema(table_name).await?);
+            }
+        }
+
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.fetch_row(table_name, key).await
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let rows = self.scan_rows(table_name).await?;
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+}
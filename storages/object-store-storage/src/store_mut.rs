@@ -0,0 +1,90 @@
+use {
+    crate::{error::ResultExt, Manifest, ObjectStoreStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+    object_store::Error as ObjectStoreError,
+};
+
+#[async_trait(?Send)]
+impl StoreMut for ObjectStoreStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        self.put_json(&self.schema_path(&schema.table_name), schema)
+            .await
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let manifest = self.manifest(table_name).await?;
+
+        self.delete_segments(table_name, &manifest.segments).await?;
+
+        for path in [self.manifest_path(table_name), self.schema_path(table_name)] {
+            match self.store.delete(&path).await {
+                Ok(()) | Err(ObjectStoreError::NotFound { .. }) => {}
+                Err(e) => return Err(e).map_storage_err(),
+            }
+        }
+
+        self.manifests.get_mut().remove(table_name);
+
+        Ok(())
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut manifest = self.manifest(table_name).await?;
+        let segment = rows
+            .into_iter()
+            .map(|row| {
+                manifest.next_id += 1;
+
+                (Key::I64(manifest.next_id), Some(row))
+            })
+            .collect();
+
+        self.write_segment(table_name, manifest, segment).await
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let manifest = self.manifest(table_name).await?;
+        let segment = rows
+            .into_iter()
+            .map(|(key, row)| (key, Some(row)))
+            .collect();
+
+        self.write_segment(table_name, manifest, segment).await
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let manifest = self.manifest(table_name).await?;
+        let segment = keys.into_iter().map(|key| (key, None)).collect();
+
+        self.write_segment(table_name, manifest, segment).await
+    }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let manifest = self.manifest(table_name).await?;
+        let prev_segments = manifest.segments.clone();
+        let manifest = Manifest {
+            segments: Vec::new(),
+            ..manifest
+        };
+
+        self.write_manifest(table_name, manifest).await?;
+        self.delete_segments(table_name, &prev_segments).await
+    }
+}
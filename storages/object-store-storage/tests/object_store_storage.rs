@@ -0,0 +1,26 @@
+use {
+    async_trait::async_trait,
+    gluesql_core::prelude::Glue,
+    gluesql_object_store_storage::{object_store::memory::InMemory, ObjectStoreStorage},
+    std::sync::Arc,
+    test_suite::*,
+};
+
+struct ObjectStoreTester {
+    glue: Glue<ObjectStoreStorage>,
+}
+
+#[async_trait(?Send)]
+impl Tester<ObjectStoreStorage> for ObjectStoreTester {
+    async fn new(namespace: &str) -> Self {
+        let storage = ObjectStoreStorage::new(Arc::new(InMemory::new()), namespace);
+        let glue = Glue::new(storage);
+        ObjectStoreTester { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<ObjectStoreStorage> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(tokio::test, ObjectStoreTester);
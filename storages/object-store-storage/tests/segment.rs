@@ -0,0 +1,89 @@
+use {
+    gluesql_core::prelude::{Error, Glue, Value::*},
+    gluesql_object_store_storage::{
+        object_store::{memory::InMemory, path::Path, ObjectStore},
+        ObjectStoreStorage,
+    },
+    std::sync::Arc,
+    test_suite::*,
+};
+
+async fn count_segments(store: &Arc<dyn ObjectStore>) -> usize {
+    store
+        .list_with_delimiter(Some(&Path::from("archive/Log/segments")))
+        .await
+        .unwrap()
+        .objects
+        .len()
+}
+
+#[tokio::test]
+async fn segment() {
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    let storage = ObjectStoreStorage::new(Arc::clone(&store), "archive");
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Log (id INTEGER, msg TEXT);")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Log VALUES (1, 'a'), (2, 'b');")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Log VALUES (3, 'c');")
+        .await
+        .unwrap();
+    glue.execute("DELETE FROM Log WHERE id = 2;").await.unwrap();
+    assert_eq!(count_segments(&store).await, 3);
+
+    let expected = || {
+        select!(
+        id  | msg
+        I64 | Str;
+        1     "a".to_owned();
+        3     "c".to_owned()
+        )
+    };
+    let actual = glue.execute("SELECT * FROM Log").await.unwrap();
+    assert_eq!(actual, vec![expected()]);
+
+    glue.storage.compact("Log").await.unwrap();
+    assert_eq!(count_segments(&store).await, 1);
+
+    let actual = glue.execute("SELECT * FROM Log").await.unwrap();
+    assert_eq!(actual, vec![expected()]);
+
+    glue.execute("DROP TABLE Log;").await.unwrap();
+    assert_eq!(count_segments(&store).await, 0);
+}
+
+#[tokio::test]
+async fn fetch_data() {
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    let storage = ObjectStoreStorage::new(Arc::clone(&store), "archive");
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Item VALUES (1, 'a'), (2, 'b');")
+        .await
+        .unwrap();
+    glue.execute("UPDATE Item SET name = 'c' WHERE id = 1;")
+        .await
+        .unwrap();
+
+    // a lookup by the primary key stops at the newest segment which has the key
+    let first_segment = Path::from("archive/Item/segments/00000000000000000000.json");
+    store.delete(&first_segment).await.unwrap();
+
+    let actual = glue.execute("SELECT name FROM Item WHERE id = 1").await;
+    assert_eq!(actual, Ok(vec![select!(name Str; "c".to_owned())]));
+
+    let actual = glue.execute("SELECT name FROM Item WHERE id = 2").await;
+    assert_eq!(
+        actual,
+        Err(Error::StorageMsg(format!(
+            "segment object not found: {first_segment}"
+        )))
+    );
+}
@@ -0,0 +1,251 @@
+use {
+    crate::error::{OptionExt, ParquetStorageError, ResultExt},
+    gluesql_core::{
+        ast::{ColumnDef, DataType},
+        chrono::{Datelike, NaiveDate, NaiveDateTime},
+        data::Value,
+        error::Result,
+    },
+    parquet::{
+        basic::{ConvertedType, LogicalType, Repetition, TimeUnit, Type as PhysicalType},
+        column::writer::ColumnWriter,
+        data_type::ByteArray,
+        record::Field,
+        schema::types::{Type, TypePtr},
+    },
+    std::sync::Arc,
+};
+
+/// Name of the only column of schemaless tables, which keeps each row as a JSON document
+pub const DOCUMENT_COLUMN: &str = "_doc";
+
+/// Days from 0001-01-01 to 1970-01-01, the epoch of parquet dates
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+pub fn parquet_schema(column_defs: Option<&[ColumnDef]>) -> Result<TypePtr> {
+    let fields = match column_defs {
+        Some(column_defs) => column_defs
+            .iter()
+            .map(
+                |ColumnDef {
+                     name, data_type, ..
+                 }| field_type(name, data_type),
+            )
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![field_type(DOCUMENT_COLUMN, &DataType::Text)?],
+    };
+
+    Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()
+        .map(Arc::new)
+        .map_storage_err()
+}
+
+fn field_type(name: &str, data_type: &DataType) -> Result<TypePtr> {
+    let integer = |bit_width, is_signed| {
+        Some(LogicalType::Integer {
+            bit_width,
+            is_signed,
+        })
+    };
+
+    let (physical_type, logical_type) = match data_type {
+        DataType::Boolean => (PhysicalType::BOOLEAN, None),
+        DataType::Int8 => (PhysicalType::INT32, integer(8, true)),
+        DataType::Int16 => (PhysicalType::INT32, integer(16, true)),
+        DataType::Int32 => (PhysicalType::INT32, None),
+        DataType::Int => (PhysicalType::INT64, None),
+        DataType::Uint8 => (PhysicalType::INT32, integer(8, false)),
+        DataType::Uint16 => (PhysicalType::INT32, integer(16, false)),
+        DataType::Uint32 => (PhysicalType::INT32, integer(32, false)),
+        DataType::Uint64 => (PhysicalType::INT64, integer(64, false)),
+        DataType::Float32 => (PhysicalType::FLOAT, None),
+        DataType::Float => (PhysicalType::DOUBLE, None),
+        DataType::Date => (PhysicalType::INT32, Some(LogicalType::Date)),
+        DataType::Timestamp => (
+            PhysicalType::INT64,
+            Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c: false,
+                unit: TimeUnit::MICROS(Default::default()),
+            }),
+        ),
+        DataType::Bytea => (PhysicalType::BYTE_ARRAY, None),
+        // the other types are kept as text and cast back to the column type when read
+        _ => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+    };
+
+    Type::primitive_type_builder(name, physical_type)
+        .with_repetition(Repetition::OPTIONAL)
+        .with_logical_type(logical_type)
+        .build()
+        .map(Arc::new)
+        .map_storage_err()
+}
+
+/// Data type of a column of a parquet file which has no schema file
+pub fn data_type(field: &Type) -> DataType {
+    let converted_type = field.get_basic_info().converted_type();
+
+    if !field.is_primitive() {
+        return match converted_type {
+            ConvertedType::LIST => DataType::List,
+            _ => DataType::Map,
+        };
+    }
+
+    match (field.get_physical_type(), converted_type) {
+        (PhysicalType::BOOLEAN, _) => DataType::Boolean,
+        (PhysicalType::INT32, ConvertedType::INT_8) => DataType::Int8,
+        (PhysicalType::INT32, ConvertedType::INT_16) => DataType::Int16,
+        (PhysicalType::INT32, ConvertedType::UINT_8) => DataType::Uint8,
+        (PhysicalType::INT32, ConvertedType::UINT_16) => DataType::Uint16,
+        (PhysicalType::INT32, ConvertedType::UINT_32) => DataType::Uint32,
+        (PhysicalType::INT32, ConvertedType::DATE) => DataType::Date,
+        (PhysicalType::INT64, ConvertedType::UINT_64) => DataType::Uint64,
+        (
+            PhysicalType::INT64,
+            ConvertedType::TIMESTAMP_MILLIS | ConvertedType::TIMESTAMP_MICROS,
+        )
+        | (PhysicalType::INT96, _) => DataType::Timestamp,
+        (_, ConvertedType::DECIMAL) => DataType::Decimal,
+        (PhysicalType::INT32, _) => DataType::Int32,
+        (PhysicalType::INT64, _) => DataType::Int,
+        (PhysicalType::FLOAT, _) => DataType::Float32,
+        (PhysicalType::DOUBLE, _) => DataType::Float,
+        (_, ConvertedType::UTF8 | ConvertedType::ENUM | ConvertedType::JSON) => DataType::Text,
+        (PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY, _) => DataType::Bytea,
+    }
+}
+
+pub fn field_to_value(field: &Field, data_type: &DataType) -> Result<Value> {
+    let value = match field {
+        Field::Null => return Ok(Value::Null),
+        Field::Bool(v) => Value::Bool(*v),
+        Field::Byte(v) => Value::I8(*v),
+        Field::Short(v) => Value::I16(*v),
+        Field::Int(v) => Value::I32(*v),
+        Field::Long(v) => Value::I64(*v),
+        Field::UByte(v) => Value::U8(*v),
+        Field::UShort(v) => Value::U16(*v),
+        Field::UInt(v) => Value::U32(*v),
+        Field::ULong(v) => Value::U64(*v),
+        Field::Float(v) => Value::F32(*v),
+        Field::Double(v) => Value::F64(*v),
+        Field::Decimal(_) => Value::Str(field.to_string()),
+        Field::Str(v) => Value::Str(v.to_owned()),
+        Field::Bytes(v) => Value::Bytea(v.data().to_vec()),
+        Field::Date(days) => NaiveDate::from_num_days_from_ce_opt(days + UNIX_EPOCH_DAYS_FROM_CE)
+            .map(Value::Date)
+            .map_storage_err(ParquetStorageError::ValueTypeDoesNotMatchWithColumn(
+                field.to_string(),
+            ))?,
+        Field::TimestampMillis(v) => NaiveDateTime::from_timestamp_millis(*v)
+            .map(Value::Timestamp)
+            .map_storage_err(ParquetStorageError::ValueTypeDoesNotMatchWithColumn(
+                field.to_string(),
+            ))?,
+        Field::TimestampMicros(v) => NaiveDateTime::from_timestamp_micros(*v)
+            .map(Value::Timestamp)
+            .map_storage_err(ParquetStorageError::ValueTypeDoesNotMatchWithColumn(
+                field.to_string(),
+            ))?,
+        Field::Group(_) | Field::ListInternal(_) | Field::MapInternal(_) => {
+            Value::try_from(field.to_json_value())?
+        }
+    };
+
+    match value.get_type() {
+        Some(value_type) if &value_type == data_type => Ok(value),
+        _ => value.cast(data_type),
+    }
+}
+
+/// Writes the values of a column, NULL is written as an undefined value
+pub fn write_column(writer: &mut ColumnWriter<'_>, values: &[Value]) -> Result<()> {
+    let def_levels = values
+        .iter()
+        .map(|value| i16::from(!value.is_null()))
+        .collect::<Vec<_>>();
+    let values = values.iter().filter(|value| !value.is_null());
+
+    match writer {
+        ColumnWriter::BoolColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::Bool(v) => Ok(*v),
+                    _ => Err(mismatch(value)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::Int32ColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::I8(v) => Ok(i32::from(*v)),
+                    Value::I16(v) => Ok(i32::from(*v)),
+                    Value::I32(v) => Ok(*v),
+                    Value::U8(v) => Ok(i32::from(*v)),
+                    Value::U16(v) => Ok(i32::from(*v)),
+                    Value::U32(v) => Ok(*v as i32),
+                    Value::Date(v) => Ok(v.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE),
+                    _ => Err(mismatch(value)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::Int64ColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::I64(v) => Ok(*v),
+                    Value::U64(v) => Ok(*v as i64),
+                    Value::Timestamp(v) => Ok(v.timestamp_micros()),
+                    _ => Err(mismatch(value)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::FloatColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::F32(v) => Ok(*v),
+                    _ => Err(mismatch(value)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::DoubleColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::F64(v) => Ok(*v),
+                    _ => Err(mismatch(value)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::ByteArrayColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::Bytea(v) => ByteArray::from(v.clone()),
+                    _ => ByteArray::from(String::from(value).into_bytes()),
+                })
+                .collect::<Vec<_>>();
+
+            writer.write_batch(&values, Some(&def_levels), None)
+        }
+        ColumnWriter::Int96ColumnWriter(_) | ColumnWriter::FixedLenByteArrayColumnWriter(_) => {
+            return Err(ParquetStorageError::UnsupportedColumnType.into());
+        }
+    }
+    .map(|_| ())
+    .map_storage_err()
+}
+
+fn mismatch(value: &Value) -> gluesql_core::error::Error {
+    ParquetStorageError::ValueTypeDoesNotMatchWithColumn(String::from(value)).into()
+}
@@ -0,0 +1,58 @@
+use {gluesql_core::error::Error, thiserror::Error};
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+pub trait OptionExt<T, E: ToString> {
+    fn map_storage_err(self, error: E) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> OptionExt<T, E> for std::option::Option<T> {
+    fn map_storage_err(self, error: E) -> Result<T, Error> {
+        self.ok_or_else(|| error.to_string())
+            .map_err(Error::StorageMsg)
+    }
+}
+
+impl From<ParquetStorageError> for Error {
+    fn from(error: ParquetStorageError) -> Self {
+        Error::StorageMsg(error.to_string())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ParquetStorageError {
+    #[error("file not found")]
+    FileNotFound,
+
+    #[error("table does not exist")]
+    TableDoesNotExist,
+
+    #[error("table name does not match with file")]
+    TableNameDoesNotMatchWithFile,
+
+    #[error("column count does not match with file")]
+    ColumnCountDoesNotMatchWithFile,
+
+    #[error("document of schemaless row is not a string")]
+    SchemalessDocumentNotString,
+
+    #[error("value {0} does not match with the column type")]
+    ValueTypeDoesNotMatchWithColumn(String),
+
+    #[error("unsupported parquet column type")]
+    UnsupportedColumnType,
+
+    #[error("unreachable map type data row found")]
+    UnreachableMapTypeDataRowFound,
+
+    #[error("unreachable vector data row type found")]
+    UnreachableVecTypeDataRowTypeFound,
+}
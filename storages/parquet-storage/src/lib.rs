@@ -0,0 +1,346 @@
+#![deny(clippy::str_to_string)]
+
+mod convert;
+pub mod error;
+mod store;
+mod store_mut;
+
+use {
+    convert::DOCUMENT_COLUMN,
+    error::{ParquetStorageError, ResultExt},
+    gluesql_core::{
+        ast::{ColumnDef, ColumnUniqueOption, DataType, IndexOperator},
+        data::{value::HashMapJsonExt, Key, Schema, Value},
+        error::Result,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
+            RowIter, Transaction,
+        },
+    },
+    parquet::{
+        file::{
+            metadata::RowGroupMetaData,
+            reader::{FileReader, SerializedFileReader},
+            serialized_reader::ReadOptionsBuilder,
+            statistics::Statistics,
+        },
+        record::{reader::RowIter as ParquetRowIter, Field},
+        schema::types::Type,
+    },
+    std::{
+        collections::HashMap,
+        fs::{self, File},
+        io::Read,
+        path::PathBuf,
+        sync::Arc,
+    },
+};
+
+pub struct ParquetStorage {
+    pub path: PathBuf,
+}
+
+impl ParquetStorage {
+    pub fn new(path: &str) -> Result<Self> {
+        fs::create_dir_all(path).map_storage_err()?;
+        let path = PathBuf::from(path);
+
+        Ok(Self { path })
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema_path = self.schema_path(table_name);
+        if !schema_path.exists() {
+            let data_path = self.data_path(table_name);
+            if !data_path.exists() {
+                return Ok(None);
+            }
+
+            // parquet files written by other tools carry their own schema
+            let reader = File::open(data_path)
+                .map_storage_err()
+                .and_then(|file| SerializedFileReader::new(file).map_storage_err())?;
+            let column_defs = reader
+                .metadata()
+                .file_metadata()
+                .schema()
+                .get_fields()
+                .iter()
+                .map(|field| ColumnDef {
+                    name: field.name().to_owned(),
+                    data_type: convert::data_type(field),
+                    nullable: field.is_optional(),
+                    default: None,
+                    unique: None,
+                    collation: None,
+                })
+                .collect::<Vec<_>>();
+
+            return Ok(Some(Schema {
+                table_name: table_name.to_owned(),
+                column_defs: Some(column_defs),
+                indexes: Vec::new(),
+                engine: None,
+                foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                view: None,
+            }));
+        }
+
+        let mut file = File::open(&schema_path).map_storage_err()?;
+        let mut ddl = String::new();
+        file.read_to_string(&mut ddl).map_storage_err()?;
+
+        let schema = Schema::from_ddl(&ddl)?;
+        if schema.table_name != table_name {
+            return Err(ParquetStorageError::TableNameDoesNotMatchWithFile.into());
+        }
+
+        Ok(Some(schema))
+    }
+
+    fn path_by(&self, table_name: &str, extension: &str) -> PathBuf {
+        let path = self.path.as_path();
+        let mut path = path.join(table_name);
+        path.set_extension(extension);
+
+        path
+    }
+
+    fn schema_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "sql")
+    }
+
+    fn data_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "parquet")
+    }
+
+    fn tmp_data_path(&self, table_name: &str) -> PathBuf {
+        self.path_by(table_name, "tmp.parquet")
+    }
+
+    /// Reads the rows of the table, only `columns` and the primary key column are read from
+    /// the file when they are given and the other values of the rows are left as NULL. Row
+    /// groups whose statistics show that none of their rows passes `predicates` are skipped.
+    fn scan_data(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        let data_path = self.data_path(table_name);
+        let schema = match (self.fetch_schema(table_name)?, data_path.exists()) {
+            (None, _) | (_, false) => return Ok(Box::new(std::iter::empty())),
+            (Some(schema), true) => schema,
+        };
+
+        let reader = File::open(data_path)
+            .map_storage_err()
+            .and_then(|file| SerializedFileReader::new(file).map_storage_err())?;
+        let file_schema = reader.metadata().file_metadata().schema();
+
+        let column_defs = match schema.column_defs {
+            Some(column_defs) => column_defs,
+            None => {
+                let rows = ParquetRowIter::from_file_into(Box::new(reader))
+                    .enumerate()
+                    .map(|(index, row)| {
+                        let document = row
+                            .map_storage_err()?
+                            .get_column_iter()
+                            .find(|(name, _)| name.as_str() == DOCUMENT_COLUMN)
+                            .map(|(_, field)| field.clone());
+
+                        let row = match document {
+                            Some(Field::Str(document)) => HashMap::parse_json_object(&document)?,
+                            _ => {
+                                return Err(ParquetStorageError::SchemalessDocumentNotString.into())
+                            }
+                        };
+
+                        Ok((Key::U64(index as u64), DataRow::Map(row)))
+                    });
+
+                return Ok(Box::new(rows));
+            }
+        };
+
+        if file_schema.get_fields().len() != column_defs.len() {
+            return Err(ParquetStorageError::ColumnCountDoesNotMatchWithFile.into());
+        }
+
+        // rows without a primary key are keyed by their position in the file, so the rows of
+        // the skipped row groups are still counted
+        let row_groups = reader.metadata().row_groups();
+        let kept = row_groups
+            .iter()
+            .map(|row_group| may_match(row_group, &column_defs, predicates))
+            .collect::<Vec<_>>();
+        let positions = row_groups
+            .iter()
+            .zip(kept.iter())
+            .scan(0, |start, (row_group, kept)| {
+                let num_rows = row_group.num_rows() as usize;
+                let positions = *start..*start + num_rows;
+                *start += num_rows;
+
+                Some(kept.then_some(positions))
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let reader = match kept.iter().all(|kept| *kept) {
+            true => reader,
+            false => {
+                let options = ReadOptionsBuilder::new()
+                    .with_predicate(Box::new(move |_: &RowGroupMetaData, index: usize| {
+                        kept[index]
+                    }))
+                    .build();
+
+                File::open(self.data_path(table_name))
+                    .map_storage_err()
+                    .and_then(|file| {
+                        SerializedFileReader::new_with_options(file, options).map_storage_err()
+                    })?
+            }
+        };
+        let file_schema = reader.metadata().file_metadata().schema();
+
+        let is_primary = |column_def: &ColumnDef| {
+            column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+        };
+        let projection = match columns {
+            Some(columns) => {
+                let mut fields = file_schema
+                    .get_fields()
+                    .iter()
+                    .zip(column_defs.iter())
+                    .filter(|(_, column_def)| {
+                        is_primary(column_def) || columns.contains(&column_def.name)
+                    })
+                    .map(|(field, _)| Arc::clone(field))
+                    .collect::<Vec<_>>();
+
+                // a row is still needed for each record when no column is read
+                if fields.is_empty() {
+                    fields.extend(file_schema.get_fields().first().cloned());
+                }
+
+                Type::group_type_builder(file_schema.name())
+                    .with_fields(fields)
+                    .build()
+                    .map(Some)
+                    .map_storage_err()?
+            }
+            None => None,
+        };
+
+        let rows = ParquetRowIter::from_file_into(Box::new(reader))
+            .project(projection)
+            .map_storage_err()?
+            .zip(positions.into_iter().flatten())
+            .map(move |(row, index)| {
+                let row = row.map_storage_err()?;
+                let fields = row.get_column_iter().collect::<HashMap<_, _>>();
+                let mut key: Option<Key> = None;
+
+                let values = column_defs
+                    .iter()
+                    .map(|column_def| {
+                        let value = match fields.get(&column_def.name) {
+                            Some(field) => convert::field_to_value(field, &column_def.data_type)?,
+                            None => Value::Null,
+                        };
+
+                        if is_primary(column_def) {
+                            key = Key::try_from(&value).map(Some)?;
+                        }
+
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let key = key.unwrap_or(Key::U64(index as u64));
+
+                Ok((key, DataRow::Vec(values)))
+            });
+
+        Ok(Box::new(rows))
+    }
+}
+
+/// Whether the statistics of the row group allow any of its rows to pass every predicate,
+/// only the predicates on `INT32`, `INT` and `TEXT` columns are checked.
+fn may_match(
+    row_group: &RowGroupMetaData,
+    column_defs: &[ColumnDef],
+    predicates: &[(String, IndexOperator, Value)],
+) -> bool {
+    predicates.iter().all(|(column, op, value)| {
+        let index = match column_defs
+            .iter()
+            .position(|column_def| &column_def.name == column)
+        {
+            Some(index) => index,
+            None => return true,
+        };
+
+        match (
+            &column_defs[index].data_type,
+            row_group.column(index).statistics(),
+            value,
+        ) {
+            (DataType::Int32, Some(Statistics::Int32(stats)), Value::I64(value))
+                if stats.has_min_max_set() =>
+            {
+                match i32::try_from(*value) {
+                    Ok(value) => within(stats.min(), stats.max(), op, &value),
+                    Err(_) => true,
+                }
+            }
+            (DataType::Int, Some(Statistics::Int64(stats)), Value::I64(value))
+                if stats.has_min_max_set() =>
+            {
+                within(stats.min(), stats.max(), op, value)
+            }
+            (DataType::Text, Some(Statistics::ByteArray(stats)), Value::Str(value))
+                if stats.has_min_max_set() =>
+            {
+                match (stats.min().as_utf8(), stats.max().as_utf8()) {
+                    (Ok(min), Ok(max)) => within(min, max, op, value.as_str()),
+                    _ => true,
+                }
+            }
+            _ => true,
+        }
+    })
+}
+
+/// Whether the statistics of a column of `data_type` are checked by `may_match` for a
+/// comparison with `value`
+fn is_prunable(data_type: &DataType, value: &Value) -> bool {
+    match (data_type, value) {
+        (DataType::Int32, Value::I64(value)) => i32::try_from(*value).is_ok(),
+        (DataType::Int, Value::I64(_)) | (DataType::Text, Value::Str(_)) => true,
+        _ => false,
+    }
+}
+
+fn within<T: PartialOrd + ?Sized>(min: &T, max: &T, op: &IndexOperator, value: &T) -> bool {
+    match op {
+        IndexOperator::Eq => min <= value && value <= max,
+        IndexOperator::Gt => max > value,
+        IndexOperator::GtEq => max >= value,
+        IndexOperator::Lt => min < value,
+        IndexOperator::LtEq => min <= value,
+    }
+}
+
+impl AlterTable for ParquetStorage {}
+impl CustomFunction for ParquetStorage {}
+impl CustomFunctionMut for ParquetStorage {}
+impl Index for ParquetStorage {}
+impl IndexMut for ParquetStorage {}
+impl Transaction for ParquetStorage {}
+impl Metadata for ParquetStorage {}
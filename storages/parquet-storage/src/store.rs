@@ -0,0 +1,112 @@
+use {
+    crate::{
+        error::{OptionExt, ParquetStorageError, ResultExt},
+        is_prunable, ParquetStorage,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::IndexOperator,
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+    std::{ffi::OsStr, fs},
+};
+
+#[async_trait(?Send)]
+impl Store for ParquetStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.fetch_schema(table_name)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let paths = fs::read_dir(&self.path).map_storage_err()?;
+        let mut schemas = paths
+            .map(|result| {
+                let path = result.map_storage_err()?.path();
+                let extension = path.extension().and_then(OsStr::to_str);
+                if extension != Some("parquet") {
+                    return Ok(None);
+                }
+
+                let table_name = path
+                    .file_stem()
+                    .and_then(OsStr::to_str)
+                    .map_storage_err(ParquetStorageError::FileNotFound)?;
+
+                if table_name.ends_with(".tmp") {
+                    return Ok(None);
+                }
+
+                self.fetch_schema(table_name)?
+                    .map_storage_err(ParquetStorageError::TableDoesNotExist)
+                    .map(Some)
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<Schema>>>()?;
+
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, target: &Key) -> Result<Option<DataRow>> {
+        for item in self.scan_data(table_name, None, &[])? {
+            let (key, row) = item?;
+
+            if &key == target {
+                return Ok(Some(row));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        self.scan_data(table_name, None, &[])
+    }
+
+    async fn scan_data_with_columns(
+        &self,
+        table_name: &str,
+        columns: &[String],
+    ) -> Result<RowIter> {
+        self.scan_data(table_name, Some(columns), &[])
+    }
+
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        self.scan_data(table_name, columns, predicates)
+    }
+
+    /// Row groups are skipped by the statistics of `INT32`, `INT` and `TEXT` columns
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        let column_defs = match self.fetch_schema(table_name)? {
+            Some(Schema {
+                column_defs: Some(column_defs),
+                ..
+            }) => column_defs,
+            _ => return Ok(Vec::new()),
+        };
+
+        let predicates = predicates
+            .iter()
+            .filter(|(column, _, value)| {
+                column_defs.iter().any(|column_def| {
+                    &column_def.name == column && is_prunable(&column_def.data_type, value)
+                })
+            })
+            .cloned()
+            .collect();
+
+        Ok(predicates)
+    }
+}
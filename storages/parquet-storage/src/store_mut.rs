@@ -0,0 +1,207 @@
+use {
+    crate::{
+        convert,
+        error::{ParquetStorageError, ResultExt},
+        ParquetStorage,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+    parquet::{
+        basic::Compression,
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+    },
+    std::{
+        cmp::Ordering,
+        fs::{remove_file, rename, File},
+        io::Write,
+        iter::Peekable,
+        sync::Arc,
+        vec::IntoIter,
+    },
+};
+
+#[async_trait(?Send)]
+impl StoreMut for ParquetStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let schema_path = self.schema_path(schema.table_name.as_str());
+        let ddl = schema.to_ddl();
+        let mut file = File::create(schema_path).map_storage_err()?;
+        file.write_all(ddl.as_bytes()).map_storage_err()?;
+
+        self.write(schema, std::iter::empty())
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        let data_path = self.data_path(table_name);
+        if data_path.exists() {
+            remove_file(data_path).map_storage_err()?;
+        }
+
+        let schema_path = self.schema_path(table_name);
+        if schema_path.exists() {
+            remove_file(schema_path).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let schema = self.schema(table_name)?;
+        let prev_rows = self.scan_data(table_name, None, &[])?;
+        let rows = prev_rows
+            .map(|item| item.map(|(_, row)| row))
+            .chain(rows.into_iter().map(Ok));
+
+        self.write(&schema, rows)
+    }
+
+    async fn insert_data(&mut self, table_name: &str, mut rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let schema = self.schema(table_name)?;
+        let prev_rows = self.scan_data(table_name, None, &[])?;
+
+        rows.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+
+        let merged = SortMerge::new(prev_rows, rows.into_iter());
+
+        self.write(&schema, merged)
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let schema = self.schema(table_name)?;
+        let prev_rows = self.scan_data(table_name, None, &[])?;
+        let rows = prev_rows.filter_map(|item| {
+            let (key, data_row) = match item {
+                Ok(item) => item,
+                Err(e) => return Some(Err(e)),
+            };
+
+            keys.iter()
+                .all(|target_key| target_key != &key)
+                .then_some(Ok(data_row))
+        });
+
+        self.write(&schema, rows)
+    }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let schema = self.schema(table_name)?;
+
+        self.write(&schema, std::iter::empty())
+    }
+}
+
+impl ParquetStorage {
+    fn schema(&self, table_name: &str) -> Result<Schema> {
+        self.fetch_schema(table_name)?
+            .ok_or_else(|| ParquetStorageError::TableDoesNotExist.into())
+    }
+
+    /// Rewrites the data file of the table as a single row group, the new file is written
+    /// next to the old one and renamed over it once every column is written.
+    fn write<T: Iterator<Item = Result<DataRow>>>(&self, schema: &Schema, rows: T) -> Result<()> {
+        let column_defs = schema.column_defs.as_deref();
+        let width = column_defs.map(<[_]>::len).unwrap_or(1);
+        let mut columns = vec![Vec::new(); width];
+
+        for row in rows {
+            match (row?, column_defs) {
+                (DataRow::Vec(values), Some(_)) => {
+                    if values.len() != width {
+                        return Err(ParquetStorageError::ColumnCountDoesNotMatchWithFile.into());
+                    }
+
+                    for (column, value) in columns.iter_mut().zip(values) {
+                        column.push(value);
+                    }
+                }
+                (DataRow::Map(values), None) => {
+                    columns[0].push(Value::Str(String::from(Value::Map(values))));
+                }
+                (DataRow::Vec(_), None) => {
+                    return Err(ParquetStorageError::UnreachableVecTypeDataRowTypeFound.into());
+                }
+                (DataRow::Map(_), Some(_)) => {
+                    return Err(ParquetStorageError::UnreachableMapTypeDataRowFound.into());
+                }
+            }
+        }
+
+        let table_name = schema.table_name.as_str();
+        let tmp_data_path = self.tmp_data_path(table_name);
+        let file = File::create(&tmp_data_path).map_storage_err()?;
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer = SerializedFileWriter::new(
+            file,
+            convert::parquet_schema(column_defs)?,
+            Arc::new(properties),
+        )
+        .map_storage_err()?;
+
+        let mut row_group_writer = writer.next_row_group().map_storage_err()?;
+        for values in columns {
+            let mut column_writer = row_group_writer
+                .next_column()
+                .map_storage_err()?
+                .ok_or(ParquetStorageError::ColumnCountDoesNotMatchWithFile)?;
+
+            convert::write_column(column_writer.untyped(), &values)?;
+            column_writer.close().map_storage_err()?;
+        }
+        row_group_writer.close().map_storage_err()?;
+        writer.close().map_storage_err()?;
+
+        rename(tmp_data_path, self.data_path(table_name)).map_storage_err()
+    }
+}
+
+struct SortMerge<T: Iterator<Item = Result<(Key, DataRow)>>> {
+    left_rows: Peekable<T>,
+    right_rows: Peekable<IntoIter<(Key, DataRow)>>,
+}
+
+impl<T> SortMerge<T>
+where
+    T: Iterator<Item = Result<(Key, DataRow)>>,
+{
+    fn new(left_rows: T, right_rows: IntoIter<(Key, DataRow)>) -> Self {
+        let left_rows = left_rows.peekable();
+        let right_rows = right_rows.peekable();
+
+        Self {
+            left_rows,
+            right_rows,
+        }
+    }
+}
+impl<T> Iterator for SortMerge<T>
+where
+    T: Iterator<Item = Result<(Key, DataRow)>>,
+{
+    type Item = Result<DataRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.left_rows.peek();
+        let right = self.right_rows.peek();
+
+        match (left, right) {
+            (Some(Ok((left_key, _))), Some((right_key, _))) => match left_key.cmp(right_key) {
+                Ordering::Less => self.left_rows.next(),
+                Ordering::Greater => self.right_rows.next().map(Ok),
+                Ordering::Equal => {
+                    self.left_rows.next();
+                    self.right_rows.next().map(Ok)
+                }
+            }
+            .map(|item| Ok(item?.1)),
+            (Some(_), _) => self.left_rows.next().map(|item| Ok(item?.1)),
+            (None, Some(_)) => self.right_rows.next().map(|item| Ok(item.1)),
+            (None, None) => None,
+        }
+    }
+}
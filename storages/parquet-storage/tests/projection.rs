@@ -0,0 +1,213 @@
+use {
+    gluesql_core::{
+        ast::IndexOperator,
+        data::Key,
+        prelude::{
+            Glue,
+            Value::{self, *},
+        },
+        store::{DataRow, Store},
+    },
+    gluesql_parquet_storage::ParquetStorage,
+    std::fs::{remove_dir_all, remove_file},
+    test_suite::*,
+};
+
+async fn setup(path: &str) -> Glue<ParquetStorage> {
+    if let Err(e) = remove_dir_all(path) {
+        println!("fs::remove_file {:?}", e);
+    };
+
+    let storage = ParquetStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage);
+
+    glue.execute(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            price FLOAT,
+            created DATE NULL
+        );
+        INSERT INTO Item VALUES
+            (1, 'apple', 1.5, '2024-01-15'),
+            (2, 'banana', 0.5, NULL),
+            (3, 'cherry', 7.0, '2024-03-01');
+        ",
+    )
+    .await
+    .unwrap();
+
+    glue
+}
+
+#[tokio::test]
+async fn scan_data_with_columns() {
+    let glue = setup("tmp/projection_scan_data_with_columns").await;
+
+    let rows = glue
+        .storage
+        .scan_data_with_columns("Item", &["price".to_owned()])
+        .await
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected = [(1, 1.5), (2, 0.5), (3, 7.0)]
+        .into_iter()
+        .map(|(id, price)| {
+            let values = vec![I64(id), Null, F64(price), Null];
+
+            (Key::I64(id), DataRow::Vec(values))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, expected);
+
+    let rows = glue
+        .storage
+        .scan_data_with_columns("Item", &[])
+        .await
+        .unwrap()
+        .map(|item| item.map(|(key, _)| key))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![Key::I64(1), Key::I64(2), Key::I64(3)]);
+}
+
+async fn scan_keys(
+    storage: &ParquetStorage,
+    predicates: Vec<(&str, IndexOperator, Value)>,
+) -> Vec<Key> {
+    let predicates = predicates
+        .into_iter()
+        .map(|(column, op, value)| (column.to_owned(), op, value))
+        .collect::<Vec<_>>();
+
+    storage
+        .scan_data_filtered("Item", None, &predicates)
+        .await
+        .unwrap()
+        .map(|item| item.map(|(key, _)| key))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn scan_data_filtered() {
+    let glue = setup("tmp/projection_scan_data_filtered").await;
+
+    // the only row group is skipped when its statistics show no row can match
+    assert_eq!(
+        scan_keys(&glue.storage, vec![("id", IndexOperator::Gt, I64(3))]).await,
+        vec![]
+    );
+    assert_eq!(
+        scan_keys(
+            &glue.storage,
+            vec![("name", IndexOperator::Lt, Str("apple".to_owned()))]
+        )
+        .await,
+        vec![]
+    );
+
+    // rows of the row groups which may match are all returned, WHERE filters them later
+    assert_eq!(
+        scan_keys(
+            &glue.storage,
+            vec![
+                ("id", IndexOperator::GtEq, I64(3)),
+                ("name", IndexOperator::Eq, Str("banana".to_owned())),
+            ]
+        )
+        .await,
+        vec![Key::I64(1), Key::I64(2), Key::I64(3)]
+    );
+}
+
+#[tokio::test]
+async fn select_few_columns() {
+    let mut glue = setup("tmp/projection_select_few_columns").await;
+
+    let actual = glue
+        .execute("SELECT name FROM Item WHERE price > 1 ORDER BY created DESC")
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    let expected = select!(
+        name
+        Str;
+        "cherry".to_owned();
+        "apple".to_owned()
+    );
+    assert_eq!(actual, expected);
+
+    let actual = glue
+        .execute("SELECT COUNT(*) AS cnt FROM Item")
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(actual, select!(cnt I64; 3));
+}
+
+#[tokio::test]
+async fn select_joined_columns() {
+    let mut glue = setup("tmp/projection_select_joined_columns").await;
+
+    glue.execute(
+        "
+        CREATE TABLE Stock (id INTEGER PRIMARY KEY, item_id INTEGER, qty INTEGER, memo TEXT);
+        INSERT INTO Stock VALUES (1, 3, 10, 'back'), (2, 1, 0, 'front'), (3, 3, 5, NULL);
+        ",
+    )
+    .await
+    .unwrap();
+
+    let actual = glue
+        .execute(
+            "
+            SELECT Item.name, s.qty
+            FROM Stock s
+            JOIN Item ON Item.id = s.item_id AND Item.price > 1
+            LEFT JOIN Stock s2 ON s2.id = s.id
+            WHERE s2.qty > 0
+            ORDER BY s.qty
+            ",
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    let expected = select!(
+        name               | qty
+        Str                | I64;
+        "cherry".to_owned()  5;
+        "cherry".to_owned()  10
+    );
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn schema_from_parquet_file() {
+    let path = "tmp/projection_schema_from_parquet_file";
+    let mut glue = setup(path).await;
+
+    remove_file(format!("{path}/Item.sql")).unwrap();
+
+    let actual = glue
+        .execute("SELECT * FROM Item WHERE id > 1")
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    let expected = select_with_null!(
+        id     | name                   | price     | created;
+        I64(2)   Str("banana".to_owned())   F64(0.5)    Null;
+        I64(3)   Str("cherry".to_owned())   F64(7.0)    Value::Date("2024-03-01".parse().unwrap())
+    );
+    assert_eq!(actual, expected);
+}
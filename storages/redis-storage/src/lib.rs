@@ -0,0 +1,74 @@
+#![deny(clippy::str_to_string)]
+
+pub mod error;
+mod store;
+mod store_mut;
+
+use {
+    error::ResultExt,
+    gluesql_core::{
+        error::Result,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, Index, IndexMut, Metadata, Transaction,
+        },
+    },
+    redis::{aio::MultiplexedConnection, Client},
+};
+
+/// Storage over a Redis server, every key which it uses starts with `{namespace}#`.
+///
+/// - `{namespace}#schemas`: hash of the schemas as JSON, by table name
+/// - `{namespace}#rows#{table}`: hash of the rows as JSON, by the comparable bytes of their keys
+/// - `{namespace}#keys#{table}`: sorted set of the same bytes, which keeps the rows in key order
+/// - `{namespace}#id#{table}`: counter which gives keys to the rows of tables without a primary key
+///
+/// Commands are sent over a multiplexed async connection, which needs a tokio runtime.
+pub struct RedisStorage {
+    pub namespace: String,
+    conn: MultiplexedConnection,
+}
+
+impl RedisStorage {
+    pub async fn new(namespace: &str, url: &str) -> Result<Self> {
+        let conn = Client::open(url)
+            .map_storage_err()?
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_storage_err()?;
+
+        Ok(Self {
+            namespace: namespace.to_owned(),
+            conn,
+        })
+    }
+
+    /// The connection is cheap to clone and its clones share the same socket, so each call
+    /// takes its own handle instead of borrowing the storage mutably.
+    fn conn(&self) -> MultiplexedConnection {
+        self.conn.clone()
+    }
+
+    fn schemas_key(&self) -> String {
+        format!("{}#schemas", self.namespace)
+    }
+
+    fn rows_key(&self, table_name: &str) -> String {
+        format!("{}#rows#{}", self.namespace, table_name)
+    }
+
+    fn keys_key(&self, table_name: &str) -> String {
+        format!("{}#keys#{}", self.namespace, table_name)
+    }
+
+    fn id_key(&self, table_name: &str) -> String {
+        format!("{}#id#{}", self.namespace, table_name)
+    }
+}
+
+impl AlterTable for RedisStorage {}
+impl CustomFunction for RedisStorage {}
+impl CustomFunctionMut for RedisStorage {}
+impl Index for RedisStorage {}
+impl IndexMut for RedisStorage {}
+impl Transaction for RedisStorage {}
+impl Metadata for RedisStorage {}
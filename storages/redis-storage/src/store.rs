@@ -0,0 +1,88 @@
+use {
+    crate::{
+        error::{RedisStorageError, ResultExt},
+        RedisStorage,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+    redis::AsyncCommands,
+};
+
+#[async_trait(?Send)]
+impl Store for RedisStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema: Option<String> = self
+            .conn()
+            .hget(self.schemas_key(), table_name)
+            .await
+            .map_storage_err()?;
+
+        schema
+            .map(|schema| serde_json::from_str(&schema).map_storage_err())
+            .transpose()
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let schemas: Vec<String> = self
+            .conn()
+            .hvals(self.schemas_key())
+            .await
+            .map_storage_err()?;
+        let mut schemas = schemas
+            .into_iter()
+            .map(|schema| serde_json::from_str::<Schema>(&schema).map_storage_err())
+            .collect::<Result<Vec<_>>>()?;
+
+        schemas.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        Ok(schemas)
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let row: Option<String> = self
+            .conn()
+            .hget(self.rows_key(table_name), key.to_cmp_be_bytes()?)
+            .await
+            .map_storage_err()?;
+
+        row.map(|row| {
+            serde_json::from_str::<(Key, DataRow)>(&row)
+                .map(|(_, row)| row)
+                .map_storage_err()
+        })
+        .transpose()
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let mut conn = self.conn();
+        let keys: Vec<Vec<u8>> = conn
+            .zrange(self.keys_key(table_name), 0, -1)
+            .await
+            .map_storage_err()?;
+
+        if keys.is_empty() {
+            return Ok(Box::new(std::iter::empty()));
+        }
+
+        let rows = redis::cmd("HMGET")
+            .arg(self.rows_key(table_name))
+            .arg(keys)
+            .query_async::<_, Vec<Option<String>>>(&mut conn)
+            .await
+            .map_storage_err()?
+            .into_iter()
+            .map(|row| {
+                let row =
+                    row.ok_or_else(|| RedisStorageError::RowNotFound(table_name.to_owned()))?;
+
+                serde_json::from_str::<(Key, DataRow)>(&row).map_storage_err()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+}
@@ -0,0 +1,95 @@
+use {
+    crate::{error::ResultExt, RedisStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+    redis::AsyncCommands,
+};
+
+#[async_trait(?Send)]
+impl StoreMut for RedisStorage {
+    async fn insert_schema(&mut self, schema: &Schema) -> Result<()> {
+        let schemas_key = self.schemas_key();
+        let value = serde_json::to_string(schema).map_storage_err()?;
+
+        self.conn()
+            .hset(schemas_key, &schema.table_name, value)
+            .await
+            .map_storage_err()
+    }
+
+    async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        redis::pipe()
+            .atomic()
+            .hdel(self.schemas_key(), table_name)
+            .del(self.rows_key(table_name))
+            .del(self.keys_key(table_name))
+            .del(self.id_key(table_name))
+            .query_async(&mut self.conn())
+            .await
+            .map_storage_err()
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let id_key = self.id_key(table_name);
+        let last_id: i64 = self
+            .conn()
+            .incr(id_key, rows.len())
+            .await
+            .map_storage_err()?;
+        let first_id = last_id - rows.len() as i64 + 1;
+        let rows = (first_id..).map(Key::I64).zip(rows).collect();
+
+        self.insert_data(table_name, rows).await
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let rows_key = self.rows_key(table_name);
+        let keys_key = self.keys_key(table_name);
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        for (key, row) in rows {
+            let member = key.to_cmp_be_bytes()?;
+            let value = serde_json::to_string(&(key, row)).map_storage_err()?;
+
+            // every member has the same score, so the sorted set is ordered by the key bytes
+            pipe.hset(&rows_key, &member, value)
+                .zadd(&keys_key, &member, 0);
+        }
+
+        pipe.query_async(&mut self.conn()).await.map_storage_err()
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let rows_key = self.rows_key(table_name);
+        let keys_key = self.keys_key(table_name);
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        for key in keys {
+            let member = key.to_cmp_be_bytes()?;
+
+            pipe.hdel(&rows_key, &member).zrem(&keys_key, &member);
+        }
+
+        pipe.query_async(&mut self.conn()).await.map_storage_err()
+    }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        redis::pipe()
+            .atomic()
+            .del(self.rows_key(table_name))
+            .del(self.keys_key(table_name))
+            .query_async(&mut self.conn())
+            .await
+            .map_storage_err()
+    }
+}
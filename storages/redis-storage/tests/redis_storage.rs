@@ -0,0 +1,43 @@
+#![cfg(feature = "test-redis")]
+
+use {
+    async_trait::async_trait,
+    gluesql_core::prelude::Glue,
+    gluesql_redis_storage::RedisStorage,
+    redis::{AsyncCommands, Client},
+    std::env,
+    test_suite::*,
+};
+
+struct RedisTester {
+    glue: Glue<RedisStorage>,
+}
+
+#[async_trait(?Send)]
+impl Tester<RedisStorage> for RedisTester {
+    async fn new(namespace: &str) -> Self {
+        let url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_owned());
+
+        let mut conn = Client::open(url.as_str())
+            .expect("redis client")
+            .get_multiplexed_tokio_connection()
+            .await
+            .expect("redis connection");
+        let keys: Vec<String> = conn.keys(format!("{namespace}#*")).await.expect("KEYS");
+        if !keys.is_empty() {
+            conn.del::<_, ()>(keys).await.expect("DEL");
+        }
+
+        let storage = RedisStorage::new(namespace, &url)
+            .await
+            .expect("RedisStorage::new");
+        let glue = Glue::new(storage);
+        RedisTester { glue }
+    }
+
+    fn get_glue(&mut self) -> &mut Glue<RedisStorage> {
+        &mut self.glue
+    }
+}
+
+generate_store_tests!(tokio::test, RedisTester);
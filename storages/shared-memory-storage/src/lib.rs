@@ -7,7 +7,7 @@ mod transaction;
 use {
     async_trait::async_trait,
     gluesql_core::{
-        data::{Key, Schema},
+        data::{Key, Schema, TableStatistics},
         error::Result,
         store::{DataRow, Metadata, RowIter, Store, StoreMut},
     },
@@ -71,6 +71,13 @@ impl Store for SharedMemoryStorage {
 
         database.scan_data(table_name).await
     }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_statistics(table_name).await
+    }
 }
 
 #[async_trait(?Send)]
@@ -109,6 +116,24 @@ impl StoreMut for SharedMemoryStorage {
 
         database.delete_data(table_name, keys).await
     }
+
+    async fn truncate_data(&mut self, table_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.truncate_data(table_name).await
+    }
+
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_statistics(table_name, statistics).await
+    }
 }
 
 impl Metadata for SharedMemoryStorage {}
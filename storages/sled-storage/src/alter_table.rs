@@ -51,6 +51,9 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = old_schema
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -61,6 +64,9 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
 
             bincode::serialize(&old_snapshot)
@@ -159,6 +165,9 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = snapshot
                 .get(txid, None)
@@ -190,6 +199,7 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                collation,
                 ..
             } = column_defs[i].clone();
 
@@ -199,6 +209,7 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                collation,
             };
             let column_defs = Vector::from(column_defs).update(i, column_def).into();
 
@@ -207,6 +218,9 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
             let value = bincode::serialize(&snapshot)
@@ -259,6 +273,9 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -356,6 +373,9 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -411,6 +431,9 @@ impl AlterTable for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -494,6 +517,9 @@ impl AlterTable for SledStorage {
                 column_defs: Some(column_defs),
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -93,6 +93,7 @@ impl Index for SledStorage {
             }
         };
         let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let (read_txid, lock_txid) = self.read_txid(txid, lock_txid)?;
 
         let prefix_len = build_index_key_prefix(table_name, index_name).len();
         let tree = self.tree.clone();
@@ -122,7 +123,7 @@ impl Index for SledStorage {
             let rows = keys
                 .into_iter()
                 .map(move |key_snapshot| -> Result<_> {
-                    let key = match key_snapshot.extract(txid, lock_txid) {
+                    let key = match key_snapshot.extract(read_txid, lock_txid) {
                         Some(key) => key,
                         None => {
                             return Ok(None);
@@ -135,7 +136,7 @@ impl Index for SledStorage {
                         .ok_or(IndexError::ConflictOnEmptyIndexValueScan)?;
                     let snapshot: Snapshot<DataRow> =
                         bincode::deserialize(&value).map_err(err_into)?;
-                    let row = snapshot.extract(txid, lock_txid);
+                    let row = snapshot.extract(read_txid, lock_txid);
                     let key = key.into_iter().skip(prefix_len).collect();
                     let item = row.map(|row| (Key::Bytea(key), row));
 
@@ -72,6 +72,9 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -99,6 +102,9 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -162,6 +168,9 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -184,6 +193,9 @@ impl IndexMut for SledStorage {
                 column_defs,
                 indexes,
                 engine,
+                foreign_keys,
+                checks,
+                view,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -8,6 +8,10 @@ pub fn data_prefix(table_name: &str) -> String {
     format!("data/{table_name}/")
 }
 
+pub fn statistics(table_name: &str) -> String {
+    format!("statistics/{table_name}")
+}
+
 pub fn data(table_name: &str, key: Vec<u8>) -> IVec {
     let key = data_prefix(table_name).into_bytes().into_iter().chain(key);
 
@@ -8,6 +8,7 @@ mod index_mut;
 mod index_sync;
 mod key;
 mod lock;
+mod migration;
 mod snapshot;
 mod store;
 mod store_mut;
@@ -17,9 +18,10 @@ mod transaction;
 pub use sled;
 
 use {
-    self::snapshot::Snapshot,
+    self::{snapshot::Snapshot, transaction::Savepoint},
     error::{err_into, tx_err_into},
     gluesql_core::{
+        ast::IsolationLevel,
         data::Schema,
         error::{Error, Result},
         store::Metadata,
@@ -30,6 +32,10 @@ use {
         },
         Config, Db,
     },
+    std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 
 /// default transaction timeout : 1 hour
@@ -52,6 +58,14 @@ pub struct SledStorage {
     pub state: State,
     /// transaction timeout in milliseconds
     pub tx_timeout: Option<u128>,
+    /// savepoints of the current transaction, from the oldest
+    savepoints: Vec<Savepoint>,
+    /// either REPEATABLE READ (default) or READ COMMITTED
+    isolation_level: IsolationLevel,
+    /// flushes the changes to the disk on each commit when enabled
+    pub flush_on_commit: bool,
+    /// latest txid registered by this storage or its clones, READ COMMITTED reads use it
+    last_txid: Arc<AtomicU64>,
 }
 
 type ExportData<T> = (u64, Vec<(Vec<u8>, Vec<u8>, T)>);
@@ -59,22 +73,38 @@ type ExportData<T> = (u64, Vec<(Vec<u8>, Vec<u8>, T)>);
 impl SledStorage {
     pub fn new(filename: &str) -> Result<Self> {
         let tree = sled::open(filename).map_err(err_into)?;
+        migration::migrate(&tree)?;
         let id_offset = get_id_offset(&tree)?;
+        let last_txid = id_offset + tree.generate_id().map_err(err_into)?;
         let state = State::Idle;
         let tx_timeout = Some(DEFAULT_TX_TIMEOUT);
 
-        Ok(Self {
+        let storage = Self {
             tree,
             id_offset,
             state,
             tx_timeout,
-        })
+            savepoints: Vec::new(),
+            isolation_level: IsolationLevel::RepeatableRead,
+            flush_on_commit: false,
+            last_txid: Arc::new(AtomicU64::new(last_txid)),
+        };
+        storage.recover()?;
+
+        Ok(storage)
     }
 
     pub fn set_transaction_timeout(&mut self, tx_timeout: Option<u128>) {
         self.tx_timeout = tx_timeout;
     }
 
+    /// When enabled, `COMMIT` returns after the changes are flushed to the disk, so committed
+    /// transactions survive a crash. Otherwise sled flushes them in the background, every
+    /// 500ms by default, see `Config::flush_every_ms`.
+    pub fn set_flush_on_commit(&mut self, flush_on_commit: bool) {
+        self.flush_on_commit = flush_on_commit;
+    }
+
     pub fn export(&self) -> Result<ExportData<impl Iterator<Item = Vec<Vec<u8>>>>> {
         let id_offset = self.id_offset + self.tree.generate_id().map_err(err_into)?;
         let data = self.tree.export();
@@ -86,7 +116,9 @@ impl SledStorage {
         let (new_id_offset, data) = export;
         let old_id_offset = get_id_offset(&self.tree)?;
 
+        migration::reset(&self.tree)?;
         self.tree.import(data);
+        migration::migrate(&self.tree)?;
 
         if new_id_offset > old_id_offset {
             self.tree
@@ -94,6 +126,7 @@ impl SledStorage {
                 .map_err(err_into)?;
 
             self.id_offset = new_id_offset;
+            self.last_txid.fetch_max(new_id_offset, Ordering::SeqCst);
         }
 
         Ok(())
@@ -105,16 +138,25 @@ impl TryFrom<Config> for SledStorage {
 
     fn try_from(config: Config) -> Result<Self> {
         let tree = config.open().map_err(err_into)?;
+        migration::migrate(&tree)?;
         let id_offset = get_id_offset(&tree)?;
+        let last_txid = id_offset + tree.generate_id().map_err(err_into)?;
         let state = State::Idle;
         let tx_timeout = Some(DEFAULT_TX_TIMEOUT);
 
-        Ok(Self {
+        let storage = Self {
             tree,
             id_offset,
             state,
             tx_timeout,
-        })
+            savepoints: Vec::new(),
+            isolation_level: IsolationLevel::RepeatableRead,
+            flush_on_commit: false,
+            last_txid: Arc::new(AtomicU64::new(last_txid)),
+        };
+        storage.recover()?;
+
+        Ok(storage)
     }
 }
 
@@ -1,6 +1,9 @@
 use {
     super::{err_into, State},
-    gluesql_core::error::{Error, Result},
+    gluesql_core::{
+        error::{Error, Result},
+        store::ConflictError,
+    },
     serde::{Deserialize, Serialize},
     sled::{
         transaction::{
@@ -8,7 +11,10 @@ use {
         },
         Db,
     },
-    std::time::{SystemTime, UNIX_EPOCH},
+    std::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    },
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,8 +42,11 @@ pub fn get_txdata_key(txid: u64) -> Vec<u8> {
         .collect::<Vec<_>>()
 }
 
-pub fn register(tree: &Db, id_offset: u64) -> Result<(u64, u128)> {
+/// Registers a new transaction, `last_txid` is raised to its txid so READ COMMITTED reads
+/// see every transaction registered so far without generating a txid of their own.
+pub fn register(tree: &Db, id_offset: u64, last_txid: &AtomicU64) -> Result<(u64, u128)> {
     let txid = id_offset + tree.generate_id().map_err(err_into)?;
+    last_txid.fetch_max(txid, Ordering::SeqCst);
     let key = get_txdata_key(txid);
     let created_at = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -151,7 +160,7 @@ pub fn acquire(
             if tx_timeout.map(|tx_timeout| now - lock_created_at >= tx_timeout) == Some(true) {
                 return Ok(LockAcquired::RollbackAndRetry { lock_txid });
             } else if txid != lock_txid {
-                return Err(Error::StorageMsg("database is locked".to_owned()))
+                return Err(ConflictError::Locked.into())
                     .map_err(ConflictableTransactionError::Abort);
             }
 
@@ -0,0 +1,126 @@
+use {
+    super::{err_into, Snapshot},
+    gluesql_core::{
+        ast::{ColumnDef, ColumnUniqueOption, DataType, Expr},
+        data::{Schema, SchemaIndex},
+        error::{Error, Result},
+    },
+    serde::Deserialize,
+    sled::{Batch, Db},
+};
+
+/// Version of the format which schemas are stored in, kept under `SCHEMA_VERSION_KEY`.
+/// Databases without the key are written by version 1.
+///
+/// - 2: foreign keys, check constraints and views of `Schema`, and the collation of `ColumnDef`
+const SCHEMA_VERSION: u64 = 2;
+
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// `Schema` of version 1
+#[derive(Deserialize)]
+struct SchemaV1 {
+    table_name: String,
+    column_defs: Option<Vec<ColumnDefV1>>,
+    indexes: Vec<SchemaIndex>,
+    engine: Option<String>,
+}
+
+/// `ColumnDef` of version 1
+#[derive(Deserialize)]
+struct ColumnDefV1 {
+    name: String,
+    data_type: DataType,
+    nullable: bool,
+    default: Option<Expr>,
+    unique: Option<ColumnUniqueOption>,
+}
+
+impl From<SchemaV1> for Schema {
+    fn from(schema: SchemaV1) -> Self {
+        let SchemaV1 {
+            table_name,
+            column_defs,
+            indexes,
+            engine,
+        } = schema;
+
+        Schema {
+            table_name,
+            column_defs: column_defs
+                .map(|column_defs| column_defs.into_iter().map(ColumnDef::from).collect()),
+            indexes,
+            engine,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
+        }
+    }
+}
+
+impl From<ColumnDefV1> for ColumnDef {
+    fn from(column_def: ColumnDefV1) -> Self {
+        let ColumnDefV1 {
+            name,
+            data_type,
+            nullable,
+            default,
+            unique,
+        } = column_def;
+
+        ColumnDef {
+            name,
+            data_type,
+            nullable,
+            default,
+            unique,
+            collation: None,
+        }
+    }
+}
+
+/// Rewrites the schemas stored by the older versions in the current format, all at once so
+/// that a database is never left with schemas of both formats.
+/// A database written by a newer version is not opened.
+pub fn migrate(tree: &Db) -> Result<()> {
+    let version = tree
+        .get(SCHEMA_VERSION_KEY)
+        .map_err(err_into)?
+        .map(|version| {
+            version
+                .as_ref()
+                .try_into()
+                .map_err(err_into)
+                .map(u64::from_be_bytes)
+        })
+        .transpose()?
+        .unwrap_or(1);
+
+    if version > SCHEMA_VERSION {
+        return Err(Error::StorageMsg(format!(
+            "schema version {version} is newer than the supported version {SCHEMA_VERSION}"
+        )));
+    } else if version == SCHEMA_VERSION {
+        return Ok(());
+    }
+
+    let mut batch = Batch::default();
+    for item in tree.scan_prefix("schema/") {
+        let (key, value) = item.map_err(err_into)?;
+        let snapshot: Snapshot<SchemaV1> = bincode::deserialize(&value).map_err(err_into)?;
+        let snapshot = bincode::serialize(&snapshot.map(Schema::from)).map_err(err_into)?;
+
+        batch.insert(key, snapshot);
+    }
+
+    batch.insert(SCHEMA_VERSION_KEY, &SCHEMA_VERSION.to_be_bytes());
+    tree.apply_batch(batch).map_err(err_into)
+}
+
+/// Lets the schemas of an imported database be migrated, `export` copies the version along
+/// with the schemas.
+pub fn reset(tree: &Db) -> Result<()> {
+    tree.remove(SCHEMA_VERSION_KEY)
+        .map(|_| ())
+        .map_err(err_into)
+}
@@ -2,15 +2,169 @@ use {
     super::{err_into, key, lock, SledStorage, Snapshot, State},
     async_trait::async_trait,
     gluesql_core::{
-        data::{Key, Schema},
+        ast::{ColumnDef, ColumnUniqueOption, IndexOperator},
+        data::{Key, Schema, TableStatistics, Value},
         error::{Error, Result},
         store::{DataRow, RowIter, Store},
     },
-    std::str,
+    std::{ops::Bound, str},
 };
 
 impl SledStorage {
     const SCHEMA_PREFIX: &str = "schema/";
+
+    async fn fetch_primary_key(&self, table_name: &str) -> Result<Option<ColumnDef>> {
+        let primary_key = self
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .and_then(|column_defs| {
+                column_defs.into_iter().find(|column_def| {
+                    column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+                })
+            });
+
+        Ok(primary_key)
+    }
+
+    /// Scans the rows whose keys are within `lower` and `upper`, the bounds of the keys
+    /// without the data prefix of the table
+    fn scan_data_range(
+        &self,
+        table_name: &str,
+        lower: Bound<Vec<u8>>,
+        upper: Bound<Vec<u8>>,
+    ) -> Result<RowIter> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg(
+                    "conflict - scan_data failed, lock does not exist".to_owned(),
+                ));
+            }
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let (read_txid, lock_txid) = self.read_txid(txid, lock_txid)?;
+
+        let prefix = key::data_prefix(table_name).into_bytes();
+        let prefix_len = prefix.len();
+        let with_prefix = |bound| match bound {
+            Bound::Included(key) => Bound::Included([prefix.as_slice(), &key].concat()),
+            Bound::Excluded(key) => Bound::Excluded([prefix.as_slice(), &key].concat()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let lower = match with_prefix(lower) {
+            Bound::Unbounded => Bound::Included(prefix.clone()),
+            lower => lower,
+        };
+        let upper = match with_prefix(upper) {
+            // the prefix ends with "/", so every key of the table is less than the prefix
+            // whose last byte is increased by one
+            Bound::Unbounded => {
+                let mut end = prefix.clone();
+                if let Some(last) = end.last_mut() {
+                    *last += 1;
+                }
+
+                Bound::Excluded(end)
+            }
+            upper => upper,
+        };
+
+        let is_empty = match (&lower, &upper) {
+            (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+            (
+                Bound::Included(lower) | Bound::Excluded(lower),
+                Bound::Included(upper) | Bound::Excluded(upper),
+            ) => lower >= upper,
+            _ => false,
+        };
+        if is_empty {
+            return Ok(Box::new(std::iter::empty()));
+        }
+
+        let result_set = self
+            .tree
+            .range((lower, upper))
+            .map(move |item| {
+                let (key, value) = item.map_err(err_into)?;
+                let key = key.subslice(prefix_len, key.len() - prefix_len).to_vec();
+                let snapshot: Snapshot<DataRow> = bincode::deserialize(&value).map_err(err_into)?;
+                let row = snapshot.extract(read_txid, lock_txid);
+                let item = row.map(|row| (Key::Bytea(key), row));
+
+                Ok(item)
+            })
+            .filter_map(|item| item.transpose());
+
+        Ok(Box::new(result_set))
+    }
+}
+
+/// Bounds of the primary key which rows have to be within to pass every predicate. Predicates
+/// whose value cannot be a key of the primary key column leave the bounds as they are.
+fn primary_key_range(
+    column_def: &ColumnDef,
+    predicates: &[(String, IndexOperator, Value)],
+) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+    let mut lower = Bound::Unbounded;
+    let mut upper = Bound::Unbounded;
+
+    for (column, op, value) in predicates {
+        if column != &column_def.name {
+            continue;
+        }
+
+        let key = match range_key(column_def, value) {
+            Ok(key) => key,
+            Err(_) => continue,
+        };
+
+        match op {
+            IndexOperator::Eq => {
+                lower = narrow(lower, Bound::Included(key.clone()), true);
+                upper = narrow(upper, Bound::Included(key), false);
+            }
+            IndexOperator::Gt => lower = narrow(lower, Bound::Excluded(key), true),
+            IndexOperator::GtEq => lower = narrow(lower, Bound::Included(key), true),
+            IndexOperator::Lt => upper = narrow(upper, Bound::Excluded(key), false),
+            IndexOperator::LtEq => upper = narrow(upper, Bound::Included(key), false),
+        }
+    }
+
+    (lower, upper)
+}
+
+/// Bytes of the primary key which `value` bounds the scan by
+fn range_key(column_def: &ColumnDef, value: &Value) -> Result<Vec<u8>> {
+    value
+        .cast(&column_def.data_type)
+        .and_then(Key::try_from)
+        .and_then(|key| key.to_cmp_be_bytes())
+}
+
+/// The tighter one of the two lower bounds, or of the two upper bounds
+fn narrow(current: Bound<Vec<u8>>, new: Bound<Vec<u8>>, is_lower: bool) -> Bound<Vec<u8>> {
+    let replace = match (&current, &new) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (
+            Bound::Included(current) | Bound::Excluded(current),
+            Bound::Included(key) | Bound::Excluded(key),
+        ) if current == key => matches!(new, Bound::Excluded(_)),
+        (
+            Bound::Included(current) | Bound::Excluded(current),
+            Bound::Included(key) | Bound::Excluded(key),
+        ) => (key > current) == is_lower,
+    };
+
+    if replace {
+        new
+    } else {
+        current
+    }
 }
 
 #[async_trait(?Send)]
@@ -20,16 +174,17 @@ impl Store for SledStorage {
             State::Transaction {
                 txid, created_at, ..
             } => (txid, created_at),
-            State::Idle => lock::register(&self.tree, self.id_offset)?,
+            State::Idle => lock::register(&self.tree, self.id_offset, &self.last_txid)?,
         };
         let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let (read_txid, lock_txid) = self.read_txid(txid, lock_txid)?;
 
         self.tree
             .scan_prefix(SledStorage::SCHEMA_PREFIX)
             .map(move |item| {
                 let (_, value) = item.map_err(err_into)?;
                 let snapshot: Snapshot<Schema> = bincode::deserialize(&value).map_err(err_into)?;
-                let schema = snapshot.extract(txid, lock_txid);
+                let schema = snapshot.extract(read_txid, lock_txid);
 
                 Ok(schema)
             })
@@ -42,10 +197,11 @@ impl Store for SledStorage {
             State::Transaction {
                 txid, created_at, ..
             } => (txid, created_at, false),
-            State::Idle => lock::register(&self.tree, self.id_offset)
+            State::Idle => lock::register(&self.tree, self.id_offset, &self.last_txid)
                 .map(|(txid, created_at)| (txid, created_at, true))?,
         };
         let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let (read_txid, lock_txid) = self.read_txid(txid, lock_txid)?;
 
         let key = format!("schema/{}", table_name);
         let schema = self
@@ -55,7 +211,7 @@ impl Store for SledStorage {
             .map(|v| bincode::deserialize(&v))
             .transpose()
             .map_err(err_into)?
-            .and_then(|snapshot: Snapshot<Schema>| snapshot.extract(txid, lock_txid));
+            .and_then(|snapshot: Snapshot<Schema>| snapshot.extract(read_txid, lock_txid));
 
         if temp {
             lock::unregister(&self.tree, txid)?;
@@ -76,6 +232,7 @@ impl Store for SledStorage {
             }
         };
         let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+        let (read_txid, lock_txid) = self.read_txid(txid, lock_txid)?;
 
         let key = key
             .to_cmp_be_bytes()
@@ -87,40 +244,58 @@ impl Store for SledStorage {
             .map(|v| bincode::deserialize(&v))
             .transpose()
             .map_err(err_into)?
-            .and_then(|snapshot: Snapshot<DataRow>| snapshot.extract(txid, lock_txid));
+            .and_then(|snapshot: Snapshot<DataRow>| snapshot.extract(read_txid, lock_txid));
 
         Ok(row)
     }
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
-        let (txid, created_at) = match self.state {
-            State::Transaction {
-                txid, created_at, ..
-            } => (txid, created_at),
-            State::Idle => {
-                return Err(Error::StorageMsg(
-                    "conflict - scan_data failed, lock does not exist".to_owned(),
-                ));
-            }
+        self.scan_data_range(table_name, Bound::Unbounded, Bound::Unbounded)
+    }
+
+    /// Scans only the range of keys which the predicates on the primary key allow
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        _columns: Option<&[String]>,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<RowIter> {
+        let (lower, upper) = match self.fetch_primary_key(table_name).await? {
+            Some(column_def) => primary_key_range(&column_def, predicates),
+            None => (Bound::Unbounded, Bound::Unbounded),
         };
-        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
 
-        let prefix = key::data_prefix(table_name);
-        let prefix_len = prefix.len();
-        let result_set = self
-            .tree
-            .scan_prefix(prefix.as_bytes())
-            .map(move |item| {
-                let (key, value) = item.map_err(err_into)?;
-                let key = key.subslice(prefix_len, key.len() - prefix_len).to_vec();
-                let snapshot: Snapshot<DataRow> = bincode::deserialize(&value).map_err(err_into)?;
-                let row = snapshot.extract(txid, lock_txid);
-                let item = row.map(|row| (Key::Bytea(key), row));
+        self.scan_data_range(table_name, lower, upper)
+    }
 
-                Ok(item)
+    /// Only the predicates on the primary key narrow the range of keys to scan
+    async fn filtered_scan_predicates(
+        &self,
+        table_name: &str,
+        predicates: &[(String, IndexOperator, Value)],
+    ) -> Result<Vec<(String, IndexOperator, Value)>> {
+        let column_def = match self.fetch_primary_key(table_name).await? {
+            Some(column_def) => column_def,
+            None => return Ok(Vec::new()),
+        };
+
+        let predicates = predicates
+            .iter()
+            .filter(|(column, _, value)| {
+                column == &column_def.name && range_key(&column_def, value).is_ok()
             })
-            .filter_map(|item| item.transpose());
+            .cloned()
+            .collect();
 
-        Ok(Box::new(result_set))
+        Ok(predicates)
+    }
+
+    async fn fetch_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>> {
+        self.tree
+            .get(key::statistics(table_name))
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)
     }
 }
@@ -10,7 +10,7 @@ use {
     async_io::block_on,
     async_trait::async_trait,
     gluesql_core::{
-        data::{Key, Schema},
+        data::{Key, Schema, TableStatistics},
         error::{Error, IndexError, Result},
         store::{DataRow, StoreMut},
     },
@@ -154,6 +154,10 @@ impl StoreMut for SledStorage {
             self.delete_schema(table_name).await?;
         }
 
+        self.tree
+            .remove(key::statistics(table_name))
+            .map_err(err_into)?;
+
         Ok(())
     }
 
@@ -359,4 +363,19 @@ impl StoreMut for SledStorage {
 
         Ok(())
     }
+
+    /// Statistics are advisory, so they are written outside of the transaction and kept even
+    /// when the transaction is rolled back.
+    async fn insert_statistics(
+        &mut self,
+        table_name: &str,
+        statistics: TableStatistics,
+    ) -> Result<()> {
+        let statistics = bincode::serialize(&statistics).map_err(err_into)?;
+
+        self.tree
+            .insert(key::statistics(table_name), statistics)
+            .map_err(err_into)
+            .map(|_| ())
+    }
 }
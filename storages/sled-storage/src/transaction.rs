@@ -1,11 +1,12 @@
 use {
     super::{
         err_into, key,
-        lock::{self, Lock},
+        lock::{self, Lock, LockAcquired, TxData},
         tx_err_into, SledStorage, Snapshot, State,
     },
     async_trait::async_trait,
     gluesql_core::{
+        ast::IsolationLevel,
         data::Schema,
         error::{Error, Result},
         store::{DataRow, Transaction},
@@ -18,9 +19,17 @@ use {
         },
         IVec,
     },
-    std::result::Result as StdResult,
+    std::{collections::HashSet, result::Result as StdResult, sync::atomic::Ordering},
 };
 
+/// Values which the transaction has written until `SAVEPOINT name`, each of temp key, value
+/// key and the value at the savepoint
+#[derive(Debug, Clone)]
+pub struct Savepoint {
+    name: String,
+    items: Vec<(IVec, IVec, Option<IVec>)>,
+}
+
 pub enum TxPayload {
     Success,
     RollbackAndRetry(u64),
@@ -35,7 +44,8 @@ impl Transaction for SledStorage {
             )),
             (State::Transaction { autocommit, .. }, true) => Ok(*autocommit),
             (State::Idle, _) => {
-                let (txid, created_at) = lock::register(&self.tree, self.id_offset)?;
+                let (txid, created_at) =
+                    lock::register(&self.tree, self.id_offset, &self.last_txid)?;
 
                 self.state = State::Transaction {
                     txid,
@@ -84,6 +94,133 @@ impl Transaction for SledStorage {
             .map_err(tx_err_into)?;
 
         self.state = State::Idle;
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        let txid = self.savepoint_txid()?;
+        let items = [
+            key::temp_data_prefix(txid),
+            key::temp_schema_prefix(txid),
+            key::temp_index_prefix(txid),
+        ]
+        .into_iter()
+        .map(|prefix| self.fetch_temp_items(prefix))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .map(|(temp_key, value_key)| {
+            let value = self.tree.get(&value_key).map_err(err_into)?;
+
+            Ok((temp_key, value_key, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+        self.savepoints.push(Savepoint {
+            name: name.to_owned(),
+            items,
+        });
+
+        Ok(())
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        let txid = self.savepoint_txid()?;
+        let position = self.find_savepoint(name)?;
+        self.savepoints.truncate(position + 1);
+
+        let items = &self.savepoints[position].items;
+        let saved = items
+            .iter()
+            .map(|(temp_key, _, _)| temp_key)
+            .collect::<HashSet<_>>();
+        let fetch_new_items = |prefix| -> Result<Vec<(IVec, IVec)>> {
+            self.fetch_temp_items(prefix).map(|items| {
+                items
+                    .into_iter()
+                    .filter(|(temp_key, _)| !saved.contains(temp_key))
+                    .collect()
+            })
+        };
+
+        // values first written after the savepoint are rolled back like a whole transaction,
+        // and the others get back their values at the savepoint
+        let data_items = fetch_new_items(key::temp_data_prefix(txid))?;
+        let schema_items = fetch_new_items(key::temp_schema_prefix(txid))?;
+        let index_items = fetch_new_items(key::temp_index_prefix(txid))?;
+        self.rollback_items(txid, data_items, schema_items, index_items)?;
+
+        self.tree
+            .transaction(move |tree| {
+                for (_, value_key, value) in items.iter() {
+                    match value {
+                        Some(value) => tree.insert(value_key, value)?,
+                        None => tree.remove(value_key)?,
+                    };
+                }
+
+                Ok(())
+            })
+            .map_err(tx_err_into)
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        self.savepoint_txid()?;
+
+        let position = self.find_savepoint(name)?;
+        self.savepoints.truncate(position);
+
+        Ok(())
+    }
+
+    /// SERIALIZABLE is not supported, and READ UNCOMMITTED runs as READ COMMITTED because
+    /// writes of a transaction are hidden from the others until it is committed.
+    async fn set_isolation_level(&mut self, isolation_level: IsolationLevel) -> Result<()> {
+        if matches!(self.state, State::Transaction { .. }) {
+            return Err(Error::StorageMsg(
+                "isolation level cannot be changed in a transaction".to_owned(),
+            ));
+        }
+
+        self.isolation_level = match isolation_level {
+            IsolationLevel::ReadUncommitted | IsolationLevel::ReadCommitted => {
+                IsolationLevel::ReadCommitted
+            }
+            IsolationLevel::RepeatableRead => IsolationLevel::RepeatableRead,
+            IsolationLevel::Serializable => {
+                return Err(Error::StorageMsg(
+                    "isolation level not supported: SERIALIZABLE".to_owned(),
+                ));
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.tree.flush().map(|_| ()).map_err(err_into)
+    }
+
+    /// SledStorage has no row-level locks, so the lock of the whole database is acquired,
+    /// which any write of the other transactions has to acquire as well.
+    async fn lock_for_update(&mut self, table_names: &[String]) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result =
+            self.tree
+                .transaction(move |tree| match lock::acquire(tree, state, tx_timeout)? {
+                    LockAcquired::Success { .. } => Ok(TxPayload::Success),
+                    LockAcquired::RollbackAndRetry { lock_txid } => {
+                        Ok(TxPayload::RollbackAndRetry(lock_txid))
+                    }
+                });
+
+        if self.check_retry(tx_result)? {
+            self.lock_for_update(table_names).await?;
+        }
+
         Ok(())
     }
 
@@ -104,6 +241,11 @@ impl Transaction for SledStorage {
             .map_err(tx_err_into)?;
 
         self.state = State::Idle;
+        self.savepoints.clear();
+
+        if self.flush_on_commit {
+            self.tree.flush().map_err(err_into)?;
+        }
 
         if self.tree.get("gc_lock").map_err(err_into)?.is_some() {
             return Ok(());
@@ -121,13 +263,75 @@ impl Transaction for SledStorage {
 
 impl SledStorage {
     pub fn rollback_txid(&self, txid: u64) -> Result<()> {
-        let fetch_items = |prefix| {
+        let data_items = self.fetch_temp_items(key::temp_data_prefix(txid))?;
+        let schema_items = self.fetch_temp_items(key::temp_schema_prefix(txid))?;
+        let index_items = self.fetch_temp_items(key::temp_index_prefix(txid))?;
+
+        self.rollback_items(txid, data_items, schema_items, index_items)
+    }
+
+    /// Cleans up transactions left by a process which ended without committing them, e.g.
+    /// by a crash. No transaction is alive when the database is opened, so the transaction
+    /// holding the lock is rolled back, the others are marked as finished so that gc can
+    /// collect their snapshots, and the lock of an interrupted gc is removed.
+    pub(crate) fn recover(&self) -> Result<()> {
+        let Lock { lock_txid, .. } = self
+            .tree
+            .get("lock/")
+            .map_err(err_into)?
+            .map(|l| bincode::deserialize(&l))
+            .transpose()
+            .map_err(err_into)?
+            .unwrap_or_default();
+
+        if let Some(lock_txid) = lock_txid {
+            self.rollback_txid(lock_txid)?;
             self.tree
-                .scan_prefix(prefix)
-                .map(|item| item.map_err(err_into))
-                .collect::<Result<Vec<_>>>()
-        };
+                .transaction(move |tree| lock::release(tree, lock_txid))
+                .map_err(tx_err_into)?;
+        }
+
+        let alive_txids = self
+            .tree
+            .scan_prefix("tx_data/")
+            .map(|item| -> Result<TxData> {
+                let (_, value) = item.map_err(err_into)?;
+
+                bincode::deserialize(&value).map_err(err_into)
+            })
+            .filter_map(|tx_data| match tx_data {
+                Ok(TxData {
+                    txid, alive: true, ..
+                }) => Some(Ok(txid)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
+        for txid in alive_txids {
+            lock::unregister(&self.tree, txid)?;
+        }
+
+        self.tree.remove("gc_lock").map_err(err_into)?;
+
+        Ok(())
+    }
+
+    /// Pairs of temp key and value key which the transaction has written with `prefix`
+    fn fetch_temp_items(&self, prefix: IVec) -> Result<Vec<(IVec, IVec)>> {
+        self.tree
+            .scan_prefix(prefix)
+            .map(|item| item.map_err(err_into))
+            .collect::<Result<Vec<_>>>()
+    }
+
+    fn rollback_items(
+        &self,
+        txid: u64,
+        data_items: Vec<(IVec, IVec)>,
+        schema_items: Vec<(IVec, IVec)>,
+        index_items: Vec<(IVec, IVec)>,
+    ) -> Result<()> {
         fn rollback_items<T: Clone + Serialize + DeserializeOwned>(
             tree: &TransactionalTree,
             txid: u64,
@@ -167,10 +371,6 @@ impl SledStorage {
             Ok(())
         }
 
-        let data_items = fetch_items(key::temp_data_prefix(txid))?;
-        let schema_items = fetch_items(key::temp_schema_prefix(txid))?;
-        let index_items = fetch_items(key::temp_index_prefix(txid))?;
-
         self.tree
             .transaction(move |tree| {
                 rollback_items::<DataRow>(tree, txid, &data_items)?;
@@ -214,6 +414,47 @@ impl SledStorage {
             .map_err(tx_err_into)
     }
 
+    fn savepoint_txid(&self) -> Result<u64> {
+        match self.state {
+            State::Transaction {
+                txid,
+                autocommit: false,
+                ..
+            } => Ok(txid),
+            _ => Err(Error::StorageMsg(
+                "savepoints can only be used in a transaction".to_owned(),
+            )),
+        }
+    }
+
+    /// Position of the latest savepoint of `name`, which an older one of the same name is
+    /// hidden by
+    fn find_savepoint(&self, name: &str) -> Result<usize> {
+        self.savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.name == name)
+            .ok_or_else(|| Error::StorageMsg(format!("savepoint not found: {name}")))
+    }
+
+    /// Txid and lock txid which reads of the transaction `txid` extract snapshots with.
+    /// READ COMMITTED reads use the latest registered txid for each statement, so the rows
+    /// committed after the transaction has started are seen while the writes of the transaction
+    /// stay visible. The txid is kept in memory, so reads do not write to the tree.
+    pub(crate) fn read_txid(
+        &self,
+        txid: u64,
+        lock_txid: Option<u64>,
+    ) -> Result<(u64, Option<u64>)> {
+        if self.isolation_level != IsolationLevel::ReadCommitted {
+            return Ok((txid, lock_txid));
+        }
+
+        let read_txid = self.last_txid.load(Ordering::SeqCst);
+        let lock_txid = lock_txid.filter(|lock_txid| *lock_txid != txid);
+
+        Ok((read_txid, lock_txid))
+    }
+
     pub fn check_retry(
         &mut self,
         tx_result: StdResult<TxPayload, TransactionError<Error>>,
@@ -0,0 +1,131 @@
+use {
+    gluesql_core::{
+        ast::DataType,
+        error::ValidateError,
+        prelude::{Value::*, *},
+    },
+    gluesql_sled_storage::SledStorage,
+    std::fs,
+    test_suite::*,
+};
+
+/// Schema of `Item` as the first version of the format stores it, written by
+///
+/// CREATE TABLE Item (
+///     id INTEGER NOT NULL,
+///     name TEXT DEFAULT 'glue',
+///     price DECIMAL,
+///     created TIMESTAMP DEFAULT NOW()
+/// );
+const SCHEMA_V1: &str = "\
+    010000000000000004000000000000004974656d01040000000000000002000000000000006964040000\
+    0000000004000000000000006e616d650d00000001010c000000020000000400000000000000676c7565\
+    000500000000000000707269636517000000010000070000000000000063726561746564110000000101\
+    0e0000002900000000000000000000000000000000000000000000";
+
+/// Row of `Item` written by `INSERT INTO Item VALUES (1, 'apple', NULL, NULL);`
+const ROW_V1: &str = "\
+    01000000000000000000000004000000000000000400000001000000000000000e000000050000000000\
+    00006170706c651900000019000000000000000000000000";
+
+fn decode(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn schema_migration() {
+    let path = "tmp/schema_migration";
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let tree = sled::open(path).unwrap();
+    tree.insert("schema/Item", decode(SCHEMA_V1)).unwrap();
+    tree.insert(
+        [b"data/Item/".as_slice(), &1_u64.to_be_bytes()].concat(),
+        decode(ROW_V1),
+    )
+    .unwrap();
+    drop(tree);
+
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage);
+
+    assert_eq!(
+        glue.execute("SHOW COLUMNS FROM Item;").await,
+        Ok(vec![Payload::ShowColumns(vec![
+            ("id".to_owned(), DataType::Int),
+            ("name".to_owned(), DataType::Text),
+            ("price".to_owned(), DataType::Decimal),
+            ("created".to_owned(), DataType::Timestamp),
+        ])])
+    );
+    assert_eq!(
+        glue.execute("SELECT id, name, price FROM Item;").await,
+        Ok(vec![select_with_null!(
+            id     | name                  | price;
+            I64(1)   Str("apple".to_owned())   Null
+        )])
+    );
+
+    // the stored DEFAULT expressions are read after the migration
+    glue.execute("INSERT INTO Item (id) VALUES (2);")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute("SELECT id, name FROM Item WHERE created IS NOT NULL;")
+            .await,
+        Ok(vec![select!(
+            id  | name
+            I64 | Str;
+            2     "glue".to_owned()
+        )])
+    );
+
+    // schemas written after the migration keep their check constraints and collations
+    glue.execute("CREATE TABLE Stock (id INTEGER, quantity INTEGER CHECK (quantity >= 0));")
+        .await
+        .unwrap();
+    glue.execute("CREATE TABLE Tag (name TEXT COLLATE NOCASE);")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Tag VALUES ('Fruit'), ('Nut');")
+        .await
+        .unwrap();
+    drop(glue);
+
+    // the migrated schemas are not migrated again
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue = Glue::new(storage);
+    assert_eq!(
+        glue.execute("SELECT id FROM Item;").await,
+        Ok(vec![select!(id I64; 1; 2)])
+    );
+    assert_eq!(
+        glue.execute("INSERT INTO Stock VALUES (1, -1);").await,
+        Err(ValidateError::CheckConstraintViolated(r#"CHECK ("quantity" >= 0)"#.to_owned()).into())
+    );
+    assert_eq!(
+        glue.execute("SELECT name FROM Tag WHERE name = 'FRUIT';")
+            .await,
+        Ok(vec![select!(name Str; "Fruit".to_owned())])
+    );
+}
+
+#[test]
+fn newer_schema_version() {
+    let path = "tmp/newer_schema_version";
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let tree = sled::open(path).unwrap();
+    tree.insert("schema_version", &3_u64.to_be_bytes()).unwrap();
+    drop(tree);
+
+    assert_eq!(
+        SledStorage::new(path).err(),
+        Some(Error::StorageMsg(
+            "schema version 3 is newer than the supported version 2".to_owned()
+        ))
+    );
+}
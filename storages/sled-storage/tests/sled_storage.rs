@@ -86,6 +86,40 @@ async fn sled_basic() {
     );
 }
 
+#[tokio::test]
+async fn sled_explain_filtered_scan() {
+    use gluesql_core::prelude::Value::*;
+
+    let mut tester = SledTester::new("sled_explain_filtered_scan").await;
+    let glue = tester.get_glue();
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, qty INTEGER);")
+        .await
+        .unwrap();
+
+    // only the comparisons on the primary key narrow the range of keys to scan
+    assert_eq!(
+        glue.execute("EXPLAIN SELECT id FROM Item WHERE id > 1 AND qty < 3;")
+            .await
+            .unwrap()
+            .remove(0),
+        select_with_null!(
+            table                  | access                                    | join;
+            Str("Item".to_owned())   Str("FILTERED SCAN (id > 1)".to_owned())    Null
+        )
+    );
+    assert_eq!(
+        glue.execute("EXPLAIN SELECT id FROM Item WHERE qty < 3;")
+            .await
+            .unwrap()
+            .remove(0),
+        select_with_null!(
+            table                  | access                        | join;
+            Str("Item".to_owned())   Str("FULL SCAN".to_owned())     Null
+        )
+    );
+}
+
 generate_store_tests!(tokio::test, SledTester);
 generate_index_tests!(tokio::test, SledTester);
 generate_transaction_tests!(tokio::test, SledTester);
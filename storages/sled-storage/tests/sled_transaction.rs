@@ -8,13 +8,13 @@ use {
     gluesql_core::{
         executor::FetchError,
         prelude::{Value::*, *},
-        store::StoreMut,
+        store::{ConflictError, StoreMut},
         *,
     },
     gluesql_sled_storage::{self, SledStorage, State},
     std::{
         fs,
-        time::{SystemTime, UNIX_EPOCH},
+        time::{Duration, SystemTime, UNIX_EPOCH},
     },
     test_suite::*,
 };
@@ -65,7 +65,7 @@ async fn sled_transaction_basic() {
     exec!(glue "CREATE TABLE AcquireLock (id INTEGER);");
     test!(
         glue2 "CREATE TABLE MeTooTheLock (id INTEGER);",
-        Err(Error::StorageMsg("database is locked".to_owned()))
+        Err(ConflictError::Locked.into())
     );
 
     exec!(glue2 "BEGIN;");
@@ -132,6 +132,75 @@ async fn sled_transaction_read_committed() {
     );
 }
 
+#[tokio::test]
+async fn sled_transaction_isolation_level() {
+    let path = &format!("{}/isolation_level", PATH_PREFIX);
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage1 = SledStorage::new(path).unwrap();
+    let storage2 = storage1.clone();
+    let storage3 = storage1.clone();
+    let mut glue1 = Glue::new(storage1);
+    let mut glue2 = Glue::new(storage2);
+    let mut glue3 = Glue::new(storage3);
+
+    exec!(glue1 "CREATE TABLE Sample (id INTEGER);");
+    exec!(glue1 "INSERT INTO Sample VALUES (1);");
+
+    exec!(glue1 "BEGIN;");
+    test!(
+        glue2 "SET TRANSACTION ISOLATION LEVEL READ COMMITTED;",
+        Ok(Payload::SetTransaction)
+    );
+    exec!(glue2 "BEGIN;");
+    test!(
+        glue2 "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ;",
+        Err(Error::StorageMsg(
+            "isolation level cannot be changed in a transaction".to_owned()
+        ))
+    );
+    test!(
+        glue2 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1))
+    );
+
+    // READ COMMITTED sees the rows committed after the transaction has started
+    exec!(glue3 "INSERT INTO Sample VALUES (2);");
+    test!(
+        glue2 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1; 2))
+    );
+    exec!(glue2 "INSERT INTO Sample VALUES (3);");
+    test!(
+        glue2 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1; 2; 3))
+    );
+
+    // READ COMMITTED reads take the latest txid without generating a new one
+    let id = glue2.storage.tree.generate_id().unwrap();
+    test!(
+        glue2 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1; 2; 3))
+    );
+    assert_eq!(glue2.storage.tree.generate_id().unwrap(), id + 1);
+
+    // REPEATABLE READ keeps reading the rows as of the start of the transaction
+    test!(
+        glue1 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1))
+    );
+
+    exec!(glue2 "COMMIT;");
+    exec!(glue1 "COMMIT;");
+
+    test!(
+        glue2 "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE;",
+        Err(Error::StorageMsg(
+            "isolation level not supported: SERIALIZABLE".to_owned()
+        ))
+    );
+}
+
 #[tokio::test]
 async fn sled_transaction_schema_mut() {
     let path = &format!("{}/transaction_schema_mut", PATH_PREFIX);
@@ -758,3 +827,141 @@ async fn sled_transaction_dictionary() {
     test_tables!(glue2 "Foo");
     test_tables!(glue3 "Foo");
 }
+
+#[tokio::test]
+async fn sled_transaction_retry() {
+    let path = &format!("{}/retry", PATH_PREFIX);
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage = SledStorage::new(path).unwrap();
+    let mut glue1 = Glue::new(storage.clone());
+    let mut glue2 = Glue::new(storage);
+    glue2.set_retry_policy(RetryPolicy {
+        max_retries: 3,
+        backoff: Duration::from_millis(1),
+    });
+
+    exec!(glue1 "CREATE TABLE Counter (id INTEGER);");
+    exec!(glue1 "BEGIN;");
+    exec!(glue1 "INSERT INTO Counter VALUES (1);");
+
+    // the lock is held until glue1 commits, so glue2 gives up after its retries
+    test!(
+        glue2 "INSERT INTO Counter VALUES (2);",
+        Err(ConflictError::Locked.into())
+    );
+
+    // glue1 commits while glue2 waits for its next attempt
+    let (inserted, committed) = futures::join!(
+        glue2.execute("INSERT INTO Counter VALUES (2);"),
+        glue1.execute("COMMIT;"),
+    );
+    assert_eq!(committed, Ok(vec![Payload::Commit]));
+    assert_eq!(inserted, Ok(vec![Payload::Insert(1)]));
+
+    test!(
+        glue1 "SELECT id FROM Counter;",
+        Ok(select!(id I64; 1; 2))
+    );
+
+    // statements inside of a transaction are not retried, glue2 fails before glue1 commits
+    exec!(glue1 "BEGIN;");
+    exec!(glue1 "INSERT INTO Counter VALUES (3);");
+    exec!(glue2 "BEGIN;");
+    let (inserted, committed) = futures::join!(
+        glue2.execute("INSERT INTO Counter VALUES (4);"),
+        glue1.execute("COMMIT;"),
+    );
+    assert_eq!(committed, Ok(vec![Payload::Commit]));
+    assert_eq!(inserted, Err(ConflictError::Locked.into()));
+    exec!(glue2 "ROLLBACK;");
+
+    test!(
+        glue2 "SELECT id FROM Counter;",
+        Ok(select!(id I64; 1; 2; 3))
+    );
+}
+
+#[tokio::test]
+async fn sled_transaction_recovery() {
+    let path = &format!("{}/recovery", PATH_PREFIX);
+    fs::remove_dir_all(path).unwrap_or(());
+
+    {
+        let mut storage = SledStorage::new(path).unwrap();
+        storage.set_flush_on_commit(true);
+
+        let mut glue = Glue::new(storage);
+        exec!(glue "CREATE TABLE Sample (id INTEGER);");
+        exec!(glue "INSERT INTO Sample VALUES (1);");
+        exec!(glue "BEGIN;");
+        exec!(glue "INSERT INTO Sample VALUES (2);");
+        test!(glue "FLUSH;", Ok(Payload::Flush));
+
+        // the storage is closed while the transaction still holds the lock
+    }
+
+    let mut glue = Glue::new(SledStorage::new(path).unwrap());
+    test!(glue "INSERT INTO Sample VALUES (3);", Ok(Payload::Insert(1)));
+    test!(glue "CHECKPOINT;", Ok(Payload::Flush));
+    test!(
+        glue "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1; 3))
+    );
+}
+
+#[tokio::test]
+async fn sled_transaction_select_for_update() {
+    let path = &format!("{}/select_for_update", PATH_PREFIX);
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage1 = SledStorage::new(path).unwrap();
+    let storage2 = storage1.clone();
+    let mut glue1 = Glue::new(storage1);
+    let mut glue2 = Glue::new(storage2);
+
+    exec!(glue1 "CREATE TABLE Job (id INTEGER, taken BOOLEAN);");
+    exec!(glue1 "INSERT INTO Job VALUES (1, FALSE), (2, FALSE);");
+
+    exec!(glue1 "BEGIN;");
+    test!(
+        glue1 "SELECT id FROM Job WHERE taken = FALSE LIMIT 1 FOR UPDATE;",
+        Ok(select!(id I64; 1))
+    );
+
+    // the claimed rows cannot be modified or locked by the others until commit
+    test!(
+        glue2 "UPDATE Job SET taken = TRUE WHERE id = 1;",
+        Err(ConflictError::Locked.into())
+    );
+    test!(
+        glue2 "SELECT id FROM Job FOR UPDATE NOWAIT;",
+        Err(ConflictError::Locked.into())
+    );
+    test!(
+        glue2 "SELECT id FROM Job WHERE taken = FALSE;",
+        Ok(select!(id I64; 1; 2))
+    );
+
+    // FOR UPDATE waits for the lock until the lock timeout elapses
+    glue2.set_lock_timeout(Duration::from_millis(20));
+    test!(
+        glue2 "SELECT id FROM Job FOR UPDATE;",
+        Err(ConflictError::Locked.into())
+    );
+    glue2.set_lock_timeout(Duration::from_secs(5));
+
+    exec!(glue1 "UPDATE Job SET taken = TRUE WHERE id = 1;");
+
+    // glue1 commits while glue2 waits for the lock
+    let (selected, committed) = futures::join!(
+        glue2.execute("SELECT id FROM Job WHERE taken = FALSE LIMIT 1 FOR UPDATE;"),
+        glue1.execute("COMMIT;"),
+    );
+    assert_eq!(committed, Ok(vec![Payload::Commit]));
+    assert_eq!(selected, Ok(vec![select!(id I64; 2)]));
+    test!(
+        glue2 "SELECT id FROM Job FOR SHARE;",
+        Err(translate::TranslateError::UnsupportedLockClause("FOR SHARE".to_owned()).into())
+    );
+}
@@ -0,0 +1,60 @@
+use {
+    gluesql_core::{ast::DataType, data::Value},
+    rusqlite::types::{Value as SqliteValue, ValueRef},
+};
+
+/// Data type of a column by its declared type, following the type affinity rules of SQLite
+pub fn data_type(declared_type: &str) -> DataType {
+    let declared_type = declared_type.to_uppercase();
+    let contains = |names: &[&str]| names.iter().any(|name| declared_type.contains(name));
+
+    if contains(&["INT"]) {
+        DataType::Int
+    } else if contains(&["BOOL"]) {
+        DataType::Boolean
+    } else if contains(&["DATETIME", "TIMESTAMP"]) {
+        DataType::Timestamp
+    } else if contains(&["DATE"]) {
+        DataType::Date
+    } else if contains(&["TIME"]) {
+        DataType::Time
+    } else if contains(&["CHAR", "CLOB", "TEXT"]) {
+        DataType::Text
+    } else if contains(&["BLOB"]) {
+        DataType::Bytea
+    } else if contains(&["REAL", "FLOA", "DOUB"]) {
+        DataType::Float
+    } else if contains(&["DECIMAL", "NUMERIC"]) {
+        DataType::Decimal
+    } else {
+        DataType::Text
+    }
+}
+
+pub fn value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(v) => Value::I64(v),
+        ValueRef::Real(v) => Value::F64(v),
+        ValueRef::Text(v) => Value::Str(String::from_utf8_lossy(v).into_owned()),
+        ValueRef::Blob(v) => Value::Bytea(v.to_vec()),
+    }
+}
+
+pub fn sqlite_value(value: Value) -> SqliteValue {
+    match value {
+        Value::Null => SqliteValue::Null,
+        Value::Bool(v) => SqliteValue::Integer(v.into()),
+        Value::I8(v) => SqliteValue::Integer(v.into()),
+        Value::I16(v) => SqliteValue::Integer(v.into()),
+        Value::I32(v) => SqliteValue::Integer(v.into()),
+        Value::I64(v) => SqliteValue::Integer(v),
+        Value::U8(v) => SqliteValue::Integer(v.into()),
+        Value::U16(v) => SqliteValue::Integer(v.into()),
+        Value::U32(v) => SqliteValue::Integer(v.into()),
+        Value::F32(v) => SqliteValue::Real(v.into()),
+        Value::F64(v) => SqliteValue::Real(v),
+        Value::Bytea(v) => SqliteValue::Blob(v),
+        value => SqliteValue::Text(String::from(value)),
+    }
+}
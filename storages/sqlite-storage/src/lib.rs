@@ -0,0 +1,176 @@
+#![deny(clippy::str_to_string)]
+
+mod convert;
+pub mod error;
+mod store;
+mod store_mut;
+
+use {
+    error::ResultExt,
+    gluesql_core::{
+        ast::{ColumnDef, ColumnUniqueOption},
+        data::{Key, Schema, Value},
+        error::Result,
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
+            Transaction,
+        },
+    },
+    rusqlite::{params_from_iter, Connection, OpenFlags, OptionalExtension},
+};
+
+/// Read-only storage over the tables of an existing SQLite database file
+pub struct SqliteStorage {
+    conn: Connection,
+}
+
+impl SqliteStorage {
+    pub fn open(path: &str) -> Result<Self> {
+        let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let conn = Connection::open_with_flags(path, flags).map_storage_err()?;
+
+        Ok(Self { conn })
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let exists = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                [table_name],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_storage_err()?
+            .is_some();
+
+        if !exists {
+            return Ok(None);
+        }
+
+        let mut statement = self
+            .conn
+            .prepare(r#"SELECT name, type, "notnull", pk FROM pragma_table_info(?1) ORDER BY cid"#)
+            .map_storage_err()?;
+        let columns = statement
+            .query_map([table_name], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, bool>(2)?,
+                    row.get::<_, i64>(3)? > 0,
+                ))
+            })
+            .map_storage_err()?
+            .collect::<Result<Vec<_>, _>>()
+            .map_storage_err()?;
+
+        // a primary key of several columns is not a primary key of GlueSQL, rowid is the key
+        let single_primary_key = columns.iter().filter(|(.., pk)| *pk).count() == 1;
+        let column_defs = columns
+            .into_iter()
+            .map(|(name, declared_type, not_null, pk)| ColumnDef {
+                name,
+                data_type: convert::data_type(&declared_type),
+                nullable: !not_null && !(pk && single_primary_key),
+                default: None,
+                unique: (pk && single_primary_key)
+                    .then_some(ColumnUniqueOption { is_primary: true }),
+                collation: None,
+            })
+            .collect();
+
+        Ok(Some(Schema {
+            table_name: table_name.to_owned(),
+            column_defs: Some(column_defs),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            view: None,
+        }))
+    }
+
+    /// Reads the rows of the table in key order, or only the row of `key` when it is given
+    fn scan_data(&self, table_name: &str, key: Option<&Key>) -> Result<Vec<(Key, DataRow)>> {
+        let column_defs = match self.fetch_schema(table_name)? {
+            Some(Schema {
+                column_defs: Some(column_defs),
+                ..
+            }) => column_defs,
+            _ => return Ok(Vec::new()),
+        };
+
+        let primary_key = column_defs.iter().position(|column_def| {
+            column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+        });
+        let key_column = match primary_key {
+            Some(index) => quote(&column_defs[index].name),
+            None => "rowid".to_owned(),
+        };
+        let columns = column_defs
+            .iter()
+            .map(|column_def| quote(&column_def.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let condition = match key {
+            Some(_) => format!("WHERE {key_column} = ?1"),
+            None => String::new(),
+        };
+        let sql = format!(
+            "SELECT {key_column}, {columns} FROM {} {condition} ORDER BY {key_column}",
+            quote(table_name)
+        );
+
+        let params = key
+            .cloned()
+            .map(Value::from)
+            .map(convert::sqlite_value)
+            .into_iter();
+        let mut statement = self.conn.prepare(&sql).map_storage_err()?;
+        let rows = statement
+            .query_map(params_from_iter(params), |row| {
+                (0..=column_defs.len())
+                    .map(|index| row.get_ref(index).map(convert::value))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_storage_err()?
+            .collect::<Result<Vec<_>, _>>()
+            .map_storage_err()?;
+
+        rows.into_iter()
+            .map(|mut values| {
+                let rowid = values.remove(0);
+                let values = values
+                    .into_iter()
+                    .zip(column_defs.iter())
+                    .map(|(value, column_def)| match value.get_type() {
+                        Some(data_type) if data_type != column_def.data_type => {
+                            value.cast(&column_def.data_type)
+                        }
+                        _ => Ok(value),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let key = match primary_key {
+                    Some(index) => Key::try_from(&values[index])?,
+                    None => Key::try_from(rowid)?,
+                };
+
+                Ok((key, DataRow::Vec(values)))
+            })
+            .collect()
+    }
+}
+
+fn quote(identifier: &str) -> String {
+    format!(r#""{}""#, identifier.replace('"', r#""""#))
+}
+
+impl AlterTable for SqliteStorage {}
+impl CustomFunction for SqliteStorage {}
+impl CustomFunctionMut for SqliteStorage {}
+impl Index for SqliteStorage {}
+impl IndexMut for SqliteStorage {}
+impl Transaction for SqliteStorage {}
+impl Metadata for SqliteStorage {}
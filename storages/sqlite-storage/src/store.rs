@@ -0,0 +1,48 @@
+use {
+    crate::{error::ResultExt, SqliteStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl Store for SqliteStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.fetch_schema(table_name)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT name FROM sqlite_master
+                WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+                ORDER BY name",
+            )
+            .map_storage_err()?;
+        let table_names = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_storage_err()?
+            .collect::<Result<Vec<_>, _>>()
+            .map_storage_err()?;
+
+        table_names
+            .iter()
+            .filter_map(|table_name| self.fetch_schema(table_name).transpose())
+            .collect()
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.scan_data(table_name, Some(key))
+            .map(|rows| rows.into_iter().next().map(|(_, row)| row))
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let rows = self.scan_data(table_name, None)?;
+
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{error::SqliteStorageError, SqliteStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, StoreMut},
+    },
+};
+
+#[async_trait(?Send)]
+impl StoreMut for SqliteStorage {
+    async fn insert_schema(&mut self, _schema: &Schema) -> Result<()> {
+        Err(SqliteStorageError::ReadOnly.into())
+    }
+
+    async fn delete_schema(&mut self, _table_name: &str) -> Result<()> {
+        Err(SqliteStorageError::ReadOnly.into())
+    }
+
+    async fn append_data(&mut self, _table_name: &str, _rows: Vec<DataRow>) -> Result<()> {
+        Err(SqliteStorageError::ReadOnly.into())
+    }
+
+    async fn insert_data(&mut self, _table_name: &str, _rows: Vec<(Key, DataRow)>) -> Result<()> {
+        Err(SqliteStorageError::ReadOnly.into())
+    }
+
+    async fn delete_data(&mut self, _table_name: &str, _keys: Vec<Key>) -> Result<()> {
+        Err(SqliteStorageError::ReadOnly.into())
+    }
+}
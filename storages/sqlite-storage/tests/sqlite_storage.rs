@@ -0,0 +1,124 @@
+use {
+    gluesql_composite_storage::CompositeStorage,
+    gluesql_core::prelude::{
+        Glue,
+        Value::{self, *},
+    },
+    gluesql_sqlite_storage::SqliteStorage,
+    memory_storage::MemoryStorage,
+    rusqlite::Connection,
+    std::fs,
+    test_suite::*,
+};
+
+fn create_sample(path: &str) {
+    fs::create_dir_all("tmp").unwrap();
+    fs::remove_file(path).unwrap_or(());
+
+    Connection::open(path)
+        .unwrap()
+        .execute_batch(
+            "
+            CREATE TABLE Customer (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                vip BOOLEAN,
+                joined DATE
+            );
+            INSERT INTO Customer VALUES
+                (2, 'Bob', 0, '2021-06-01'),
+                (1, 'Alice', 1, '2020-01-15'),
+                (3, 'Carol', NULL, NULL);
+
+            CREATE TABLE Note (body TEXT, score REAL);
+            INSERT INTO Note VALUES ('first', 1.5), ('second', NULL);
+            ",
+        )
+        .unwrap();
+}
+
+#[tokio::test]
+async fn sqlite_storage() {
+    let path = "tmp/sqlite_storage.sqlite";
+    create_sample(path);
+
+    let mut glue = Glue::new(SqliteStorage::open(path).unwrap());
+
+    let actual = glue.execute("SELECT * FROM Customer").await.unwrap();
+    let expected = select_with_null!(
+        id     | name                    | vip            | joined;
+        I64(1)   Str("Alice".to_owned())   Bool(true)       Value::Date("2020-01-15".parse().unwrap());
+        I64(2)   Str("Bob".to_owned())     Bool(false)      Value::Date("2021-06-01".parse().unwrap());
+        I64(3)   Str("Carol".to_owned())   Null             Null
+    );
+    assert_eq!(actual, vec![expected]);
+
+    let actual = glue
+        .execute("SELECT body, score FROM Note WHERE score IS NULL")
+        .await
+        .unwrap();
+    let expected = select_with_null!(
+        body                       | score;
+        Str("second".to_owned())     Null
+    );
+    assert_eq!(actual, vec![expected]);
+
+    let actual = glue
+        .execute("SELECT TABLE_NAME FROM GLUE_TABLES")
+        .await
+        .unwrap();
+    let expected = select!(
+        TABLE_NAME
+        Str;
+        "Customer".to_owned();
+        "Note".to_owned()
+    );
+    assert_eq!(actual, vec![expected]);
+
+    let actual = glue
+        .execute("INSERT INTO Note VALUES ('third', 3.0)")
+        .await
+        .map_err(|e| e.to_string());
+    assert_eq!(actual, Err("sqlite storage is read-only".to_owned()));
+}
+
+#[tokio::test]
+async fn join_with_glue_table() {
+    let path = "tmp/join_with_glue_table.sqlite";
+    create_sample(path);
+
+    let mut storage = CompositeStorage::new();
+    storage.push("SQLITE", SqliteStorage::open(path).unwrap());
+    storage.push("MEMORY", MemoryStorage::default());
+    storage.set_default("SQLITE");
+
+    let mut glue = Glue::new(storage);
+    glue.execute(
+        "
+        CREATE TABLE Orders (customer_id INTEGER, amount INTEGER) ENGINE = MEMORY;
+        INSERT INTO Orders VALUES (1, 100), (3, 50), (1, 20);
+        ",
+    )
+    .await
+    .unwrap();
+
+    let actual = glue
+        .execute(
+            "
+            SELECT Customer.name, SUM(Orders.amount) AS total
+            FROM Customer
+            JOIN Orders ON Orders.customer_id = Customer.id
+            GROUP BY Customer.name
+            ORDER BY Customer.name
+            ",
+        )
+        .await
+        .unwrap();
+    let expected = select!(
+        name                | total
+        Str                 | I64;
+        "Alice".to_owned()    120;
+        "Carol".to_owned()    50
+    );
+    assert_eq!(actual, vec![expected]);
+}
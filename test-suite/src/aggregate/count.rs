@@ -43,6 +43,24 @@ test_case!(count, {
                 3                              4
             ),
         ),
+        (
+            "SELECT COUNT(DISTINCT total), COUNT(DISTINCT age) FROM Item;",
+            select!(
+                "COUNT(DISTINCT total)" | "COUNT(DISTINCT age)";
+                I64                     |                   I64;
+                3                                             3
+            ),
+        ),
+        (
+            "SELECT total, COUNT(DISTINCT age) AS c FROM Item GROUP BY total;",
+            select!(
+                total | c;
+                I64   | I64;
+                1       2;
+                2       1;
+                3       0
+            ),
+        ),
         (
             "SELECT COUNT(NULL);",
             select!(
@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
 
 test_case!(group_by, {
     let g = get_tester!();
@@ -92,6 +95,91 @@ test_case!(group_by, {
                 21                2            "Seoul".to_owned()
             ),
         ),
+        (
+            "SELECT city FROM Item GROUP BY city HAVING COUNT(*) > 1",
+            select!(city Str; "Seoul".to_owned()),
+        ),
+        (
+            "SELECT city, COUNT(*) FROM Item GROUP BY city HAVING SUM(quantity) > 20 AND MAX(ratio) < 6",
+            select!(
+                city | "COUNT(*)"
+                Str  | I64;
+                "Seoul".to_owned()     2;
+                "Daejeon".to_owned()   1
+            ),
+        ),
+        (
+            "SELECT id FROM Item GROUP BY id HAVING COUNT(*) > COUNT(quantity)",
+            select!(id I64; 3),
+        ),
+        (
+            "SELECT id FROM Item GROUP BY id HAVING SUM(quantity) > (SELECT MIN(quantity) FROM Item WHERE quantity > 10)",
+            select!(id I64; 5),
+        ),
+        (
+            "SELECT COUNT(*) FROM Item HAVING SUM(ratio) > 100",
+            Payload::Select {
+                labels: vec!["COUNT(*)".to_owned()],
+                rows: vec![],
+            },
+        ),
+        (
+            "SELECT COUNT(*) FROM Item WHERE id > 10 HAVING COUNT(*) = 0",
+            select!("COUNT(*)" I64; 0),
+        ),
+        (
+            "SELECT city, MIN(quantity), MAX(quantity), AVG(quantity) FROM Item WHERE id = 3 GROUP BY city",
+            select_with_null!(
+                city                       | "MIN(quantity)" | "MAX(quantity)" | "AVG(quantity)";
+                Str("Beijing".to_owned())    Null              Null              Null;
+                Str("Daejeon".to_owned())    I64(30)           I64(30)           F64(30.0)
+            ),
+        ),
+        (
+            "SELECT id, MIN(quantity), MAX(quantity) FROM Item WHERE id = 3 GROUP BY id",
+            select!(
+                id  | "MIN(quantity)" | "MAX(quantity)"
+                I64 | I64             | I64;
+                3     30                30
+            ),
+        ),
+        (
+            "SELECT id % 2 AS odd, COUNT(*) AS c, MIN(quantity) AS q FROM Item GROUP BY id % 2",
+            select!(
+                odd | c   | q
+                I64 | I64 | I64;
+                1     4     10;
+                0     2     0
+            ),
+        ),
+        (
+            "SELECT id % 2 AS odd, COUNT(*) AS c FROM Item GROUP BY odd HAVING c > 2",
+            select!(odd | c; I64 | I64; 1 4),
+        ),
+        (
+            "SELECT city AS c, SUM(quantity) AS total FROM Item GROUP BY c HAVING total > 20 ORDER BY total DESC",
+            select!(
+                c                      | total
+                Str                    | I64;
+                "Daejeon".to_owned()     30;
+                "Seattle".to_owned()     24;
+                "Seoul".to_owned()       21
+            ),
+        ),
+        (
+            "SELECT COUNT(*), COUNT(quantity), SUM(quantity), MIN(ratio), AVG(id) FROM Item WHERE id > 10",
+            select_with_null!(
+                "COUNT(*)" | "COUNT(quantity)" | "SUM(quantity)" | "MIN(ratio)" | "AVG(id)";
+                I64(0)       I64(0)              Null              Null           Null
+            ),
+        ),
+        (
+            "SELECT city, COUNT(*) FROM Item WHERE id > 10 GROUP BY city",
+            Payload::Select {
+                labels: vec!["city".to_owned(), "COUNT(*)".to_owned()],
+                rows: vec![],
+            },
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -25,6 +25,13 @@ test_case!(alter_table_rename, {
             "ALTER TABLE Foo2 RENAME TO Bar;",
             Err(AlterTableError::TableNotFound("Foo2".to_owned()).into()),
         ),
+        ("CREATE TABLE Taken (id INTEGER);", Ok(Payload::Create)),
+        (
+            // Cannot rename to an existing table name
+            "ALTER TABLE Foo RENAME TO Taken;",
+            Err(AlterError::TableAlreadyExists("Taken".to_owned()).into()),
+        ),
+        ("SELECT id FROM Foo", Ok(select!(id; I64; 1; 2; 3))),
         ("ALTER TABLE Foo RENAME TO Bar;", Ok(Payload::AlterTable)),
         ("SELECT id FROM Bar", Ok(select!(id; I64; 1; 2; 3))),
         (
@@ -63,6 +70,7 @@ test_case!(alter_table_add_drop, {
                 nullable: false,
                 default: None,
                 unique: None,
+                collation: None,
             })
             .into()),
         ),
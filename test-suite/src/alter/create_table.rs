@@ -120,6 +120,32 @@ test_case!(create_table, {
                 I64(2)   I64(2)   Str("2".to_owned())
             )),
         ),
+        (
+            // Columns are inferred from the output of the source query
+            "CREATE TABLE TargetTableProjected AS SELECT name, num * 2 AS double FROM CreateTable2",
+            Ok(Payload::Create),
+        ),
+        (
+            "SHOW COLUMNS FROM TargetTableProjected",
+            Ok(Payload::ShowColumns(vec![
+                ("name".to_owned(), gluesql_core::ast::DataType::Text),
+                ("double".to_owned(), gluesql_core::ast::DataType::Int),
+            ])),
+        ),
+        (
+            "SELECT * FROM TargetTableProjected",
+            Ok(select!(
+                name            | double
+                Str             | I64;
+                "1".to_owned()    2;
+                "2".to_owned()    4
+            )),
+        ),
+        (
+            "CREATE TABLE TargetTableCount AS SELECT COUNT(*) AS cnt FROM CreateTable2",
+            Ok(Payload::Create),
+        ),
+        ("SELECT * FROM TargetTableCount", Ok(select!(cnt I64; 2))),
         (
             // Target Table already exists
             "CREATE TABLE TargetTableWithData AS SELECT * FROM CreateTable2",
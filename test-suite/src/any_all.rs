@@ -0,0 +1,136 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(any_all, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Employee (
+            id INTEGER,
+            salary INTEGER NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        CREATE TABLE Intern (
+            id INTEGER,
+            salary INTEGER NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Employee (id, salary) VALUES
+            (1,  100),
+            (2,  300),
+            (3,  500),
+            (4, NULL);
+    ",
+    )
+    .await;
+    g.run("INSERT INTO Intern (id, salary) VALUES (1, 200), (2, 250);")
+        .await;
+
+    let test_cases = [
+        (
+            2,
+            "SELECT id FROM Employee WHERE salary > ALL (SELECT salary FROM Intern)",
+        ),
+        (
+            2,
+            "SELECT id FROM Employee WHERE salary > ANY (SELECT salary FROM Intern)",
+        ),
+        (
+            1,
+            "SELECT id FROM Employee WHERE salary < ANY(SELECT salary FROM Intern)",
+        ),
+        (
+            1,
+            "SELECT id FROM Employee WHERE salary <= ALL (SELECT salary FROM Intern)",
+        ),
+        (
+            2,
+            "SELECT id FROM Employee WHERE salary >= ANY (SELECT salary + 50 FROM Intern)",
+        ),
+        (
+            1,
+            "SELECT id FROM Employee WHERE salary = ANY (SELECT salary * 2 FROM Intern)",
+        ),
+        (
+            2,
+            "SELECT id FROM Employee WHERE salary <> ALL (SELECT salary + 50 FROM Intern)",
+        ),
+        // a NULL in the subquery makes ALL unknown unless some comparison is false
+        (
+            0,
+            "SELECT id FROM Employee WHERE salary >= ALL (SELECT salary FROM Employee)",
+        ),
+        (
+            1,
+            "SELECT id FROM Employee WHERE salary >= ALL (
+                SELECT salary FROM Employee WHERE salary IS NOT NULL
+            )",
+        ),
+        // ALL over an empty subquery is true, ANY is false
+        (
+            4,
+            "SELECT id FROM Employee WHERE salary > ALL (SELECT salary FROM Intern WHERE id > 5)",
+        ),
+        (
+            0,
+            "SELECT id FROM Employee WHERE salary > ANY (SELECT salary FROM Intern WHERE id > 5)",
+        ),
+    ];
+
+    for (num, sql) in test_cases {
+        g.count(sql, num).await;
+    }
+
+    g.named_test(
+        "correlated ALL subquery in projection",
+        "
+        SELECT
+            id,
+            salary <= ALL (SELECT salary FROM Intern WHERE Intern.id = Employee.id) AS lowest
+        FROM Employee
+        ",
+        Ok(select_with_null!(
+            id     | lowest;
+            I64(1)   Bool(true);
+            I64(2)   Bool(false);
+            I64(3)   Bool(true);
+            I64(4)   Bool(true)
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "NULL target yields NULL",
+        "SELECT salary = ANY (SELECT salary FROM Intern) AS found FROM Employee WHERE id = 4",
+        Ok(select_with_null!(found; Null)),
+    )
+    .await;
+
+    g.named_test(
+        "quantified comparison requires a subquery",
+        "SELECT id FROM Employee WHERE salary > ALL (ARRAY[1, 2])",
+        Err(TranslateError::UnsupportedExpr("salary > ALL(ARRAY[1, 2])".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "quantified comparison with non comparison operator",
+        "SELECT id FROM Employee WHERE salary + ANY ((SELECT salary FROM Intern)) = 1",
+        Err(TranslateError::UnsupportedExpr(
+            "salary + ANY((SELECT salary FROM Intern))".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+});
@@ -67,4 +67,45 @@ test_case!(on_where, {
     for (num, sql) in test_cases {
         g.count(sql, num).await;
     }
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            price FLOAT,
+            quantity INTEGER,
+            discount DECIMAL NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Item (id, price, quantity, discount) VALUES
+            (1, 10.5, 10,  1.5),
+            (2,  3.0, 20, NULL),
+            (3, 200,   1,  0.5);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (2, "SELECT * FROM Item WHERE price * quantity > 100;"),
+        (2, "SELECT * FROM Item WHERE quantity * price > 100;"),
+        (1, "SELECT * FROM Item WHERE quantity * 1.5 > 20;"),
+        (1, "SELECT * FROM Item WHERE quantity * discount > 10;"),
+        (1, "SELECT * FROM Item WHERE quantity / discount = 2;"),
+        (1, "SELECT * FROM Item WHERE price / discount = 400;"),
+        (1, "SELECT * FROM Item WHERE quantity % price = 10;"),
+        (1, "SELECT * FROM Item WHERE quantity - 0.5 = 0.5;"),
+        (0, "SELECT * FROM Item WHERE id = 1.5;"),
+        (
+            0,
+            "SELECT * FROM Item WHERE discount + quantity IS NOT NULL AND id = 2;",
+        ),
+    ];
+
+    for (num, sql) in test_cases {
+        g.count(sql, num).await;
+    }
 });
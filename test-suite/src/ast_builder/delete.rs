@@ -67,4 +67,45 @@ test_case!(delete, {
         rows: vec![],
     });
     assert_eq!(actual, expected, "select * from Foo");
+
+    // delete with order by and limit
+    let actual = table("Foo")
+        .insert()
+        .values(vec![
+            vec![num(4), num(200), true.into()],
+            vec![num(5), num(500), false.into()],
+            vec![num(6), num(100), false.into()],
+            vec![num(7), num(500), true.into()],
+        ])
+        .execute(glue)
+        .await;
+    let expected = Ok(Payload::Insert(4));
+    assert_eq!(actual, expected, "insert into Foo");
+
+    let actual = table("Foo")
+        .delete()
+        .order_by(vec!["score DESC", "id"])
+        .limit(2)
+        .execute(glue)
+        .await;
+    let expected = Ok(Payload::Delete(2));
+    assert_eq!(actual, expected, "delete with order by and limit");
+
+    let actual = table("Foo")
+        .delete()
+        .filter(col("flag").eq(false))
+        .order_by("score")
+        .limit(1)
+        .execute(glue)
+        .await;
+    let expected = Ok(Payload::Delete(1));
+    assert_eq!(actual, expected, "delete using filter, order by and limit");
+
+    let actual = table("Foo").select().execute(glue).await;
+    let expected = Ok(select!(
+        id  | score | flag
+        I64 | I64   | Bool;
+        4     200     true
+    ));
+    assert_eq!(actual, expected, "select * from Foo");
 });
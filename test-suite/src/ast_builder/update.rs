@@ -77,4 +77,36 @@ test_case!(update, {
         3     70      true
     ));
     assert_eq!(actual, expected, "select * from Foo");
+
+    // update with order by and limit
+    let actual = table("Foo")
+        .update()
+        .set("flag", false)
+        .filter(col("flag").eq(true))
+        .order_by("score DESC")
+        .limit(1)
+        .execute(glue)
+        .await;
+    let expected = Ok(Payload::Update(1));
+    assert_eq!(actual, expected, "update with order by and limit");
+
+    let actual = table("Foo")
+        .update()
+        .set("score", 0)
+        .order_by(vec!["flag", "id DESC"])
+        .limit(2)
+        .execute(glue)
+        .await;
+    let expected = Ok(Payload::Update(2));
+    assert_eq!(actual, expected, "update with multiple order by and limit");
+
+    let actual = table("Foo").select().execute(glue).await;
+    let expected = Ok(select!(
+        id  | score | flag
+        I64 | I64   | Bool;
+        1     0       false;
+        2     65      true;
+        3     0       false
+    ));
+    assert_eq!(actual, expected, "select * from Foo");
 });
@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::TranslateError,
+        error::{FetchError, TranslateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -97,7 +97,12 @@ test_case!(basic, {
 
     g.test(
         "SELECT id FROM FOO.Test",
-        Err(TranslateError::CompoundObjectNotSupported("FOO.Test".to_owned()).into()),
+        Err(FetchError::TableNotFound("FOO.Test".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM FOO.BAR.Test",
+        Err(TranslateError::CompoundObjectNotSupported("FOO.BAR.Test".to_owned()).into()),
     )
     .await;
 });
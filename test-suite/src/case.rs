@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::TranslateError,
+        error::{EvaluateError, TranslateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -105,6 +105,59 @@ test_case!(case, {
                 5
             )),
         ),
+        (
+            "
+            SELECT CASE NULL
+                WHEN NULL THEN 'matched'
+                ELSE 'unmatched' END
+            AS case FROM Item WHERE id = 1;
+            ",
+            Ok(select!(case Str; "unmatched".to_owned())),
+        ),
+        (
+            "
+            SELECT CASE
+                WHEN NULL THEN 'null'
+                WHEN id > NULL THEN 'unknown'
+                ELSE 'else' END
+            AS case FROM Item WHERE id = 1;
+            ",
+            Ok(select!(case Str; "else".to_owned())),
+        ),
+        (
+            "
+            SELECT id FROM Item
+            WHERE CASE WHEN id > 1 THEN name ELSE 'none' END <> 'Ron';
+            ",
+            Ok(select!(id I64; 1; 3)),
+        ),
+        (
+            "
+            SELECT id FROM Item
+            ORDER BY CASE id WHEN 2 THEN 0 ELSE id END DESC;
+            ",
+            Ok(select!(id I64; 3; 1; 2)),
+        ),
+        (
+            "
+            SELECT
+                SUM(CASE WHEN id % 2 = 1 THEN 1 ELSE 0 END) AS odd,
+                SUM(CASE WHEN id % 2 = 0 THEN 1 ELSE 0 END) AS even
+            FROM Item;
+            ",
+            Ok(select!(odd | even I64 | I64; 2 1)),
+        ),
+        (
+            "
+            SELECT CASE WHEN id = 1 THEN 'first' ELSE 10 / (id - id) END
+            AS case FROM Item WHERE id = 1;
+            ",
+            Ok(select!(case Str; "first".to_owned())),
+        ),
+        (
+            "SELECT CASE WHEN id THEN 'yes' END AS case FROM Item;",
+            Err(EvaluateError::BooleanTypeRequired("I64(1)".to_owned()).into()),
+        ),
         (
             "
             SELECT CASE 1 COLLATE Item
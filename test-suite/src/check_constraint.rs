@@ -0,0 +1,103 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(check_constraint, {
+    let g = get_tester!();
+
+    g.named_test(
+        "CHECK can only refer to the columns of its table",
+        "CREATE TABLE Wrong (id INTEGER CHECK (amount > 0));",
+        Err(AlterError::UnsupportedCheckExpr(r#"CHECK ("amount" > 0)"#.to_owned()).into()),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            price INTEGER CHECK (price > 0),
+            discount INTEGER NULL,
+            CONSTRAINT valid_discount CHECK (discount < price)
+        );
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "CHECK evaluated to NULL is not a violation",
+        "INSERT INTO Item VALUES (1, 100, 10), (2, 50, NULL);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "column level CHECK violated on INSERT",
+        "INSERT INTO Item VALUES (3, 0, NULL);",
+        Err(ValidateError::CheckConstraintViolated(r#"CHECK ("price" > 0)"#.to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "table level CHECK violated on INSERT",
+        "INSERT INTO Item VALUES (3, 30, 40);",
+        Err(ValidateError::CheckConstraintViolated(
+            r#"CONSTRAINT "valid_discount" CHECK ("discount" < "price")"#.to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "CHECK violated on UPDATE",
+        "UPDATE Item SET price = 5 WHERE id = 1;",
+        Err(ValidateError::CheckConstraintViolated(
+            r#"CONSTRAINT "valid_discount" CHECK ("discount" < "price")"#.to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "CHECK violated on INSERT ON CONFLICT DO UPDATE",
+        "INSERT INTO Item VALUES (2, 50, 70) ON CONFLICT (id) DO UPDATE SET discount = excluded.discount;",
+        Err(ValidateError::CheckConstraintViolated(
+            r#"CONSTRAINT "valid_discount" CHECK ("discount" < "price")"#.to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.test("UPDATE Item SET discount = 5;", Ok(Payload::Update(2)))
+        .await;
+    g.test(
+        "SELECT id, price, discount FROM Item",
+        Ok(select!(
+            id  | price | discount
+            I64 | I64   | I64;
+            1     100     5;
+            2     50      5
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "column used by CHECK cannot be dropped",
+        "ALTER TABLE Item DROP COLUMN discount;",
+        Err(AlterError::CheckColumnInUse {
+            column_name: "discount".to_owned(),
+            check: r#"CONSTRAINT "valid_discount" CHECK ("discount" < "price")"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "column used by CHECK cannot be renamed",
+        "ALTER TABLE Item RENAME COLUMN price TO cost;",
+        Err(AlterError::CheckColumnInUse {
+            column_name: "price".to_owned(),
+            check: r#"CHECK ("price" > 0)"#.to_owned(),
+        }
+        .into()),
+    )
+    .await;
+});
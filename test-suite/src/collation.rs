@@ -0,0 +1,141 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{AlterError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(collation, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Account (
+            id INTEGER,
+            name TEXT COLLATE NOCASE,
+            code TEXT
+        );
+        ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Account VALUES
+            (1, 'Alice', 'A'),
+            (2, 'bob', 'b'),
+            (3, 'ALICE', 'c'),
+            (4, 'Carol', 'B');
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "equality on a NOCASE column",
+        "SELECT id, name FROM Account WHERE name = 'alice'",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Alice".to_owned();
+            3     "ALICE".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "equality on a column without collation",
+        "SELECT id FROM Account WHERE code = 'b'",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "COLLATE on an expression",
+        "SELECT id FROM Account WHERE code COLLATE NOCASE = 'b'",
+        Ok(select!(id I64; 2; 4)),
+    )
+    .await;
+    g.named_test(
+        "COLLATE BINARY overrides the column collation",
+        "SELECT id FROM Account WHERE name COLLATE BINARY = 'ALICE'",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+    g.named_test(
+        "IN list and BETWEEN on a NOCASE column",
+        "SELECT id FROM Account WHERE name IN ('BOB', 'carol') OR name BETWEEN 'a' AND 'alz'",
+        Ok(select!(id I64; 1; 2; 3; 4)),
+    )
+    .await;
+    g.named_test(
+        "LIKE on a NOCASE column",
+        "SELECT id FROM Account WHERE name LIKE 'AL%'",
+        Ok(select!(id I64; 1; 3)),
+    )
+    .await;
+    g.named_test(
+        "ordering by a NOCASE column",
+        "SELECT name FROM Account ORDER BY name DESC, id",
+        Ok(select!(
+            name
+            Str;
+            "Carol".to_owned();
+            "bob".to_owned();
+            "Alice".to_owned();
+            "ALICE".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "ordering by a column without collation",
+        "SELECT code FROM Account ORDER BY code",
+        Ok(select!(
+            code
+            Str;
+            "A".to_owned();
+            "B".to_owned();
+            "b".to_owned();
+            "c".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "collation in a correlated subquery",
+        "SELECT id FROM Account a WHERE EXISTS (SELECT 1 FROM Account b WHERE b.name = a.name AND b.id <> a.id)",
+        Ok(select!(id I64; 1; 3)),
+    )
+    .await;
+    g.test(
+        "UPDATE Account SET code = 'x' WHERE name = 'CAROL'",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "DELETE FROM Account WHERE name = 'BOB'",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, code FROM Account",
+        Ok(select!(
+            id  | code
+            I64 | Str;
+            1     "A".to_owned();
+            3     "c".to_owned();
+            4     "x".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "collation of a non text column",
+        "CREATE TABLE Wrong (id INTEGER COLLATE NOCASE)",
+        Err(AlterError::UnsupportedDataTypeForCollation("id".to_owned(), DataType::Int).into()),
+    )
+    .await;
+    g.named_test(
+        "unsupported collation",
+        "SELECT id FROM Account WHERE name COLLATE \"de_DE\" = 'a'",
+        Err(TranslateError::UnsupportedCollation("de_DE".to_owned()).into()),
+    )
+    .await;
+});
@@ -0,0 +1,175 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::Statement,
+        executor::{FetchError, SelectError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(cte, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Employee (
+            id INTEGER,
+            name TEXT,
+            manager_id INTEGER NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Employee VALUES
+            (1, 'Alice', NULL),
+            (2, 'Bob', 1),
+            (3, 'Carol', 2),
+            (4, 'Dave', 2),
+            (5, 'Erin', 1),
+            (6, 'Frank', 3);
+    ",
+    )
+    .await;
+    g.run("CREATE TABLE Edge (src INTEGER, dst INTEGER);").await;
+    g.run("INSERT INTO Edge VALUES (1, 2), (2, 3), (3, 1), (3, 4);")
+        .await;
+
+    let test_cases = [
+        (
+            "
+            WITH Direct AS (SELECT id, name FROM Employee WHERE manager_id = 1)
+            SELECT name FROM Direct ORDER BY id
+            ",
+            Ok(select!(
+                name
+                Str;
+                "Bob".to_owned();
+                "Erin".to_owned()
+            )),
+        ),
+        (
+            "WITH A(x) AS (SELECT 1), B AS (SELECT x + 1 AS y FROM A) SELECT * FROM A JOIN B ON TRUE",
+            Ok(select!(
+                x   | y
+                I64 | I64;
+                1     2
+            )),
+        ),
+        (
+            "WITH Employee AS (SELECT 100 AS id) SELECT * FROM Employee",
+            Ok(select!(id I64; 100)),
+        ),
+        (
+            "
+            SELECT name FROM Employee
+            WHERE id IN (WITH Picked AS (SELECT 3 AS id) SELECT id FROM Picked)
+            ",
+            Ok(select!(name Str; "Carol".to_owned())),
+        ),
+        (
+            "WITH RECURSIVE Counter(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM Counter WHERE n < 5) SELECT * FROM Counter",
+            Ok(select!(n I64; 1; 2; 3; 4; 5)),
+        ),
+        (
+            "
+            WITH RECURSIVE Report(id, name, depth) AS (
+                SELECT id, name, 0 FROM Employee WHERE id = 2
+                UNION ALL
+                SELECT e.id, e.name, r.depth + 1
+                FROM Employee e
+                JOIN Report r ON e.manager_id = r.id
+            )
+            SELECT name, depth FROM Report ORDER BY depth, id
+            ",
+            Ok(select!(
+                name              | depth
+                Str               | I64;
+                "Bob".to_owned()    0;
+                "Carol".to_owned()  1;
+                "Dave".to_owned()   1;
+                "Frank".to_owned()  2
+            )),
+        ),
+        (
+            "
+            WITH RECURSIVE Reachable(node) AS (
+                SELECT 1
+                UNION
+                SELECT e.dst FROM Edge e JOIN Reachable r ON e.src = r.node
+            )
+            SELECT node FROM Reachable ORDER BY node
+            ",
+            Ok(select!(node I64; 1; 2; 3; 4)),
+        ),
+        (
+            "WITH RECURSIVE Plain AS (SELECT id FROM Employee WHERE id < 3) SELECT * FROM Plain",
+            Ok(select!(id I64; 1; 2)),
+        ),
+        (
+            "WITH RECURSIVE Loop(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM Loop) SELECT * FROM Loop",
+            Err(SelectError::RecursionLimitExceeded {
+                name: "Loop".to_owned(),
+                limit: 1000,
+            }
+            .into()),
+        ),
+        (
+            "WITH RECURSIVE Selfish(n) AS (SELECT n FROM Selfish) SELECT * FROM Selfish",
+            Err(SelectError::RecursiveCteUnionRequired("Selfish".to_owned()).into()),
+        ),
+        (
+            "WITH RECURSIVE Wide(n) AS (SELECT 1 UNION ALL SELECT n, n FROM Wide WHERE n < 3) SELECT * FROM Wide",
+            Err(SelectError::SetOperationColumnCountMismatch {
+                op: "UNION".to_owned(),
+                left: 1,
+                right: 2,
+            }
+            .into()),
+        ),
+        (
+            "WITH Narrow(a, b) AS (SELECT 1) SELECT * FROM Narrow",
+            Err(FetchError::TooManyColumnAliases("Narrow".to_owned(), 1, 2).into()),
+        ),
+        (
+            "WITH Unknown AS (SELECT 1) SELECT * FROM Missing",
+            Err(FetchError::TableNotFound("Missing".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.run("CREATE TABLE Sequence (n INTEGER);").await;
+    g.test(
+        "
+        INSERT INTO Sequence
+        WITH RECURSIVE Counter(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM Counter WHERE n < 3)
+        SELECT n FROM Counter
+        ",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+    g.test("SELECT * FROM Sequence", Ok(select!(n I64; 1; 2; 3)))
+        .await;
+});
+
+test_case!(max_recursion, {
+    let glue = get_glue!();
+
+    let sql = "WITH RECURSIVE Counter(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM Counter WHERE n < 5) SELECT * FROM Counter";
+    let mut statements = glue.plan(sql).await.unwrap();
+    if let Statement::Query(query) = &mut statements[0] {
+        query.with.as_mut().unwrap().max_recursion = 3;
+    }
+
+    let actual = glue.execute_stmt(&statements[0]).await;
+    let expected = Err(SelectError::RecursionLimitExceeded {
+        name: "Counter".to_owned(),
+        limit: 3,
+    }
+    .into());
+    assert_eq!(actual, expected);
+});
@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{AlterError, EvaluateError, TranslateError},
+        error::{AlterError, Error, EvaluateError, TranslateError, ValueError},
         prelude::{Payload, PayloadVariable, Value::*},
     },
 };
@@ -124,3 +124,59 @@ test_case!(custom, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(returns, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER, price INTEGER NULL);")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 100), (2, 250), (3, NULL);")
+        .await;
+
+    g.named_test(
+        "body given as a string",
+        "CREATE FUNCTION add_tax(p FLOAT) RETURNS FLOAT AS 'p * 1.1'",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.named_test(
+        "result is cast to the return type",
+        "SELECT id, add_tax(price) AS total FROM Item",
+        Ok(select_with_null!(
+            id     | total;
+            I64(1)   F64(100.0 * 1.1);
+            I64(2)   F64(250.0 * 1.1);
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "RETURNS casts the result of RETURN",
+        "CREATE FUNCTION half(n INT) RETURNS FLOAT RETURN n / 2",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.named_test(
+        "function in WHERE",
+        "SELECT id, half(price) AS h FROM Item WHERE half(price) > 100",
+        Ok(select!(id | h I64 | F64; 2 125.0)),
+    )
+    .await;
+    g.named_test(
+        "quotes inside the body",
+        "CREATE FUNCTION code() RETURNS INT AS '''abc'''",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.named_test(
+        "result which cannot be cast",
+        "SELECT code()",
+        Err(ValueError::LiteralCastFromTextToIntegerFailed("abc".to_owned()).into()),
+    )
+    .await;
+    assert!(matches!(
+        g.run_err("CREATE FUNCTION broken() RETURNS INT AS '1 +'")
+            .await,
+        Error::Parser(_)
+    ));
+});
@@ -4,8 +4,11 @@ use {
     gluesql_core::{
         ast::DataType,
         data::Literal,
-        error::{LiteralError, ValueError},
-        prelude::{Payload, Value::Bytea},
+        error::{EvaluateError, LiteralError, ValueError},
+        prelude::{
+            Payload,
+            Value::{Bool, Bytea, Str},
+        },
     },
     std::borrow::Cow,
 };
@@ -52,4 +55,54 @@ test_case!(bytea, {
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    g.run(r"INSERT INTO Bytea VALUES ('\x0a0b'), (DECODE('R2x1ZQ==', 'base64'));")
+        .await;
+    g.named_test(
+        "equality with hex text",
+        r"SELECT * FROM Bytea WHERE bytes = '\x0a0b' OR bytes = 'ab0123'",
+        Ok(select!(
+            bytes
+            Bytea;
+            bytea("ab0123");
+            bytea("0a0b")
+        )),
+    )
+    .await;
+    g.named_test(
+        "ENCODE and DECODE",
+        "SELECT
+            ENCODE(bytes, 'base64') AS b64,
+            ENCODE(bytes, 'hex') AS hex,
+            DECODE(ENCODE(bytes, 'base64'), 'base64') = bytes AS same
+        FROM Bytea WHERE bytes = X'476c7565'",
+        Ok(select!(
+            b64                    | hex                    | same
+            Str                    | Str                    | Bool;
+            "R2x1ZQ==".to_owned()    "476c7565".to_owned()    true
+        )),
+    )
+    .await;
+    g.named_test(
+        "DECODE with unknown format",
+        "SELECT DECODE('1234', 'base32') AS v",
+        Err(EvaluateError::UnsupportedEncodingFormat("base32".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "DECODE with broken text",
+        "SELECT DECODE('R2x1ZQ=', 'base64') AS v",
+        Err(EvaluateError::FailedToDecode {
+            text: "R2x1ZQ=".to_owned(),
+            format: "base64".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "ENCODE requires bytea",
+        "SELECT ENCODE('Glue', 'hex') AS v",
+        Err(EvaluateError::FunctionRequiresByteaValue("ENCODE".to_owned()).into()),
+    )
+    .await;
 });
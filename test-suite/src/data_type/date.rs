@@ -85,6 +85,20 @@ INSERT INTO DateLog VALUES
     )
     .await;
 
+    g.named_test(
+        "date compared with timestamp text",
+        "SELECT id FROM DateLog WHERE date1 > '2020-09-30 12:00:00';",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "date equals timestamp text at midnight",
+        "SELECT id FROM DateLog WHERE date1 = '2020-06-11T00:00:00Z';",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
     g.test(
         "SELECT * FROM DateLog WHERE '1999-01-03' < DATE '2000-01-01';",
         Ok(select!(
@@ -1,4 +1,12 @@
-use {crate::*, gluesql_core::prelude::Value::*, rust_decimal::prelude::Decimal as D};
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{TranslateError, ValueError},
+        prelude::{Payload, Value::*},
+    },
+    rust_decimal::prelude::Decimal as D,
+};
 
 test_case!(decimal, {
     let g = get_tester!();
@@ -19,9 +27,9 @@ test_case!(decimal, {
             FROM DECIMAL_ITEM
                 ",
         Ok(select!(
-            a       | b       | c   | d       | e   | f       | g;
-            Decimal | Decimal | I64 | Decimal | I64 | Decimal | I64;
-            D::ONE    D::TWO    2     D::ZERO   0     D::TWO    2
+            a       | b       | c       | d       | e       | f       | g;
+            Decimal | Decimal | Decimal | Decimal | Decimal | Decimal | Decimal;
+            D::ONE    D::TWO    D::TWO    D::ZERO   D::ZERO   D::TWO    D::TWO
         )),
     )
     .await;
@@ -36,9 +44,9 @@ test_case!(decimal, {
             FROM DECIMAL_ITEM
                 ",
         Ok(select!(
-            h            | i   | j   | k;
-            Decimal      | I64 | I64 | Decimal;
-            D::new(5, 1)   2     0     D::ONE
+            h            | i       | j       | k;
+            Decimal      | Decimal | Decimal | Decimal;
+            D::new(5, 1)   D::TWO    D::ZERO   D::ONE
         )),
     )
     .await;
@@ -56,4 +64,79 @@ test_case!(decimal, {
         )),
     )
     .await;
+
+    g.run("CREATE TABLE Price (id INTEGER, amount DECIMAL(5, 2), rate NUMERIC(3))")
+        .await;
+    g.test(
+        "SHOW COLUMNS FROM Price",
+        Ok(Payload::ShowColumns(vec![
+            ("id".to_owned(), DataType::Int),
+            ("amount".to_owned(), DataType::DecimalPrecision(5, 2)),
+            ("rate".to_owned(), DataType::DecimalPrecision(3, 0)),
+        ])),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Price VALUES (1, 123.45, 100), (2, 0.1, -999);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "exact arithmetic on declared scale",
+        "SELECT amount + 0.2 AS a, amount * 3 AS b FROM Price WHERE id = 2",
+        Ok(select!(
+            a            | b;
+            Decimal      | Decimal;
+            D::new(3, 1)   D::new(3, 1)
+        )),
+    )
+    .await;
+    g.named_test(
+        "value with more digits than the scale",
+        "INSERT INTO Price VALUES (3, 1.234, 1);",
+        Err(ValueError::DecimalOutOfRange {
+            value: "1.234".to_owned(),
+            precision: 5,
+            scale: 2,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "value with more digits than the precision",
+        "UPDATE Price SET rate = 1000 WHERE id = 1;",
+        Err(ValueError::DecimalOutOfRange {
+            value: "1000".to_owned(),
+            precision: 3,
+            scale: 0,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "CAST rounds to the scale before the range is checked",
+        "SELECT CAST(amount AS DECIMAL(3, 1)) AS a FROM Price WHERE id = 1",
+        Err(ValueError::DecimalOutOfRange {
+            value: "123.5".to_owned(),
+            precision: 3,
+            scale: 1,
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT CAST(amount AS DECIMAL(4, 1)) AS a FROM Price WHERE id = 1",
+        Ok(select!(a Decimal; D::new(1235, 1))),
+    )
+    .await;
+    g.named_test(
+        "scale larger than the precision",
+        "CREATE TABLE Wrong (v DECIMAL(2, 3))",
+        Err(TranslateError::InvalidDecimalPrecision {
+            precision: 2,
+            scale: 3,
+        }
+        .into()),
+    )
+    .await;
 });
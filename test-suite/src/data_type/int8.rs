@@ -1,6 +1,10 @@
 use {
     crate::*,
-    gluesql_core::{error::ValueError, prelude::Value::*},
+    gluesql_core::{
+        ast::DataType,
+        error::ValueError,
+        prelude::{Payload, Value::*},
+    },
 };
 
 test_case!(int8, {
@@ -101,5 +105,45 @@ test_case!(int8, {
     )
     .await;
 
+    g.named_test(
+        "integers of other widths are stored in range",
+        "INSERT INTO Item SELECT 100, -128;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.named_test(
+        "integers of other widths out of range",
+        "INSERT INTO Item SELECT 100, 200;",
+        Err(ValueError::IntegerOutOfRange {
+            data_type: DataType::Int8,
+            value: "200".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "unary minus overflow",
+        "SELECT -field_two AS neg FROM Item WHERE field_one = 100",
+        Err(ValueError::UnaryMinusOverflow.into()),
+    )
+    .await;
+    g.named_test(
+        "ABS overflow",
+        "SELECT ABS(field_two) AS abs FROM Item WHERE field_one = 100",
+        Err(ValueError::UnaryMinusOverflow.into()),
+    )
+    .await;
+    g.named_test(
+        "UPDATE with an integer of another width",
+        "UPDATE Item SET field_two = CAST(5 AS INT) WHERE field_one = 100",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT field_two FROM Item WHERE field_one = 100",
+        Ok(select!(field_two I8; 5)),
+    )
+    .await;
+
     g.run("DELETE FROM Item").await;
 });
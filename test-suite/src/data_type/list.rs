@@ -1,8 +1,11 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{KeyError, ValueError},
-        prelude::Value::{self, *},
+        error::{EvaluateError, FetchError, KeyError, ValueError},
+        prelude::{
+            Payload,
+            Value::{self, *},
+        },
     },
 };
 
@@ -148,4 +151,90 @@ INSERT INTO ListType2 VALUES
         Err(ValueError::InvalidJsonString("{{ ok [1, 2, 3] }".to_owned()).into()),
     )
     .await;
+
+    g.run("CREATE TABLE Tagged (id INTEGER, tags LIST);").await;
+    g.named_test(
+        "insert ARRAY constructors",
+        "INSERT INTO Tagged VALUES (1, ARRAY['a', 'b']), (2, ARRAY['b', 'c', 'd']), (3, NULL);",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+    g.test(
+        "SELECT id, tags FROM Tagged",
+        Ok(select_with_null!(
+            id     | tags;
+            I64(1)   l(r#"["a", "b"]"#);
+            I64(2)   l(r#"["b", "c", "d"]"#);
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "element access on ARRAY constructor",
+        "SELECT ARRAY[1, 2 + 3, 'x'][1] AS second",
+        Ok(select_with_null!(second; I64(5))),
+    )
+    .await;
+    g.named_test(
+        "contains",
+        "SELECT id FROM Tagged WHERE tags @> ARRAY['b']",
+        Ok(select!(id I64; 1; 2)),
+    )
+    .await;
+    g.named_test(
+        "contained by",
+        "SELECT id FROM Tagged WHERE tags <@ ARRAY['a', 'b', 'c']",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+    g.named_test(
+        "containment with NULL",
+        "SELECT tags @> ARRAY['a'] AS found FROM Tagged WHERE id = 3",
+        Ok(select_with_null!(found; Null)),
+    )
+    .await;
+    g.named_test(
+        "containment requires lists",
+        "SELECT id FROM Tagged WHERE tags @> 'a'",
+        Err(EvaluateError::ListTypeRequired.into()),
+    )
+    .await;
+    g.named_test(
+        "UNNEST expands the list of each row",
+        "SELECT id, tag FROM Tagged, UNNEST(tags) AS t(tag)",
+        Ok(select!(
+            id  | tag
+            I64 | Str;
+            1     "a".to_owned();
+            1     "b".to_owned();
+            2     "b".to_owned();
+            2     "c".to_owned();
+            2     "d".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "UNNEST with LEFT JOIN keeps rows without items",
+        "SELECT id, tag FROM Tagged LEFT JOIN UNNEST(tags) AS t(tag) ON tag <> 'b'",
+        Ok(select_with_null!(
+            id     | tag;
+            I64(1)   s("a");
+            I64(2)   s("c");
+            I64(2)   s("d");
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "UNNEST without column alias",
+        "SELECT * FROM UNNEST(ARRAY[10, 20])",
+        Ok(select!(UNNEST I64; 10; 20)),
+    )
+    .await;
+    g.named_test(
+        "UNNEST requires a list",
+        "SELECT * FROM UNNEST(1)",
+        Err(FetchError::UnnestListTypeRequired.into()),
+    )
+    .await;
 });
@@ -166,4 +166,50 @@ INSERT INTO MapType2 VALUES
         Err(ValueError::JsonObjectTypeRequired.into()),
     )
     .await;
+
+    g.named_test(
+        "filter by index expr path",
+        "SELECT id FROM MapType2 WHERE nested['a']['red'] = 'cherry'",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "select by arrow path",
+        "SELECT id, nested -> 'a' -> 'blue' AS blue, nested -> 'a' ->> 'red' AS red FROM MapType2",
+        Ok(select!(
+            id  | blue | red
+            I64 | I64  | Str;
+            1     1      "apple".to_owned();
+            2     2      "cherry".to_owned();
+            3     3      "berry".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "long arrow returns text",
+        "SELECT nested ->> 'b' AS b, nested ->> 'a' AS a, nested ->> 'c' AS c FROM MapType2 WHERE id = 3",
+        Ok(select_with_null!(
+            b       | a                                    | c;
+            s("30")   s(r#"{"blue":3,"red":"berry"}"#)       s("TRUE")
+        )),
+    )
+    .await;
+    g.named_test(
+        "filter by arrow path",
+        "SELECT id FROM MapType2 WHERE nested -> 'a' -> 'blue' > 1 AND nested ->> 'c' IS NULL",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "arrow path on NULL and missing key",
+        "SELECT NULL -> 'a' AS n, nested -> 'x' -> 'y' AS m FROM MapType2 WHERE id = 1",
+        Ok(select_with_null!(n | m; Null Null)),
+    )
+    .await;
+    g.named_test(
+        "arrow path requires map or list",
+        "SELECT id -> 'a' FROM MapType2",
+        Err(ValueError::SelectorRequiresMapOrListTypes.into()),
+    )
+    .await;
 });
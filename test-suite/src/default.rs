@@ -2,7 +2,7 @@ use {
     crate::*,
     chrono::NaiveDate,
     gluesql_core::{
-        error::EvaluateError,
+        error::{AlterError, EvaluateError, ValueError},
         prelude::{Payload, Value::*},
     },
 };
@@ -96,4 +96,66 @@ test_case!(default, {
         )),
     )
     .await;
+
+    g.named_test(
+        "NOT NULL column cannot default to NULL",
+        "CREATE TABLE NullDefault (id INTEGER NOT NULL DEFAULT NULL)",
+        Err(AlterError::NullDefaultOnNotNullColumn("id".to_owned()).into()),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Ticket (
+            id INTEGER,
+            status TEXT NOT NULL DEFAULT 'open',
+            note TEXT
+        )",
+    )
+    .await;
+    g.named_test(
+        "DEFAULT keyword in VALUES",
+        "INSERT INTO Ticket VALUES (1, DEFAULT, 'first'), (2, 'closed', DEFAULT);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Ticket (id, status) VALUES (3, DEFAULT);",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, status, note FROM Ticket",
+        Ok(select_with_null!(
+            id     | status                   | note;
+            I64(1)   Str("open".to_owned())     Str("first".to_owned());
+            I64(2)   Str("closed".to_owned())   Null;
+            I64(3)   Str("open".to_owned())     Null
+        )),
+    )
+    .await;
+
+    g.run("UPDATE Ticket SET status = 'closed';").await;
+    g.named_test(
+        "DEFAULT keyword in UPDATE SET",
+        "UPDATE Ticket SET status = DEFAULT, note = DEFAULT WHERE id = 1;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, status, note FROM Ticket",
+        Ok(select_with_null!(
+            id     | status                   | note;
+            I64(1)   Str("open".to_owned())     Null;
+            I64(2)   Str("closed".to_owned())   Null;
+            I64(3)   Str("closed".to_owned())   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "UPDATE cannot set NULL on NOT NULL column",
+        "UPDATE Ticket SET status = NULL WHERE id = 2;",
+        Err(ValueError::NullValueOnNotNullField.into()),
+    )
+    .await;
 });
@@ -0,0 +1,129 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::TranslateError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(distinct, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            category TEXT,
+            price INTEGER NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Item (id, category, price) VALUES
+            (1, 'fruit',  10),
+            (2, 'fruit',  10),
+            (3, 'meat',   30),
+            (4, 'fruit',  NULL),
+            (5, 'meat',   30),
+            (6, 'snack',  NULL),
+            (7, 'snack',  5);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "SELECT DISTINCT category FROM Item",
+            Ok(select!(
+                category
+                Str;
+                "fruit".to_owned();
+                "meat".to_owned();
+                "snack".to_owned()
+            )),
+        ),
+        (
+            "SELECT DISTINCT category, price FROM Item",
+            Ok(select_with_null!(
+                category                 | price;
+                Str("fruit".to_owned())    I64(10);
+                Str("meat".to_owned())     I64(30);
+                Str("fruit".to_owned())    Null;
+                Str("snack".to_owned())    Null;
+                Str("snack".to_owned())    I64(5)
+            )),
+        ),
+        (
+            "SELECT DISTINCT price FROM Item ORDER BY price DESC NULLS LAST LIMIT 2",
+            Ok(select!(price I64; 30; 10)),
+        ),
+        (
+            "SELECT DISTINCT id % 2 AS odd FROM Item WHERE id > 1",
+            Ok(select!(odd I64; 0; 1)),
+        ),
+        (
+            "SELECT DISTINCT COUNT(*) AS cnt FROM Item GROUP BY category",
+            Ok(select!(cnt I64; 3; 2)),
+        ),
+        (
+            "SELECT * FROM (SELECT DISTINCT category FROM Item) AS Sub WHERE category <> 'meat'",
+            Ok(select!(
+                category
+                Str;
+                "fruit".to_owned();
+                "snack".to_owned()
+            )),
+        ),
+        (
+            "
+            SELECT
+                COUNT(DISTINCT category) AS categories,
+                COUNT(DISTINCT price) AS prices,
+                SUM(DISTINCT id % 3) AS ids
+            FROM Item
+            ",
+            Ok(select!(
+                categories | prices | ids
+                I64        | I64    | I64;
+                3            3        3
+            )),
+        ),
+        (
+            "SELECT category, SUM(DISTINCT price) AS total FROM Item WHERE price IS NOT NULL GROUP BY category",
+            Ok(select!(
+                category | total
+                Str      | I64;
+                "fruit".to_owned()   10;
+                "meat".to_owned()    30;
+                "snack".to_owned()   5
+            )),
+        ),
+        (
+            "SELECT COUNT(DISTINCT *) FROM Item",
+            Err(TranslateError::DistinctWildcardInCountNotSupported.into()),
+        ),
+        (
+            "SELECT UPPER(DISTINCT category) FROM Item",
+            Err(TranslateError::DistinctOnNonAggregateFunction("UPPER".to_owned()).into()),
+        ),
+        (
+            "SELECT DISTINCT ON (category) category FROM Item",
+            Err(TranslateError::SelectDistinctOnNotSupported.into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.test(
+        "SELECT DISTINCT category FROM Item WHERE id > 10",
+        Ok(Payload::Select {
+            labels: vec!["category".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+});
@@ -0,0 +1,94 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(explain, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE TABLE Stock (item_id INTEGER, qty INTEGER);
+        INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');
+        INSERT INTO Stock VALUES (1, 10), (2, 0);
+    ",
+    )
+    .await;
+
+    let str = |v: &str| Str(v.to_owned());
+
+    g.test(
+        "EXPLAIN SELECT * FROM Item",
+        Ok(select_with_null!(
+            table       | access            | join;
+            str("Item")   str("FULL SCAN")    Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "equality on PRIMARY KEY is a primary key lookup",
+        "EXPLAIN SELECT name FROM Item WHERE id = 1",
+        Ok(select_with_null!(
+            table       | access                            | join;
+            str("Item")   str("PRIMARY KEY LOOKUP (id = 1)")  Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "comparisons which cannot be given to the storage scan read every row",
+        "EXPLAIN SELECT name FROM Item i WHERE i.name <> 'apple'",
+        Ok(select_with_null!(
+            table    | access            | join;
+            str("i")   str("FULL SCAN")    Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "joins are listed in order with their executors",
+        "
+        EXPLAIN SELECT *
+        FROM Item
+        JOIN Stock ON Stock.item_id = Item.id
+        LEFT JOIN Item AS Other ON Other.id > Item.id
+        ",
+        Ok(select_with_null!(
+            table        | access            | join;
+            str("Item")    str("FULL SCAN")    Null;
+            str("Stock")   str("FULL SCAN")    str("HASH JOIN");
+            str("Other")   str("FULL SCAN")    str("NESTED LOOP")
+        )),
+    )
+    .await;
+    g.test(
+        "EXPLAIN SELECT id FROM Item UNION SELECT item_id FROM Stock",
+        Ok(select_with_null!(
+            table        | access            | join;
+            str("Item")    str("FULL SCAN")    Null;
+            str("Stock")   str("FULL SCAN")    Null
+        )),
+    )
+    .await;
+    g.test(
+        "EXPLAIN VALUES (1, 'apple')",
+        Ok(select_with_null!(
+            table | access         | join;
+            Null    str("VALUES")    Null
+        )),
+    )
+    .await;
+
+    g.test(
+        "EXPLAIN ANALYZE SELECT * FROM Item",
+        Err(
+            TranslateError::UnsupportedExplain("EXPLAIN ANALYZE SELECT * FROM Item".to_owned())
+                .into(),
+        ),
+    )
+    .await;
+    g.test(
+        "EXPLAIN DELETE FROM Item",
+        Err(TranslateError::UnsupportedExplain("EXPLAIN DELETE FROM Item".to_owned()).into()),
+    )
+    .await;
+});
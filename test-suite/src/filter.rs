@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::data::*};
+use {
+    crate::*,
+    gluesql_core::{data::*, prelude::Value::*},
+};
 
 test_case!(filter, {
     let g = get_tester!();
@@ -78,12 +81,58 @@ test_case!(filter, {
         (3, "SELECT id FROM Hunter WHERE +2 > +1.0"),
         (2, "SELECT name FROM Boss WHERE id <= +2"),
         (2, "SELECT name FROM Boss WHERE +id <= 2"),
+        (2, "SELECT id FROM Boss WHERE id BETWEEN 1.5 AND 3"),
+        (2, "SELECT id FROM Boss WHERE strength BETWEEN 20 AND 40"),
+        (0, "SELECT id FROM Boss WHERE NULL BETWEEN 1 AND 5"),
+        (0, "SELECT id FROM Boss WHERE id BETWEEN NULL AND 3"),
+        (2, "SELECT id FROM Boss WHERE id NOT BETWEEN NULL AND 3"),
+        (1, "SELECT id FROM Boss WHERE id NOT BETWEEN 2 AND NULL"),
     ];
 
     for (num, sql) in select_sqls {
         g.count(sql, num).await;
     }
 
+    g.named_test(
+        "EXISTS stops at the first row of the subquery",
+        "SELECT id FROM Hunter WHERE EXISTS (SELECT 10 / (3 - id) FROM Boss)",
+        Ok(select!(id I64; 1; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "nested correlated EXISTS in projection",
+        "
+        SELECT
+            id,
+            EXISTS (
+                SELECT * FROM Boss
+                WHERE Boss.name = Hunter.name AND EXISTS (
+                    SELECT * FROM Hunter h2 WHERE h2.id = Boss.id - 3
+                )
+            ) AS found
+        FROM Hunter
+        ",
+        Ok(select!(
+            id  | found
+            I64 | Bool;
+            1     false;
+            2     true;
+            3     true
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "BETWEEN with NULL operand",
+        "SELECT NULL BETWEEN 1 AND 2 AS a, 3 BETWEEN NULL AND 2 AS b, 1 NOT BETWEEN 0 AND NULL AS c",
+        Ok(select_with_null!(
+            a | b | c;
+            Value::Null Value::Bool(false) Value::Null
+        )),
+    )
+    .await;
+
     let select_opt_sqls = [
         (5, "SELECT name FROM Boss WHERE 2 = 1.0 + 1"),
         (3, "SELECT id FROM Hunter WHERE -1.0 - 1.0 < -1"),
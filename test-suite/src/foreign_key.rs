@@ -0,0 +1,202 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{AlterError, ForeignKeyError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(foreign_key, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Parent (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+
+    g.named_test(
+        "referenced table must exist",
+        "CREATE TABLE Child (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES Nothing (id));",
+        Err(AlterError::ReferencedTableNotFound("Nothing".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "referenced column must be the primary key",
+        "CREATE TABLE Child (id INTEGER PRIMARY KEY, parent_name TEXT REFERENCES Parent (name));",
+        Err(AlterError::ReferencingNonPrimaryKeyColumn {
+            referenced_table_name: "Parent".to_owned(),
+            referenced_column_name: "name".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "foreign key column must have the same type as the referenced column",
+        "CREATE TABLE Child (id INTEGER PRIMARY KEY, parent_id TEXT REFERENCES Parent (id));",
+        Err(AlterError::ForeignKeyDataTypeMismatch {
+            referencing_column_name: "parent_id".to_owned(),
+            referencing_column_type: DataType::Text,
+            referenced_column_name: "id".to_owned(),
+            referenced_column_type: DataType::Int,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "ON DELETE SET NULL requires a nullable column",
+        "
+        CREATE TABLE Child (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER NOT NULL REFERENCES Parent (id) ON DELETE SET NULL
+        );
+        ",
+        Err(AlterError::SetNullOnNotNullColumn("parent_id".to_owned()).into()),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Child (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER REFERENCES Parent (id) ON DELETE CASCADE
+        );
+        ",
+    )
+    .await;
+    g.run(
+        "
+        CREATE TABLE Pet (
+            id INTEGER PRIMARY KEY,
+            owner_id INTEGER,
+            CONSTRAINT FK_owner FOREIGN KEY (owner_id) REFERENCES Parent (id) ON DELETE SET NULL
+        );
+        ",
+    )
+    .await;
+    g.run("CREATE TABLE Toy (id INTEGER PRIMARY KEY, child_id INTEGER REFERENCES Child (id));")
+        .await;
+
+    g.run("INSERT INTO Parent VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Carol');")
+        .await;
+    g.test(
+        "INSERT INTO Child VALUES (1, 1), (2, 2), (3, NULL);",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+    g.run("INSERT INTO Pet VALUES (1, 1), (2, 2);").await;
+    g.run("INSERT INTO Toy VALUES (1, 2);").await;
+
+    g.named_test(
+        "inserted value must exist in the referenced table",
+        "INSERT INTO Child VALUES (4, 9);",
+        Err(ForeignKeyError::ReferencedValueNotFound {
+            foreign_key_name: "FK_parent_id-Parent_id".to_owned(),
+            referenced_table_name: "Parent".to_owned(),
+            value: I64(9),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "updated value must exist in the referenced table",
+        "UPDATE Child SET parent_id = 9 WHERE id = 1;",
+        Err(ForeignKeyError::ReferencedValueNotFound {
+            foreign_key_name: "FK_parent_id-Parent_id".to_owned(),
+            referenced_table_name: "Parent".to_owned(),
+            value: I64(9),
+        }
+        .into()),
+    )
+    .await;
+
+    g.named_test(
+        "cascaded delete is blocked by a NO ACTION foreign key",
+        "DELETE FROM Parent WHERE id = 2;",
+        Err(ForeignKeyError::RowReferenced {
+            table_name: "Child".to_owned(),
+            referencing_table_name: "Toy".to_owned(),
+            foreign_key_name: "FK_child_id-Child_id".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT id, parent_id FROM Child",
+        Ok(select_with_null!(
+            id     | parent_id;
+            I64(1)   I64(1);
+            I64(2)   I64(2);
+            I64(3)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ON DELETE CASCADE and ON DELETE SET NULL",
+        "DELETE FROM Parent WHERE id = 1;",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, parent_id FROM Child",
+        Ok(select_with_null!(
+            id     | parent_id;
+            I64(2)   I64(2);
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.test(
+        "SELECT id, owner_id FROM Pet",
+        Ok(select_with_null!(
+            id     | owner_id;
+            I64(1)   Null;
+            I64(2)   I64(2)
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "referenced table cannot be dropped",
+        "DROP TABLE Child;",
+        Err(AlterError::TableReferenced {
+            table_name: "Child".to_owned(),
+            referencing_table_name: "Toy".to_owned(),
+            foreign_key_name: "FK_child_id-Child_id".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "foreign key column cannot be dropped",
+        "ALTER TABLE Pet DROP COLUMN owner_id;",
+        Err(AlterError::ForeignKeyColumnInUse {
+            column_name: "owner_id".to_owned(),
+            foreign_key_name: "FK_owner".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+
+    g.test("DELETE FROM Toy;", Ok(Payload::Delete(1))).await;
+    g.named_test(
+        "TRUNCATE applies ON DELETE actions",
+        "TRUNCATE TABLE Parent;",
+        Ok(Payload::Truncate),
+    )
+    .await;
+    g.test(
+        "SELECT id, parent_id FROM Child",
+        Ok(select_with_null!(
+            id     | parent_id;
+            I64(3)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "referencing tables can be dropped together",
+        "DROP TABLE Toy, Child, Pet, Parent;",
+        Ok(Payload::DropTable),
+    )
+    .await;
+});
@@ -420,4 +420,23 @@ test_case!(cast_value, {
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    g.named_test(
+        "text literal compared with a number is read as the number",
+        "SELECT id FROM IntervalLog WHERE id = '3' OR id > '6'",
+        Ok(select!(id I64; 3; 7)),
+    )
+    .await;
+    g.named_test(
+        "text literal in arithmetic with a number",
+        "SELECT id + '10' AS n FROM IntervalLog WHERE id = 1",
+        Ok(select!(n I64; 11)),
+    )
+    .await;
+    g.named_test(
+        "text literal which is not a number",
+        "SELECT id + 'ten' AS n FROM IntervalLog WHERE id = 1",
+        Err(ValueError::LiteralCastFromTextToIntegerFailed("ten".to_owned()).into()),
+    )
+    .await;
 });
@@ -4,6 +4,7 @@ use {
         error::{EvaluateError, TranslateError},
         prelude::Value::*,
     },
+    rust_decimal::Decimal,
 };
 
 test_case!(ceil, {
@@ -23,6 +24,18 @@ test_case!(ceil, {
                 1.0       0.0       10.0      7.0
             )),
         ),
+        (
+            "SELECT
+                CEIL(CAST(2.1 AS DECIMAL)) AS ceil1,
+                CEIL(CAST(-2.1 AS DECIMAL)) AS ceil2,
+                CEIL(CAST(7 AS INT16)) AS ceil3
+            ;",
+            Ok(select!(
+                ceil1              | ceil2               | ceil3
+                Decimal            | Decimal             | F64;
+                Decimal::from(3)     Decimal::from(-2)     7.0
+            )),
+        ),
         (
             "SELECT CEIL('string') AS ceil;",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("CEIL")).into()),
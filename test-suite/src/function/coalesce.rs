@@ -1,6 +1,9 @@
 use {
     crate::*,
-    gluesql_core::{error::EvaluateError, prelude::Value::*},
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(coalesce, {
@@ -184,4 +187,17 @@ test_case!(coalesce, {
             I64(5)   Str("Universe".to_owned())
         ))
     ).await;
+
+    g.named_test(
+        "arguments after the first non-null one are not evaluated",
+        "SELECT COALESCE(integer_value, integer_value / 0) AS coalesce FROM TestCoalesce WHERE id = 2",
+        Ok(select!(coalesce I64; 42)),
+    )
+    .await;
+    g.named_test(
+        "arguments are evaluated until a non-null one is found",
+        "SELECT COALESCE(integer_value, integer_value / 0) AS coalesce FROM TestCoalesce WHERE id = 1",
+        Err(ValueError::DivisorShouldNotBeZero.into()),
+    )
+    .await;
 });
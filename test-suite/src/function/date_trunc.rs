@@ -0,0 +1,123 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(date_trunc, {
+    let g = get_tester!();
+
+    macro_rules! t {
+        ($timestamp: expr) => {
+            $timestamp.parse().unwrap()
+        };
+    }
+
+    macro_rules! d {
+        ($date: expr) => {
+            $date.parse().unwrap()
+        };
+    }
+
+    g.run(
+        "CREATE TABLE Visit (
+            id INTEGER,
+            visited_at TIMESTAMP,
+            visited_on DATE
+        );",
+    )
+    .await;
+    g.run(
+        "INSERT INTO Visit VALUES
+            (1, '2023-05-17T13:24:51.123', '2023-05-17'),
+            (2, '2023-05-17T23:59:59', '2023-05-17'),
+            (3, '2023-11-03T08:00:10', '2023-11-03'),
+            (4, NULL, NULL);",
+    )
+    .await;
+
+    g.named_test(
+        "truncate timestamps by every field",
+        "SELECT
+            DATE_TRUNC('year', visited_at) AS y,
+            DATE_TRUNC('quarter', visited_at) AS q,
+            DATE_TRUNC('month', visited_at) AS m,
+            DATE_TRUNC('week', visited_at) AS w,
+            DATE_TRUNC('day', visited_at) AS d,
+            DATE_TRUNC('hour', visited_at) AS h,
+            DATE_TRUNC('minute', visited_at) AS mi,
+            DATE_TRUNC('second', visited_at) AS s
+        FROM Visit WHERE id = 1",
+        Ok(select!(
+            y | q | m | w | d | h | mi | s
+            Timestamp | Timestamp | Timestamp | Timestamp | Timestamp | Timestamp | Timestamp | Timestamp;
+            t!("2023-01-01T00:00:00")
+            t!("2023-04-01T00:00:00")
+            t!("2023-05-01T00:00:00")
+            t!("2023-05-15T00:00:00")
+            t!("2023-05-17T00:00:00")
+            t!("2023-05-17T13:00:00")
+            t!("2023-05-17T13:24:00")
+            t!("2023-05-17T13:24:51")
+        )),
+    )
+    .await;
+    g.named_test(
+        "truncate dates",
+        "SELECT DATE_TRUNC('QUARTER', visited_on) AS q FROM Visit WHERE id = 3",
+        Ok(select!(q Date; d!("2023-10-01"))),
+    )
+    .await;
+    g.named_test(
+        "bucket timestamps by day",
+        "SELECT DATE_TRUNC('day', visited_at) AS day, COUNT(*) AS cnt
+        FROM Visit
+        WHERE visited_at IS NOT NULL
+        GROUP BY DATE_TRUNC('day', visited_at)",
+        Ok(select!(
+            day | cnt
+            Timestamp | I64;
+            t!("2023-05-17T00:00:00") 2;
+            t!("2023-11-03T00:00:00") 1
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULL is kept",
+        "SELECT DATE_TRUNC('day', visited_at) AS day FROM Visit WHERE id = 4",
+        Ok(select_with_null!(day; Null)),
+    )
+    .await;
+    g.named_test(
+        "CURRENT_DATE and CURRENT_TIMESTAMP",
+        "SELECT id FROM Visit WHERE visited_on < CURRENT_DATE AND visited_at < CURRENT_TIMESTAMP",
+        Ok(select!(id I64; 1; 2; 3)),
+    )
+    .await;
+
+    g.named_test(
+        "unsupported field",
+        "SELECT DATE_TRUNC('decade', visited_at) FROM Visit WHERE id = 1",
+        Err(EvaluateError::UnsupportedDateTruncField("decade".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "non temporal value",
+        "SELECT DATE_TRUNC('day', id) FROM Visit WHERE id = 1",
+        Err(EvaluateError::FunctionRequiresDateOrDateTimeValue("DATE_TRUNC".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "wrong number of arguments",
+        "SELECT DATE_TRUNC('day') FROM Visit",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "DATE_TRUNC".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into()),
+    )
+    .await;
+});
@@ -130,8 +130,8 @@ test_case!(div_mod, {
             ",
             Ok(select_with_null!(
                 "DIV(dividend, divisor)"    | "MOD(dividend, divisor)";
-                I64(eval_div(12_f64, 3.0))    I64(0);
-                I64(eval_div(12_f64, 34.0))   I64(12);
+                I64(eval_div(12_f64, 3.0))    F64(0.0);
+                I64(eval_div(12_f64, 34.0))   F64(12.0);
                 Null                          Null;
                 Null                          Null;
                 Null                          Null
@@ -26,6 +26,14 @@ test_case!(log2, {
                 f64::from(32).log2()
             )),
         ),
+        (
+            "SELECT LOG2(CAST(32 AS UINT8)) AS log2_1, LOG2(CAST(0.5 AS DECIMAL)) AS log2_2;",
+            Ok(select!(
+                log2_1 | log2_2
+                F64    | F64;
+                5.0      -1.0
+            )),
+        ),
         (
             "SELECT LOG2('string') AS log2;",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("LOG2")).into()),
@@ -4,6 +4,7 @@ use {
         error::{EvaluateError, TranslateError},
         prelude::Value::*,
     },
+    rust_decimal::Decimal,
 };
 
 test_case!(floor, {
@@ -24,6 +25,18 @@ test_case!(floor, {
                 0.0      f64::from(-1)   10.0     6.0
             )),
         ),
+        (
+            "SELECT
+                FLOOR(CAST(2.9 AS DECIMAL)) AS floor1,
+                FLOOR(CAST(-2.1 AS DECIMAL)) AS floor2,
+                FLOOR(CAST(7 AS INT32)) AS floor3
+            ;",
+            Ok(select!(
+                floor1             | floor2              | floor3
+                Decimal            | Decimal             | F64;
+                Decimal::from(2)     Decimal::from(-3)     7.0
+            )),
+        ),
         (
             "SELECT FLOOR('string') AS floor",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("FLOOR")).into()),
@@ -15,6 +15,27 @@ test_case!(generate_uuid, {
             r#"INSERT INTO SingleItem VALUES (GENERATE_UUID())"#,
             Ok(Payload::Insert(1)),
         ),
+        (
+            "CREATE TABLE AliasItem (id UUID DEFAULT UUID(), num INTEGER)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO AliasItem (num) VALUES (1), (2)",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "INSERT INTO AliasItem VALUES (UUID(), 3)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT uuid(1) as uuid FROM SingleItem",
+            Err(TranslateError::FunctionArgsLengthNotMatching {
+                name: "UUID".to_owned(),
+                expected: 0,
+                found: 1,
+            }
+            .into()),
+        ),
         (
             "SELECT generate_uuid(0) as uuid FROM SingleItem",
             Err(TranslateError::FunctionArgsLengthNotMatching {
@@ -31,6 +52,7 @@ test_case!(generate_uuid, {
     }
 
     g.count("SELECT GENERATE_UUID() FROM SingleItem", 1).await;
+    g.count("SELECT DISTINCT id FROM AliasItem", 3).await;
     g.type_match(
         "SELECT GENERATE_UUID() as uuid FROM SingleItem",
         &[DataType::Uuid],
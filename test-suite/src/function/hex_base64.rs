@@ -0,0 +1,69 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::EvaluateError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(hex_base64, {
+    let g = get_tester!();
+
+    g.test(
+        "CREATE TABLE Encoding (id INTEGER, text TEXT NULL, bytes BYTEA NULL);",
+        Ok(Payload::Create),
+    )
+    .await;
+
+    g.test(
+        "INSERT INTO Encoding VALUES (1, 'GlueSQL', X'0A0B0C'), (2, NULL, NULL);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+
+    g.test(
+        "SELECT HEX(text) AS text, HEX(bytes) AS bytes FROM Encoding WHERE id = 1;",
+        Ok(select!(
+            text | bytes
+            Str | Str;
+            "476c756553514c".to_owned() "0a0b0c".to_owned()
+        )),
+    )
+    .await;
+
+    g.test(
+        "SELECT BASE64(text) AS text, BASE64(bytes) AS bytes FROM Encoding WHERE id = 1;",
+        Ok(select!(
+            text | bytes
+            Str | Str;
+            "R2x1ZVNRTA==".to_owned() "CgsM".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "BASE64 and HEX agree with ENCODE",
+        "SELECT id FROM Encoding
+        WHERE BASE64(bytes) = ENCODE(bytes, 'base64') AND HEX(bytes) = ENCODE(bytes, 'hex');",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+
+    g.test(
+        "SELECT HEX(text) AS hex, BASE64(bytes) AS base64 FROM Encoding WHERE id = 2;",
+        Ok(select_with_null!(hex | base64; Null Null)),
+    )
+    .await;
+
+    g.test(
+        "SELECT HEX(id) FROM Encoding;",
+        Err(EvaluateError::FunctionRequiresStringOrByteaValue("HEX".to_owned()).into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT BASE64(1.5) FROM Encoding;",
+        Err(EvaluateError::FunctionRequiresStringOrByteaValue("BASE64".to_owned()).into()),
+    )
+    .await;
+});
@@ -71,4 +71,11 @@ test_case!(ifnull, {
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
+
+    g.named_test(
+        "the second argument is not evaluated when the first one is not NULL",
+        "SELECT IFNULL(int8, int8 / 0) AS int8 FROM SingleItem WHERE id IS NOT NULL",
+        Ok(select!(int8 I8; 1)),
+    )
+    .await;
 });
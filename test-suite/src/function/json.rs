@@ -0,0 +1,111 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(json, {
+    let g = get_tester!();
+
+    g.run(
+        "CREATE TABLE Event (
+            id INTEGER,
+            payload TEXT NULL,
+            meta MAP NULL
+        );",
+    )
+    .await;
+    g.run(
+        r#"INSERT INTO Event VALUES
+            (1, '{"user": {"name": "Alice", "age": 30}, "tags": ["a", "b"], "level": "info"}', '{"source": "web"}'),
+            (2, '{"user": {"name": "Bob"}, "tags": [], "level": "error"}', '{"source": "batch", "retry": 2}'),
+            (3, NULL, NULL);"#,
+    )
+    .await;
+
+    g.named_test(
+        "JSON_EXTRACT selects values from json text",
+        "SELECT
+            id,
+            JSON_EXTRACT(payload, '$.user.name') AS name,
+            JSON_EXTRACT(payload, '$.user.age') AS age,
+            JSON_EXTRACT(payload, '$.tags[1]') AS tag
+        FROM Event",
+        Ok(select_with_null!(
+            id     | name                   | age     | tag;
+            I64(1)   Str("Alice".to_owned())  I64(30)   Str("b".to_owned());
+            I64(2)   Str("Bob".to_owned())    Null      Null;
+            I64(3)   Null                     Null      Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "JSON_EXTRACT returns maps and lists as they are",
+        "SELECT JSON_EXTRACT(payload, '$.tags') AS tags FROM Event WHERE id = 1",
+        Ok(select!(tags List; vec![Str("a".to_owned()), Str("b".to_owned())])),
+    )
+    .await;
+    g.named_test(
+        "JSON_EXTRACT on a MAP column and the root path",
+        "SELECT JSON_EXTRACT(meta, '$.retry') AS retry, JSON_VALUE(meta, '$') AS meta FROM Event WHERE id = 2",
+        Ok(select!(
+            retry | meta
+            I64   | Str;
+            2       r#"{"retry":2,"source":"batch"}"#.to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "JSON_VALUE returns text",
+        "SELECT JSON_VALUE(payload, '$.user.age') AS age, JSON_VALUE(payload, '$.user') AS user FROM Event WHERE id = 1",
+        Ok(select!(
+            age | user
+            Str | Str;
+            "30".to_owned() r#"{"age":30,"name":"Alice"}"#.to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "filter by JSON_VALUE",
+        "SELECT id FROM Event WHERE JSON_VALUE(payload, '$.level') = 'error'",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "arrow operators on json text",
+        "SELECT id, payload -> 'user' ->> 'name' AS name FROM Event WHERE payload -> 'tags' ->> 0 = 'a'",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Alice".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "invalid json path",
+        "SELECT JSON_EXTRACT(payload, 'user.name') FROM Event",
+        Err(EvaluateError::InvalidJsonPath("user.name".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "invalid json index",
+        "SELECT JSON_EXTRACT(payload, '$.tags[x]') FROM Event",
+        Err(EvaluateError::InvalidJsonPath("$.tags[x]".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "invalid json text",
+        "SELECT JSON_EXTRACT('{a', '$.a')",
+        Err(ValueError::InvalidJsonString("{a".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "JSON_EXTRACT on a non json value",
+        "SELECT JSON_EXTRACT(id, '$.a') FROM Event",
+        Err(EvaluateError::FunctionRequiresJsonValue("JSON_EXTRACT".to_owned()).into()),
+    )
+    .await;
+});
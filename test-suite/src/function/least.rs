@@ -0,0 +1,79 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(least, {
+    let g = get_tester!();
+
+    g.test(
+        "SELECT LEAST(1, 6, 9, 7, 0, 10) AS least;",
+        Ok(select!("least"; I64; 0)),
+    )
+    .await;
+
+    g.test(
+        "SELECT LEAST(1.2, 6.8, 0.1, 10.5) AS least;",
+        Ok(select!("least"; F64; 0.1)),
+    )
+    .await;
+
+    g.test(
+        "SELECT LEAST('bibibik', 'babamba', 'melona') AS least;",
+        Ok(select!("least"; Str; "babamba".to_owned())),
+    )
+    .await;
+
+    macro_rules! date {
+        ($date: expr) => {
+            $date.parse().unwrap()
+        };
+    }
+
+    g.test(
+        "SELECT LEAST(DATE '2023-07-17', DATE '2022-07-17', DATE '2024-07-18') AS least;",
+        Ok(select!("least"; Date; date!("2022-07-17"))),
+    )
+    .await;
+
+    g.run("CREATE TABLE Price (id INTEGER, a INTEGER, b INTEGER, c INTEGER);")
+        .await;
+    g.run("INSERT INTO Price VALUES (1, 3, 1, 2), (2, 5, 8, 4);")
+        .await;
+    g.test(
+        "SELECT id, LEAST(a, b, c) AS lo, GREATEST(a, b, c) AS hi FROM Price;",
+        Ok(select!(
+            id  | lo  | hi
+            I64 | I64 | I64;
+            1     1     3;
+            2     4     8
+        )),
+    )
+    .await;
+
+    g.test(
+        "SELECT LEAST(1) AS least;",
+        Err(TranslateError::FunctionArgsLengthNotMatchingMin {
+            name: "LEAST".to_owned(),
+            expected_minimum: 2,
+            found: 1,
+        }
+        .into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT LEAST(1, 2, 'bibibik') AS least;",
+        Err(EvaluateError::NonComparableArgumentError("LEAST".to_owned()).into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT LEAST(NULL, 1) AS least;",
+        Err(EvaluateError::NonComparableArgumentError("LEAST".to_owned()).into()),
+    )
+    .await;
+});
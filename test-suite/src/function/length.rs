@@ -1,4 +1,7 @@
-use {crate::*, gluesql_core::prelude::Value::*};
+use {
+    crate::*,
+    gluesql_core::{error::EvaluateError, prelude::Value::*},
+};
 
 test_case!(length, {
     let g = get_tester!();
@@ -101,4 +104,18 @@ test_case!(length, {
         )),
     )
     .await;
+
+    g.named_test(
+        "test length with null",
+        "SELECT LENGTH(NULL) AS len;",
+        Ok(select_with_null!(len; Null)),
+    )
+    .await;
+
+    g.named_test(
+        "test length with number",
+        "SELECT LENGTH(10) AS len;",
+        Err(EvaluateError::FunctionRequiresStrOrListOrMapValue("LENGTH".to_owned()).into()),
+    )
+    .await;
 });
@@ -38,6 +38,16 @@ test_case!(md5, {
     )
     .await;
 
+    g.test(
+        "SELECT MD5(X'0A0B0C') AS md5 FROM MD5;",
+        Ok(select!(
+            md5
+            Str;
+            "94263e4d553bcec128704e354b659526".to_owned()
+        )),
+    )
+    .await;
+
     g.test(
         r#"SELECT MD5(NULL) AS md5 FROM MD5;"#,
         Ok(select_with_null!(md5; Null)),
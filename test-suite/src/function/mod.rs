@@ -7,6 +7,7 @@ pub mod chr;
 pub mod coalesce;
 pub mod concat;
 pub mod concat_ws;
+pub mod date_trunc;
 pub mod degrees;
 pub mod div_mod;
 pub mod entries;
@@ -19,10 +20,13 @@ pub mod gcd_lcm;
 pub mod generate_uuid;
 pub mod geometry;
 pub mod greatest;
+pub mod hex_base64;
 pub mod ifnull;
 pub mod initcap;
 pub mod is_empty;
+pub mod json;
 pub mod last_day;
+pub mod least;
 pub mod left_right;
 pub mod length;
 pub mod lpad_rpad;
@@ -30,15 +34,18 @@ pub mod ltrim_rtrim;
 pub mod math_function;
 pub mod md5;
 pub mod now;
+pub mod nullif;
 pub mod pi;
 pub mod position;
 pub mod prepend;
 pub mod radians;
 pub mod rand;
+pub mod regexp;
 pub mod repeat;
 pub mod replace;
 pub mod reverse;
 pub mod round;
+pub mod sha256;
 pub mod sign;
 pub mod skip;
 pub mod slice;
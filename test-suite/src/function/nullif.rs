@@ -0,0 +1,65 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(nullif, {
+    let g = get_tester!();
+
+    g.run(
+        "CREATE TABLE Score (
+            id INTEGER,
+            total INTEGER NULL,
+            count INTEGER NULL,
+            label TEXT NULL
+        );",
+    )
+    .await;
+    g.run(
+        "INSERT INTO Score VALUES
+            (1, 10, 2, 'a'),
+            (2, 10, 0, ''),
+            (3, NULL, NULL, NULL);",
+    )
+    .await;
+
+    g.named_test(
+        "NULLIF returns NULL when both arguments are equal",
+        "SELECT id, NULLIF(count, 0) AS count, NULLIF(label, '') AS label FROM Score",
+        Ok(select_with_null!(
+            id     | count  | label;
+            I64(1)   I64(2)   Str("a".to_owned());
+            I64(2)   Null     Null;
+            I64(3)   Null     Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLIF avoids division by zero",
+        "SELECT id, total / NULLIF(count, 0) AS avg FROM Score",
+        Ok(select_with_null!(
+            id     | avg;
+            I64(1)   I64(5);
+            I64(2)   Null;
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLIF with a NULL second argument",
+        "SELECT NULLIF(1, NULL) AS value",
+        Ok(select!(value I64; 1)),
+    )
+    .await;
+    g.named_test(
+        "NULLIF requires two arguments",
+        "SELECT NULLIF(1) AS value",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "NULLIF".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into()),
+    )
+    .await;
+});
@@ -0,0 +1,153 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{Error, EvaluateError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(regexp, {
+    let g = get_tester!();
+
+    g.run(
+        "CREATE TABLE Log (
+            id INTEGER,
+            line TEXT NULL
+        );",
+    )
+    .await;
+    g.run(
+        "INSERT INTO Log VALUES
+            (1, 'GET /users 200 12ms'),
+            (2, 'POST /users 500 340ms'),
+            (3, 'get /items 404 3ms'),
+            (4, NULL);",
+    )
+    .await;
+
+    g.named_test(
+        "~ matches any part of the text",
+        "SELECT id FROM Log WHERE line ~ ' 5[0-9]{2} '",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "~* ignores the letter case",
+        "SELECT id FROM Log WHERE line ~* '^get '",
+        Ok(select!(id I64; 1; 3)),
+    )
+    .await;
+    g.named_test(
+        "!~ and !~* negate the match",
+        "SELECT id FROM Log WHERE line !~ '^GET' AND line !~* 'items'",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "regex match on NULL",
+        "SELECT line ~ 'a' AS matched FROM Log WHERE id = 4",
+        Ok(select_with_null!(matched; Null)),
+    )
+    .await;
+
+    g.named_test(
+        "REGEXP_MATCHES returns the capture groups of the first match",
+        r"SELECT id, REGEXP_MATCHES(line, '(\d+) (\d+)ms') AS matches FROM Log WHERE id < 3",
+        Ok(select!(
+            id  | matches
+            I64 | List;
+            1     vec![Str("200".to_owned()), Str("12".to_owned())];
+            2     vec![Str("500".to_owned()), Str("340".to_owned())]
+        )),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_MATCHES without groups returns the whole match",
+        r"SELECT REGEXP_MATCHES(line, '/\w+') AS matches FROM Log WHERE id = 3",
+        Ok(select!(matches List; vec![Str("/items".to_owned())])),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_MATCHES returns every match with the g flag",
+        r"SELECT REGEXP_MATCHES(line, '\d+', 'g') AS matches FROM Log WHERE id = 1",
+        Ok(select!(
+            matches
+            List;
+            vec![
+                List(vec![Str("200".to_owned())]),
+                List(vec![Str("12".to_owned())])
+            ]
+        )),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_MATCHES without a match",
+        "SELECT REGEXP_MATCHES(line, 'DELETE') AS matches FROM Log WHERE id = 1",
+        Ok(select_with_null!(matches; Null)),
+    )
+    .await;
+
+    g.named_test(
+        "REGEXP_REPLACE replaces the first match",
+        r"SELECT REGEXP_REPLACE(line, '\d+', 'N') AS line FROM Log WHERE id = 1",
+        Ok(select!(line Str; "GET /users N 12ms".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_REPLACE with flags and capture groups",
+        r"SELECT REGEXP_REPLACE(line, '^(get|post) (\S+)', '$2 ($1)', 'gi') AS line FROM Log ORDER BY id",
+        Ok(select_with_null!(
+            line;
+            Str("/users (GET) 200 12ms".to_owned());
+            Str("/users (POST) 500 340ms".to_owned());
+            Str("/items (get) 404 3ms".to_owned());
+            Null
+        )),
+    )
+    .await;
+    g.test(
+        r"UPDATE Log SET line = REGEXP_REPLACE(line, '\d+ms$', '-', 'g') WHERE line ~ 'users'",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.test(
+        "SELECT line FROM Log WHERE id = 2",
+        Ok(select!(line Str; "POST /users 500 -".to_owned())),
+    )
+    .await;
+
+    assert!(matches!(
+        g.run_err("SELECT id FROM Log WHERE line ~ '('").await,
+        Error::Evaluate(EvaluateError::InvalidRegex(_))
+    ));
+    g.named_test(
+        "unsupported flag",
+        "SELECT REGEXP_REPLACE(line, 'a', 'b', 'x') FROM Log WHERE id = 1",
+        Err(EvaluateError::UnsupportedRegexFlag("x".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "regex match on a non text value",
+        "SELECT id FROM Log WHERE id ~ '1'",
+        Err(EvaluateError::RegexMatchOnNonString.into()),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_MATCHES on a non text value",
+        "SELECT REGEXP_MATCHES(id, '1') FROM Log",
+        Err(EvaluateError::FunctionRequiresStringValue("REGEXP_MATCHES".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "REGEXP_REPLACE requires three or four arguments",
+        "SELECT REGEXP_REPLACE(line, 'a') FROM Log",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "REGEXP_REPLACE".to_owned(),
+            expected_minimum: 3,
+            expected_maximum: 4,
+            found: 2,
+        }
+        .into()),
+    )
+    .await;
+});
@@ -4,6 +4,7 @@ use {
         error::{EvaluateError, TranslateError},
         prelude::Value::*,
     },
+    rust_decimal::Decimal,
 };
 
 test_case!(round, {
@@ -23,6 +24,19 @@ test_case!(round, {
                 0.0      f64::from(-1)   10.0     7.0
             )),
         ),
+        (
+            "SELECT
+                ROUND(CAST(2.5 AS DECIMAL)) AS round1,
+                ROUND(CAST(-2.5 AS DECIMAL)) AS round2,
+                ROUND(CAST(7 AS INT8)) AS round3,
+                ROUND(CAST(7 AS UINT64)) AS round4
+            ;",
+            Ok(select!(
+                round1             | round2              | round3 | round4
+                Decimal            | Decimal             | F64    | F64;
+                Decimal::from(3)     Decimal::from(-3)     7.0      7.0
+            )),
+        ),
         (
             "SELECT ROUND('string') AS round",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("ROUND")).into()),
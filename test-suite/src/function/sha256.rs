@@ -0,0 +1,67 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(sha256, {
+    let g = get_tester!();
+
+    g.test(
+        "VALUES(SHA256('GlueSQL'))",
+        Ok(select!(
+            column1
+            Str;
+            "d1097a4523f5088125b35861110d6550d2947465dedcc9fd426811785b35fee3".to_owned()
+        )),
+    )
+    .await;
+
+    g.test(
+        "CREATE TABLE Sha (id INTEGER, text TEXT, bytes BYTEA);",
+        Ok(Payload::Create),
+    )
+    .await;
+
+    g.test(
+        "INSERT INTO Sha VALUES (1, 'GlueSQL Hi', X'0A0B0C');",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+
+    g.test(
+        "SELECT SHA256(text) AS text, SHA256(bytes) AS bytes FROM Sha;",
+        Ok(select!(
+            text | bytes
+            Str | Str;
+            "27f23325e4e3c5b31d799a7178e2999de823e797e7aeb5f140160b357bc2a299".to_owned()
+            "9909ec831e2cf6d0c73fb5480f31945a80987a13faee005704166cb53a26ceca".to_owned()
+        )),
+    )
+    .await;
+
+    g.test(
+        "SELECT SHA256(NULL) AS sha FROM Sha;",
+        Ok(select_with_null!(sha; Null)),
+    )
+    .await;
+
+    g.test(
+        "SELECT SHA256(id) FROM Sha;",
+        Err(EvaluateError::FunctionRequiresStringOrByteaValue("SHA256".to_owned()).into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT SHA256() FROM Sha;",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "SHA256".to_owned(),
+            expected: 1,
+            found: 0,
+        }
+        .into()),
+    )
+    .await;
+});
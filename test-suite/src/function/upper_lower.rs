@@ -62,6 +62,19 @@ test_case!(upper_lower, {
                 Str("efgi".to_owned())   Str("EFGI".to_owned())
             )),
         ),
+        (
+            "UPDATE Item SET opt_name = UPPER(TRIM(REPLACE(name, 'b', ' '))) WHERE LENGTH(opt_name) = 4",
+            Ok(Payload::Update(2)),
+        ),
+        (
+            "SELECT opt_name FROM Item;",
+            Ok(select_with_null!(
+                opt_name;
+                Str("A CD".to_owned());
+                Null;
+                Str("ABCD".to_owned())
+            )),
+        ),
         (
             "SELECT LOWER() FROM Item",
             Err(TranslateError::FunctionArgsLengthNotMatching {
@@ -75,6 +75,16 @@ test_case!(basic, {
     )
     .await;
 
+    g.named_test(
+        "EXPLAIN shows the index scan",
+        "EXPLAIN SELECT id, num, name FROM Test WHERE id < 20",
+        Ok(select_with_null!(
+            table                | access                                   | join;
+            Str("Test".to_owned())   Str("INDEX SCAN USING idx_id (id < 20)".to_owned())   Null
+        )),
+    )
+    .await;
+
     g.test_idx(
         "SELECT id, num, name FROM Test WHERE 20 > id",
         Ok(select!(
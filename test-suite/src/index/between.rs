@@ -0,0 +1,80 @@
+use {
+    crate::*,
+    gluesql_core::{ast::IndexOperator::*, prelude::*},
+    Value::*,
+};
+
+test_case!(between, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Score (
+            id INTEGER,
+            name TEXT
+        )
+    ",
+    )
+    .await;
+
+    g.run(
+        "
+        INSERT INTO Score
+            (id, name)
+        VALUES
+            (1, 'a'),
+            (3, 'b'),
+            (5, 'c'),
+            (7, 'd'),
+            (9, 'e');
+    ",
+    )
+    .await;
+
+    g.test(
+        "CREATE INDEX idx_id ON Score (id)",
+        Ok(Payload::CreateIndex),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id, name FROM Score WHERE id BETWEEN 3 AND 7",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "b".to_owned();
+            5     "c".to_owned();
+            7     "d".to_owned()
+        )),
+        idx!(idx_id, GtEq, "3"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Score WHERE name = 'c' AND id BETWEEN 2 + 2 AND 8",
+        Ok(select!(id I64; 5)),
+        idx!(idx_id, GtEq, "2 + 2"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Score WHERE id BETWEEN 8 AND 2",
+        Ok(select!(id)),
+        idx!(idx_id, GtEq, "8"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Score WHERE id NOT BETWEEN 3 AND 7",
+        Ok(select!(id I64; 1; 9)),
+        idx!(),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Score WHERE name BETWEEN 'b' AND 'c'",
+        Ok(select!(id I64; 3; 5)),
+        idx!(),
+    )
+    .await;
+});
@@ -1,5 +1,6 @@
 mod and;
 mod basic;
+mod between;
 mod expr;
 mod nested;
 mod null;
@@ -10,6 +11,7 @@ mod value;
 pub use {
     and::and,
     basic::basic,
+    between::between,
     expr::expr,
     nested::nested,
     null::null,
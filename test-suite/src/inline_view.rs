@@ -237,19 +237,24 @@ test_case!(inline_view, {
             )),
         ),
         (
-            // unsupported implicit join
+            // implicit join with inline view
             "SELECT *
             FROM OuterTable, (
                     SELECT id
                     FROM InnerTable
                     WHERE InnerTable.id = OuterTable.id
                 ) AS InlineView",
-            Err(TranslateError::TooManyTables.into()),
+            Ok(select!(
+                id  | name                | id
+                I64 | Str                 | I64;
+                1     "WORKS!".to_owned()   1;
+                2     "EXTRA".to_owned()    2
+            )),
         ),
         (
-            // unsupported select distinct
-            "SELECT DISTINCT id FROM OuterTable",
-            Err(TranslateError::SelectDistinctNotSupported.into()),
+            // unsupported select distinct on
+            "SELECT DISTINCT ON (id) id FROM OuterTable",
+            Err(TranslateError::SelectDistinctOnNotSupported.into()),
         ),
         (
             // inline view subquery + join with inline view
@@ -266,6 +271,45 @@ test_case!(inline_view, {
                 2     "SQL".to_owned()    2     "EXTRA".to_owned()
             )),
         ),
+        (
+            // alias-qualified filter and projection on inline view
+            "SELECT InlineView.name
+            FROM (
+                SELECT id, name FROM InnerTable
+            ) AS InlineView
+            WHERE InlineView.id > 1",
+            Ok(select!(
+                name
+                Str;
+                "SQL".to_owned();
+                "SQL".to_owned()
+            )),
+        ),
+        (
+            // join between two inline views
+            "SELECT Names.name, Counts.cnt
+            FROM (
+                SELECT id, name FROM OuterTable
+            ) AS Names
+            JOIN (
+                SELECT id, COUNT(*) AS cnt FROM InnerTable GROUP BY id
+            ) AS Counts ON Names.id = Counts.id
+            WHERE Counts.cnt > 0",
+            Ok(select!(
+                name                | cnt
+                Str                 | I64;
+                "WORKS!".to_owned()   1;
+                "EXTRA".to_owned()    1
+            )),
+        ),
+        (
+            // inline view inside of subquery
+            "SELECT name FROM OuterTable
+            WHERE id IN (
+                SELECT id FROM (SELECT id FROM InnerTable WHERE name = 'SQL') AS InlineView
+            )",
+            Ok(select!(name Str; "EXTRA".to_owned())),
+        ),
     ];
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
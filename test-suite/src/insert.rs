@@ -100,4 +100,46 @@ CREATE TABLE Test (
         )),
     )
     .await;
+
+    g.named_test(
+        "insert from source into specified columns",
+        "INSERT INTO Target (name, num) SELECT name, id FROM Test WHERE id = 17;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+
+    g.named_test(
+        "unspecified columns are filled with defaults",
+        "SELECT * FROM Target WHERE name = 'Sullivan';",
+        Ok(select!(
+            id  | num | name
+            I64 | I64 | Str;
+            17    30    "Sullivan".to_owned();
+            1     17    "Sullivan".to_owned()
+        )),
+    )
+    .await;
+
+    let error_cases = [
+        (
+            "INSERT INTO Target (id, name) SELECT id FROM Test;",
+            InsertError::ColumnAndValuesNotMatched,
+        ),
+        (
+            "INSERT INTO Target SELECT id, num, name, name FROM Test;",
+            InsertError::TooManyValues,
+        ),
+        (
+            "INSERT INTO Target (id, nothing) SELECT id, name FROM Test;",
+            InsertError::WrongColumnName("nothing".to_owned()),
+        ),
+        (
+            "INSERT INTO Target (id, num) SELECT id, num FROM Test;",
+            InsertError::LackOfRequiredColumn("name".to_owned()),
+        ),
+    ];
+
+    for (sql, error) in error_cases {
+        g.test(sql, Err(error.into())).await;
+    }
 });
@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{PlanError, TranslateError},
+        error::{FetchError, PlanError, TranslateError},
         prelude::*,
     },
     Value::*,
@@ -139,6 +139,13 @@ test_case!(join, {
         (15, "SELECT * FROM Player INNER JOIN Item ON Player.id = Item.player_id;"),
         (25, "SELECT * FROM Player p1 LEFT JOIN Player p2 ON 1 = 1"),
         (30, "SELECT * FROM Item INNER JOIN Item i2 ON i2.id IN (101, 103);"),
+        (75, "SELECT * FROM Item CROSS JOIN Player"),
+        (75, "SELECT * FROM Item, Player"),
+        (125, "SELECT * FROM Player p1, Player p2, Player p3"),
+        (15, "SELECT * FROM Item, Player WHERE Player.id = Item.player_id"),
+        (7, "SELECT * FROM Item i, Player p WHERE p.id = i.player_id AND p.id = 1;"),
+        (7, "SELECT * FROM Item i CROSS JOIN Player p WHERE p.id = i.player_id AND p.id = 1;"),
+        (20, "SELECT * FROM Player p, Item i LEFT JOIN Player p2 ON p2.id = i.player_id WHERE p.id < 3 AND i.quantity > 1"),
     ];
 
     for (num, sql) in select_sqls {
@@ -258,6 +265,54 @@ test_case!(project, {
     );
     g.test(sql, Ok(expected)).await;
 
+    // ON predicates only decide which right rows match, unmatched left rows are kept
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p
+        LEFT JOIN Item i
+        ON p.id = i.player_id AND i.quantity > 1
+    ";
+    let expected = select_with_null!(
+        id     | id;
+        I64(1)   Null;
+        I64(2)   I64(102);
+        I64(3)   Null;
+        I64(4)   I64(103);
+        I64(5)   Null
+    );
+    g.test(sql, Ok(expected)).await;
+
+    // WHERE predicates run after the join, so NULL-padded rows are filtered out
+    let sql = "
+        SELECT p.id, i.id
+        FROM Player p
+        LEFT JOIN Item i
+        ON p.id = i.player_id
+        WHERE i.quantity > 1
+    ";
+    let expected = select!(
+        id  | id;
+        I64 | I64;
+        2     102;
+        4     103
+    );
+    g.test(sql, Ok(expected)).await;
+
+    let sql = "
+        SELECT p.id, p.name
+        FROM Player p
+        LEFT JOIN Item i
+        ON p.id = i.player_id
+        WHERE i.id IS NULL
+    ";
+    let expected = select!(
+        id  | name;
+        I64 | Str;
+        3     "Jorno".to_owned();
+        5     "Hwan".to_owned()
+    );
+    g.test(sql, Ok(expected)).await;
+
     // To test `PlanError` while using `JOIN`
     g.run("CREATE TABLE Users (id INTEGER, name TEXT);").await;
     g.run("INSERT INTO Users (id, name) VALUES (1, 'Harry');")
@@ -269,12 +324,8 @@ test_case!(project, {
 
     let error_cases = [
         (
-            "SELECT * FROM TableA JOIN TableA USING (id);",
-            TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into(),
-        ),
-        (
-            "SELECT * FROM TableA CROSS JOIN TableA as A;",
-            TranslateError::UnsupportedJoinOperator("CrossJoin".to_owned()).into(),
+            "SELECT * FROM TableA CROSS APPLY TableA as A;",
+            TranslateError::UnsupportedJoinOperator("CrossApply".to_owned()).into(),
         ),
         (
             "SELECT id FROM Users JOIN Testers ON Users.id = Testers.id;",
@@ -294,8 +345,8 @@ test_case!(project, {
             PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
         ),
         (
-            "SELECT * FROM ProjectUser, ProjectItem",
-            TranslateError::TooManyTables.into(),
+            "SELECT * FROM Users, Testers RIGHT JOIN Users u ON u.id = Testers.id",
+            TranslateError::UnsupportedJoinAfterCommaJoin("RIGHT OUTER JOIN".to_owned()).into(),
         ),
     ];
 
@@ -303,3 +354,348 @@ test_case!(project, {
         g.test(sql, Err(error)).await;
     }
 });
+
+test_case!(outer, {
+    let g = get_tester!();
+
+    let create_sqls = [
+        "CREATE TABLE Player (id INTEGER PRIMARY KEY, name TEXT);",
+        "CREATE TABLE Item (id INTEGER, quantity INTEGER, player_id INTEGER);",
+    ];
+
+    for sql in create_sqls {
+        g.run(sql).await;
+    }
+
+    let insert_sqls = [
+        "
+        INSERT INTO Player (id, name) VALUES
+            (1, 'Taehoon'),
+            (2,    'Mike'),
+            (3,   'Jorno');
+        ",
+        "
+        INSERT INTO Item (id, quantity, player_id) VALUES
+            (101, 1,    1),
+            (102, 4,    1),
+            (103, 2,    4),
+            (104, 7, NULL);
+        ",
+    ];
+
+    for sql in insert_sqls {
+        g.run(sql).await;
+    }
+
+    let test_cases = [
+        (
+            "
+            SELECT p.id, i.id
+            FROM Player p
+            RIGHT JOIN Item i ON p.id = i.player_id
+            ",
+            select_with_null!(
+                id     | id;
+                I64(1)   I64(101);
+                I64(1)   I64(102);
+                Null     I64(103);
+                Null     I64(104)
+            ),
+        ),
+        (
+            "
+            SELECT p.id, i.id
+            FROM Player p
+            FULL OUTER JOIN Item i ON p.id = i.player_id
+            ",
+            select_with_null!(
+                id     | id;
+                I64(1)   I64(101);
+                I64(1)   I64(102);
+                I64(2)   Null;
+                I64(3)   Null;
+                Null     I64(103);
+                Null     I64(104)
+            ),
+        ),
+        (
+            "
+            SELECT *
+            FROM Player p
+            FULL JOIN Item i ON p.id = i.player_id AND i.quantity > 1
+            ",
+            select_with_null!(
+                id     | name                      | id       | quantity | player_id;
+                I64(1)   Str("Taehoon".to_owned())   I64(102)   I64(4)     I64(1);
+                I64(2)   Str("Mike".to_owned())      Null       Null       Null;
+                I64(3)   Str("Jorno".to_owned())     Null       Null       Null;
+                Null     Null                        I64(101)   I64(1)     I64(1);
+                Null     Null                        I64(103)   I64(2)     I64(4);
+                Null     Null                        I64(104)   I64(7)     Null
+            ),
+        ),
+        (
+            // not hashable, runs as a nested loop join
+            "
+            SELECT p.id, i.id
+            FROM Player p
+            FULL JOIN Item i ON p.id < i.player_id - 2
+            ",
+            select_with_null!(
+                id     | id;
+                I64(1)   I64(103);
+                I64(2)   Null;
+                I64(3)   Null;
+                Null     I64(101);
+                Null     I64(102);
+                Null     I64(104)
+            ),
+        ),
+        (
+            "
+            SELECT i.id
+            FROM Player p
+            RIGHT JOIN Item i ON p.id = i.player_id
+            WHERE p.id IS NULL
+            ",
+            select!(id; I64; 103; 104),
+        ),
+        (
+            // primary key lookup must not be pushed into the left side
+            "
+            SELECT p.name, i.id
+            FROM Player p
+            RIGHT JOIN Item i ON p.id = i.player_id
+            WHERE p.id = 1
+            ",
+            select!(
+                name                 | id
+                Str                  | I64;
+                "Taehoon".to_owned()   101;
+                "Taehoon".to_owned()   102
+            ),
+        ),
+        (
+            "
+            SELECT p2.id, p.id, i.id
+            FROM Player p
+            JOIN Item i ON p.id = i.player_id
+            RIGHT JOIN Player p2 ON p2.id = p.id
+            ",
+            select_with_null!(
+                id     | id     | id;
+                I64(1)   I64(1)   I64(101);
+                I64(1)   I64(1)   I64(102);
+                I64(2)   Null     Null;
+                I64(3)   Null     Null
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+});
+
+test_case!(using, {
+    let g = get_tester!();
+
+    let create_sqls = [
+        "CREATE TABLE Player (id INTEGER, name TEXT, team_id INTEGER);",
+        "CREATE TABLE Item (id INTEGER, name TEXT, player_id INTEGER);",
+        "CREATE TABLE Team (team_id INTEGER, team TEXT);",
+    ];
+
+    for sql in create_sqls {
+        g.run(sql).await;
+    }
+
+    let insert_sqls = [
+        "INSERT INTO Player VALUES (1, 'Taehoon', 10), (2, 'Mike', 20), (3, 'Jorno', NULL);",
+        "INSERT INTO Item VALUES (1, 'sword', 1), (2, 'Mike', 2), (4, 'shield', 1);",
+        "INSERT INTO Team VALUES (10, 'red'), (30, 'blue');",
+    ];
+
+    for sql in insert_sqls {
+        g.run(sql).await;
+    }
+
+    let test_cases = [
+        (
+            "SELECT * FROM Player JOIN Item USING (id)",
+            select!(
+                id  | name                 | team_id | name               | player_id
+                I64 | Str                  | I64     | Str                | I64;
+                1     "Taehoon".to_owned()   10        "sword".to_owned()   1;
+                2     "Mike".to_owned()      20        "Mike".to_owned()    2
+            ),
+        ),
+        (
+            "SELECT id, Player.name, Item.name FROM Player JOIN Item USING (id)",
+            select!(
+                id  | name                 | name
+                I64 | Str                  | Str;
+                1     "Taehoon".to_owned()   "sword".to_owned();
+                2     "Mike".to_owned()      "Mike".to_owned()
+            ),
+        ),
+        (
+            "SELECT * FROM Player NATURAL JOIN Item",
+            select!(
+                id  | name              | team_id | player_id
+                I64 | Str               | I64     | I64;
+                2     "Mike".to_owned()   20        2
+            ),
+        ),
+        (
+            "SELECT * FROM Player p NATURAL JOIN Team t",
+            select!(
+                id  | name                 | team_id | team
+                I64 | Str                  | I64     | Str;
+                1     "Taehoon".to_owned()   10        "red".to_owned()
+            ),
+        ),
+        (
+            "SELECT * FROM Player LEFT JOIN Team USING (team_id)",
+            select_with_null!(
+                id     | name                      | team_id | team;
+                I64(1)   Str("Taehoon".to_owned())   I64(10)   Str("red".to_owned());
+                I64(2)   Str("Mike".to_owned())      I64(20)   Null;
+                I64(3)   Str("Jorno".to_owned())     Null      Null
+            ),
+        ),
+        (
+            // merged column takes the value of whichever side is present
+            "SELECT team_id, team FROM Player FULL JOIN Team USING (team_id)",
+            select_with_null!(
+                team_id | team;
+                I64(10)   Str("red".to_owned());
+                I64(20)   Null;
+                Null      Null;
+                I64(30)   Str("blue".to_owned())
+            ),
+        ),
+        (
+            "SELECT * FROM Player RIGHT JOIN Team USING (team_id)",
+            select_with_null!(
+                id     | name                      | team_id | team;
+                I64(1)   Str("Taehoon".to_owned())   I64(10)   Str("red".to_owned());
+                Null     Null                        I64(30)   Str("blue".to_owned())
+            ),
+        ),
+        (
+            "SELECT * FROM Player JOIN Team USING (team_id) JOIN Item USING (id)",
+            select!(
+                id  | name                 | team_id | team             | name               | player_id
+                I64 | Str                  | I64     | Str              | Str                | I64;
+                1     "Taehoon".to_owned()   10        "red".to_owned()   "sword".to_owned()   1
+            ),
+        ),
+        (
+            "SELECT Item.* FROM Player JOIN Item USING (id)",
+            select!(
+                id  | name               | player_id
+                I64 | Str                | I64;
+                1     "sword".to_owned()   1;
+                2     "Mike".to_owned()    2
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+
+    g.test(
+        "SELECT * FROM Player JOIN Item USING (team_id)",
+        Err(FetchError::UsingColumnNotFound("team_id".to_owned()).into()),
+    )
+    .await;
+});
+
+test_case!(self_join, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Employee (id INTEGER PRIMARY KEY, name TEXT, manager_id INTEGER);")
+        .await;
+    g.run("INSERT INTO Employee VALUES (1, 'Ada', NULL), (2, 'Bob', 1), (3, 'Cid', 1), (4, 'Dan', 2);")
+        .await;
+
+    let test_cases = [
+        (
+            "SELECT a.id, b.id FROM Employee a JOIN Employee b ON a.manager_id = b.id",
+            select!(id | id; I64 | I64; 2 1; 3 1; 4 2),
+        ),
+        (
+            "
+            SELECT a.name AS employee, b.name AS manager
+            FROM Employee a
+            JOIN Employee b ON a.manager_id = b.id
+            WHERE b.id = 1
+            ",
+            select!(
+                employee         | manager
+                Str              | Str;
+                "Bob".to_owned()   "Ada".to_owned();
+                "Cid".to_owned()   "Ada".to_owned()
+            ),
+        ),
+        (
+            "
+            SELECT a.name, c.name
+            FROM Employee a
+            JOIN Employee b ON a.manager_id = b.id
+            JOIN Employee c ON b.manager_id = c.id
+            ",
+            select!(name | name; Str | Str; "Dan".to_owned() "Ada".to_owned()),
+        ),
+        (
+            "
+            SELECT a.*, b.name
+            FROM Employee a
+            JOIN Employee b ON a.manager_id = b.id
+            WHERE a.id = 4
+            ",
+            select!(
+                id  | name             | manager_id | name
+                I64 | Str              | I64        | Str;
+                4     "Dan".to_owned()   2            "Bob".to_owned()
+            ),
+        ),
+        (
+            "
+            SELECT b.name, COUNT(*) AS reports
+            FROM Employee a
+            JOIN Employee b ON a.manager_id = b.id
+            GROUP BY b.name
+            ",
+            select!(
+                name             | reports
+                Str              | I64;
+                "Ada".to_owned()   2;
+                "Bob".to_owned()   1
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+
+    g.named_test(
+        "left self join keeps employees without a manager",
+        "
+        SELECT a.name AS employee, b.name AS manager
+        FROM Employee a
+        LEFT JOIN Employee b ON a.manager_id = b.id
+        ",
+        Ok(select_with_null!(
+            employee                | manager;
+            Str("Ada".to_owned())     Null;
+            Str("Bob".to_owned())     Str("Ada".to_owned());
+            Str("Cid".to_owned())     Str("Ada".to_owned());
+            Str("Dan".to_owned())     Str("Bob".to_owned())
+        )),
+    )
+    .await;
+});
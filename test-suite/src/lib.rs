@@ -2,21 +2,28 @@
 
 pub mod aggregate;
 pub mod alter;
+pub mod any_all;
 pub mod arithmetic;
 pub mod ast_builder;
 pub mod basic;
 pub mod bitwise_and;
 pub mod bitwise_shift;
 pub mod case;
+pub mod check_constraint;
+pub mod collation;
 pub mod column_alias;
 pub mod concat;
+pub mod cte;
 pub mod custom_function;
 pub mod data_type;
 pub mod default;
 pub mod delete;
 pub mod dictionary;
 pub mod dictionary_index;
+pub mod distinct;
+pub mod explain;
 pub mod filter;
+pub mod foreign_key;
 pub mod function;
 pub mod index;
 pub mod inline_view;
@@ -24,24 +31,36 @@ pub mod insert;
 pub mod join;
 pub mod like_ilike;
 pub mod limit;
+pub mod materialized_view;
+pub mod merge;
 pub mod metadata;
 pub mod migrate;
+pub mod native_function;
 pub mod nested_select;
 pub mod nullable;
 pub mod order_by;
 pub mod ordering;
 pub mod primary_key;
 pub mod project;
+pub mod read_only;
+pub mod returning;
 pub mod schemaless;
 pub mod series;
+pub mod set_operation;
 pub mod show_columns;
 pub mod synthesize;
+pub mod table_function;
+pub mod temporary_table;
 pub mod transaction;
+pub mod truncate;
 pub mod type_match;
 pub mod unary_operator;
 pub mod update;
+pub mod upsert;
 pub mod validate;
 pub mod values;
+pub mod view;
+pub mod window;
 
 pub mod tester;
 
@@ -70,7 +89,16 @@ macro_rules! generate_store_tests {
         }
         glue!(update, update::update);
         glue!(insert, insert::insert);
+        glue!(upsert, upsert::upsert);
+        glue!(returning, returning::returning);
         glue!(delete, delete::delete);
+        glue!(merge, merge::merge);
+        glue!(foreign_key, foreign_key::foreign_key);
+        glue!(check_constraint, check_constraint::check_constraint);
+        glue!(view, view::view);
+        glue!(materialized_view, materialized_view::materialized_view);
+        glue!(temporary_table, temporary_table::temporary_table);
+        glue!(truncate, truncate::truncate);
         glue!(basic, basic::basic);
         glue!(bitwise_and, bitwise_and::bitwise_and);
         glue!(aggregate_avg, aggregate::avg::avg);
@@ -93,9 +121,17 @@ macro_rules! generate_store_tests {
         glue!(default, default::default);
         glue!(limit, limit::limit);
         glue!(like_ilike, like_ilike::like_ilike);
+        glue!(collation, collation::collation);
+        glue!(native_function, native_function::native_function);
+        glue!(table_function, table_function::table_function);
+        glue!(read_only, read_only::read_only);
+        glue!(explain, explain::explain);
         glue!(filter, filter::filter);
+        glue!(any_all, any_all::any_all);
+        glue!(distinct, distinct::distinct);
         glue!(inline_view, inline_view::inline_view);
         glue!(values, values::values);
+        glue!(window, window::window);
         glue!(unary_operator, unary_operator::unary_operator);
         glue!(function_values, function::values::values);
         glue!(function_upper_lower, function::upper_lower::upper_lower);
@@ -114,6 +150,7 @@ macro_rules! generate_store_tests {
         glue!(function_concat, function::concat::concat);
         glue!(function_concat_ws, function::concat_ws::concat_ws);
         glue!(function_ifnull, function::ifnull::ifnull);
+        glue!(function_nullif, function::nullif::nullif);
         glue!(function_is_empty, function::is_empty::is_empty);
         glue!(function_math_function_asin, function::math_function::asin);
         glue!(function_math_function_acos, function::math_function::acos);
@@ -140,6 +177,8 @@ macro_rules! generate_store_tests {
         glue!(function_ascii, function::ascii::ascii);
         glue!(function_chr, function::chr::chr);
         glue!(function_mod, function::md5::md5);
+        glue!(function_sha256, function::sha256::sha256);
+        glue!(function_hex_base64, function::hex_base64::hex_base64);
         glue!(function_replace, function::replace::replace);
         glue!(function_length, function::length::length);
         glue!(function_position, function::position::position);
@@ -154,10 +193,16 @@ macro_rules! generate_store_tests {
         glue!(function_entries, function::entries::entries);
         glue!(join, join::join);
         glue!(join_project, join::project);
+        glue!(join_outer, join::outer);
+        glue!(join_using, join::using);
+        glue!(join_self_join, join::self_join);
         glue!(migrate, migrate::migrate);
         glue!(nested_select, nested_select::nested_select);
         glue!(primary_key, primary_key::primary_key);
         glue!(series, series::series);
+        glue!(set_operation, set_operation::set_operation);
+        glue!(cte, cte::cte);
+        glue!(cte_max_recursion, cte::max_recursion);
         glue!(nullable, nullable::nullable);
         glue!(nullable_text, nullable::nullable_text);
         glue!(nullable_implicit_insert, nullable::nullable_implicit_insert);
@@ -190,6 +235,9 @@ macro_rules! generate_store_tests {
         glue!(validate_types, validate::types::types);
         glue!(function_extract, function::extract::extract);
         glue!(function_radians, function::radians::radians);
+        glue!(function_regexp, function::regexp::regexp);
+        glue!(function_json, function::json::json);
+        glue!(function_date_trunc, function::date_trunc::date_trunc);
         glue!(function_degrees, function::degrees::degrees);
         glue!(function_pi, function::pi::pi);
         glue!(function_reverse, function::reverse::reverse);
@@ -203,6 +251,7 @@ macro_rules! generate_store_tests {
             function::generate_uuid::generate_uuid
         );
         glue!(function_greatest, function::greatest::greatest);
+        glue!(function_least, function::least::least);
         glue!(type_match, type_match::type_match);
         glue!(dictionary, dictionary::dictionary);
         glue!(function_append, function::append::append);
@@ -303,6 +352,7 @@ macro_rules! generate_custom_function_tests {
         }
 
         glue!(function_custom, custom_function::custom);
+        glue!(function_custom_returns, custom_function::returns);
     };
 }
 
@@ -317,6 +367,7 @@ macro_rules! generate_index_tests {
 
         glue!(index_basic, index::basic);
         glue!(index_and, index::and);
+        glue!(index_between, index::between);
         glue!(index_nested, index::nested);
         glue!(index_null, index::null);
         glue!(index_expr, index::expr);
@@ -343,6 +394,7 @@ macro_rules! generate_transaction_tests {
             transaction::create_drop_table
         );
         glue!(transaction_dictionary, transaction::dictionary);
+        glue!(transaction_savepoint, transaction::savepoint);
     };
 }
 
@@ -84,6 +84,51 @@ test_case!(like_ilike, {
         g.count(sql, num).await;
     }
 
+    g.run(
+        "
+        CREATE TABLE Memo (
+            id INTEGER,
+            body TEXT NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Memo (id, body) VALUES
+            (1,        '100%'),
+            (2, '100 percent'),
+            (3,         'a_c'),
+            (4,         'abc'),
+            (5,         'a.c'),
+            (6,          NULL);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (1, r"SELECT id FROM Memo WHERE body LIKE '100\%'"),
+        (2, "SELECT id FROM Memo WHERE body LIKE '100%'"),
+        (1, r"SELECT id FROM Memo WHERE body LIKE 'a\_c'"),
+        (3, "SELECT id FROM Memo WHERE body LIKE 'a_c'"),
+        (1, "SELECT id FROM Memo WHERE body LIKE 'a.c'"),
+        (1, r"SELECT id FROM Memo WHERE body ILIKE 'A\_C'"),
+        (2, "SELECT id FROM Memo WHERE body NOT LIKE 'a%'"),
+        (0, "SELECT id FROM Memo WHERE body LIKE NULL"),
+        (0, "SELECT id FROM Memo WHERE body NOT ILIKE NULL"),
+    ];
+
+    for (num, sql) in test_cases {
+        g.count(sql, num).await;
+    }
+
+    g.named_test(
+        "LIKE and NOT LIKE with NULL operand return NULL",
+        "SELECT body LIKE '%' AS a, body NOT LIKE '%' AS b, NULL ILIKE 'a' AS c FROM Memo WHERE id = 6",
+        Ok(select_with_null!(a | b | c; Value::Null Value::Null Value::Null)),
+    )
+    .await;
+
     let error_sqls = [
         (
             "SELECT name FROM Item WHERE 'ABC' LIKE 10",
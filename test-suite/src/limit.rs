@@ -52,6 +52,26 @@ test_case!(limit, {
                 5     1
             ),
         ),
+        (
+            "SELECT * FROM Test LIMIT 0",
+            Payload::Select {
+                labels: vec!["id".to_owned()],
+                rows: vec![],
+            },
+        ),
+        (
+            "SELECT * FROM Test LIMIT 1 + 1 OFFSET 2 * 3",
+            select!(id; I64; 7; 8),
+        ),
+        (
+            // rows after the limit are never evaluated, so id = 3 does not divide by zero
+            "SELECT 12 / (3 - id) AS v FROM Test LIMIT 2",
+            select!(v; I64; 6; 12),
+        ),
+        (
+            "SELECT id FROM Test WHERE 12 / (3 - id) > 0 LIMIT 1 OFFSET 1",
+            select!(id; I64; 2),
+        ),
         (
             "CREATE TABLE InsertTest (
                 case_no INTEGER,
@@ -0,0 +1,102 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, ExecuteError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(materialized_view, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            category TEXT,
+            price INTEGER
+        );
+        ",
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (1, 'pen', 30), (2, 'bag', 500), (3, 'pen', 80);")
+        .await;
+
+    g.test(
+        "
+        CREATE MATERIALIZED VIEW Total AS
+        SELECT category, SUM(price) AS total FROM Item GROUP BY category;
+        ",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Total ORDER BY category",
+        Ok(select!(
+            category         | total
+            Str              | I64;
+            "bag".to_owned()   500;
+            "pen".to_owned()   110
+        )),
+    )
+    .await;
+
+    g.run("INSERT INTO Item VALUES (4, 'cup', 20), (5, 'pen', 10);")
+        .await;
+    g.named_test(
+        "stored rows are kept until refreshed",
+        "SELECT total FROM Total WHERE category = 'pen'",
+        Ok(select!(total I64; 110)),
+    )
+    .await;
+    g.test(
+        "REFRESH MATERIALIZED VIEW Total;",
+        Ok(Payload::RefreshMaterializedView),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Total ORDER BY category",
+        Ok(select!(
+            category         | total
+            Str              | I64;
+            "bag".to_owned()   500;
+            "cup".to_owned()   20;
+            "pen".to_owned()   120
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "materialized view cannot be modified",
+        "DELETE FROM Total;",
+        Err(ExecuteError::TableIsView("Total".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "REFRESH MATERIALIZED VIEW on a table",
+        "REFRESH MATERIALIZED VIEW Item;",
+        Err(AlterError::MaterializedViewNotFound("Item".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "table used by a materialized view cannot be dropped",
+        "DROP TABLE Item;",
+        Err(AlterError::TableReferencedByView {
+            table_name: "Item".to_owned(),
+            view_name: "Total".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+
+    g.test(
+        "CREATE OR REPLACE MATERIALIZED VIEW Total AS SELECT COUNT(*) AS n FROM Item;",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test("SELECT * FROM Total", Ok(select!(n I64; 5))).await;
+
+    g.test("DROP MATERIALIZED VIEW Total;", Ok(Payload::DropView))
+        .await;
+    g.test("DROP TABLE Item;", Ok(Payload::DropTable)).await;
+});
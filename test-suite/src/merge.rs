@@ -0,0 +1,152 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{MergeError, TranslateError, UpdateError, ValidateError},
+        prelude::{Key, Payload, Value::*},
+    },
+};
+
+test_case!(merge, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            qty INTEGER DEFAULT 0,
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        CREATE TABLE Stock (
+            id INTEGER,
+            name TEXT,
+            qty INTEGER,
+        );
+    ",
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (1, 'apple', 10), (2, 'banana', 20), (3, 'cherry', 30);")
+        .await;
+    g.run("INSERT INTO Stock VALUES (1, 'apple', 15), (2, 'banana', 0), (4, 'date', 40);")
+        .await;
+
+    g.named_test(
+        "MERGE updates, deletes and inserts in a single statement",
+        "
+        MERGE INTO Item USING Stock ON Item.id = Stock.id
+        WHEN MATCHED AND Stock.qty = 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET qty = Stock.qty
+        WHEN NOT MATCHED THEN INSERT (id, name, qty) VALUES (Stock.id, Stock.name, Stock.qty);
+        ",
+        Ok(Payload::Merge(3)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, qty FROM Item",
+        Ok(select!(
+            id  | name                  | qty
+            I64 | Str                   | I64;
+            1     "apple".to_owned()      15;
+            3     "cherry".to_owned()     30;
+            4     "date".to_owned()       40
+        )),
+    )
+    .await;
+
+    g.run("DELETE FROM Stock;").await;
+    g.run("INSERT INTO Stock VALUES (3, 'coconut', 1), (5, 'elderberry', 50), (6, 'fig', 0);")
+        .await;
+    g.named_test(
+        "target alias, assignments referring both sides and defaults for omitted columns",
+        "
+        MERGE INTO Item t USING Stock s ON t.id = s.id
+        WHEN MATCHED THEN UPDATE SET name = s.name, qty = t.qty + s.qty
+        WHEN NOT MATCHED AND s.qty > 0 THEN INSERT (id, name) VALUES (s.id, s.name);
+        ",
+        Ok(Payload::Merge(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, qty FROM Item",
+        Ok(select!(
+            id  | name                     | qty
+            I64 | Str                      | I64;
+            1     "apple".to_owned()         15;
+            3     "coconut".to_owned()       31;
+            4     "date".to_owned()          40;
+            5     "elderberry".to_owned()    0
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "derived table as the source",
+        "
+        MERGE INTO Item USING (SELECT 1 AS id, 100 AS qty) AS src ON Item.id = src.id
+        WHEN MATCHED THEN UPDATE SET qty = src.qty;
+        ",
+        Ok(Payload::Merge(1)),
+    )
+    .await;
+    g.test(
+        "SELECT qty FROM Item WHERE id = 1",
+        Ok(select!(qty I64; 100)),
+    )
+    .await;
+
+    g.run("INSERT INTO Stock VALUES (3, 'cranberry', 2);").await;
+    g.named_test(
+        "a target row cannot be matched by multiple source rows",
+        "
+        MERGE INTO Item USING Stock ON Item.id = Stock.id
+        WHEN MATCHED THEN UPDATE SET name = Stock.name;
+        ",
+        Err(MergeError::TargetRowMatchedMultipleTimes.into()),
+    )
+    .await;
+    g.test(
+        "SELECT name FROM Item WHERE id = 3",
+        Ok(select!(name Str; "coconut".to_owned())),
+    )
+    .await;
+
+    g.test(
+        "
+        MERGE INTO Item USING Stock ON Item.id = Stock.id + 100
+        WHEN NOT MATCHED THEN INSERT VALUES (1, 'apricot', 1);
+        ",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(1)).into()),
+    )
+    .await;
+    g.test(
+        "
+        MERGE INTO Item USING Stock ON Item.id = Stock.id
+        WHEN MATCHED THEN UPDATE SET id = 10;
+        ",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "
+        MERGE INTO Nothing USING Stock ON Nothing.id = Stock.id
+        WHEN MATCHED THEN DELETE;
+        ",
+        Err(MergeError::TableNotFound("Nothing".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "
+        MERGE INTO Item USING Stock ON Item.id = Stock.id
+        WHEN NOT MATCHED THEN INSERT VALUES (7, 'grape', 1), (8, 'guava', 1);
+        ",
+        Err(TranslateError::MergeInsertRequiresSingleRow(
+            "WHEN NOT MATCHED THEN INSERT () VALUES (7, 'grape', 1), (8, 'guava', 1)".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+});
@@ -47,18 +47,14 @@ test_case!(migrate, {
             "SELECT * FROM Test WHERE Here.User.id = 1",
             TranslateError::UnsupportedExpr("Here.User.id".to_owned()).into(),
         ),
-        (
-            "SELECT * FROM Test NATURAL JOIN Test",
-            TranslateError::UnsupportedJoinConstraint("NATURAL".to_owned()).into(),
-        ),
         (
             "SELECT 1 ^ 2 FROM Test;",
             TranslateError::UnsupportedBinaryOperator("^".to_owned()).into(),
         ),
         (
-            "SELECT * FROM Test UNION SELECT * FROM Test;",
+            "SELECT * FROM Test UNION BY NAME SELECT * FROM Test;",
             TranslateError::UnsupportedQuerySetExpr(
-                "SELECT * FROM Test UNION SELECT * FROM Test".to_owned(),
+                "SELECT * FROM Test UNION BY NAME SELECT * FROM Test".to_owned(),
             )
             .into(),
         ),
@@ -71,8 +67,11 @@ test_case!(migrate, {
             FetchError::TableNotFound("Nothing".to_owned()).into(),
         ),
         (
-            "TRUNCATE TABLE ProjectUser;",
-            TranslateError::UnsupportedStatement("TRUNCATE TABLE ProjectUser".to_owned()).into(),
+            "TRUNCATE TABLE ProjectUser PARTITION (id = 1);",
+            TranslateError::UnsupportedStatement(
+                "TRUNCATE TABLE ProjectUser PARTITION (id = 1)".to_owned(),
+            )
+            .into(),
         ),
     ];
 
@@ -0,0 +1,85 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::{DataType, Value::*},
+    },
+};
+
+test_case!(native_function, {
+    let g = get_tester!();
+
+    let glue = g.get_glue();
+    glue.register_function("add_tax", vec![DataType::Float], |args| {
+        match args.as_slice() {
+            [F64(price)] => Ok(F64(price * 1.1)),
+            _ => Ok(Null),
+        }
+    });
+    glue.register_function(
+        "greet",
+        vec![DataType::Text, DataType::Int],
+        |args| match args.as_slice() {
+            [Str(name), I64(times)] => Ok(Str(format!("Hello {name}").repeat(*times as usize))),
+            _ => Ok(Null),
+        },
+    );
+    glue.register_function("fail", vec![], |_| {
+        Err(ValueError::DivisorShouldNotBeZero.into())
+    });
+
+    g.run("CREATE TABLE Item (id INTEGER, name TEXT, price INTEGER NULL);")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 'a', 100), (2, 'b', 250), (3, 'c', NULL);")
+        .await;
+
+    g.named_test(
+        "arguments are cast to the registered types",
+        "SELECT id, ADD_TAX(price) AS total FROM Item",
+        Ok(select_with_null!(
+            id     | total;
+            I64(1)   F64(100.0 * 1.1);
+            I64(2)   F64(250.0 * 1.1);
+            I64(3)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "native functions in WHERE and with several arguments",
+        "SELECT greet(name, 2) AS greeting FROM Item WHERE add_tax(price) > 200",
+        Ok(select!(greeting Str; "Hello bHello b".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "wrong number of arguments",
+        "SELECT greet(name) FROM Item",
+        Err(EvaluateError::FunctionArgsLengthNotWithinRange {
+            name: "greet".to_owned(),
+            expected_minimum: 2,
+            expected_maximum: 2,
+            found: 1,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "argument which cannot be cast",
+        "SELECT greet(name, 'x') FROM Item",
+        Err(ValueError::LiteralCastFromTextToIntegerFailed("x".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "errors of the function are returned as they are",
+        "SELECT fail() FROM Item",
+        Err(ValueError::DivisorShouldNotBeZero.into()),
+    )
+    .await;
+
+    assert!(g.get_glue().unregister_function("FAIL"));
+    g.named_test(
+        "unregistered function",
+        "SELECT fail() FROM Item",
+        Err(EvaluateError::UnsupportedFunction("FAIL".to_owned()).into()),
+    )
+    .await;
+});
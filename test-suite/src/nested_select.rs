@@ -97,6 +97,35 @@ test_case!(nested_select, {
                 Value::Null
             )),
         ),
+        (
+            "
+            SELECT id, (SELECT quantity FROM Request WHERE user_id = Player.id AND quantity > 7) AS q
+            FROM Player WHERE id < 4
+            ",
+            Ok(select_with_null!(
+                id              | q;
+                Value::I64(1)   Value::I64(8);
+                Value::I64(2)   Value::Null;
+                Value::I64(3)   Value::I64(9)
+            )),
+        ),
+        (
+            "
+            SELECT id FROM Player
+            WHERE NOT (1 = (SELECT quantity FROM Request WHERE user_id = Player.id AND quantity = 1 AND id < 102))
+            ",
+            Ok(Payload::Select {
+                labels: vec!["id".to_owned()],
+                rows: vec![],
+            }),
+        ),
+        (
+            "
+            SELECT id FROM Player
+            WHERE (SELECT quantity FROM Request WHERE user_id = Player.id AND quantity > 7) IS NULL
+            ",
+            Ok(select!(id Value::I64; 2; 4; 5)),
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -221,6 +221,93 @@ test_case!(nullable, {
                 Null       Null       Null       Null       Null       Null       Null       Null
             ),
         ),
+        (
+            "SELECT id, num FROM Test WHERE id NOT IN (1, NULL)",
+            select!(id | num),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id NOT IN (1)",
+            select!(
+                id  | num
+                I64 | I64;
+                3     4
+            ),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id IN (1, NULL)",
+            select!(
+                id  | num
+                I64 | I64;
+                1     9
+            ),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE num NOT IN (SELECT id FROM Test)",
+            select!(id | num),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE num NOT IN (SELECT id FROM Test WHERE id IS NOT NULL)",
+            select_with_null!(
+                id     | num;
+                Null     I64(2);
+                I64(1)   I64(9);
+                I64(3)   I64(4)
+            ),
+        ),
+        (
+            "SELECT id IN (1, NULL) AS a, id NOT IN (1, NULL) AS b FROM Test",
+            select_with_null!(
+                a          | b;
+                Null         Null;
+                Bool(true)   Bool(false);
+                Null         Null
+            ),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id > 1 OR name = 'Hello'",
+            select_with_null!(
+                id     | num;
+                Null     I64(2);
+                I64(3)   I64(4)
+            ),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id > 1 AND name = 'Hello'",
+            select!(id | num),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE NOT (id > 1)",
+            select!(
+                id  | num
+                I64 | I64;
+                1     9
+            ),
+        ),
+        (
+            "SELECT id, num FROM Test WHERE id <> 1",
+            select!(
+                id  | num
+                I64 | I64;
+                3     4
+            ),
+        ),
+        (
+            "
+            SELECT
+                id = 1 AS eq,
+                id > 1 AND FALSE AS and_false,
+                id > 1 AND TRUE AS and_true,
+                id > 1 OR TRUE AS or_true,
+                id > 1 OR FALSE AS or_false,
+                (id > 1) XOR TRUE AS xor,
+                NOT (id > 1) AS not
+            FROM Test WHERE id IS NULL
+            ",
+            select_with_null!(
+                eq   | and_false   | and_true | or_true    | or_false | xor  | not;
+                Null   Bool(false)   Null       Bool(true)   Null       Null   Null
+            ),
+        ),
     ];
 
     for (sql, expected) in test_cases {
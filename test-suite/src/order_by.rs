@@ -162,8 +162,57 @@ CREATE TABLE Test (
     )
     .await;
 
-    g.test(
-        "SELECT * FROM Test ORDER BY id NULLS FIRST",
+    g.named_test(
+        "NULLS FIRST puts NULL before values in ascending order",
+        "SELECT id, rate FROM Test ORDER BY rate NULLS FIRST, id",
+        Ok(select_with_null!(
+            id     | rate;
+            I64(1)   Null;
+            I64(4)   Null;
+            I64(3)   F64(1.0);
+            I64(1)   F64(3.0)
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLS LAST puts NULL after values in descending order",
+        "SELECT id, rate FROM Test ORDER BY rate DESC NULLS LAST, id DESC",
+        Ok(select_with_null!(
+            id     | rate;
+            I64(1)   F64(3.0);
+            I64(3)   F64(1.0);
+            I64(4)   Null;
+            I64(1)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLS FIRST works with aliases",
+        "SELECT name AS n, id FROM Test ORDER BY n ASC NULLS FIRST",
+        Ok(select_with_null!(
+            n                | id;
+            Null               I64(1);
+            s!("Hello")        I64(1);
+            s!("Thursday")     I64(4);
+            s!("World")        I64(3)
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLS LAST works with COLUMN_INDEX",
+        "SELECT id, rate FROM Test ORDER BY 2 DESC NULLS LAST, 1",
+        Ok(select_with_null!(
+            id     | rate;
+            I64(1)   F64(3.0);
+            I64(3)   F64(1.0);
+            I64(1)   Null;
+            I64(4)   Null
+        )),
+    )
+    .await;
+    g.named_test(
+        "NULLS FIRST is not supported in CREATE INDEX",
+        "CREATE INDEX idx_rate ON Test (rate NULLS FIRST)",
         Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into()),
     )
     .await;
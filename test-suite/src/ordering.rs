@@ -68,4 +68,47 @@ test_case!(ordering, {
     for (num, sql) in test_cases {
         g.count(sql, num).await;
     }
+
+    g.run(
+        "
+        CREATE TABLE Measure (
+            id INTEGER,
+            ratio FLOAT NULL,
+            amount DECIMAL NULL,
+            label TEXT NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Measure (id, ratio, amount, label) VALUES
+            (1,  1.5,  1.5,  'a'),
+            (2, NULL, NULL, NULL),
+            (3,  3.0,    3,  'c');
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (1, "SELECT * FROM Measure WHERE id < 1.5;"),
+        (1, "SELECT * FROM Measure WHERE id > 2.5;"),
+        (1, "SELECT * FROM Measure WHERE 1.5 >= id;"),
+        (1, "SELECT * FROM Measure WHERE ratio <= 2;"),
+        (1, "SELECT * FROM Measure WHERE ratio >= 2;"),
+        (1, "SELECT * FROM Measure WHERE amount <= 2;"),
+        (1, "SELECT * FROM Measure WHERE amount < 1.6;"),
+        (1, "SELECT * FROM Measure WHERE amount > id;"),
+        (1, "SELECT * FROM Measure WHERE ratio > id;"),
+        (2, "SELECT * FROM Measure WHERE id <= ratio;"),
+        (1, "SELECT * FROM Measure WHERE id = amount;"),
+        (1, "SELECT * FROM Measure WHERE label >= 'b';"),
+        (1, "SELECT * FROM Measure WHERE label <= 'b';"),
+        (0, "SELECT * FROM Measure WHERE id >= 'a';"),
+        (0, "SELECT * FROM Measure WHERE id <= 'a';"),
+    ];
+
+    for (num, sql) in test_cases {
+        g.count(sql, num).await;
+    }
 });
@@ -109,6 +109,32 @@ test_case!(primary_key, {
     )
     .await;
 
+    g.named_test(
+        "range of PRIMARY KEY",
+        "SELECT id, name FROM Allegro WHERE id > 2 AND (id <= 4 AND name <> 'world')",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            4     "bar".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "range of PRIMARY KEY with the literal on the left",
+        "SELECT id FROM Allegro WHERE 4 > id AND id >= 2",
+        Ok(select!(id I64; 2; 3)),
+    )
+    .await;
+    g.named_test(
+        "empty range of PRIMARY KEY",
+        "SELECT id FROM Allegro WHERE id = 3 AND id > 3",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        }),
+    )
+    .await;
+
     g.run("DELETE FROM Allegro WHERE id > 3").await;
     g.test(
         "SELECT id, name FROM Allegro",
@@ -121,6 +147,40 @@ test_case!(primary_key, {
         )),
     )
     .await;
+
+    g.named_test(
+        "UPDATE fetches the row by PRIMARY KEY",
+        "UPDATE Allegro SET name = 'hi' WHERE 3 - 1 = id AND name = 'foo'",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "UPDATE Allegro SET name = 'nothing' WHERE id = 10",
+        Ok(Payload::Update(0)),
+    )
+    .await;
+    g.named_test(
+        "DELETE fetches the row by PRIMARY KEY",
+        "DELETE FROM Allegro WHERE Allegro.id = 3",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "DELETE FROM Allegro WHERE id = 1 AND name = 'world'",
+        Ok(Payload::Delete(0)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name FROM Allegro",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "hello".to_owned();
+            2     "hi".to_owned()
+        )),
+    )
+    .await;
+
     g.run(
         "
         CREATE TABLE Strslice (
@@ -139,6 +199,23 @@ test_case!(primary_key, {
     )
     .await;
 
+    g.named_test(
+        "PRIMARY KEY is unique within a multi-row insert",
+        "INSERT INTO Allegro VALUES (7, 'first'), (7, 'second');",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(7)).into()),
+    )
+    .await;
+
+    g.named_test(
+        "multi-row insert is applied all or nothing",
+        "SELECT id FROM Allegro WHERE id = 7",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+
     g.named_test(
         "PRIMARY KEY includes NOT NULL constraint",
         "INSERT INTO Allegro VALUES (NULL, 'hello');",
@@ -113,6 +113,15 @@ test_case!(project, {
                 3     "Jorno".to_owned()     105   3           1
             ),
         ),
+        (
+            "SELECT s.* FROM (SELECT id, name FROM ProjectUser WHERE id > 1) AS s",
+            select!(
+                id  | name
+                I64 | Str;
+                2     "Mike".to_owned();
+                3     "Jorno".to_owned()
+            ),
+        ),
         (
             "SELECT id as Ident, name FROM ProjectUser",
             select!(
@@ -142,12 +151,70 @@ test_case!(project, {
             );",
             select!(id; I64; 2),
         ),
+        (
+            "
+            SELECT
+                id,
+                (SELECT MAX(quantity) FROM ProjectItem WHERE player_id = ProjectUser.id) AS max
+            FROM ProjectUser
+            ",
+            select!(id | max; I64 | I64; 1 1; 2 9; 3 2),
+        ),
+        (
+            "
+            SELECT
+                u.name,
+                (SELECT COUNT(*) FROM ProjectItem i WHERE i.player_id = u.id) AS cnt
+            FROM ProjectUser u
+            WHERE u.id > 1
+            ",
+            select!(
+                name              | cnt
+                Str               | I64;
+                "Mike".to_owned()   2;
+                "Jorno".to_owned()  2
+            ),
+        ),
     ];
 
     for (sql, expected) in test_cases {
         g.test(sql, Ok(expected)).await;
     }
 
+    g.named_test(
+        "scalar subquery without matching row returns NULL",
+        "
+        SELECT
+            id,
+            (SELECT quantity FROM ProjectItem WHERE quantity > 5 AND player_id = ProjectUser.id) AS q
+        FROM ProjectUser
+        ",
+        Ok(select_with_null!(
+            id     | q;
+            I64(1)   Null;
+            I64(2)   I64(9);
+            I64(3)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "qualified wildcard of unmatched left join side is filled with NULL",
+        "
+        SELECT i.*, u.name
+        FROM ProjectUser u
+        LEFT JOIN ProjectItem i ON u.id = i.player_id AND i.quantity > 3
+        ",
+        Ok(select_with_null!(
+            id       | player_id | quantity | name;
+            Null       Null        Null       Str("Taehoon".to_owned());
+            I64(102)   I64(2)      I64(4)     Str("Mike".to_owned());
+            I64(103)   I64(2)      I64(9)     Str("Mike".to_owned());
+            Null       Null        Null       Str("Jorno".to_owned())
+        )),
+    )
+    .await;
+
     let error_cases = [
         (
             "SELECT Whatever.* FROM ProjectUser",
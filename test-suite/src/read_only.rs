@@ -0,0 +1,53 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::ExecuteError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(read_only, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER, name TEXT);").await;
+    g.run("INSERT INTO Item VALUES (1, 'glue'), (2, 'sql');")
+        .await;
+
+    g.get_glue().set_read_only(true);
+
+    for sql in [
+        "INSERT INTO Item VALUES (3, 'rust');",
+        "UPDATE Item SET name = 'rust' WHERE id = 1;",
+        "DELETE FROM Item;",
+        "CREATE TABLE Another (id INTEGER);",
+        "ALTER TABLE Item ADD COLUMN price INTEGER NULL;",
+        "DROP TABLE Item;",
+    ] {
+        g.named_test(
+            "statements modifying the storage are rejected",
+            sql,
+            Err(ExecuteError::ReadOnly.into()),
+        )
+        .await;
+    }
+
+    g.named_test(
+        "queries are still allowed",
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "glue".to_owned();
+            2     "sql".to_owned()
+        )),
+    )
+    .await;
+
+    g.get_glue().set_read_only(false);
+    g.named_test(
+        "writes are allowed again",
+        "DELETE FROM Item WHERE id = 2;",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+});
@@ -0,0 +1,119 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, FetchError},
+        prelude::Value::{self, *},
+    },
+    serde_json::json,
+};
+
+test_case!(returning, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            qty INTEGER DEFAULT 1,
+        );
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "INSERT returns the inserted rows including the default values",
+        "INSERT INTO Item (id, name) VALUES (1, 'apple'), (2, 'banana') RETURNING *;",
+        Ok(select!(
+            id  | name                 | qty
+            I64 | Str                  | I64;
+            1     "apple".to_owned()     1;
+            2     "banana".to_owned()    1
+        )),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Item VALUES (3, 'cherry', 30) RETURNING id, qty * 2 AS double;",
+        Ok(select!(
+            id  | double
+            I64 | I64;
+            3     60
+        )),
+    )
+    .await;
+    g.test(
+        "
+        INSERT INTO Item VALUES (1, 'avocado', 5), (4, 'date', 40)
+        ON CONFLICT (id) DO UPDATE SET name = excluded.name
+        RETURNING id, name;
+        ",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            4     "date".to_owned();
+            1     "avocado".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE returns the rows after the assignments",
+        "UPDATE Item SET qty = qty + 10 WHERE id < 3 RETURNING Item.*;",
+        Ok(select!(
+            id  | name                  | qty
+            I64 | Str                   | I64;
+            1     "avocado".to_owned()    11;
+            2     "banana".to_owned()     11
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DELETE returns the deleted rows",
+        "DELETE FROM Item WHERE qty > 20 RETURNING name;",
+        Ok(select!(
+            name
+            Str;
+            "cherry".to_owned();
+            "date".to_owned()
+        )),
+    )
+    .await;
+    g.test("SELECT id FROM Item", Ok(select!(id I64; 1; 2)))
+        .await;
+
+    g.named_test(
+        "RETURNING is evaluated before the rows are written",
+        "DELETE FROM Item WHERE id > 0 RETURNING noname;",
+        Err(EvaluateError::ValueNotFound("noname".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "UPDATE Item SET qty = 0 RETURNING Other.*;",
+        Err(FetchError::TableAliasNotFound("Other".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "SELECT id, qty FROM Item",
+        Ok(select!(
+            id  | qty
+            I64 | I64;
+            1     11;
+            2     11
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Logs;").await;
+    g.named_test(
+        "schemaless rows are returned as maps",
+        r#"INSERT INTO Logs VALUES ('{"id": 1, "msg": "hello"}') RETURNING *;"#,
+        Ok(select_map![json!({ "id": 1, "msg": "hello" })]),
+    )
+    .await;
+    g.test(
+        "DELETE FROM Logs WHERE id = 1 RETURNING id;",
+        Ok(select!(id I64; 1)),
+    )
+    .await;
+});
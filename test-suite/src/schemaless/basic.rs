@@ -1,6 +1,9 @@
 use {
     crate::*,
-    gluesql_core::prelude::Value::{self, *},
+    gluesql_core::prelude::{
+        Payload,
+        Value::{self, *},
+    },
     serde_json::json,
 };
 
@@ -127,4 +130,27 @@ test_case!(basic, {
         )),
     )
     .await;
+
+    g.named_test(
+        "insert a document from a column list",
+        "INSERT INTO Player (id, name) VALUES (1003, 'Mono'), (1004, UPPER('ra'))",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "insert documents selected from a table",
+        "INSERT INTO Player (id, name, cost) SELECT id, name, obj['cost'] FROM Item",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, flag, cost FROM Player WHERE id NOT IN (1001, 1002)",
+        Ok(select_with_null!(
+            id        | name                       | flag | cost;
+            I64(1003)   Str("Mono".to_owned())       Null   Null;
+            I64(1004)   Str("RA".to_owned())         Null   Null;
+            I64(101)    Str("Test 001".to_owned())   Null   I64(3000)
+        )),
+    )
+    .await;
 });
@@ -62,6 +62,18 @@ test_case!(error, {
         Err(InsertError::OnlySingleValueAcceptedForSchemalessRow.into()),
     )
     .await;
+    g.named_test(
+        "column list and values of a document not matched",
+        "INSERT INTO Item (id, name) VALUES (1)",
+        Err(InsertError::ColumnAndValuesNotMatched.into()),
+    )
+    .await;
+    g.named_test(
+        "column list and selected values of a document not matched",
+        "INSERT INTO Item (id) SELECT id, name FROM Item",
+        Err(InsertError::ColumnAndValuesNotMatched.into()),
+    )
+    .await;
     g.test(
         "INSERT INTO Item VALUES ('[1, 2, 3]');",
         Err(ValueError::JsonObjectTypeRequired.into()),
@@ -129,23 +129,41 @@ test_case!(series, {
             )),
         ),
         (
-            // `SELECT *` fetches column `N` for now
-            "SELECT *",
+            // SELECT without Table evaluates the projection once
+            "SELECT 1 + 2 AS three, UPPER('abc') AS up WHERE 1 = 1",
             Ok(select!(
-                N
-                I64;
-                1
+                three | up
+                I64   | Str;
+                3       "ABC".to_owned()
             )),
         ),
+        (
+            "SELECT 1 AS one WHERE 1 = 0",
+            Ok(Payload::Select {
+                labels: vec!["one".into()],
+                rows: Vec::new(),
+            }),
+        ),
+        ("SELECT COUNT(*) AS cnt", Ok(select!(cnt I64; 1))),
+        (
+            // there is no table to expand the wildcard against
+            "SELECT *",
+            Err(TranslateError::WildcardWithoutFrom.into()),
+        ),
+        (
+            "SELECT Series.*",
+            Err(TranslateError::WildcardWithoutFrom.into()),
+        ),
         (
             // CTAS without Table
             "CREATE TABLE TargetTable AS SELECT 1",
             Ok(Payload::Create),
         ),
         (
+            // column is named after the label of the source query
             "SELECT * FROM TargetTable",
             Ok(select!(
-                N
+                "1"
                 I64;
                 1
             )),
@@ -155,4 +173,119 @@ test_case!(series, {
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    macro_rules! d {
+        ($date: expr) => {
+            Date($date.parse().unwrap())
+        };
+    }
+
+    g.named_test(
+        "SERIES with start and end",
+        "SELECT N FROM SERIES(3, 5)",
+        Ok(select!(N I64; 3; 4; 5)),
+    )
+    .await;
+    g.named_test(
+        "GENERATE_SERIES with step",
+        "SELECT * FROM GENERATE_SERIES(1, 10, 3) AS S",
+        Ok(select!(N I64; 1; 4; 7; 10)),
+    )
+    .await;
+    g.named_test(
+        "descending series with negative step",
+        "SELECT N FROM SERIES(5, 1, -2)",
+        Ok(select!(N I64; 5; 3; 1)),
+    )
+    .await;
+    g.named_test(
+        "start greater than end gives an empty series",
+        "SELECT N FROM SERIES(5, 1)",
+        Ok(Payload::Select {
+            labels: vec!["N".into()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+    g.named_test(
+        "date series steps by a day",
+        "SELECT N FROM SERIES(DATE '2024-01-30', DATE '2024-02-01')",
+        Ok(select_with_null!(
+            N;
+            d!("2024-01-30");
+            d!("2024-01-31");
+            d!("2024-02-01")
+        )),
+    )
+    .await;
+    g.named_test(
+        "date series with interval step",
+        "SELECT N FROM GENERATE_SERIES(DATE '2024-01-15', DATE '2024-04-20', INTERVAL '1' MONTH)",
+        Ok(select_with_null!(
+            N;
+            d!("2024-01-15");
+            d!("2024-02-15");
+            d!("2024-03-15");
+            d!("2024-04-15")
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Sale (day DATE, amount INTEGER);").await;
+    g.run("INSERT INTO Sale VALUES ('2024-01-01', 10), ('2024-01-03', 30);")
+        .await;
+    g.named_test(
+        "gap filling join",
+        "SELECT S.N AS day, Sale.amount
+        FROM SERIES(DATE '2024-01-01', DATE '2024-01-04') AS S
+        LEFT JOIN Sale ON Sale.day = S.N",
+        Ok(select_with_null!(
+            day              | amount;
+            d!("2024-01-01")   I64(10);
+            d!("2024-01-02")   Null;
+            d!("2024-01-03")   I64(30);
+            d!("2024-01-04")   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "NULL gives an empty series",
+        "SELECT N FROM SERIES(1, NULL)",
+        Ok(Payload::Select {
+            labels: vec!["N".into()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+    g.named_test(
+        "step of zero",
+        "SELECT N FROM SERIES(1, 10, 0)",
+        Err(FetchError::SeriesStepZero.into()),
+    )
+    .await;
+    g.named_test(
+        "GENERATE_SERIES without start",
+        "SELECT N FROM GENERATE_SERIES(10)",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "GENERATE_SERIES".to_owned(),
+            expected_minimum: 2,
+            expected_maximum: 3,
+            found: 1,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "too many arguments",
+        "SELECT N FROM SERIES(1, 2, 3, 4)",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "SERIES".to_owned(),
+            expected_minimum: 1,
+            expected_maximum: 3,
+            found: 4,
+        }
+        .into()),
+    )
+    .await;
 });
@@ -0,0 +1,176 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::TranslateError,
+        executor::SelectError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(set_operation, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE TableA (
+            id INTEGER NULL,
+            name TEXT
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        CREATE TABLE TableB (
+            id INTEGER NULL,
+            name TEXT
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO TableA VALUES
+            (1, 'apple'),
+            (2, 'banana'),
+            (2, 'banana'),
+            (3, 'cherry'),
+            (NULL, 'none');
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO TableB VALUES
+            (2, 'banana'),
+            (3, 'cherry'),
+            (3, 'cherry'),
+            (4, 'durian'),
+            (NULL, 'none');
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "SELECT id, name FROM TableA UNION SELECT id, name FROM TableB ORDER BY id",
+            Ok(select_with_null!(
+                id     | name;
+                I64(1)   Str("apple".to_owned());
+                I64(2)   Str("banana".to_owned());
+                I64(3)   Str("cherry".to_owned());
+                I64(4)   Str("durian".to_owned());
+                Null     Str("none".to_owned())
+            )),
+        ),
+        (
+            "SELECT id FROM TableA UNION ALL SELECT id FROM TableB ORDER BY id DESC NULLS LAST",
+            Ok(select_with_null!(
+                id;
+                I64(4);
+                I64(3);
+                I64(3);
+                I64(3);
+                I64(2);
+                I64(2);
+                I64(2);
+                I64(1);
+                Null;
+                Null
+            )),
+        ),
+        (
+            "SELECT id FROM TableA UNION ALL SELECT id FROM TableB ORDER BY id LIMIT 2 OFFSET 3",
+            Ok(select!(id I64; 2; 3)),
+        ),
+        (
+            "SELECT id, name FROM TableA INTERSECT SELECT id, name FROM TableB ORDER BY id",
+            Ok(select_with_null!(
+                id     | name;
+                I64(2)   Str("banana".to_owned());
+                I64(3)   Str("cherry".to_owned());
+                Null     Str("none".to_owned())
+            )),
+        ),
+        (
+            "SELECT name FROM TableA INTERSECT ALL SELECT name FROM TableB ORDER BY name",
+            Ok(select!(
+                name
+                Str;
+                "banana".to_owned();
+                "cherry".to_owned();
+                "none".to_owned()
+            )),
+        ),
+        (
+            "SELECT id FROM TableA EXCEPT SELECT id FROM TableB",
+            Ok(select!(id I64; 1)),
+        ),
+        (
+            "SELECT id FROM TableA EXCEPT ALL SELECT id FROM TableB ORDER BY id",
+            Ok(select!(id I64; 1; 2)),
+        ),
+        (
+            "SELECT id FROM TableA UNION SELECT 10 UNION VALUES (20) EXCEPT SELECT id FROM TableB ORDER BY id NULLS FIRST",
+            Ok(select!(id I64; 1; 10; 20)),
+        ),
+        (
+            "(SELECT name FROM TableA WHERE id = 1) UNION (SELECT name FROM TableB WHERE id = 4)",
+            Ok(select!(
+                name
+                Str;
+                "apple".to_owned();
+                "durian".to_owned()
+            )),
+        ),
+        (
+            "SELECT * FROM (SELECT id FROM TableA EXCEPT SELECT id FROM TableB) AS Sub",
+            Ok(select!(id I64; 1)),
+        ),
+        (
+            "SELECT name FROM TableA WHERE id IN (SELECT id FROM TableB INTERSECT SELECT 3)",
+            Ok(select!(name Str; "cherry".to_owned())),
+        ),
+        (
+            "SELECT id FROM TableA UNION SELECT id, name FROM TableB",
+            Err(SelectError::SetOperationColumnCountMismatch {
+                op: "UNION".to_owned(),
+                left: 1,
+                right: 2,
+            }
+            .into()),
+        ),
+        (
+            "SELECT id FROM TableA EXCEPT VALUES (1, 2)",
+            Err(SelectError::SetOperationColumnCountMismatch {
+                op: "EXCEPT".to_owned(),
+                left: 1,
+                right: 2,
+            }
+            .into()),
+        ),
+        (
+            "(SELECT id FROM TableA LIMIT 1) UNION SELECT id FROM TableB",
+            Err(TranslateError::UnsupportedQuerySetExpr(
+                "(SELECT id FROM TableA LIMIT 1)".to_owned(),
+            )
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.run("CREATE TABLE Merged (id INTEGER NULL);").await;
+    g.test(
+        "INSERT INTO Merged SELECT id FROM TableA UNION SELECT id FROM TableB",
+        Ok(Payload::Insert(5)),
+    )
+    .await;
+    g.test(
+        "SELECT COUNT(*) AS cnt FROM Merged",
+        Ok(select!(cnt I64; 5)),
+    )
+    .await;
+});
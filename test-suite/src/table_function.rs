@@ -0,0 +1,145 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, FetchError},
+        prelude::{DataType, Value::*},
+    },
+    std::sync::{Arc, Mutex},
+};
+
+test_case!(table_function, {
+    let g = get_tester!();
+
+    let users = Arc::new(Mutex::new(vec![
+        (1, "Alice", 34),
+        (2, "Bob", 27),
+        (3, "Carol", 41),
+    ]));
+
+    let glue = g.get_glue();
+    let app_users = Arc::clone(&users);
+    glue.register_table_function(
+        "app_users",
+        vec![],
+        vec!["id".to_owned(), "name".to_owned(), "age".to_owned()],
+        move |_| {
+            Ok(app_users
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(id, name, age)| vec![I64(*id), Str(name.to_string()), I64(*age)])
+                .collect())
+        },
+    );
+    glue.register_table_function(
+        "split_words",
+        vec![DataType::Text],
+        vec!["position".to_owned(), "word".to_owned()],
+        |args| match args.as_slice() {
+            [Str(text)] => Ok(text
+                .split_whitespace()
+                .enumerate()
+                .map(|(i, word)| vec![I64(i as i64 + 1), Str(word.to_owned())])
+                .collect()),
+            _ => Ok(Vec::new()),
+        },
+    );
+    glue.register_table_function("broken", vec![], vec!["a".to_owned()], |_| {
+        Ok(vec![vec![I64(1), I64(2)]])
+    });
+
+    g.run("CREATE TABLE Post (id INTEGER, user_id INTEGER, title TEXT NULL);")
+        .await;
+    g.run("INSERT INTO Post VALUES (1, 1, 'hello world'), (2, 3, 'glue sql'), (3, 3, NULL);")
+        .await;
+
+    g.named_test(
+        "rows of the host application",
+        "SELECT * FROM app_users()",
+        Ok(select!(
+            id  | name               | age
+            I64 | Str                | I64;
+            1     "Alice".to_owned()   34;
+            2     "Bob".to_owned()     27;
+            3     "Carol".to_owned()   41
+        )),
+    )
+    .await;
+    g.named_test(
+        "filter and column aliases",
+        "SELECT U.n FROM APP_USERS() AS U(uid, n) WHERE U.age > 30 AND uid > 1",
+        Ok(select!(n Str; "Carol".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "join with a table",
+        "SELECT Post.id, U.name FROM Post JOIN app_users() AS U ON U.id = Post.user_id",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Alice".to_owned();
+            2     "Carol".to_owned();
+            3     "Carol".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "arguments referring to the preceding tables",
+        "SELECT Post.id, W.position, W.word FROM Post, split_words(Post.title) AS W",
+        Ok(select!(
+            id  | position | word
+            I64 | I64      | Str;
+            1     1          "hello".to_owned();
+            1     2          "world".to_owned();
+            2     1          "glue".to_owned();
+            2     2          "sql".to_owned()
+        )),
+    )
+    .await;
+
+    users.lock().unwrap().push((4, "Dave", 19));
+    g.named_test(
+        "rows are read again for each query",
+        "SELECT COUNT(*) AS cnt FROM app_users()",
+        Ok(select!(cnt I64; 4)),
+    )
+    .await;
+
+    g.named_test(
+        "wrong number of arguments",
+        "SELECT * FROM split_words()",
+        Err(EvaluateError::FunctionArgsLengthNotWithinRange {
+            name: "split_words".to_owned(),
+            expected_minimum: 1,
+            expected_maximum: 1,
+            found: 0,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "row which does not match the columns",
+        "SELECT * FROM broken()",
+        Err(FetchError::TableFunctionRowLengthNotMatching {
+            name: "broken".to_owned(),
+            expected: 1,
+            found: 2,
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "too many column aliases",
+        "SELECT * FROM broken() AS B(x, y)",
+        Err(FetchError::TooManyColumnAliases("broken".to_owned(), 1, 2).into()),
+    )
+    .await;
+
+    assert!(g.get_glue().unregister_table_function("BROKEN"));
+    g.named_test(
+        "unregistered table function",
+        "SELECT * FROM broken()",
+        Err(FetchError::TableFunctionNotFound("broken".to_owned()).into()),
+    )
+    .await;
+});
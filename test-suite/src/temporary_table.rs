@@ -0,0 +1,99 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, AlterTableError, IndexError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(temporary_table, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 'pen'), (2, 'bag');")
+        .await;
+
+    g.test(
+        "CREATE TEMPORARY TABLE Cart (item_id INTEGER, qty INTEGER);",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Cart VALUES (1, 3), (2, 1);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "temporary table joined with a table",
+        "SELECT Item.name, Cart.qty FROM Cart JOIN Item ON Item.id = Cart.item_id",
+        Ok(select!(
+            name             | qty
+            Str              | I64;
+            "pen".to_owned()   3;
+            "bag".to_owned()   1
+        )),
+    )
+    .await;
+    g.test(
+        "UPDATE Cart SET qty = 5 WHERE item_id = 1;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test("DELETE FROM Cart WHERE qty = 1;", Ok(Payload::Delete(1)))
+        .await;
+    g.test(
+        "SELECT * FROM Cart",
+        Ok(select!(
+            item_id | qty
+            I64     | I64;
+            1         5
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "temporary table from a query",
+        "CREATE TEMPORARY TABLE Snapshot AS SELECT name FROM Item WHERE id = 2;",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Snapshot",
+        Ok(select!(name Str; "bag".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "temporary table cannot take the name of a table",
+        "CREATE TEMPORARY TABLE Item (id INTEGER);",
+        Err(AlterError::TableAlreadyExists("Item".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "table cannot take the name of a temporary table",
+        "CREATE TABLE Cart (id INTEGER);",
+        Err(AlterError::TableAlreadyExists("Cart".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "temporary table does not support index",
+        "CREATE INDEX idx_qty ON Cart (qty);",
+        Err(IndexError::TemporaryTableFound("Cart".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "temporary table does not support ALTER TABLE",
+        "ALTER TABLE Cart ADD COLUMN note TEXT NULL;",
+        Err(AlterTableError::TemporaryTableFound("Cart".to_owned()).into()),
+    )
+    .await;
+
+    g.test("DROP TABLE Cart, Snapshot;", Ok(Payload::DropTable))
+        .await;
+    g.test(
+        "SELECT * FROM Item",
+        Ok(select!(id | name I64 | Str; 1 "pen".to_owned(); 2 "bag".to_owned())),
+    )
+    .await;
+});
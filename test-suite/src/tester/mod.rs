@@ -26,7 +26,7 @@ pub async fn run<T: GStore + GStoreMut>(
     println!("[SQL] {}", sql);
     let parsed = parse(sql)?;
     let statement = translate(&parsed[0])?;
-    let statement = plan(&glue.storage, statement).await?;
+    let statement = glue.plan_stmt(statement).await?;
 
     test_indexes(&statement, indexes);
 
@@ -153,7 +153,7 @@ pub trait Tester<T: GStore + GStoreMut> {
         println!("[RUN] {}", sql);
         let parsed = parse(sql)?;
         let statement = translate(&parsed[0])?;
-        let statement = plan(&glue.storage, statement).await?;
+        let statement = glue.plan_stmt(statement).await?;
 
         glue.execute_stmt(&statement).await
     }
@@ -199,7 +199,7 @@ pub trait Tester<T: GStore + GStoreMut> {
 
         let parsed = parse(sql).unwrap();
         let statement = translate(&parsed[0]).unwrap();
-        let statement = plan(&glue.storage, statement).await.unwrap();
+        let statement = glue.plan_stmt(statement).await.unwrap();
 
         test_indexes(&statement, Some(indexes));
 
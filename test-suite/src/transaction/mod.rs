@@ -2,10 +2,12 @@ mod alter_table;
 mod basic;
 mod dictionary;
 mod index;
+mod savepoint;
 mod table;
 
 pub use alter_table::*;
 pub use basic::basic;
 pub use dictionary::dictionary;
 pub use index::*;
+pub use savepoint::savepoint;
 pub use table::*;
@@ -0,0 +1,106 @@
+use {
+    crate::*,
+    gluesql_core::{error::FetchError, prelude::*},
+    Value::*,
+};
+
+test_case!(savepoint, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE TxTest (id INTEGER, name TEXT);").await;
+    g.run("INSERT INTO TxTest VALUES (1, 'Friday'), (2, 'Phone');")
+        .await;
+
+    g.test(
+        "SAVEPOINT sp1;",
+        Err(Error::StorageMsg(
+            "savepoints can only be used in a transaction".to_owned(),
+        )),
+    )
+    .await;
+
+    g.test("BEGIN;", Ok(Payload::StartTransaction)).await;
+    g.run("INSERT INTO TxTest VALUES (3, 'Vienna');").await;
+    g.test("SAVEPOINT sp1;", Ok(Payload::Savepoint)).await;
+    g.run("INSERT INTO TxTest VALUES (4, 'Oslo');").await;
+    g.run("UPDATE TxTest SET name = 'Monday' WHERE id = 1;")
+        .await;
+    g.run("DELETE FROM TxTest WHERE id = 2;").await;
+    g.run("CREATE TABLE Another (id INTEGER);").await;
+    g.test("SAVEPOINT sp2;", Ok(Payload::Savepoint)).await;
+    g.run("INSERT INTO TxTest VALUES (5, 'Rome');").await;
+
+    g.named_test(
+        "rows of the transaction are seen before ROLLBACK TO SAVEPOINT",
+        "SELECT id, name FROM TxTest",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Monday".to_owned();
+            3     "Vienna".to_owned();
+            4     "Oslo".to_owned();
+            5     "Rome".to_owned()
+        )),
+    )
+    .await;
+
+    g.test(
+        "ROLLBACK TO SAVEPOINT sp1;",
+        Ok(Payload::RollbackToSavepoint),
+    )
+    .await;
+    g.named_test(
+        "changes after the savepoint are undone",
+        "SELECT id, name FROM TxTest",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Friday".to_owned();
+            2     "Phone".to_owned();
+            3     "Vienna".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "table created after the savepoint is dropped",
+        "SELECT * FROM Another",
+        Err(FetchError::TableNotFound("Another".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "savepoints created after the savepoint are released",
+        "ROLLBACK TO sp2;",
+        Err(Error::StorageMsg("savepoint not found: sp2".to_owned())),
+    )
+    .await;
+
+    g.run("INSERT INTO TxTest VALUES (6, 'Paris');").await;
+    g.test(
+        "ROLLBACK TO SAVEPOINT sp1;",
+        Ok(Payload::RollbackToSavepoint),
+    )
+    .await;
+    g.run("INSERT INTO TxTest VALUES (7, 'Seoul');").await;
+    g.test("RELEASE SAVEPOINT sp1;", Ok(Payload::ReleaseSavepoint))
+        .await;
+    g.test(
+        "ROLLBACK TRANSACTION TO SAVEPOINT sp1;",
+        Err(Error::StorageMsg("savepoint not found: sp1".to_owned())),
+    )
+    .await;
+
+    g.test("COMMIT;", Ok(Payload::Commit)).await;
+    g.named_test(
+        "work before the savepoint and after rolling back to it is committed",
+        "SELECT id, name FROM TxTest",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "Friday".to_owned();
+            2     "Phone".to_owned();
+            3     "Vienna".to_owned();
+            7     "Seoul".to_owned()
+        )),
+    )
+    .await;
+});
@@ -0,0 +1,89 @@
+use {
+    crate::*,
+    gluesql_core::{
+        executor::ExecuteError,
+        prelude::{Payload, Value::*},
+    },
+    serde_json::json,
+};
+
+test_case!(truncate, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+        );
+    ",
+    )
+    .await;
+    g.run("CREATE TABLE Log (id INTEGER, msg TEXT);").await;
+    g.run("INSERT INTO Item VALUES (1, 'apple'), (2, 'banana');")
+        .await;
+    g.run("INSERT INTO Log VALUES (1, 'hello'), (2, 'world'), (3, 'glue');")
+        .await;
+
+    g.test("TRUNCATE TABLE Item;", Ok(Payload::Truncate)).await;
+    g.test(
+        "SELECT id, name FROM Item",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+    g.named_test(
+        "truncate only removes the rows of the given table",
+        "SELECT id FROM Log",
+        Ok(select!(id I64; 1; 2; 3)),
+    )
+    .await;
+    g.named_test(
+        "truncated table accepts the same primary keys again",
+        "INSERT INTO Item VALUES (1, 'avocado');",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "avocado".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "TABLE keyword is optional",
+        "TRUNCATE Log;",
+        Ok(Payload::Truncate),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM Log",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+
+    g.run("CREATE TABLE Schemaless;").await;
+    g.run(&format!(
+        "INSERT INTO Schemaless VALUES ('{}');",
+        json!({ "id": 1 })
+    ))
+    .await;
+    g.test("TRUNCATE TABLE Schemaless;", Ok(Payload::Truncate))
+        .await;
+    g.test("SELECT * FROM Schemaless", Ok(select_map![])).await;
+
+    g.test(
+        "TRUNCATE TABLE Nothing;",
+        Err(ExecuteError::TableNotFound("Nothing".to_owned()).into()),
+    )
+    .await;
+});
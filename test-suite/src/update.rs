@@ -97,7 +97,38 @@ test_case!(update, {
             "SELECT id, num, num2 FROM TableA",
             Ok(select!(id | num | num2; I64 | I64 | I64; 2 2 1; 4 9 9; 2 4 7; 2 7 4))
         ),
-
+        (
+            // every assignment is evaluated against the row before the update
+            "UPDATE TableA SET num = num * 10, num2 = num + num2 WHERE id = 2",
+            Ok(Payload::Update(3))
+        ),
+        (
+            "SELECT id, num, num2 FROM TableA",
+            Ok(select!(id | num | num2; I64 | I64 | I64; 2 20 3; 4 9 9; 2 40 11; 2 70 11))
+        ),
+        (
+            "UPDATE TableA SET num = num2, num2 = num WHERE num > 30",
+            Ok(Payload::Update(2))
+        ),
+        (
+            "SELECT id, num, num2 FROM TableA",
+            Ok(select!(id | num | num2; I64 | I64 | I64; 2 20 3; 4 9 9; 2 11 40; 2 11 70))
+        ),
+        (
+            "UPDATE TableA SET name = UPPER(name) || CAST(num AS TEXT) WHERE num2 > 10",
+            Ok(Payload::Update(2))
+        ),
+        (
+            "SELECT name FROM TableA",
+            Ok(select!(
+                name
+                Str;
+                "Hello".to_owned();
+                "John".to_owned();
+                "GREAT11".to_owned();
+                "JOB11".to_owned()
+            ))
+        ),
     ];
 
     for (sql, expected) in test_cases {
@@ -0,0 +1,137 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{InsertError, TranslateError, UpdateError, ValidateError},
+        prelude::{Key, Payload, Value::*},
+    },
+};
+
+test_case!(upsert, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            qty INTEGER,
+        );
+    ",
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (1, 'apple', 10), (2, 'banana', 20);")
+        .await;
+
+    g.named_test(
+        "DO NOTHING keeps the existing rows and inserts the new ones",
+        "INSERT INTO Item VALUES (1, 'avocado', 0), (3, 'cherry', 30) ON CONFLICT DO NOTHING;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, qty FROM Item",
+        Ok(select!(
+            id  | name                  | qty
+            I64 | Str                   | I64;
+            1     "apple".to_owned()      10;
+            2     "banana".to_owned()     20;
+            3     "cherry".to_owned()     30
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE reads the proposed row through excluded",
+        "
+        INSERT INTO Item VALUES (1, 'apricot', 5), (4, 'date', 40)
+        ON CONFLICT (id) DO UPDATE SET name = excluded.name, qty = qty + excluded.qty;
+        ",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, qty FROM Item",
+        Ok(select!(
+            id  | name                  | qty
+            I64 | Str                   | I64;
+            1     "apricot".to_owned()    15;
+            2     "banana".to_owned()     20;
+            3     "cherry".to_owned()     30;
+            4     "date".to_owned()       40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE skips the existing rows which do not satisfy WHERE",
+        "
+        INSERT INTO Item VALUES (2, 'blueberry', 1), (3, 'coconut', 1)
+        ON CONFLICT (id) DO UPDATE SET name = excluded.name WHERE qty > 25;
+        ",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name FROM Item WHERE id IN (2, 3)",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "banana".to_owned();
+            3     "coconut".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO NOTHING also skips the duplicates within the statement",
+        "INSERT INTO Item VALUES (5, 'elderberry', 1), (5, 'fig', 2) ON CONFLICT DO NOTHING;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT name FROM Item WHERE id = 5",
+        Ok(select!(name Str; "elderberry".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE cannot affect the same row twice",
+        "
+        INSERT INTO Item VALUES (6, 'grape', 1), (6, 'guava', 2)
+        ON CONFLICT (id) DO UPDATE SET name = excluded.name;
+        ",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(6)).into()),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Item VALUES (1, 'apple', 1) ON CONFLICT (id) DO UPDATE SET id = 7;",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Item VALUES (1, 'apple', 1) ON CONFLICT (name) DO NOTHING;",
+        Err(InsertError::UnsupportedConflictTarget("name".to_owned()).into()),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Item VALUES (1, 'apple', 1) ON CONFLICT ON CONSTRAINT item_pkey DO NOTHING;",
+        Err(TranslateError::UnsupportedOnInsert(
+            "ON CONFLICT ON CONSTRAINT item_pkey DO NOTHING".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE NoKey (id INTEGER);").await;
+    g.test(
+        "INSERT INTO NoKey VALUES (1) ON CONFLICT DO NOTHING;",
+        Err(InsertError::OnConflictWithoutPrimaryKey("NoKey".to_owned()).into()),
+    )
+    .await;
+    g.run("CREATE TABLE Schemaless;").await;
+    g.test(
+        r#"INSERT INTO Schemaless VALUES ('{"id": 1}') ON CONFLICT DO NOTHING;"#,
+        Err(InsertError::OnConflictWithoutPrimaryKey("Schemaless".to_owned()).into()),
+    )
+    .await;
+});
@@ -33,6 +33,11 @@ test_case!(unique, {
         "INSERT INTO TestC VALUES (2, 2), (NULL, 3)",
         "UPDATE TestC SET id = 1 WHERE num = 1",
         "UPDATE TestC SET id = NULL WHERE num = 1",
+        // updating rows to the unique values they already hold
+        "UPDATE TestA SET id = 1 WHERE id = 1",
+        "UPDATE TestB SET id = id, num = num",
+        "UPDATE TestB SET num = id + 10",
+        "UPDATE TestB SET num = num - 10",
     ];
 
     for query in queries {
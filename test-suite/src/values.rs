@@ -167,4 +167,37 @@ test_case!(values, {
     for (sql, expected) in test_cases {
         g.test(sql, expected).await;
     }
+
+    g.run("INSERT INTO Items (id, name) VALUES (2, 'glue'), (3, 'sql');")
+        .await;
+    g.named_test(
+        "join against VALUES as a derived table",
+        "
+        SELECT Items.id, v.label
+        FROM Items
+        JOIN (VALUES (1, 'one'), (3, 'three')) AS v(id, label) ON Items.id = v.id
+        ",
+        Ok(select!(
+            id  | label
+            I64 | Str;
+            1     "one".to_owned();
+            3     "three".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "left join against VALUES as a derived table",
+        "
+        SELECT Items.id, v.label
+        FROM Items
+        LEFT JOIN (VALUES (1, 'one'), (3, 'three')) AS v(id, label) ON Items.id = v.id
+        ",
+        Ok(select_with_null!(
+            id     | label;
+            I64(1)   Str("one".to_owned());
+            I64(2)   Null;
+            I64(3)   Str("three".to_owned())
+        )),
+    )
+    .await;
 });
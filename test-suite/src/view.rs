@@ -0,0 +1,184 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, ExecuteError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(view, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            price INTEGER
+        );
+        ",
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (1, 'pen', 30), (2, 'bag', 500), (3, 'cup', 80);")
+        .await;
+
+    g.test(
+        "CREATE VIEW Cheap AS SELECT id, name FROM Item WHERE price < 100;",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Cheap",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "pen".to_owned();
+            3     "cup".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "view with alias, filter and ordering",
+        "SELECT c.name FROM Cheap AS c WHERE c.id > 1 ORDER BY c.name",
+        Ok(select!(name Str; "cup".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "view joined with a table",
+        "SELECT Cheap.name, Item.price FROM Item JOIN Cheap ON Cheap.id = Item.id",
+        Ok(select!(
+            name              | price
+            Str               | I64;
+            "pen".to_owned()    30;
+            "cup".to_owned()    80
+        )),
+    )
+    .await;
+    g.named_test(
+        "view in a subquery",
+        "SELECT name FROM Item WHERE id NOT IN (SELECT id FROM Cheap)",
+        Ok(select!(name Str; "bag".to_owned())),
+    )
+    .await;
+
+    g.run("CREATE VIEW CheapName AS SELECT name FROM Cheap;")
+        .await;
+    g.run("INSERT INTO Item VALUES (4, 'map', 10);").await;
+    g.named_test(
+        "view on a view reads the current rows",
+        "SELECT * FROM CheapName",
+        Ok(select!(
+            name
+            Str;
+            "pen".to_owned();
+            "cup".to_owned();
+            "map".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "CTE shadows a view of the same name",
+        "WITH Cheap AS (SELECT 1 AS n) SELECT * FROM Cheap",
+        Ok(select!(n I64; 1)),
+    )
+    .await;
+
+    g.named_test(
+        "view cannot be modified",
+        "INSERT INTO Cheap VALUES (5, 'ink');",
+        Err(ExecuteError::TableIsView("Cheap".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "view cannot be dropped by DROP TABLE",
+        "DROP TABLE Cheap;",
+        Err(ExecuteError::TableIsView("Cheap".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "view name is taken",
+        "CREATE VIEW Cheap AS SELECT id FROM Item;",
+        Err(AlterError::TableAlreadyExists("Cheap".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "table name is taken",
+        "CREATE OR REPLACE VIEW Item AS SELECT 1;",
+        Err(AlterError::TableAlreadyExists("Item".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "view cannot refer to itself",
+        "CREATE OR REPLACE VIEW Cheap AS SELECT name AS id, name FROM CheapName;",
+        Err(AlterError::CyclicViewReference("Cheap".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "column list of view",
+        "CREATE VIEW Named (a, b) AS SELECT id, name FROM Item;",
+        Err(TranslateError::ViewColumnListNotSupported.into()),
+    )
+    .await;
+
+    g.test(
+        "CREATE OR REPLACE VIEW Cheap AS SELECT id, name FROM Item WHERE price < 50;",
+        Ok(Payload::Create),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM CheapName",
+        Ok(select!(
+            name
+            Str;
+            "pen".to_owned();
+            "map".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "table used by a view cannot be dropped",
+        "DROP TABLE Item;",
+        Err(AlterError::TableReferencedByView {
+            table_name: "Item".to_owned(),
+            view_name: "Cheap".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "table used by a view cannot be renamed",
+        "ALTER TABLE Item RENAME TO Goods;",
+        Err(AlterError::TableReferencedByView {
+            table_name: "Item".to_owned(),
+            view_name: "Cheap".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "view used by a view cannot be dropped",
+        "DROP VIEW Cheap;",
+        Err(AlterError::TableReferencedByView {
+            table_name: "Cheap".to_owned(),
+            view_name: "CheapName".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "DROP VIEW on a table",
+        "DROP VIEW Item;",
+        Err(AlterError::ViewNotFound("Item".to_owned()).into()),
+    )
+    .await;
+    g.test("DROP VIEW IF EXISTS Nothing;", Ok(Payload::DropView))
+        .await;
+    g.named_test(
+        "views can be dropped together",
+        "DROP VIEW Cheap, CheapName;",
+        Ok(Payload::DropView),
+    )
+    .await;
+    g.test("DROP TABLE Item;", Ok(Payload::DropTable)).await;
+});
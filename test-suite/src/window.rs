@@ -0,0 +1,178 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{TranslateError, WindowError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(window, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Score (
+            id INTEGER,
+            team TEXT,
+            name TEXT,
+            score INTEGER
+        );
+        ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Score VALUES
+            (1, 'a', 'Alice', 90),
+            (2, 'a', 'Bob', 80),
+            (3, 'a', 'Carol', 90),
+            (4, 'b', 'Dave', 70),
+            (5, 'b', 'Eve', 60),
+            (6, 'b', 'Frank', 75);
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "ROW_NUMBER with PARTITION BY and ORDER BY",
+        "SELECT id, ROW_NUMBER() OVER (PARTITION BY team ORDER BY score DESC, id) AS rn
+        FROM Score ORDER BY id",
+        Ok(select!(
+            id  | rn
+            I64 | I64;
+            1     1;
+            2     3;
+            3     2;
+            4     2;
+            5     3;
+            6     1
+        )),
+    )
+    .await;
+    g.named_test(
+        "RANK and DENSE_RANK give peers the same rank",
+        "SELECT
+            id,
+            RANK() OVER (ORDER BY score DESC) AS r,
+            DENSE_RANK() OVER (ORDER BY score DESC) AS dr
+        FROM Score ORDER BY id",
+        Ok(select!(
+            id  | r   | dr
+            I64 | I64 | I64;
+            1     1     1;
+            2     3     2;
+            3     1     1;
+            4     5     4;
+            5     6     5;
+            6     4     3
+        )),
+    )
+    .await;
+    g.named_test(
+        "LAG and LEAD stay inside of the partition",
+        "SELECT
+            id,
+            LAG(score) OVER (PARTITION BY team ORDER BY id) AS prev,
+            LEAD(score, 1, 0) OVER (PARTITION BY team ORDER BY id) AS next
+        FROM Score ORDER BY id",
+        Ok(select_with_null!(
+            id     | prev    | next;
+            I64(1)   Null      I64(80);
+            I64(2)   I64(90)   I64(90);
+            I64(3)   I64(80)   I64(0);
+            I64(4)   Null      I64(60);
+            I64(5)   I64(70)   I64(75);
+            I64(6)   I64(60)   I64(0)
+        )),
+    )
+    .await;
+    g.named_test(
+        "aggregates over a partition and running aggregates",
+        "SELECT
+            id,
+            SUM(score) OVER (PARTITION BY team) AS total,
+            SUM(score) OVER (ORDER BY id) AS running,
+            MAX(score) OVER (PARTITION BY team ORDER BY id) AS running_max
+        FROM Score ORDER BY id",
+        Ok(select!(
+            id  | total | running | running_max
+            I64 | I64   | I64     | I64;
+            1     260     90        90;
+            2     260     170       90;
+            3     260     260       90;
+            4     205     330       70;
+            5     205     390       70;
+            6     205     465       75
+        )),
+    )
+    .await;
+    g.named_test(
+        "running aggregates include peers",
+        "SELECT id, COUNT(*) OVER (ORDER BY score DESC) AS cnt FROM Score WHERE team = 'a' ORDER BY id",
+        Ok(select!(
+            id  | cnt
+            I64 | I64;
+            1     2;
+            2     3;
+            3     2
+        )),
+    )
+    .await;
+    g.named_test(
+        "window functions over grouped rows",
+        "SELECT team, SUM(score) AS total, RANK() OVER (ORDER BY SUM(score)) AS r
+        FROM Score GROUP BY team ORDER BY team",
+        Ok(select!(
+            team              | total | r
+            Str               | I64   | I64;
+            "a".to_owned()      260     2;
+            "b".to_owned()      205     1
+        )),
+    )
+    .await;
+    g.named_test(
+        "window function in ORDER BY",
+        "SELECT name FROM Score ORDER BY ROW_NUMBER() OVER (ORDER BY score DESC, id DESC) LIMIT 2",
+        Ok(select!(
+            name
+            Str;
+            "Carol".to_owned();
+            "Alice".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ranking function without OVER",
+        "SELECT ROW_NUMBER() FROM Score",
+        Err(TranslateError::WindowFunctionRequiresOver("ROW_NUMBER".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "OVER on a scalar function",
+        "SELECT LOWER(name) OVER () FROM Score",
+        Err(TranslateError::UnsupportedWindowFunction("LOWER".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "DISTINCT in a window function",
+        "SELECT COUNT(DISTINCT team) OVER () FROM Score",
+        Err(TranslateError::UnsupportedWindowFunction("COUNT".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "window frame",
+        "SELECT SUM(score) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM Score",
+        Err(TranslateError::UnsupportedWindowFrame(
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "negative offset",
+        "SELECT LAG(score, -1) OVER (ORDER BY id) FROM Score",
+        Err(WindowError::InvalidOffset("LAG".to_owned()).into()),
+    )
+    .await;
+});